@@ -60,7 +60,7 @@ async fn import(opt: &ImportOptions, assets: &AssetCache) -> anyhow::Result<()>
         anyhow::bail!("Unknown file type");
     };
 
-    if ext == "wav" || ext == "mp3" || ext == "ogg" {
+    if ext == "wav" || ext == "mp3" || ext == "ogg" || ext == "opus" || ext == "flac" {
         let convert = opt.convert_audio;
         ambient_build::pipelines::import_audio(opt.path.clone(), convert)
             .context("Failed to import audio")?;
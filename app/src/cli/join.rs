@@ -5,7 +5,10 @@ use ambient_native_std::{
 use ambient_network::native::client::ResolvedAddr;
 use clap::Parser;
 
-use crate::{client, server::QUIC_INTERFACE_PORT};
+use crate::{
+    client::{self, ClientSource},
+    server::QUIC_INTERFACE_PORT,
+};
 
 use super::ClientCli;
 
@@ -40,5 +43,11 @@ pub fn handle(args: &Join, rt: &tokio::runtime::Runtime, assets: AssetCache) ->
         }
         ResolvedAddr::lookup_host(&host).await
     })?;
-    client::run(rt, assets, server_addr, &args.client, None)
+    client::run(
+        rt,
+        assets,
+        ClientSource::Server(server_addr),
+        &args.client,
+        None,
+    )
 }
@@ -6,6 +6,7 @@ pub mod assets;
 pub mod join;
 pub mod login;
 pub mod package;
+pub mod replay;
 
 mod package_path;
 pub use package_path::*;
@@ -16,6 +17,7 @@ use self::{
     package::{
         build::Build, deploy::Deploy, new::New, run::Run, serve::Serve, Package, PackageArgs,
     },
+    replay::Replay,
 };
 
 #[derive(Parser, Clone)]
@@ -34,6 +36,7 @@ pub enum Commands {
     Deploy(Deploy),
     Serve(Serve),
     Join(Join),
+    Replay(Replay),
     Package {
         #[command(subcommand)]
         package: Package,
@@ -112,6 +115,10 @@ pub struct ClientCli {
     /// Window height override
     #[arg(long)]
     pub window_height: Option<u32>,
+
+    /// Record the session to this file, to be played back with `ambient replay`
+    #[arg(long)]
+    pub record: Option<PathBuf>,
 }
 
 impl Cli {
@@ -125,6 +132,7 @@ impl Cli {
             Commands::Deploy(Deploy { package, .. }) => Some(package),
             Commands::Serve(Serve { package, .. }) => Some(package),
             Commands::Join(Join { .. }) => None,
+            Commands::Replay(Replay { .. }) => None,
             Commands::Assets { .. } => None,
             Commands::Login => None,
         }
@@ -139,7 +147,12 @@ impl Cli {
             C::Run(Run { package, .. }) | C::Build(Build { package, .. }) => {
                 package.is_release().unwrap_or(false)
             }
-            C::New(_) | C::Join(_) | C::Assets { .. } | C::Package { .. } | C::Login => false,
+            C::New(_)
+            | C::Join(_)
+            | C::Replay(_)
+            | C::Assets { .. }
+            | C::Package { .. }
+            | C::Login => false,
         }
     }
 }
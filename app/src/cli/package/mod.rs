@@ -131,6 +131,27 @@ pub struct HostCli {
     /// Shutdown the server after the specified number of seconds of inactivity
     #[arg(long)]
     pub shutdown_after_inactivity_seconds: Option<u64>,
+
+    /// Save the world to this file, and restore it from this file on startup
+    ///
+    /// Only components with the `Store` attribute are saved
+    #[arg(long)]
+    pub world_save: Option<PathBuf>,
+    /// How often to save the world, in seconds; set to 0 to only save when the server shuts down
+    #[arg(long, requires("world_save"), default_value_t = 60)]
+    pub world_save_interval_seconds: u64,
+
+    /// Limit how much fuel each server module can use while handling a single message; fuel is
+    /// roughly the number of WebAssembly instructions executed
+    ///
+    /// Modules that exceed this are unloaded. Defaults to no limit
+    #[arg(long)]
+    pub module_fuel_per_call: Option<u64>,
+    /// Limit the memory of each server module to this many megabytes
+    ///
+    /// Modules that exceed this are unloaded. Defaults to no limit
+    #[arg(long)]
+    pub module_memory_limit_mb: Option<u64>,
 }
 
 pub fn handle(
@@ -1,7 +1,10 @@
 use ambient_native_std::asset_cache::AssetCache;
 use clap::Parser;
 
-use crate::{cli::ClientCli, client};
+use crate::{
+    cli::ClientCli,
+    client::{self, ClientSource},
+};
 
 use super::{serve, HostCli, PackageArgs};
 
@@ -33,7 +36,7 @@ pub fn handle(
     client::run(
         rt,
         assets,
-        server_handle.resolve_as_localhost(),
+        ClientSource::Server(server_handle.resolve_as_localhost()),
         &args.run,
         package_path.fs_path,
     )
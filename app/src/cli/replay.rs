@@ -0,0 +1,48 @@
+use std::{path::PathBuf, sync::Arc};
+
+use ambient_native_std::{asset_cache::AssetCache, asset_url::AbsAssetUrl};
+use ambient_network::recording::Recording;
+use clap::Parser;
+
+use crate::client::{self, ClientSource};
+
+use super::ClientCli;
+
+#[derive(Parser, Clone, Debug)]
+/// Play back a session recorded with `--record`
+pub struct Replay {
+    #[command(flatten)]
+    pub client: ClientCli,
+    /// The recording to play back
+    pub file: PathBuf,
+    /// Load the package's assets from this URL instead of the content URL the recorded server
+    /// provided, which is only reachable while that server is running
+    #[arg(long)]
+    pub content_base_url: Option<AbsAssetUrl>,
+}
+
+pub fn handle(
+    args: &Replay,
+    rt: &tokio::runtime::Runtime,
+    assets: AssetCache,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        args.client.record.is_none(),
+        "--record can not be used when replaying a recording"
+    );
+
+    let mut recording = Recording::load(&args.file)?;
+    if let Some(url) = &args.content_base_url {
+        recording.set_content_base_url(url.clone());
+    }
+    if let Some(url) = recording.content_base_url() {
+        tracing::info!("Loading the package's assets from {url}");
+    }
+    client::run(
+        rt,
+        assets,
+        ClientSource::Replay(Arc::new(recording)),
+        &args.client,
+        None,
+    )
+}
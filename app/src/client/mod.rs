@@ -1,7 +1,7 @@
 use std::{collections::HashMap, path::PathBuf, sync::Arc, time::Duration};
 
 use ambient_app::{fps_stats, window_title, AppBuilder};
-use ambient_audio::{AudioMixer, AudioStream};
+use ambient_audio::{bus::MASTER_BUS, AudioMixer, AudioStream};
 use ambient_cameras::UICamera;
 use ambient_client_shared::game_view::GameView;
 use ambient_core::{
@@ -14,14 +14,22 @@ use ambient_element::{
     consume_context, element_component, use_effect, use_ref_with, use_spawn, use_state,
     use_state_with, Element, ElementComponentExt, Group, Hooks,
 };
+use ambient_input::{
+    actions::{input_binding_overrides, InputBindingOverrides},
+    gamepad::{gamepad_deadzones, GamepadDeadzones},
+};
 use ambient_native_std::{
     asset_cache::{AssetCache, SyncAssetKeyExt},
-    cb,
+    cb, Cb,
 };
 use ambient_network::{
-    client::{client_network_stats, GameClientRenderTarget},
+    client::{client_network_stats, GameClientRenderTarget, LoadedFunc},
     hooks::use_remote_resource,
-    native::client::{ClientView, ResolvedAddr},
+    native::{
+        client::{ClientView, ResolvedAddr},
+        replay::ReplayView,
+    },
+    recording::Recording,
 };
 use ambient_settings::SettingsKey;
 use ambient_sys::time::Instant;
@@ -35,11 +43,20 @@ use crate::{
 
 mod wasm;
 
+/// Where the client gets its world from
+#[derive(Debug, Clone)]
+pub enum ClientSource {
+    /// A server to connect to
+    Server(ResolvedAddr),
+    /// A recorded session to play back
+    Replay(Arc<Recording>),
+}
+
 /// Construct an app and enter the main client view
 pub fn run(
     rt: &tokio::runtime::Runtime,
     assets: AssetCache,
-    server_addr: ResolvedAddr,
+    source: ClientSource,
     args: &ClientCli,
     golden_image_output_dir: Option<PathBuf>,
 ) -> anyhow::Result<()> {
@@ -57,6 +74,13 @@ pub fn run(
     let mixer = audio_stream.as_ref().map(|v| v.mixer().clone());
     let settings = SettingsKey.get(&assets);
 
+    if let Some(mixer) = &mixer {
+        mixer.set_bus_user_volume(MASTER_BUS, settings.audio.master_volume);
+        for (bus, volume) in &settings.audio.bus_volumes {
+            mixer.set_bus_user_volume(bus, *volume);
+        }
+    }
+
     let user_id = match args.user_id.clone().or(settings.general.user_id) {
         Some(user_id) => user_id,
         None => {
@@ -116,6 +140,12 @@ pub fn run(
     let mut app = rt.block_on(builder.build()).expect("Failed to create app");
 
     *app.world.resource_mut(window_title()) = "Ambient".to_string();
+    *app.world.resource_mut(gamepad_deadzones()) = GamepadDeadzones {
+        stick: settings.input.gamepad_stick_deadzone,
+        trigger: settings.input.gamepad_trigger_deadzone,
+    };
+    *app.world.resource_mut(input_binding_overrides()) =
+        InputBindingOverrides::parse(&settings.input.bindings);
 
     #[cfg(feature = "production")]
     let fail_on_version_mismatch = true;
@@ -124,13 +154,14 @@ pub fn run(
     let fail_on_version_mismatch = !args.dev_allow_version_mismatch;
 
     MainApp {
-        server_addr,
+        source,
         user_id,
         fail_on_version_mismatch,
         show_debug: is_debug,
         golden_image_cmd: args.golden_image,
         golden_image_output_dir,
         cert,
+        record: args.record.clone(),
         mixer,
     }
     .el()
@@ -179,76 +210,94 @@ fn TitleUpdater(hooks: &mut Hooks) -> Element {
 #[element_component]
 fn MainApp(
     hooks: &mut Hooks,
-    server_addr: ResolvedAddr,
+    source: ClientSource,
     golden_image_output_dir: Option<PathBuf>,
     user_id: String,
     fail_on_version_mismatch: bool,
     show_debug: bool,
     golden_image_cmd: Option<GoldenImageCommand>,
     cert: Option<Vec<u8>>,
+    record: Option<PathBuf>,
     mixer: Option<AudioMixer>,
 ) -> Element {
     let (loaded, set_loaded) = use_state(hooks, false);
 
-    Group::el([
-        UICamera.el(),
-        ambient_client_shared::player::PlayerRawInputHandler.el(),
-        WindowSized::el([ClientView {
+    // NOTE: client.game_state is **locked** and accesible through game_state.
+    //
+    // This is to prevent another thread from updating using the client after connection but
+    // just before `on_loaded`. This is a very small window of time, but does occasionally
+    // happen, especially when joining a server which is already running and finished
+    // loading.
+    let on_loaded: LoadedFunc = cb(move |_, game_state| {
+        let world = &mut game_state.world;
+        let assets = world.resource(asset_cache()).clone();
+
+        wasm::initialize(world, &assets, mixer.clone()).unwrap();
+
+        UICamera.el().spawn_static(world);
+        set_loaded(true);
+
+        Ok(Box::new(|| {
+            tracing::info!("Disconnecting client");
+        }))
+    });
+    let systems_and_resources: Cb<dyn Fn() -> (SystemGroup, Entity) + Sync + Send> = cb(|| {
+        let mut resources = Entity::new();
+
+        let bistream_handlers = HashMap::new();
+        resources.set(
+            ambient_network::client::bi_stream_handlers(),
+            bistream_handlers,
+        );
+
+        let unistream_handlers = HashMap::new();
+        resources.set(
+            ambient_network::client::uni_stream_handlers(),
+            unistream_handlers,
+        );
+
+        let dgram_handlers = HashMap::new();
+        resources.set(ambient_network::client::datagram_handlers(), dgram_handlers);
+
+        (systems(), resources)
+    });
+    let inner = Dock::el(vec![
+        TitleUpdater.el(),
+        if let Some(golden_image_cmd) = golden_image_cmd.filter(|_| loaded) {
+            GoldenImageTest::el(golden_image_output_dir, golden_image_cmd)
+        } else {
+            Element::new()
+        },
+        GameView { show_debug }.el(),
+    ]);
+
+    let view = match source {
+        ClientSource::Server(server_addr) => ClientView {
             server_addr,
             user_id,
             fail_on_version_mismatch,
-            // NOTE: client.game_state is **locked** and accesible through game_state.
-            //
-            // This is to prevent another thread from updating using the client after connection but
-            // just before `on_loaded`. This is a very small window of time, but does occasionally
-            // happen, especially when joining a server which is already running and finished
-            // loading.
-            on_loaded: cb(move |_, game_state| {
-                let world = &mut game_state.world;
-                let assets = world.resource(asset_cache()).clone();
-
-                wasm::initialize(world, &assets, mixer.clone()).unwrap();
-
-                UICamera.el().spawn_static(world);
-                set_loaded(true);
-
-                Ok(Box::new(|| {
-                    tracing::info!("Disconnecting client");
-                }))
-            }),
-            systems_and_resources: cb(|| {
-                let mut resources = Entity::new();
-
-                let bistream_handlers = HashMap::new();
-                resources.set(
-                    ambient_network::client::bi_stream_handlers(),
-                    bistream_handlers,
-                );
-
-                let unistream_handlers = HashMap::new();
-                resources.set(
-                    ambient_network::client::uni_stream_handlers(),
-                    unistream_handlers,
-                );
-
-                let dgram_handlers = HashMap::new();
-                resources.set(ambient_network::client::datagram_handlers(), dgram_handlers);
-
-                (systems(), resources)
-            }),
+            record,
+            on_loaded,
+            systems_and_resources,
             cert,
             create_rpc_registry: cb(shared::create_server_rpc_registry),
-            inner: Dock::el(vec![
-                TitleUpdater.el(),
-                if let Some(golden_image_cmd) = golden_image_cmd.filter(|_| loaded) {
-                    GoldenImageTest::el(golden_image_output_dir, golden_image_cmd)
-                } else {
-                    Element::new()
-                },
-                GameView { show_debug }.el(),
-            ]),
+            inner,
         }
-        .el()]),
+        .el(),
+        ClientSource::Replay(recording) => ReplayView {
+            recording,
+            systems_and_resources,
+            on_loaded,
+            create_rpc_registry: cb(shared::create_server_rpc_registry),
+            inner,
+        }
+        .el(),
+    };
+
+    Group::el([
+        UICamera.el(),
+        ambient_client_shared::player::PlayerRawInputHandler.el(),
+        WindowSized::el([view]),
     ])
 }
 
@@ -409,6 +458,7 @@ fn systems() -> SystemGroup {
             Box::new(ambient_sky::systems()),
             Box::new(ambient_water::systems()),
             Box::new(ambient_gizmos::client_systems()),
+            Box::new(ambient_client_shared::player::systems()),
             Box::new(ambient_timings::wrap_system(
                 wasm::systems(),
                 TimingEventType::ScriptingStarted,
@@ -19,14 +19,16 @@ use ambient_native_std::{
     cb,
 };
 use ambient_network::{
-    is_persistent_resources, is_synced_resources,
+    interpolation, is_persistent_resources, is_synced_resources,
     native::{
         client::ResolvedAddr,
         server::{Crypto, GameServer},
     },
+    persistence::{self, WorldSaveSettings},
     server::{ForkingEvent, ProxySettings, SharedServerState, ShutdownEvent},
 };
 use ambient_sys::task::RuntimeHandle;
+use ambient_wasm::shared::ModuleLimits;
 use anyhow::Context;
 use axum::{
     extract::{Host, State},
@@ -162,6 +164,14 @@ pub async fn start(
         );
     }
 
+    let world_save = host_cli.world_save.clone();
+    let world_save_interval = Duration::from_secs(host_cli.world_save_interval_seconds);
+    let module_limits = ModuleLimits {
+        fuel_per_call: host_cli.module_fuel_per_call,
+        max_memory: host_cli
+            .module_memory_limit_mb
+            .map(|mb| mb as usize * 1024 * 1024),
+    };
     let join_handle = tokio::task::spawn(async move {
         let mut server_world = World::new_with_config("server", WorldContext::Server, true);
         server_world.init_shape_change_tracking();
@@ -191,15 +201,20 @@ pub async fn start(
                 Default::default(),
             )
             .spawn(&mut server_world);
-        // Note: this should not be reset every time the server is created. Remove this when it becomes possible to load/save worlds.
+        // The `Store` components of this are restored below if the world is persisted
         Entity::new()
             .with(ambient_core::name(), "Persistent resources".to_string())
             .with(is_persistent_resources(), ())
             .spawn(&mut server_world);
 
-        wasm::initialize(&mut server_world, &assets, working_directory.join("data"))
-            .await
-            .unwrap();
+        wasm::initialize(
+            &mut server_world,
+            &assets,
+            working_directory.join("data"),
+            module_limits,
+        )
+        .await
+        .unwrap();
 
         ambient_package_semantic_native::initialize(
             &mut server_world,
@@ -209,6 +224,23 @@ pub async fn start(
         .await
         .unwrap();
 
+        // Restore after the packages have been initialized, so that their components are known,
+        // but before their modules have been loaded
+        if let Some(path) = &world_save {
+            server_world.add_resource(
+                persistence::world_save_settings(),
+                WorldSaveSettings {
+                    path: path.clone(),
+                    interval: (world_save_interval > Duration::ZERO).then_some(world_save_interval),
+                    package_version: manifest.package.version.to_string(),
+                },
+            );
+            // Reading and parsing a large save takes a while, so keep it off the async workers
+            ambient_sys::task::block_in_place(|| persistence::restore_world(&mut server_world))
+                .context("Failed to restore the saved world")
+                .unwrap();
+        }
+
         tracing::debug!("Starting server");
         server
             .run(
@@ -246,6 +278,8 @@ fn systems(_world: &mut World) -> SystemGroup {
             Box::new(ambient_physics::server_systems()),
             Box::new(ambient_package_semantic_native::server_systems()),
             Box::new(wasm::systems()),
+            Box::new(persistence::systems()),
+            Box::new(interpolation::server_systems()),
         ],
     )
 }
@@ -262,6 +296,8 @@ fn on_shutdown_systems() -> SystemGroup<ShutdownEvent> {
     SystemGroup::new(
         "on_shutdown_systems",
         vec![
+            // Save before anything is torn down
+            Box::new(persistence::on_shutdown_systems()),
             Box::new(ambient_physics::on_shutdown_systems()),
             Box::new(wasm::on_shutdown_systems()),
         ],
@@ -1,12 +1,12 @@
 use std::{fmt::Display, path::PathBuf, sync::Arc};
 
-use ambient_ecs::{Entity, EntityId, SystemGroup, World};
+use ambient_ecs::{dont_store, Entity, EntityId, SystemGroup, World};
 use ambient_native_std::asset_cache::AssetCache;
 use ambient_package_semantic_native::{WasmSpawnRequest, WasmSpawnResponse};
 pub use ambient_wasm::server::{on_forking_systems, on_shutdown_systems};
 use ambient_wasm::shared::{
     bytecode_from_url, is_module, is_module_on_server, module_enabled, module_name, package_ref,
-    MessageType,
+    MessageType, ModuleLimits,
 };
 
 pub fn systems() -> SystemGroup {
@@ -17,6 +17,7 @@ pub async fn initialize(
     world: &mut World,
     assets: &AssetCache,
     data_path: PathBuf,
+    limits: ModuleLimits,
 ) -> anyhow::Result<()> {
     let messenger = Arc::new(
         |world: &World, id: EntityId, ty: MessageType, message: &str| {
@@ -32,7 +33,7 @@ pub async fn initialize(
     );
 
     let hosted = std::env::var("AMBIENT_HOSTED").is_ok();
-    ambient_wasm::server::initialize(world, assets, hosted, data_path, messenger)?;
+    ambient_wasm::server::initialize(world, assets, hosted, data_path, limits, messenger)?;
 
     Ok(())
 }
@@ -75,8 +76,10 @@ pub fn spawn_package(
         (Side::Server, server_request),
     ] {
         for (url, enabled) in modules {
+            // Modules are respawned from their packages on startup, so they are not saved
             let entity = Entity::new()
                 .with(self::is_module(), ())
+                .with(dont_store(), ())
                 .with(self::bytecode_from_url(), url.to_string())
                 .with(self::module_enabled(), enabled)
                 .with(self::package_ref(), package_id);
@@ -844,6 +844,25 @@ impl App {
                                 });
                             }
                         }
+                        WindowCtl::RumbleGamepad {
+                            gamepad,
+                            strong,
+                            weak,
+                            duration,
+                        } => {
+                            world
+                                .resource_mut(ambient_input::gamepad::pending_gamepad_rumbles())
+                                .push(ambient_input::gamepad::GamepadRumble {
+                                    gamepad,
+                                    strong,
+                                    weak,
+                                    duration,
+                                });
+                        }
+                        WindowCtl::SetTouchMouseEmulation(enabled) => {
+                            *world.resource_mut(ambient_input::touch::touch_mouse_emulation()) =
+                                enabled;
+                        }
                         WindowCtl::ExitProcess(exit_status) => {
                             *control_flow = ControlFlow::Exit;
                             return exit_status;
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+#[cfg(not(target_os = "unknown"))]
+use ambient_native_std::download_asset::BytesFromUrlCachedPath;
 use ambient_native_std::{
     asset_cache::{AsyncAssetKey, AsyncAssetKeyExt},
     asset_url::{AbsAssetUrl, AssetType, GetAssetType},
@@ -7,11 +9,20 @@ use ambient_native_std::{
 };
 use async_trait::*;
 
+#[cfg(not(target_os = "unknown"))]
+use crate::streamed_track::StreamedTrack;
 use crate::{
     track::{AudioFormat, Track},
     vorbis::VorbisTrack,
     Error,
 };
+
+fn url_format(url: &AbsAssetUrl) -> Result<AudioFormat, Arc<Error>> {
+    let extension = url.extension().unwrap_or_default();
+    AudioFormat::from_extension(&extension)
+        .ok_or_else(|| Arc::new(Error::UnsupportedFormat(extension)))
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct AudioFromUrl {
     pub url: AbsAssetUrl,
@@ -26,15 +37,7 @@ impl AsyncAssetKey<Result<Arc<Track>, Arc<Error>>> for AudioFromUrl {
     where
         Self: 'async_trait,
     {
-        let format = match self.url.extension().as_ref().map(|x| x as &str) {
-            Some("wav") => AudioFormat::Wav,
-            Some("ogg") => AudioFormat::Vorbis,
-            v => {
-                return Err(Arc::new(Error::UnsupportedFormat(
-                    v.unwrap_or_default().to_string(),
-                )))
-            }
-        };
+        let format = url_format(&self.url)?;
         let bytes: Arc<[u8]> = BytesFromUrl::new(self.url.clone(), true)
             .get(&assets)
             .await
@@ -45,6 +48,35 @@ impl AsyncAssetKey<Result<Arc<Track>, Arc<Error>>> for AudioFromUrl {
     }
 }
 
+/// Downloads the audio to the asset cache, and streams it from there while it plays.
+#[cfg(not(target_os = "unknown"))]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct AudioStreamFromUrl {
+    pub url: AbsAssetUrl,
+}
+
+#[cfg(not(target_os = "unknown"))]
+#[async_trait]
+impl AsyncAssetKey<Result<Arc<StreamedTrack>, Arc<Error>>> for AudioStreamFromUrl {
+    async fn load(
+        self,
+        assets: ambient_native_std::asset_cache::AssetCache,
+    ) -> Result<Arc<StreamedTrack>, Arc<Error>>
+    where
+        Self: 'async_trait,
+    {
+        let format = url_format(&self.url)?;
+        let path = BytesFromUrlCachedPath {
+            url: self.url.clone(),
+        }
+        .get(&assets)
+        .await
+        .map_err(|e| Arc::new(e.into()))?;
+
+        Ok(Arc::new(StreamedTrack::new((*path).clone(), format)?))
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct VorbisFromUrl {
     pub url: AbsAssetUrl,
@@ -0,0 +1,350 @@
+//! Buses group the sounds that are played on a mixer, so that they can be controlled together.
+//!
+//! Every sound is played on a bus, which has its own volume, mute and effects, and can be ducked
+//! by another bus. The buses are mixed into the master bus, which is the output of the mixer.
+use std::{collections::HashMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Frame, SampleRate};
+
+/// The bus that all other buses are mixed into.
+pub const MASTER_BUS: &str = "master";
+pub const MUSIC_BUS: &str = "music";
+pub const SFX_BUS: &str = "sfx";
+pub const VOICE_BUS: &str = "voice";
+pub const UI_BUS: &str = "ui";
+
+/// The buses that every mixer starts with, apart from the master bus.
+pub const STANDARD_BUSES: [&str; 4] = [MUSIC_BUS, SFX_BUS, VOICE_BUS, UI_BUS];
+
+/// The bus that sounds are played on if they do not specify one.
+pub const DEFAULT_BUS: &str = SFX_BUS;
+
+/// An effect that is applied to everything played on a bus, before its volume.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BusEffect {
+    /// A one pole low pass filter with the given cutoff frequency, in Hz
+    LowPass(f32),
+    /// A one pole high pass filter with the given cutoff frequency, in Hz
+    HighPass(f32),
+    /// Multiplies the signal by the given gain
+    Gain(f32),
+}
+
+impl BusEffect {
+    fn process(&self, buffer: &mut [Frame], y1: &mut Frame, sample_rate: SampleRate) {
+        match *self {
+            BusEffect::LowPass(cutoff) => {
+                let (a, b) = one_pole_coefficients(cutoff, sample_rate);
+                for frame in buffer {
+                    *y1 = *frame * a + *y1 * b;
+                    *frame = *y1;
+                }
+            }
+            BusEffect::HighPass(cutoff) => {
+                let (a, b) = one_pole_coefficients(cutoff, sample_rate);
+                for frame in buffer {
+                    *y1 = *frame * a + *y1 * b;
+                    *frame -= *y1;
+                }
+            }
+            BusEffect::Gain(gain) => {
+                for frame in buffer {
+                    *frame *= gain;
+                }
+            }
+        }
+    }
+}
+
+fn one_pole_coefficients(cutoff: f32, sample_rate: SampleRate) -> (f32, f32) {
+    let b = (-2.0 * std::f32::consts::PI * cutoff / sample_rate as f32).exp();
+    (1.0 - b, b)
+}
+
+/// Side-chain ducking: lowers the volume of a bus while another bus is playing, such as lowering
+/// the music while dialogue is playing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ducking {
+    /// The bus whose level ducks this bus
+    pub trigger: String,
+    /// The gain that this bus is lowered to while ducked, from 0 to 1
+    pub gain: f32,
+    /// The peak level of the trigger bus above which this bus is ducked
+    pub threshold: f32,
+    /// How long it takes to lower the volume
+    pub attack: Duration,
+    /// How long it takes to restore the volume after the trigger bus goes quiet
+    pub release: Duration,
+}
+
+impl Ducking {
+    pub fn new(trigger: impl Into<String>, gain: f32) -> Self {
+        Self {
+            trigger: trigger.into(),
+            gain,
+            threshold: 0.01,
+            attack: Duration::from_millis(50),
+            release: Duration::from_millis(500),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bus {
+    volume: f32,
+    user_volume: f32,
+    muted: bool,
+    /// The effects, with the state of their filter
+    effects: Vec<(BusEffect, Frame)>,
+    ducking: Option<Ducking>,
+    /// The gain that was applied at the end of the last block, which the next block ramps from
+    /// to avoid clicks
+    applied_gain: f32,
+    duck_gain: f32,
+    /// The peak level of the last block, after effects and volume
+    level: f32,
+    buffer: Vec<Frame>,
+}
+
+impl Bus {
+    fn new(len: usize) -> Self {
+        Self {
+            volume: 1.0,
+            user_volume: 1.0,
+            muted: false,
+            effects: Vec::new(),
+            ducking: None,
+            applied_gain: 1.0,
+            duck_gain: 1.0,
+            level: 0.0,
+            buffer: vec![Frame::ZERO; len],
+        }
+    }
+
+    fn gain(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.volume * self.user_volume
+        }
+    }
+
+    /// Applies the effects and volume of the bus to its buffer.
+    fn process(&mut self, sample_rate: SampleRate) {
+        for (effect, y1) in &mut self.effects {
+            effect.process(&mut self.buffer, y1, sample_rate);
+        }
+
+        let gain = self.gain();
+        apply_ramp(&mut self.buffer, self.applied_gain, gain);
+        self.applied_gain = gain;
+
+        self.level = self
+            .buffer
+            .iter()
+            .map(|frame| frame.abs().max_element())
+            .fold(0.0, f32::max);
+    }
+
+    fn duck(&mut self, active: bool, sample_rate: SampleRate) {
+        let Some(ducking) = &self.ducking else {
+            self.duck_gain = 1.0;
+            return;
+        };
+
+        let target = if active { ducking.gain } else { 1.0 };
+        let time = if target < self.duck_gain {
+            ducking.attack
+        } else {
+            ducking.release
+        };
+        let block = self.buffer.len() as f32 / sample_rate as f32;
+        let coefficient = if time.is_zero() {
+            0.0
+        } else {
+            (-block / time.as_secs_f32()).exp()
+        };
+
+        let start = self.duck_gain;
+        self.duck_gain = target + (start - target) * coefficient;
+        apply_ramp(&mut self.buffer, start, self.duck_gain);
+    }
+}
+
+/// Multiplies the buffer by a gain that changes linearly from `from` to `to`.
+fn apply_ramp(buffer: &mut [Frame], from: f32, to: f32) {
+    if from == 1.0 && to == 1.0 {
+        return;
+    }
+
+    let len = buffer.len() as f32;
+    for (i, frame) in buffer.iter_mut().enumerate() {
+        *frame *= from + (to - from) * ((i + 1) as f32 / len);
+    }
+}
+
+/// The buses of a mixer.
+#[derive(Debug)]
+pub(crate) struct Buses {
+    master: Bus,
+    buses: HashMap<String, Bus>,
+    /// Whether each bus in `buses` is ducked, in iteration order
+    ducked: Vec<bool>,
+    len: usize,
+}
+
+impl Buses {
+    pub fn new() -> Self {
+        Self {
+            master: Bus::new(0),
+            buses: STANDARD_BUSES
+                .iter()
+                .map(|name| (name.to_string(), Bus::new(0)))
+                .collect(),
+            ducked: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn bus_mut(&mut self, name: &str) -> &mut Bus {
+        if name == MASTER_BUS {
+            return &mut self.master;
+        }
+
+        if !self.buses.contains_key(name) {
+            self.buses.insert(name.to_string(), Bus::new(self.len));
+        }
+        self.buses.get_mut(name).unwrap()
+    }
+
+    pub fn set_volume(&mut self, name: &str, volume: f32) {
+        self.bus_mut(name).volume = volume.max(0.0);
+    }
+
+    pub fn set_user_volume(&mut self, name: &str, volume: f32) {
+        self.bus_mut(name).user_volume = volume.max(0.0);
+    }
+
+    pub fn set_muted(&mut self, name: &str, muted: bool) {
+        self.bus_mut(name).muted = muted;
+    }
+
+    pub fn set_effects(&mut self, name: &str, effects: Vec<BusEffect>) {
+        let bus = self.bus_mut(name);
+        // Keep the state of the filters if nothing changed, so that this can be called every frame
+        if !bus.effects.iter().map(|(effect, _)| effect).eq(&effects) {
+            bus.effects = effects
+                .into_iter()
+                .map(|effect| (effect, Frame::ZERO))
+                .collect();
+        }
+    }
+
+    pub fn set_ducking(&mut self, name: &str, ducking: Option<Ducking>) {
+        self.bus_mut(name).ducking = ducking;
+    }
+
+    /// Clears the buffers of the buses, so that `len` frames can be mixed into them.
+    pub fn begin(&mut self, len: usize) {
+        self.len = len;
+        for bus in self.buses.values_mut().chain([&mut self.master]) {
+            bus.buffer.clear();
+            bus.buffer.resize(len, Frame::ZERO);
+        }
+    }
+
+    /// The buffer that sounds on the bus `name` are mixed into.
+    pub fn buffer(&mut self, name: &str) -> &mut [Frame] {
+        &mut self.bus_mut(name).buffer
+    }
+
+    /// Processes the buses, and adds the result to `output`.
+    pub fn mix(&mut self, output: &mut [Frame], sample_rate: SampleRate) {
+        for bus in self.buses.values_mut() {
+            bus.process(sample_rate);
+        }
+
+        self.ducked.clear();
+        self.ducked.extend(self.buses.values().map(|bus| {
+            bus.ducking.as_ref().is_some_and(|ducking| {
+                self.buses
+                    .get(&ducking.trigger)
+                    .is_some_and(|trigger| trigger.level > ducking.threshold)
+            })
+        }));
+
+        for (bus, &ducked) in self.buses.values_mut().zip(&self.ducked) {
+            bus.duck(ducked, sample_rate);
+            for (dst, src) in self.master.buffer.iter_mut().zip(&bus.buffer) {
+                *dst += *src;
+            }
+        }
+
+        self.master.process(sample_rate);
+        for (dst, src) in output.iter_mut().zip(&self.master.buffer) {
+            *dst += *src;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec2;
+
+    use super::*;
+    use crate::{streaming_source::StreamingSource, AudioMixer, Source};
+
+    fn constant(len: usize) -> StreamingSource<std::vec::IntoIter<Frame>> {
+        StreamingSource::new(vec![vec2(1.0, 1.0); len], 100)
+    }
+
+    #[test]
+    fn bus_volume_and_mute() {
+        let mut mixer = AudioMixer::new(100);
+        mixer.play_on_bus(constant(20), MUSIC_BUS);
+        mixer.play_on_bus(constant(20), VOICE_BUS);
+        mixer.set_bus_volume(MUSIC_BUS, 0.5);
+        mixer.set_bus_muted(VOICE_BUS, true);
+
+        // The first block ramps from the previous volume
+        let mut output = [Frame::ZERO; 10];
+        mixer.sample_buffered(&mut output);
+
+        let mut output = [Frame::ZERO; 10];
+        mixer.sample_buffered(&mut output);
+        assert_eq!(output, [vec2(0.5, 0.5); 10]);
+    }
+
+    #[test]
+    fn ducking() {
+        let mut mixer = AudioMixer::new(100);
+        mixer.play_on_bus(constant(1000), MUSIC_BUS);
+        mixer.set_bus_ducking(
+            MUSIC_BUS,
+            Some(Ducking {
+                attack: Duration::ZERO,
+                release: Duration::ZERO,
+                ..Ducking::new(VOICE_BUS, 0.25)
+            }),
+        );
+
+        let mut output = [Frame::ZERO; 10];
+        mixer.sample_buffered(&mut output);
+        assert_eq!(output[9], vec2(1.0, 1.0));
+
+        // The voice is mixed on top of the ducked music
+        mixer.play_on_bus(constant(10), VOICE_BUS);
+        let mut output = [Frame::ZERO; 10];
+        mixer.sample_buffered(&mut output);
+        assert_eq!(output[9], vec2(1.25, 1.25));
+
+        // The music is restored once the voice has finished
+        let mut output = [Frame::ZERO; 10];
+        mixer.sample_buffered(&mut output);
+        let mut output = [Frame::ZERO; 10];
+        mixer.sample_buffered(&mut output);
+        assert_eq!(output[9], vec2(1.0, 1.0));
+    }
+}
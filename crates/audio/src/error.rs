@@ -29,6 +29,15 @@ pub enum Error {
     ContentDownload(#[from] AssetError),
     #[error("Failed to decode vorbis")]
     Vorbis(#[from] VorbisError),
+    #[error("Failed to decode audio: {0}")]
+    Symphonia(#[from] symphonia::core::errors::Error),
+    #[error("Failed to read ogg stream")]
+    Ogg(#[from] ogg::OggReadError),
+    #[cfg(not(target_os = "unknown"))]
+    #[error("Failed to decode opus: {0}")]
+    Opus(#[from] audiopus::Error),
+    #[error("Invalid opus stream: {0}")]
+    InvalidOpus(&'static str),
     #[error("Invalid channel configuration: {0}")]
     InvalidChannelCount(u16),
     #[error("Failed to load IR sphere for spatial audio")]
@@ -6,11 +6,17 @@ mod stream;
 
 mod barycentric;
 pub mod blt;
+pub mod bus;
 /// Fast fourier transform
 pub mod hrtf;
+#[cfg(not(target_os = "unknown"))]
+pub mod opus;
 pub mod signal;
 pub mod source;
 mod spatial;
+#[cfg(not(target_os = "unknown"))]
+pub mod streamed_track;
+pub mod symphonia_track;
 pub mod track;
 pub mod utils;
 pub mod value;
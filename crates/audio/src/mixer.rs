@@ -10,6 +10,7 @@ use parking_lot::Mutex;
 use slotmap::{new_key_type, SlotMap};
 
 use crate::{
+    bus::{BusEffect, Buses, Ducking, DEFAULT_BUS},
     signal::{AsyncSignal, BlockingSignal, Signal},
     Frame, SampleConversion, SampleRate, Source,
 };
@@ -24,6 +25,7 @@ struct PlayingSound {
     #[allow(dead_code)]
     cursor: usize,
     source: Box<dyn Source>,
+    bus: String,
 }
 
 /// Handle to a playing sound
@@ -104,6 +106,7 @@ pub struct AudioMixerInner {
     pub sample_rate: SampleRate,
     waiters: Mutex<SignalVec>,
     sources: Mutex<SlotMap<SoundId, PlayingSound>>,
+    buses: Mutex<Buses>,
 }
 
 impl std::fmt::Debug for AudioMixerInner {
@@ -122,6 +125,7 @@ impl AudioMixer {
                 sample_rate,
                 sources: Mutex::default(),
                 waiters: Default::default(),
+                buses: Mutex::new(Buses::new()),
             }),
         }
     }
@@ -132,8 +136,14 @@ impl AudioMixer {
         }
     }
 
-    /// Play a source on the mixer, returning a handle which can be used to control it
+    /// Play a source on the default bus of the mixer, returning a handle which can be used to
+    /// control it
     pub fn play<S: Source + 'static>(&self, source: S) -> Sound {
+        self.play_on_bus(source, DEFAULT_BUS)
+    }
+
+    /// Play a source on the bus `bus`, which is created if it does not exist yet
+    pub fn play_on_bus<S: Source + 'static>(&self, source: S, bus: &str) -> Sound {
         let sample_rate = source.sample_rate();
 
         let source = if sample_rate == self.inner.sample_rate {
@@ -142,11 +152,11 @@ impl AudioMixer {
             Box::new(SampleConversion::new(source, self.inner.sample_rate as _)) as Box<dyn Source>
         };
 
-        let id = self
-            .inner
-            .sources
-            .lock()
-            .insert(PlayingSound { cursor: 0, source });
+        let id = self.inner.sources.lock().insert(PlayingSound {
+            cursor: 0,
+            source,
+            bus: bus.to_string(),
+        });
         Sound {
             id,
             mixer: self.clone(),
@@ -157,6 +167,32 @@ impl AudioMixer {
         self.inner.sources.lock().remove(key);
     }
 
+    /// Sets the volume of the bus `bus`, as controlled by the game
+    pub fn set_bus_volume(&self, bus: &str, volume: f32) {
+        self.inner.buses.lock().set_volume(bus, volume);
+    }
+
+    /// Sets the volume of the bus `bus` that the player chose, such as in their settings. It is
+    /// multiplied with the volume of the bus.
+    pub fn set_bus_user_volume(&self, bus: &str, volume: f32) {
+        self.inner.buses.lock().set_user_volume(bus, volume);
+    }
+
+    pub fn set_bus_muted(&self, bus: &str, muted: bool) {
+        self.inner.buses.lock().set_muted(bus, muted);
+    }
+
+    /// Sets the effects that are applied to the bus `bus`, in order. The effects keep their state
+    /// if they are the same as the current effects.
+    pub fn set_bus_effects(&self, bus: &str, effects: Vec<BusEffect>) {
+        self.inner.buses.lock().set_effects(bus, effects);
+    }
+
+    /// Sets which bus ducks the bus `bus`, if any. The master bus can not be ducked.
+    pub fn set_bus_ducking(&self, bus: &str, ducking: Option<Ducking>) {
+        self.inner.buses.lock().set_ducking(bus, ducking);
+    }
+
     fn notify_sound_waiters(&self, id: SoundId) {
         // Wake the wakers which are parked on this id, and remove them from the waiting list
         self.inner.waiters.lock().retain_mut(|(sound_id, signal)| {
@@ -177,22 +213,9 @@ impl AudioMixer {
 
 impl Source for AudioMixer {
     fn next_sample(&mut self) -> Option<crate::Frame> {
-        let mut sources = self.inner.sources.lock();
-        let mut res = Frame::ZERO;
-        sources.retain(|id, source| {
-            let sample = match source.source.next_sample() {
-                Some(v) => v,
-                None => {
-                    self.terminate_source(id, source);
-                    return false;
-                }
-            };
-            res += sample;
-
-            true
-        });
-
-        Some(res)
+        let mut res = [Frame::ZERO];
+        self.sample_buffered(&mut res);
+        Some(res[0])
     }
 
     fn sample_rate(&self) -> crate::SampleRate {
@@ -201,8 +224,10 @@ impl Source for AudioMixer {
 
     fn sample_buffered(&mut self, output: &mut [Frame]) -> usize {
         let mut sources = self.inner.sources.lock();
+        let mut buses = self.inner.buses.lock();
+        buses.begin(output.len());
         sources.retain(|id, source| {
-            let written = source.source.sample_buffered(output);
+            let written = source.source.sample_buffered(buses.buffer(&source.bus));
 
             // No more samples in source
             if written != output.len() {
@@ -212,6 +237,7 @@ impl Source for AudioMixer {
 
             true
         });
+        buses.mix(output, self.inner.sample_rate);
 
         output.len()
     }
@@ -0,0 +1,290 @@
+use std::{
+    io::{Cursor, Read, Seek},
+    sync::Arc,
+};
+
+use audiopus::{coder::Decoder, packet::Packet, Channels, MutSignals};
+use derivative::Derivative;
+use itertools::Itertools;
+
+use crate::{Error, Frame, Result, SampleRate, Source};
+
+/// Opus is always decoded at 48 kHz, whatever the sample rate of the original audio was
+const OPUS_SAMPLE_RATE: SampleRate = 48_000;
+/// The longest duration of a single Opus packet (120 ms), in frames
+const MAX_PACKET_FRAMES: usize = 5760;
+
+/// The identification header of an Ogg Opus stream, as specified by RFC 7845
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OpusHead {
+    pub channels: u8,
+    /// The number of frames to discard from the start of the decoded audio
+    pub pre_skip: u16,
+    /// The gain to apply to the decoded audio, as a linear factor
+    pub gain: f32,
+}
+
+impl OpusHead {
+    pub fn parse(packet: &[u8]) -> Result<Self> {
+        if packet.len() < 19 || !packet.starts_with(b"OpusHead") {
+            return Err(Error::InvalidOpus("missing OpusHead header"));
+        }
+        // Only the major version, in the upper 4 bits, is incompatible
+        if packet[8] >> 4 != 0 {
+            return Err(Error::InvalidOpus("unsupported version"));
+        }
+
+        let channels = packet[9];
+        // Mapping family 0 is mono or stereo; the other families are for surround sound
+        if packet[18] != 0 || !(1..=2).contains(&channels) {
+            return Err(Error::InvalidChannelCount(channels as _));
+        }
+
+        // The output gain is in dB, as a Q7.8 fixed point number
+        let gain_db = i16::from_le_bytes([packet[16], packet[17]]) as f32 / 256.0;
+
+        Ok(Self {
+            channels,
+            pre_skip: u16::from_le_bytes([packet[10], packet[11]]),
+            gain: 10f32.powf(gain_db / 20.0),
+        })
+    }
+}
+
+/// Reads and decodes the packets of an Ogg Opus stream.
+pub(crate) struct OpusReader<R: Read + Seek> {
+    packets: ogg::PacketReader<R>,
+    decoder: Decoder,
+    stream_serial: u32,
+    head: OpusHead,
+    /// The number of frames that still have to be discarded from the start of the stream
+    skip: usize,
+    /// The end of the last decoded packet, in frames since the start of the stream
+    position: u64,
+    buffer: Vec<f32>,
+}
+
+impl<R: Read + Seek> OpusReader<R> {
+    pub fn new(source: R) -> Result<Self> {
+        let mut packets = ogg::PacketReader::new(source);
+
+        let first = packets
+            .read_packet()?
+            .ok_or(Error::InvalidOpus("empty stream"))?;
+        let head = OpusHead::parse(&first.data)?;
+        // The comment header is not needed for playback
+        packets
+            .read_packet()?
+            .ok_or(Error::InvalidOpus("missing OpusTags header"))?;
+
+        let channels = match head.channels {
+            1 => Channels::Mono,
+            _ => Channels::Stereo,
+        };
+
+        Ok(Self {
+            packets,
+            decoder: Decoder::new(audiopus::SampleRate::Hz48000, channels)?,
+            stream_serial: first.stream_serial(),
+            skip: head.pre_skip as usize,
+            buffer: vec![0.0; MAX_PACKET_FRAMES * head.channels as usize],
+            head,
+            position: 0,
+        })
+    }
+
+    pub fn sample_rate(&self) -> SampleRate {
+        OPUS_SAMPLE_RATE
+    }
+
+    /// Decodes the next packet of the stream, or returns `None` at the end of the stream.
+    pub fn next_packet(&mut self) -> Result<Option<Vec<Frame>>> {
+        loop {
+            let Some(packet) = self.packets.read_packet()? else {
+                return Ok(None);
+            };
+            // Only the first logical stream of the file is played
+            if packet.stream_serial() != self.stream_serial {
+                continue;
+            }
+            let Ok(input) = Packet::try_from(&packet.data[..]) else {
+                continue;
+            };
+
+            let output = MutSignals::try_from(&mut self.buffer[..])?;
+            let len = match self.decoder.decode_float(Some(input), output, false) {
+                Ok(len) => len,
+                // A corrupt packet only causes a short gap, so skip it rather than stopping
+                Err(err) => {
+                    tracing::warn!("Skipping corrupt audio packet: {err}");
+                    continue;
+                }
+            };
+
+            let start = self.position;
+            self.position += len as u64;
+
+            // The granule position of the last page is where the audio ends, which can be in
+            // the middle of the last packet
+            let end = if packet.last_in_stream() {
+                len.min(packet.absgp_page().saturating_sub(start) as usize)
+            } else {
+                len
+            };
+            let skip = self.skip.min(end);
+            self.skip -= skip;
+
+            let channels = self.head.channels as usize;
+            let samples = &self.buffer[skip * channels..end * channels];
+            let gain = self.head.gain;
+            let frames = match channels {
+                1 => samples
+                    .iter()
+                    .map(|&s| Frame::splat(s * gain))
+                    .collect_vec(),
+                _ => samples
+                    .chunks_exact(2)
+                    .map(|s| Frame::new(s[0], s[1]) * gain)
+                    .collect_vec(),
+            };
+
+            return Ok(Some(frames));
+        }
+    }
+}
+
+/// A track that is encoded with Opus, in an Ogg container.
+#[derive(Derivative, Clone)]
+#[derivative(Debug)]
+pub struct OpusTrack {
+    #[derivative(Debug = "ignore")]
+    bytes: Arc<[u8]>,
+    /// The number of frames in the fully decoded audio
+    decoded_len: u64,
+}
+
+impl OpusTrack {
+    pub fn new(bytes: impl Into<Arc<[u8]>>) -> Result<Self> {
+        // Decode once to ensure there are no encoding errors, and acquire the duration, which is
+        // only stored in the last page of the stream
+        let bytes = bytes.into();
+        let mut reader = OpusReader::new(Cursor::new(bytes.clone()))?;
+
+        let mut decoded_len = 0;
+        while let Some(frames) = reader.next_packet()? {
+            decoded_len += frames.len() as u64;
+        }
+
+        Ok(Self { bytes, decoded_len })
+    }
+
+    pub fn decode(&self) -> OpusDecodeStream {
+        // Creation of the track ensures that it can be read
+        let reader = OpusReader::new(Cursor::new(self.bytes.clone())).unwrap();
+
+        OpusDecodeStream {
+            track: self.clone(),
+            reader,
+            packet: Vec::new(),
+            cursor: 0,
+        }
+    }
+}
+
+/// Audio source which decodes an [OpusTrack] as it plays
+pub struct OpusDecodeStream {
+    track: OpusTrack,
+    reader: OpusReader<Cursor<Arc<[u8]>>>,
+    packet: Vec<Frame>,
+    cursor: usize,
+}
+
+impl Clone for OpusDecodeStream {
+    fn clone(&self) -> Self {
+        self.track.decode()
+    }
+}
+
+impl Source for OpusDecodeStream {
+    #[inline]
+    fn next_sample(&mut self) -> Option<Frame> {
+        loop {
+            if let Some(&s) = self.packet.get(self.cursor) {
+                self.cursor += 1;
+                return Some(s);
+            }
+
+            self.packet = match self.reader.next_packet() {
+                Ok(packet) => packet?,
+                Err(err) => {
+                    tracing::error!("Failed to decode audio: {err}");
+                    return None;
+                }
+            };
+            self.cursor = 0;
+        }
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.reader.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        Some(self.track.decoded_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::track::AudioFormat;
+
+    fn opus_head(channels: u8, mapping_family: u8) -> Vec<u8> {
+        let mut head = b"OpusHead".to_vec();
+        head.extend([1, channels]);
+        head.extend(312u16.to_le_bytes());
+        head.extend(44_100u32.to_le_bytes());
+        // An output gain of -6 dB
+        head.extend((-6i16 * 256).to_le_bytes());
+        head.push(mapping_family);
+        head
+    }
+
+    #[test]
+    fn parses_opus_head() {
+        let head = OpusHead::parse(&opus_head(2, 0)).unwrap();
+        assert_eq!(head.channels, 2);
+        assert_eq!(head.pre_skip, 312);
+        approx::assert_relative_eq!(head.gain, 0.501_187, epsilon = 1e-5);
+
+        assert!(matches!(
+            OpusHead::parse(&opus_head(6, 1)),
+            Err(Error::InvalidChannelCount(6))
+        ));
+        assert!(matches!(
+            OpusHead::parse(b"OpusTags"),
+            Err(Error::InvalidOpus(_))
+        ));
+    }
+
+    #[test]
+    fn detects_opus_in_ogg_files() {
+        let page = |packet: &[u8]| {
+            let mut page = b"OggS".to_vec();
+            page.extend([0; 22]);
+            page.extend([1, packet.len() as u8]);
+            page.extend(packet);
+            page
+        };
+
+        assert_eq!(
+            AudioFormat::Vorbis.refine(&page(&opus_head(1, 0))),
+            AudioFormat::Opus
+        );
+        assert_eq!(
+            AudioFormat::Vorbis.refine(&page(b"\x01vorbis")),
+            AudioFormat::Vorbis
+        );
+        assert_eq!(AudioFormat::Vorbis.refine(&[]), AudioFormat::Vorbis);
+    }
+}
@@ -5,12 +5,14 @@ pub(crate) mod dynamic_delay;
 pub mod gain;
 pub mod history;
 mod mix;
+mod occlusion;
 mod onepole;
 mod oscilloscope;
 mod pad_to;
 mod pan;
 mod peek;
 mod repeat;
+mod reverb;
 mod sample_bufferer;
 mod sample_rate;
 mod slice;
@@ -32,11 +34,13 @@ use circular_queue::CircularQueue;
 pub use crossfade::*;
 pub use gain::*;
 pub use mix::*;
+pub use occlusion::*;
 pub use onepole::*;
 pub use pan::*;
 use parking_lot::Mutex;
 pub use peek::*;
 pub use repeat::*;
+pub use reverb::*;
 pub use sample_rate::*;
 pub use slice::*;
 pub use spatial::*;
@@ -199,6 +203,22 @@ pub trait Source: Send {
         Spatial::new(self, hrtf_lib, listener, params)
     }
 
+    fn reverb<P>(self, params: P) -> Reverb<Self, P>
+    where
+        Self: Sized,
+        P: for<'x> Value<'x, Item = ReverbParams>,
+    {
+        Reverb::new(self, params)
+    }
+
+    fn occlusion<P>(self, params: P) -> Occlusion<Self, P>
+    where
+        Self: Sized,
+        P: for<'x> Value<'x, Item = OcclusionParams>,
+    {
+        Occlusion::new(self, params)
+    }
+
     fn high_pass(self, freq: f32, bandwidth: f32) -> BilinearTransform<Self, Hpf, Constant<Hpf>>
     where
         Self: Sized,
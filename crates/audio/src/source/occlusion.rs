@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{value::Value, Frame, SampleRate, Source};
+
+/// The parameters of an [Occlusion].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OcclusionParams {
+    /// How much of the source is blocked from the listener, from 0 (not at all) to 1 (fully)
+    pub amount: f32,
+    /// The cutoff frequency of the low pass filter when the source is fully occluded, in Hz
+    pub low_pass: f32,
+    /// The gain of the source when it is fully occluded
+    pub gain: f32,
+}
+
+impl Default for OcclusionParams {
+    fn default() -> Self {
+        Self {
+            amount: 0.0,
+            low_pass: 800.0,
+            gain: 0.4,
+        }
+    }
+}
+
+/// The cutoff frequency when the source is not occluded, which leaves it audibly unfiltered
+const OPEN_CUTOFF: f32 = 20000.0;
+
+/// Muffles a source that is blocked from the listener by lowering its volume and its high
+/// frequencies, like a sound heard through a wall.
+#[derive(Debug, Clone)]
+pub struct Occlusion<S, P> {
+    source: S,
+    params: P,
+    /// The amount, which follows the parameter smoothly to avoid clicks
+    amount: f32,
+    y1: Frame,
+}
+
+impl<S, P> Occlusion<S, P>
+where
+    S: Source,
+    P: for<'x> Value<'x, Item = OcclusionParams>,
+{
+    pub fn new(source: S, params: P) -> Self {
+        let amount = params.get().amount;
+        Self {
+            source,
+            params,
+            amount,
+            y1: Frame::ZERO,
+        }
+    }
+}
+
+impl<S, P> Source for Occlusion<S, P>
+where
+    S: Source,
+    P: for<'x> Value<'x, Item = OcclusionParams>,
+{
+    fn next_sample(&mut self) -> Option<Frame> {
+        let sample = self.source.next_sample()?;
+
+        let params = *self.params.get();
+        self.amount += (params.amount.clamp(0.0, 1.0) - self.amount) * 0.001;
+        if self.amount <= 0.0 {
+            self.y1 = sample;
+            return Some(sample);
+        }
+
+        // Interpolate the cutoff exponentially, as pitch is perceived logarithmically
+        let cutoff =
+            OPEN_CUTOFF * (params.low_pass.clamp(1.0, OPEN_CUTOFF) / OPEN_CUTOFF).powf(self.amount);
+        let b = (-2.0 * std::f32::consts::PI * cutoff / self.sample_rate() as f32).exp();
+        self.y1 = sample * (1.0 - b) + self.y1 * b;
+
+        let gain = 1.0 + (params.gain - 1.0) * self.amount;
+        Some(self.y1 * gain)
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.source.sample_count()
+    }
+}
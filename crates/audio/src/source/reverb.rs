@@ -0,0 +1,242 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{value::Value, Frame, SampleRate, Source};
+
+/// The parameters of a [Reverb].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReverbParams {
+    /// How large the room sounds, from 0 to 1. Larger rooms have longer tails
+    pub room_size: f32,
+    /// How quickly high frequencies are absorbed, from 0 to 1
+    pub damping: f32,
+    /// The level of the reverberated signal that is added to the dry signal, from 0 to 1
+    pub wet: f32,
+}
+
+impl Default for ReverbParams {
+    fn default() -> Self {
+        Self {
+            room_size: 0.5,
+            damping: 0.5,
+            wet: 0.0,
+        }
+    }
+}
+
+/// The delays of the comb and allpass filters at 44.1 kHz, from Freeverb
+const COMB_TUNING: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
+const ALLPASS_TUNING: [usize; 4] = [556, 441, 341, 225];
+/// The offset of the delays of the right channel, to decorrelate it from the left
+const STEREO_SPREAD: usize = 23;
+const INPUT_GAIN: f32 = 0.015;
+/// The level below which the tail is considered to have ended
+const SILENCE: f32 = 1e-4;
+
+#[derive(Debug, Clone)]
+struct Comb {
+    buffer: Vec<f32>,
+    index: usize,
+    filter_store: f32,
+}
+
+impl Comb {
+    fn new(len: usize) -> Self {
+        Self {
+            buffer: vec![0.0; len.max(1)],
+            index: 0,
+            filter_store: 0.0,
+        }
+    }
+
+    fn process(&mut self, input: f32, feedback: f32, damp: f32) -> f32 {
+        let output = self.buffer[self.index];
+        self.filter_store = output * (1.0 - damp) + self.filter_store * damp;
+        self.buffer[self.index] = input + self.filter_store * feedback;
+        self.index = (self.index + 1) % self.buffer.len();
+        output
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Allpass {
+    buffer: Vec<f32>,
+    index: usize,
+}
+
+impl Allpass {
+    fn new(len: usize) -> Self {
+        Self {
+            buffer: vec![0.0; len.max(1)],
+            index: 0,
+        }
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let buffered = self.buffer[self.index];
+        self.buffer[self.index] = input + buffered * 0.5;
+        self.index = (self.index + 1) % self.buffer.len();
+        buffered - input
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Channel {
+    combs: Vec<Comb>,
+    allpasses: Vec<Allpass>,
+}
+
+impl Channel {
+    fn new(sample_rate: SampleRate, spread: usize) -> Self {
+        let scale = |len: usize| (len + spread) * sample_rate as usize / 44100;
+        Self {
+            combs: COMB_TUNING
+                .iter()
+                .map(|&len| Comb::new(scale(len)))
+                .collect(),
+            allpasses: ALLPASS_TUNING
+                .iter()
+                .map(|&len| Allpass::new(scale(len)))
+                .collect(),
+        }
+    }
+
+    fn process(&mut self, input: f32, feedback: f32, damp: f32) -> f32 {
+        let mut output = self
+            .combs
+            .iter_mut()
+            .map(|comb| comb.process(input, feedback, damp))
+            .sum::<f32>();
+        for allpass in &mut self.allpasses {
+            output = allpass.process(output);
+        }
+        output
+    }
+}
+
+/// An algorithmic reverb, based on Freeverb, which adds the reverberation of a room to a source.
+///
+/// The parameters can be changed while the source is playing. The reverb keeps playing its tail
+/// after the source has ended.
+#[derive(Debug, Clone)]
+pub struct Reverb<S, P> {
+    source: Option<S>,
+    params: P,
+    sample_rate: SampleRate,
+    left: Channel,
+    right: Channel,
+    /// The wet level, which follows the parameter smoothly to avoid clicks
+    wet: f32,
+    /// How many samples of silence the tail has produced
+    silent: usize,
+    /// How many samples of silence it takes for all the delay lines to be empty
+    tail_len: usize,
+}
+
+impl<S, P> Reverb<S, P>
+where
+    S: Source,
+    P: for<'x> Value<'x, Item = ReverbParams>,
+{
+    pub fn new(source: S, params: P) -> Self {
+        let sample_rate = source.sample_rate();
+        let wet = params.get().wet;
+        let tail_len = (COMB_TUNING.iter().max().unwrap()
+            + ALLPASS_TUNING.iter().sum::<usize>()
+            + STEREO_SPREAD)
+            * sample_rate as usize
+            / 44100;
+
+        Self {
+            source: Some(source),
+            params,
+            sample_rate,
+            left: Channel::new(sample_rate, 0),
+            right: Channel::new(sample_rate, STEREO_SPREAD),
+            wet,
+            silent: 0,
+            tail_len,
+        }
+    }
+}
+
+impl<S, P> Source for Reverb<S, P>
+where
+    S: Source,
+    P: for<'x> Value<'x, Item = ReverbParams>,
+{
+    fn next_sample(&mut self) -> Option<Frame> {
+        let params = *self.params.get();
+        self.wet += (params.wet.clamp(0.0, 1.0) - self.wet) * 0.001;
+
+        let dry = match &mut self.source {
+            Some(source) => match source.next_sample() {
+                Some(sample) => sample,
+                None => {
+                    self.source = None;
+                    Frame::ZERO
+                }
+            },
+            None => Frame::ZERO,
+        };
+
+        if self.source.is_none() && (self.silent >= self.tail_len || self.wet < SILENCE) {
+            return None;
+        }
+
+        let feedback = 0.7 + 0.28 * params.room_size.clamp(0.0, 1.0);
+        let damp = 0.4 * params.damping.clamp(0.0, 1.0);
+        let input = (dry.x + dry.y) * INPUT_GAIN;
+        let wet = Frame::new(
+            self.left.process(input, feedback, damp),
+            self.right.process(input, feedback, damp),
+        ) * self.wet;
+
+        if wet.abs().max_element() < SILENCE {
+            self.silent += 1;
+        } else {
+            self.silent = 0;
+        }
+
+        Some(dry + wet)
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        // The length of the tail depends on the parameters
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec2;
+
+    use super::*;
+    use crate::streaming_source::StreamingSource;
+
+    #[test]
+    fn dry() {
+        let source = StreamingSource::new([vec2(1.0, 1.0); 4], 44100);
+        let params = ReverbParams::default();
+        let mut reverb = Reverb::new(source, parking_lot::Mutex::new(params));
+        let samples = std::iter::from_fn(|| reverb.next_sample()).collect::<Vec<_>>();
+
+        assert_eq!(samples, [vec2(1.0, 1.0); 4]);
+    }
+
+    #[test]
+    fn tail() {
+        let source = StreamingSource::new([vec2(1.0, 1.0); 4], 44100);
+        let params = ReverbParams {
+            wet: 1.0,
+            ..Default::default()
+        };
+        let mut reverb = Reverb::new(source, parking_lot::Mutex::new(params));
+        let samples = std::iter::from_fn(|| reverb.next_sample()).count();
+
+        assert!(samples > COMB_TUNING[0]);
+    }
+}
@@ -46,3 +46,93 @@ where
         Some(self.iter.len().try_into().unwrap())
     }
 }
+
+/// Sends the chunks of samples that a [ChunkedSource] plays.
+pub type ChunkSender = flume::Sender<Vec<Frame>>;
+
+/// A source that plays chunks of samples as they are produced on another thread, such as by
+/// decoding a file while it plays.
+///
+/// The channel is bounded, so the producer is only ever a few chunks ahead. If the producer falls
+/// behind, the source plays silence until the next chunk arrives rather than blocking the audio
+/// thread. The source ends once the sender is dropped and all chunks have been played.
+#[derive(Debug)]
+pub struct ChunkedSource {
+    receiver: flume::Receiver<Vec<Frame>>,
+    chunk: Vec<Frame>,
+    cursor: usize,
+    sample_rate: SampleRate,
+    sample_count: Option<u64>,
+}
+
+impl ChunkedSource {
+    /// Creates a source and the sender of its chunks, which buffers at most `capacity` chunks.
+    pub fn channel(
+        sample_rate: SampleRate,
+        sample_count: Option<u64>,
+        capacity: usize,
+    ) -> (ChunkSender, Self) {
+        let (sender, receiver) = flume::bounded(capacity);
+        (
+            sender,
+            Self {
+                receiver,
+                chunk: Vec::new(),
+                cursor: 0,
+                sample_rate,
+                sample_count,
+            },
+        )
+    }
+}
+
+impl Source for ChunkedSource {
+    fn next_sample(&mut self) -> Option<Frame> {
+        loop {
+            if let Some(&s) = self.chunk.get(self.cursor) {
+                self.cursor += 1;
+                return Some(s);
+            }
+
+            match self.receiver.try_recv() {
+                Ok(chunk) => {
+                    self.chunk = chunk;
+                    self.cursor = 0;
+                }
+                Err(flume::TryRecvError::Empty) => return Some(Frame::ZERO),
+                Err(flume::TryRecvError::Disconnected) => return None,
+            }
+        }
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.sample_count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec2;
+
+    use super::*;
+
+    #[test]
+    fn chunked() {
+        let (sender, mut source) = ChunkedSource::channel(100, None, 2);
+        sender.send(vec![vec2(1.0, 1.0); 2]).unwrap();
+
+        assert_eq!(source.next_sample(), Some(vec2(1.0, 1.0)));
+        assert_eq!(source.next_sample(), Some(vec2(1.0, 1.0)));
+        // Underruns are silent
+        assert_eq!(source.next_sample(), Some(Frame::ZERO));
+
+        sender.send(vec![vec2(0.5, 0.5)]).unwrap();
+        drop(sender);
+        assert_eq!(source.next_sample(), Some(vec2(0.5, 0.5)));
+        assert_eq!(source.next_sample(), None);
+    }
+}
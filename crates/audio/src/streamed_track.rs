@@ -0,0 +1,151 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use lewton::inside_ogg::OggStreamReader;
+
+use crate::{
+    opus::OpusReader,
+    streaming_source::{ChunkSender, ChunkedSource},
+    symphonia_track::SymphoniaReader,
+    track::AudioFormat,
+    vorbis::FramedSamples,
+    Error, Frame, Result, SampleRate,
+};
+
+/// The number of frames that are decoded at a time
+const CHUNK_LEN: usize = 8192;
+/// The number of chunks that are decoded ahead of playback
+const BUFFERED_CHUNKS: usize = 4;
+
+/// A track that is decoded from a file while it plays, rather than being loaded into memory
+/// first. This is intended for long sounds, such as music and ambience.
+#[derive(Debug, Clone)]
+pub struct StreamedTrack {
+    path: PathBuf,
+    format: AudioFormat,
+    sample_rate: SampleRate,
+    /// The number of frames in the track, if the file stores it
+    sample_count: Option<u64>,
+}
+
+impl StreamedTrack {
+    pub fn new(path: impl Into<PathBuf>, format: AudioFormat) -> Result<Self> {
+        let path = path.into();
+        // Only the headers are read here, to ensure the file can be decoded
+        let reader = PacketReader::open(&path, &format)?;
+
+        Ok(Self {
+            sample_rate: reader.sample_rate(),
+            sample_count: reader.frame_count(),
+            path,
+            format,
+        })
+    }
+
+    /// Starts decoding the track on a background thread. If `looping` is set, the track restarts
+    /// from the beginning when it ends, until the returned source is dropped.
+    pub fn decode(&self, looping: bool) -> ChunkedSource {
+        let sample_count = if looping { None } else { self.sample_count };
+        let (sender, source) =
+            ChunkedSource::channel(self.sample_rate, sample_count, BUFFERED_CHUNKS);
+
+        let track = self.clone();
+        let spawned = std::thread::Builder::new()
+            .name("audio stream".into())
+            .spawn(move || {
+                if let Err(err) = track.stream(&sender, looping) {
+                    tracing::error!(path = ?track.path, "Failed to stream audio: {err}");
+                }
+            });
+        if let Err(err) = spawned {
+            // The sender was dropped with the thread, so the source ends immediately
+            tracing::error!("Failed to start audio stream: {err}");
+        }
+
+        source
+    }
+
+    fn stream(&self, sender: &ChunkSender, looping: bool) -> Result<()> {
+        loop {
+            let mut reader = PacketReader::open(&self.path, &self.format)?;
+            let mut chunk = Vec::with_capacity(CHUNK_LEN);
+
+            while let Some(frames) = reader.next_packet()? {
+                chunk.extend(frames);
+                if chunk.len() >= CHUNK_LEN {
+                    // The source was dropped, so the sound has stopped
+                    if sender.send(std::mem::take(&mut chunk)).is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+
+            if !chunk.is_empty() && sender.send(chunk).is_err() {
+                return Ok(());
+            }
+            if !looping {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Decodes the packets of a file, in any of the supported formats.
+enum PacketReader {
+    Vorbis(Box<OggStreamReader<BufReader<File>>>),
+    Opus(Box<OpusReader<BufReader<File>>>),
+    Symphonia(SymphoniaReader),
+}
+
+impl PacketReader {
+    fn open(path: &Path, format: &AudioFormat) -> Result<Self> {
+        let file = File::open(path).map_err(|err| Error::Io(err, path.to_path_buf()))?;
+
+        Ok(match format {
+            AudioFormat::Vorbis | AudioFormat::Opus => {
+                let mut file = BufReader::new(file);
+                let header = file
+                    .fill_buf()
+                    .map_err(|err| Error::Io(err, path.to_path_buf()))?;
+                if format.refine(header) == AudioFormat::Opus {
+                    Self::Opus(Box::new(OpusReader::new(file)?))
+                } else {
+                    Self::Vorbis(Box::new(OggStreamReader::new(file)?))
+                }
+            }
+            AudioFormat::Wav | AudioFormat::Flac | AudioFormat::Mp3 => {
+                Self::Symphonia(SymphoniaReader::new(Box::new(file), format)?)
+            }
+        })
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        match self {
+            Self::Vorbis(reader) => reader.ident_hdr.audio_sample_rate as _,
+            Self::Opus(reader) => reader.sample_rate(),
+            Self::Symphonia(reader) => reader.sample_rate(),
+        }
+    }
+
+    fn frame_count(&self) -> Option<u64> {
+        match self {
+            // The length of an Ogg stream is only known once it has been read to the end
+            Self::Vorbis(_) | Self::Opus(_) => None,
+            Self::Symphonia(reader) => reader.frame_count(),
+        }
+    }
+
+    fn next_packet(&mut self) -> Result<Option<Vec<Frame>>> {
+        match self {
+            Self::Vorbis(reader) => match reader.read_dec_packet_generic::<FramedSamples>()? {
+                Some(packet) => Ok(Some(packet.samples?)),
+                None => Ok(None),
+            },
+            Self::Opus(reader) => reader.next_packet(),
+            Self::Symphonia(reader) => reader.next_packet(),
+        }
+    }
+}
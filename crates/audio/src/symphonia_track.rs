@@ -0,0 +1,201 @@
+use std::{io::Cursor, sync::Arc};
+
+use derivative::Derivative;
+use itertools::Itertools;
+use symphonia::core::{
+    audio::SampleBuffer,
+    codecs::{Decoder, DecoderOptions},
+    errors::Error as SymphoniaError,
+    formats::{FormatOptions, FormatReader},
+    io::{MediaSource, MediaSourceStream},
+    meta::MetadataOptions,
+    probe::Hint,
+};
+
+use crate::{track::AudioFormat, Error, Frame, Result, SampleRate, Source};
+
+/// Reads and decodes the packets of the default track of a media source.
+pub(crate) struct SymphoniaReader {
+    format: Box<dyn FormatReader>,
+    decoder: Box<dyn Decoder>,
+    track_id: u32,
+    sample_rate: SampleRate,
+    /// The number of frames in the track, if the container stores it
+    frame_count: Option<u64>,
+}
+
+impl SymphoniaReader {
+    pub fn new(source: Box<dyn MediaSource>, format: &AudioFormat) -> Result<Self> {
+        let mut hint = Hint::new();
+        hint.with_extension(format.extension());
+
+        let probed = symphonia::default::get_probe().format(
+            &hint,
+            MediaSourceStream::new(source, Default::default()),
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )?;
+        let reader = probed.format;
+
+        let track = reader
+            .default_track()
+            .ok_or(SymphoniaError::Unsupported("no audio track"))?;
+        let track_id = track.id;
+        let params = track.codec_params.clone();
+
+        let decoder = symphonia::default::get_codecs().make(&params, &DecoderOptions::default())?;
+        let sample_rate = params
+            .sample_rate
+            .ok_or(SymphoniaError::Unsupported("unknown sample rate"))?;
+
+        Ok(Self {
+            format: reader,
+            decoder,
+            track_id,
+            sample_rate: sample_rate as _,
+            frame_count: params.n_frames,
+        })
+    }
+
+    pub fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    pub fn frame_count(&self) -> Option<u64> {
+        self.frame_count
+    }
+
+    /// Decodes the next packet of the track, or returns `None` at the end of the stream.
+    pub fn next_packet(&mut self) -> Result<Option<Vec<Frame>>> {
+        loop {
+            let packet = match self.format.next_packet() {
+                Ok(packet) => packet,
+                Err(SymphoniaError::IoError(err))
+                    if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+                {
+                    return Ok(None)
+                }
+                Err(SymphoniaError::ResetRequired) => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+
+            if packet.track_id() != self.track_id {
+                continue;
+            }
+
+            let decoded = match self.decoder.decode(&packet) {
+                Ok(decoded) => decoded,
+                // A corrupt packet only causes a short gap, so skip it rather than stopping
+                Err(SymphoniaError::DecodeError(err)) => {
+                    tracing::warn!("Skipping corrupt audio packet: {err}");
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            let spec = *decoded.spec();
+            let channels = spec.channels.count();
+            let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+            buffer.copy_interleaved_ref(decoded);
+
+            let samples = buffer.samples();
+            let frames = match channels {
+                1 => samples.iter().copied().map(Frame::splat).collect_vec(),
+                2 => samples
+                    .chunks_exact(2)
+                    .map(|s| Frame::new(s[0], s[1]))
+                    .collect_vec(),
+                _ => return Err(Error::InvalidChannelCount(channels as _)),
+            };
+
+            return Ok(Some(frames));
+        }
+    }
+}
+
+/// A track in one of the formats that are decoded with symphonia, such as FLAC or MP3.
+#[derive(Derivative, Clone)]
+#[derivative(Debug)]
+pub struct SymphoniaTrack {
+    #[derivative(Debug = "ignore")]
+    bytes: Arc<[u8]>,
+    format: AudioFormat,
+    /// The number of frames in the fully decoded audio
+    decoded_len: u64,
+}
+
+impl SymphoniaTrack {
+    pub fn new(bytes: impl Into<Arc<[u8]>>, format: AudioFormat) -> Result<Self> {
+        // Decode once to ensure there are no encoding errors, and acquire the duration, which
+        // MP3 files do not always store
+        let bytes = bytes.into();
+        let mut reader = SymphoniaReader::new(Box::new(Cursor::new(bytes.clone())), &format)?;
+
+        let mut decoded_len = 0;
+        while let Some(frames) = reader.next_packet()? {
+            decoded_len += frames.len() as u64;
+        }
+
+        Ok(Self {
+            bytes,
+            format,
+            decoded_len,
+        })
+    }
+
+    pub fn decode(&self) -> SymphoniaDecodeStream {
+        // Creation of the track ensures that it can be read
+        let reader =
+            SymphoniaReader::new(Box::new(Cursor::new(self.bytes.clone())), &self.format).unwrap();
+
+        SymphoniaDecodeStream {
+            track: self.clone(),
+            reader,
+            packet: Vec::new(),
+            cursor: 0,
+        }
+    }
+}
+
+/// Audio source which decodes a [SymphoniaTrack] as it plays
+pub struct SymphoniaDecodeStream {
+    track: SymphoniaTrack,
+    reader: SymphoniaReader,
+    packet: Vec<Frame>,
+    cursor: usize,
+}
+
+impl Clone for SymphoniaDecodeStream {
+    fn clone(&self) -> Self {
+        self.track.decode()
+    }
+}
+
+impl Source for SymphoniaDecodeStream {
+    #[inline]
+    fn next_sample(&mut self) -> Option<Frame> {
+        loop {
+            if let Some(&s) = self.packet.get(self.cursor) {
+                self.cursor += 1;
+                return Some(s);
+            }
+
+            self.packet = match self.reader.next_packet() {
+                Ok(packet) => packet?,
+                Err(err) => {
+                    tracing::error!("Failed to decode audio: {err}");
+                    return None;
+                }
+            };
+            self.cursor = 0;
+        }
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.reader.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        Some(self.track.decoded_len)
+    }
+}
@@ -2,17 +2,61 @@ use std::sync::Arc;
 
 use derive_more::From;
 
+#[cfg(not(target_os = "unknown"))]
+use crate::opus::{OpusDecodeStream, OpusTrack};
 use crate::{
     error::Result,
+    symphonia_track::{SymphoniaDecodeStream, SymphoniaTrack},
     vorbis::{VorbisDecodeStream, VorbisTrack},
     wav::{WavDecodeStream, WavTrack},
     Source,
 };
 
-#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
 pub enum AudioFormat {
     Wav,
     Vorbis,
+    Opus,
+    Flac,
+    Mp3,
+}
+
+impl AudioFormat {
+    /// The format of files with the extension `ext`, if it is supported.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext {
+            "wav" => Some(Self::Wav),
+            "ogg" => Some(Self::Vorbis),
+            "opus" => Some(Self::Opus),
+            "flac" => Some(Self::Flac),
+            "mp3" => Some(Self::Mp3),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Wav => "wav",
+            Self::Vorbis => "ogg",
+            Self::Opus => "opus",
+            Self::Flac => "flac",
+            Self::Mp3 => "mp3",
+        }
+    }
+
+    /// Detects Opus audio in `.ogg` files, which are otherwise assumed to contain Vorbis.
+    /// `header` is the start of the file, which has to include its first page.
+    pub fn refine(self, header: &[u8]) -> Self {
+        // The first page has a 27 byte header, followed by the sizes of its segments
+        let first_packet = header
+            .get(26)
+            .and_then(|&segments| header.get(27 + segments as usize..));
+
+        match self {
+            Self::Vorbis if first_packet.is_some_and(|p| p.starts_with(b"OpusHead")) => Self::Opus,
+            format => format,
+        }
+    }
 }
 
 #[derive(From, Debug, Clone)]
@@ -22,6 +66,9 @@ pub enum AudioFormat {
 pub enum Track {
     Vorbis(VorbisTrack),
     Wav(WavTrack),
+    Symphonia(SymphoniaTrack),
+    #[cfg(not(target_os = "unknown"))]
+    Opus(OpusTrack),
 }
 
 impl Track {
@@ -34,9 +81,18 @@ impl Track {
     }
 
     pub fn from_format(bytes: impl Into<Arc<[u8]>>, format: AudioFormat) -> Result<Self> {
-        match format {
-            AudioFormat::Wav => Self::from_wav(bytes.into()),
-            AudioFormat::Vorbis => Self::from_vorbis(bytes.into()),
+        let bytes = bytes.into();
+        match format.refine(&bytes) {
+            AudioFormat::Wav => Self::from_wav(bytes),
+            AudioFormat::Vorbis => Self::from_vorbis(bytes),
+            #[cfg(not(target_os = "unknown"))]
+            AudioFormat::Opus => Ok(Self::Opus(OpusTrack::new(bytes)?)),
+            // Opus is decoded by libopus, which is not built for the web
+            #[cfg(target_os = "unknown")]
+            AudioFormat::Opus => Err(crate::Error::UnsupportedFormat("opus".into())),
+            AudioFormat::Flac | AudioFormat::Mp3 => {
+                Ok(Self::Symphonia(SymphoniaTrack::new(bytes, format)?))
+            }
         }
     }
 
@@ -44,6 +100,9 @@ impl Track {
         match self {
             Track::Vorbis(v) => TrackDecodeStream::Vorbis(Box::new(v.decode())),
             Track::Wav(v) => TrackDecodeStream::Wav(v.decode()),
+            Track::Symphonia(v) => TrackDecodeStream::Symphonia(Box::new(v.decode())),
+            #[cfg(not(target_os = "unknown"))]
+            Track::Opus(v) => TrackDecodeStream::Opus(Box::new(v.decode())),
         }
     }
 }
@@ -52,6 +111,9 @@ impl Track {
 pub enum TrackDecodeStream {
     Vorbis(Box<VorbisDecodeStream>),
     Wav(WavDecodeStream),
+    Symphonia(Box<SymphoniaDecodeStream>),
+    #[cfg(not(target_os = "unknown"))]
+    Opus(Box<OpusDecodeStream>),
 }
 
 impl Source for TrackDecodeStream {
@@ -59,6 +121,9 @@ impl Source for TrackDecodeStream {
         match self {
             TrackDecodeStream::Vorbis(v) => v.next_sample(),
             TrackDecodeStream::Wav(v) => v.next_sample(),
+            TrackDecodeStream::Symphonia(v) => v.next_sample(),
+            #[cfg(not(target_os = "unknown"))]
+            TrackDecodeStream::Opus(v) => v.next_sample(),
         }
     }
 
@@ -66,6 +131,9 @@ impl Source for TrackDecodeStream {
         match self {
             TrackDecodeStream::Vorbis(v) => v.sample_buffered(output),
             TrackDecodeStream::Wav(v) => v.sample_buffered(output),
+            TrackDecodeStream::Symphonia(v) => v.sample_buffered(output),
+            #[cfg(not(target_os = "unknown"))]
+            TrackDecodeStream::Opus(v) => v.sample_buffered(output),
         }
     }
 
@@ -73,6 +141,9 @@ impl Source for TrackDecodeStream {
         match self {
             TrackDecodeStream::Vorbis(v) => v.sample_rate(),
             TrackDecodeStream::Wav(v) => v.sample_rate(),
+            TrackDecodeStream::Symphonia(v) => v.sample_rate(),
+            #[cfg(not(target_os = "unknown"))]
+            TrackDecodeStream::Opus(v) => v.sample_rate(),
         }
     }
 
@@ -80,6 +151,9 @@ impl Source for TrackDecodeStream {
         match self {
             TrackDecodeStream::Vorbis(v) => v.sample_count(),
             TrackDecodeStream::Wav(v) => v.sample_count(),
+            TrackDecodeStream::Symphonia(v) => v.sample_count(),
+            #[cfg(not(target_os = "unknown"))]
+            TrackDecodeStream::Opus(v) => v.sample_count(),
         }
     }
 }
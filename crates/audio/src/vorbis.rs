@@ -7,7 +7,7 @@ use lewton::inside_ogg::OggStreamReader;
 use crate::{ChannelCount, Error, Frame, Result, SampleRate, Source};
 
 /// A packet of multi-channel interleaved samples
-struct FramedSamples {
+pub(crate) struct FramedSamples {
     pub samples: Result<Vec<Frame>>,
     pub channel_count: usize,
 }
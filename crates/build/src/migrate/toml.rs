@@ -256,6 +256,9 @@ impl From<json_pipeline::AudioPipeline> for AudioPipeline {
     fn from(value: json_pipeline::AudioPipeline) -> Self {
         Self {
             convert: value.convert,
+            codec: None,
+            bitrate: None,
+            normalize: None,
         }
     }
 }
@@ -1,5 +1,5 @@
 use ambient_native_std::asset_url::AssetType;
-use ambient_pipeline_types::audio::AudioPipeline;
+use ambient_pipeline_types::audio::{AudioCodec, AudioPipeline};
 use ambient_world_audio::AudioNode;
 use anyhow::Context;
 use optivorbis::Remuxer;
@@ -13,12 +13,15 @@ use super::{
 
 pub const SOUND_GRAPH_EXTENSION: &str = "sgr";
 
+/// The number of frames that are passed to the Vorbis encoder at a time
+const ENCODE_BLOCK_LEN: usize = 4096;
+
 pub async fn pipeline(ctx: &PipelineCtx, config: AudioPipeline) -> Vec<OutAsset> {
     ctx.process_files(
         |file| {
             matches!(
                 file.extension().as_deref(),
-                Some("ogg") | Some("wav") | Some("mp3")
+                Some("ogg") | Some("opus") | Some("wav") | Some("mp3") | Some("flac")
             )
         },
         move |ctx, file| async move {
@@ -28,30 +31,30 @@ pub async fn pipeline(ctx: &PipelineCtx, config: AudioPipeline) -> Vec<OutAsset>
 
             let rel_path = ctx.in_root().relative_path(file.decoded_path());
 
-            let content_url = match file.extension().as_deref() {
-                Some("wav") => {
-                    if config.convert {
-                        tracing::debug!("Processing wav file");
-                        let contents = symphonia_convert("wav", contents).await?;
-                        ctx.write_file(rel_path.with_extension("ogg"), contents)
-                            .await
-                    } else {
-                        ctx.write_file(&rel_path, contents).await
+            let ext = file.extension().unwrap_or_default();
+            let content_url = match config.codec_for(&ext) {
+                AudioCodec::Original => {
+                    if config.normalize.is_some() {
+                        tracing::warn!(
+                            "{file} is not normalized, as it is not encoded with Vorbis"
+                        );
+                    }
+                    ctx.write_file(&rel_path, contents).await
+                }
+                AudioCodec::Vorbis if ext == "ogg" || ext == "opus" => {
+                    if config.bitrate.is_some() || config.normalize.is_some() {
+                        tracing::warn!("{file} is already compressed, so it is kept as it is");
                     }
+                    ctx.write_file(&rel_path, contents).await
                 }
-                Some("ogg") => ctx.write_file(&rel_path, contents).await,
-                Some(ext @ "mp3") => {
-                    tracing::debug!("Processing mp3 file");
+                AudioCodec::Vorbis => {
+                    tracing::debug!("Encoding {ext} file with Vorbis");
                     // Make sure to take the contents, to avoid having both the input and output in
                     // memory at once
-                    let contents = symphonia_convert(ext, contents).await?;
+                    let contents = symphonia_convert(&ext, contents, &config).await?;
                     ctx.write_file(rel_path.with_extension("ogg"), contents)
                         .await
                 }
-                other => anyhow::bail!(
-                    "Audio filetype {:?} is not yet supported",
-                    other.unwrap_or_default()
-                ),
             };
 
             let root_node = AudioNode::Vorbis {
@@ -100,8 +103,12 @@ fn save_audio_graph(root: AudioNode) -> anyhow::Result<Vec<u8>> {
         .into_bytes())
 }
 
-#[tracing::instrument(level = "debug", skip(input))]
-async fn symphonia_convert(ext: &str, input: Vec<u8>) -> anyhow::Result<Vec<u8>> {
+#[tracing::instrument(level = "debug", skip(input, config))]
+async fn symphonia_convert(
+    ext: &str,
+    input: Vec<u8>,
+    config: &AudioPipeline,
+) -> anyhow::Result<Vec<u8>> {
     use std::num::{NonZeroU32, NonZeroU8};
 
     use symphonia::core::{
@@ -168,8 +175,15 @@ async fn symphonia_convert(ext: &str, input: Vec<u8>) -> anyhow::Result<Vec<u8>>
         .context("Audio must have >0 channels")?;
 
     // select a bitrate
-    let bitrate = VorbisBitrateManagementStrategy::QualityVbr {
-        target_quality: 0.9,
+    let bitrate = match config.bitrate {
+        Some(kbps) => VorbisBitrateManagementStrategy::Vbr {
+            target_bitrate: (kbps * 1000)
+                .try_into()
+                .context("Audio bitrate must be >0")?,
+        },
+        None => VorbisBitrateManagementStrategy::QualityVbr {
+            target_quality: 0.9,
+        },
     };
 
     // create the ogg Vorbis encoder
@@ -182,7 +196,8 @@ async fn symphonia_convert(ext: &str, input: Vec<u8>) -> anyhow::Result<Vec<u8>>
     .bitrate_management_strategy(bitrate)
     .build()?;
 
-    // process all packets in the input file
+    // decode all packets in the input file, as the whole file is needed to normalize it
+    let mut samples = vec![Vec::new(); channels.get() as usize];
     let result = loop {
         // read the next packet
         let packet = match format.next_packet() {
@@ -200,12 +215,9 @@ async fn symphonia_convert(ext: &str, input: Vec<u8>) -> anyhow::Result<Vec<u8>>
         let mut block = decoded.make_equivalent::<f32>();
         decoded.convert(&mut block);
 
-        // get the samples as &[&[f32]]
-        let planes = block.planes();
-        let plane_samples = planes.planes();
-
-        // feed the samples into the encoder
-        encoder.encode_audio_block(plane_samples)?;
+        for (channel, plane) in samples.iter_mut().zip(block.planes().planes()) {
+            channel.extend_from_slice(plane);
+        }
     };
 
     // process the error returned by the loop
@@ -218,6 +230,21 @@ async fn symphonia_convert(ext: &str, input: Vec<u8>) -> anyhow::Result<Vec<u8>>
         err => return Err(err.into()),
     }
 
+    if let Some(target) = config.normalize {
+        normalize(&mut samples, target);
+    }
+
+    // feed the samples into the encoder, one block at a time
+    let len = samples.first().map_or(0, Vec::len);
+    for start in (0..len).step_by(ENCODE_BLOCK_LEN) {
+        let end = (start + ENCODE_BLOCK_LEN).min(len);
+        let block = samples
+            .iter()
+            .map(|channel| &channel[start..end])
+            .collect::<Vec<_>>();
+        encoder.encode_audio_block(block)?;
+    }
+
     // finish encoding
     let output = encoder.finish()?;
     let output_size = output.len();
@@ -233,3 +260,24 @@ async fn symphonia_convert(ext: &str, input: Vec<u8>) -> anyhow::Result<Vec<u8>>
 
     Ok(optimized_output)
 }
+
+/// Scales the samples so that their RMS level is `target` dBFS, without letting them clip.
+fn normalize(samples: &mut [Vec<f32>], target: f32) {
+    let (sum, count, peak) = samples
+        .iter()
+        .flatten()
+        .fold((0.0f64, 0usize, 0.0f32), |(sum, count, peak), &s| {
+            (sum + (s as f64).powi(2), count + 1, peak.max(s.abs()))
+        });
+    if count == 0 || peak == 0.0 {
+        return;
+    }
+
+    let rms = (sum / count as f64).sqrt() as f32;
+    let gain = (10f32.powf(target / 20.0) / rms).min(1.0 / peak);
+    tracing::debug!("Normalizing audio from {rms} RMS with a gain of {gain}");
+
+    for s in samples.iter_mut().flatten() {
+        *s *= gain;
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use ambient_core::window::{
     cursor_position, set_cursor, window_logical_size, window_physical_size, window_scale_factor,
@@ -10,15 +10,18 @@ use ambient_element::{
     consume_context, element_component, use_frame, use_runtime_message, use_state, Element,
     ElementComponentExt, Hooks,
 };
+use ambient_input::touch::touches;
 use ambient_layout::Docking;
 use ambient_network::client::{ClientState, GameClientRenderTarget, GameClientWorld};
-use ambient_shared_types::CursorIcon;
+use ambient_shared_types::{CursorIcon, VirtualKeyCode};
 use ambient_ui_native::{
     cb, docking, padding, width, Borders, Button, Dock, MeasureSize, ScrollArea, ScrollAreaSizing,
     UIExt, STREET,
 };
 use glam::{uvec2, vec4, Vec2};
 
+use crate::input_bindings::InputBindingsEditor;
+
 const ECS_DEBUGGER: bool = true;
 
 #[element_component]
@@ -34,6 +37,17 @@ pub fn GameView(hooks: &mut Hooks, show_debug: bool) -> Element {
     let (w_memory, set_w_memory) = use_state(hooks, 0.0);
     let (mouse_on_edge, set_mouse_on_edge) = use_state(hooks, false);
     let (should_track_resize, set_should_track_resize) = use_state(hooks, false);
+    let (show_bindings, set_show_bindings) = use_state(hooks, false);
+
+    use_runtime_message::<messages::WindowKeyboardInput>(hooks, move |_world, event| {
+        let keycode = event
+            .keycode
+            .as_deref()
+            .and_then(|keycode| VirtualKeyCode::from_str(keycode).ok());
+        if event.pressed && keycode == Some(VirtualKeyCode::F10) {
+            set_show_bindings(!show_bindings);
+        }
+    });
 
     use_runtime_message::<messages::WindowMouseInput>(hooks, {
         move |_world, event| {
@@ -67,14 +81,24 @@ pub fn GameView(hooks: &mut Hooks, show_debug: bool) -> Element {
                 set_w(mouse_pos.x);
                 set_w_memory(mouse_pos.x);
             }
-            mouse_pos.x -= ecs_size.x;
-            mouse_pos.y -= debugger_size.y;
+            let offset = Vec2::new(ecs_size.x, debugger_size.y);
+            mouse_pos -= offset;
 
             state
                 .world
                 .set_if_changed(EntityId::resources(), cursor_position(), mouse_pos)
                 .unwrap();
 
+            let game_touches = world
+                .resource(touches())
+                .iter()
+                .map(|(id, position)| (*id, *position - offset))
+                .collect();
+            state
+                .world
+                .set_if_changed(EntityId::resources(), touches(), game_touches)
+                .unwrap();
+
             let size = uvec2(
                 render_target.0.color_buffer.size.width,
                 render_target.0.color_buffer.size.height,
@@ -163,6 +187,18 @@ pub fn GameView(hooks: &mut Hooks, show_debug: bool) -> Element {
         } else {
             Element::new()
         },
+        if show_bindings {
+            Dock::el([ScrollArea::el(
+                ScrollAreaSizing::FitParentWidth,
+                InputBindingsEditor.el(),
+            )])
+            .with(width(), 400.)
+            .with(docking(), Docking::Right)
+            .with_background(vec4(0., 0., 0., 0.9))
+            .with(padding(), Borders::even(STREET).into())
+        } else {
+            Element::new()
+        },
         if show_debug {
             Dock::el([GameClientWorld.el()])
                 .with_background(vec4(0.2, 0.2, 0.2, 1.))
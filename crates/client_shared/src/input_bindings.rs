@@ -0,0 +1,263 @@
+//! A screen that lets the player rebind the input actions and axes declared by packages.
+use std::str::FromStr;
+
+use ambient_core::asset_cache;
+use ambient_ecs::{
+    generated::{
+        app::components::name,
+        input::components::{
+            input_action_bindings, input_action_id, input_action_package, input_axis_id,
+            input_axis_negative_bindings, input_axis_package, input_axis_positive_bindings,
+        },
+        messages,
+        package::components as package,
+    },
+    query, EntityId, World,
+};
+use ambient_element::{
+    consume_context, element_component, use_frame, use_runtime_message, use_state, Element,
+    ElementComponentExt, Hooks,
+};
+use ambient_input::actions::{axis_slot, input_binding_overrides, InputBindingOverrides};
+use ambient_native_std::asset_cache::SyncAssetKeyExt;
+use ambient_network::client::ClientState;
+use ambient_settings::SettingsKey;
+use ambient_shared_types::{GamepadAxis, GamepadButton, InputBinding, MouseButton, VirtualKeyCode};
+use ambient_ui_native::{
+    space_between_items, Button, ButtonStyle, FlowColumn, FlowRow, StylesExt, Text, STREET,
+};
+
+/// The bindings of an action, or of one direction of an axis.
+#[derive(Debug, Clone, PartialEq)]
+struct BindingSlot {
+    package_id: String,
+    package_name: String,
+    label: String,
+    slot: String,
+    defaults: Vec<InputBinding>,
+}
+
+#[element_component]
+pub fn InputBindingsEditor(hooks: &mut Hooks) -> Element {
+    let (client_state, _) = consume_context::<ClientState>(hooks).unwrap();
+    let (slots, set_slots) = use_state(hooks, Vec::<BindingSlot>::new());
+    // The package ID and slot that the next key or button press will be bound to
+    let (listening, set_listening) = use_state(hooks, None::<(String, String)>);
+
+    // The actions are spawned along with their packages, which can happen at any time
+    use_frame(hooks, {
+        let slots = slots.clone();
+        move |_| {
+            let new_slots = binding_slots(&client_state.game_state.lock().world);
+            if new_slots != slots {
+                set_slots(new_slots);
+            }
+        }
+    });
+
+    let bind = {
+        let listening = listening.clone();
+        let set_listening = set_listening.clone();
+        move |world: &mut World, binding: InputBinding| {
+            if let Some((package_id, slot)) = &listening {
+                update_overrides(world, |overrides| {
+                    overrides.set(package_id, slot, vec![binding])
+                });
+                set_listening(None);
+            }
+        }
+    };
+    use_runtime_message::<messages::WindowKeyboardInput>(hooks, {
+        let bind = bind.clone();
+        let set_listening = set_listening.clone();
+        move |world, event| {
+            let key = event
+                .keycode
+                .as_deref()
+                .and_then(|key| VirtualKeyCode::from_str(key).ok());
+            match key {
+                Some(VirtualKeyCode::Escape) if event.pressed => set_listening(None),
+                Some(key) if event.pressed => bind(world, InputBinding::Key(key)),
+                _ => {}
+            }
+        }
+    });
+    use_runtime_message::<messages::WindowMouseInput>(hooks, {
+        let bind = bind.clone();
+        move |world, event| {
+            if event.pressed {
+                bind(world, InputBinding::Mouse(MouseButton::from(event.button)));
+            }
+        }
+    });
+    use_runtime_message::<messages::WindowGamepadButton>(hooks, {
+        let bind = bind.clone();
+        move |world, event| {
+            if let Ok(button) = GamepadButton::from_str(&event.button) {
+                if event.pressed {
+                    bind(world, InputBinding::Gamepad(button));
+                }
+            }
+        }
+    });
+    use_runtime_message::<messages::WindowGamepadAxis>(hooks, move |world, event| {
+        // Only movement in the positive direction is bound, as that is what drives the slot
+        if let Ok(axis) = GamepadAxis::from_str(&event.axis) {
+            if event.value > 0.5 {
+                bind(world, InputBinding::GamepadAxis(axis));
+            }
+        }
+    });
+
+    let overrides = hooks.world.resource(input_binding_overrides()).clone();
+
+    let mut items = vec![
+        Text::el("Controls").header_style(),
+        Text::el("Press F10 to close").small_style(),
+    ];
+    if slots.is_empty() {
+        items.push(Text::el(
+            "None of the loaded packages have any input actions.",
+        ));
+    }
+
+    let mut current_package = None;
+    for slot in slots {
+        if current_package.as_ref() != Some(&slot.package_id) {
+            items.push(Text::el(slot.package_name.clone()).section_style());
+            current_package = Some(slot.package_id.clone());
+        }
+
+        let key = (slot.package_id, slot.slot);
+        let is_listening = listening.as_ref() == Some(&key);
+        let overridden = overrides.get(&key.0, &key.1);
+        let bindings = overridden.unwrap_or(slot.defaults.as_slice());
+
+        let label = if is_listening {
+            "Press a key or button...".to_string()
+        } else if bindings.is_empty() {
+            "Unbound".to_string()
+        } else {
+            bindings
+                .iter()
+                .map(|binding| binding.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        items.push(
+            FlowRow::el([
+                Text::el(slot.label),
+                Button::new(label, {
+                    let key = key.clone();
+                    let set_listening = set_listening.clone();
+                    move |_| set_listening(Some(key.clone()))
+                })
+                .style(ButtonStyle::Flat)
+                .toggled(is_listening)
+                .el(),
+                if overridden.is_some() {
+                    Button::new("Reset", {
+                        let set_listening = set_listening.clone();
+                        move |world| {
+                            update_overrides(world, |overrides| overrides.reset(&key.0, &key.1));
+                            set_listening(None);
+                        }
+                    })
+                    .style(ButtonStyle::Flat)
+                    .el()
+                } else {
+                    Element::new()
+                },
+            ])
+            .with(space_between_items(), STREET),
+        );
+    }
+
+    FlowColumn::el(items).with(space_between_items(), STREET)
+}
+
+fn binding_slots(world: &World) -> Vec<BindingSlot> {
+    let package_info = |id: EntityId| {
+        Some((
+            world.get_cloned(id, package::id()).ok()?,
+            world.get_cloned(id, package::name()).ok()?,
+        ))
+    };
+    let parse = |bindings: &[String]| {
+        bindings
+            .iter()
+            .filter_map(|binding| InputBinding::from_str(binding).ok())
+            .collect::<Vec<_>>()
+    };
+
+    let mut slots = vec![];
+    for (id, (action, &package_entity, defaults)) in query((
+        input_action_id(),
+        input_action_package(),
+        input_action_bindings(),
+    ))
+    .iter(world, None)
+    {
+        let Some((package_id, package_name)) = package_info(package_entity) else {
+            continue;
+        };
+        slots.push(BindingSlot {
+            package_id,
+            package_name,
+            label: world
+                .get_cloned(id, name())
+                .unwrap_or_else(|_| action.clone()),
+            slot: action.clone(),
+            defaults: parse(defaults),
+        });
+    }
+    for (id, (axis, &package_entity, positive, negative)) in query((
+        input_axis_id(),
+        input_axis_package(),
+        input_axis_positive_bindings(),
+        input_axis_negative_bindings(),
+    ))
+    .iter(world, None)
+    {
+        let Some((package_id, package_name)) = package_info(package_entity) else {
+            continue;
+        };
+        let label = world
+            .get_cloned(id, name())
+            .unwrap_or_else(|_| axis.clone());
+        for (is_positive, defaults) in [(true, positive), (false, negative)] {
+            slots.push(BindingSlot {
+                package_id: package_id.clone(),
+                package_name: package_name.clone(),
+                label: format!("{label} ({})", if is_positive { "+" } else { "-" }),
+                slot: axis_slot(axis, is_positive),
+                defaults: parse(defaults),
+            });
+        }
+    }
+
+    slots.sort_by(|a, b| {
+        (&a.package_name, &a.package_id, &a.label).cmp(&(&b.package_name, &b.package_id, &b.label))
+    });
+    slots
+}
+
+/// Applies the change to the player's bindings, and saves them to their settings.
+///
+/// The web client has no settings file, so its bindings are only kept for the rest of the
+/// session and reset to the packages' defaults when the page is reloaded.
+fn update_overrides(world: &mut World, update: impl FnOnce(&mut InputBindingOverrides)) {
+    let overrides = world.resource_mut(input_binding_overrides());
+    update(overrides);
+    let bindings = overrides.to_raw();
+
+    let assets = world.resource(asset_cache()).clone();
+    let mut settings = SettingsKey.get(&assets);
+    settings.input.bindings = bindings;
+    #[cfg(not(target_os = "unknown"))]
+    if let Err(err) = settings.write_to_file(None) {
+        tracing::error!("Failed to save the input bindings: {err:?}");
+    }
+    SettingsKey.insert(&assets, settings);
+}
@@ -1,3 +1,4 @@
 pub mod game_view;
+pub mod input_bindings;
 pub mod player;
 pub mod util;
@@ -1,13 +1,29 @@
 use std::str::FromStr;
 
 use ambient_core::window::cursor_position;
-use ambient_ecs::{generated::messages, query_mut, SystemGroup, World};
-use ambient_element::{element_component, use_runtime_message, use_state, Element, Hooks};
-use ambient_input::{player_prev_raw_input, player_raw_input, PlayerRawInput};
+use ambient_ecs::{generated::messages, query_mut, EntityId, SystemGroup, World};
+use ambient_element::{
+    element_component, use_frame, use_runtime_message, use_state, Element, Hooks,
+};
+use ambient_input::{
+    actions::input_binding_overrides,
+    gamepad::{connected_gamepads, GamepadState},
+    player_prev_raw_input, player_raw_input,
+    touch::touches,
+    PlayerRawInput,
+};
 use ambient_network::client::client_state;
-use ambient_shared_types::VirtualKeyCode;
+use ambient_shared_types::{GamepadAxis, GamepadButton, VirtualKeyCode};
 use glam::Vec2;
 
+/// Resolves the input actions and axes, which should happen before the modules run.
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "player/client_systems",
+        vec![Box::new(ambient_input::actions::client_systems())],
+    )
+}
+
 pub fn systems_final() -> SystemGroup {
     SystemGroup::new(
         "player/client_systems_final",
@@ -78,6 +94,74 @@ pub fn PlayerRawInputHandler(hooks: &mut Hooks) -> Element {
         });
     });
 
+    // The gamepads are synced every frame rather than when they are connected, as they may have
+    // been connected before the game client was
+    use_frame(hooks, |world| {
+        let connected = world.resource(connected_gamepads()).clone();
+        process_input(world, true, |input, _| {
+            input.gamepads.retain(|id, _| connected.contains_key(id));
+            for (id, name) in &connected {
+                input
+                    .gamepads
+                    .entry(*id)
+                    .or_insert_with(|| GamepadState::new(name.clone()));
+            }
+        });
+    });
+
+    // The touches are synced every frame, as their gestures are recognized between frames
+    use_frame(hooks, |world| {
+        let Some(Some(gc)) = world.resource_opt(client_state()).cloned() else {
+            return;
+        };
+        gc.with_physics_world(|w| {
+            let touches = w.resource(touches()).clone();
+            w.resource_mut(player_raw_input()).set_touches(&touches);
+        });
+    });
+
+    // The binding overrides live in the app world, where the rebinding screen changes them
+    use_frame(hooks, |world| {
+        let Some(Some(gc)) = world.resource_opt(client_state()).cloned() else {
+            return;
+        };
+        let overrides = world.resource(input_binding_overrides()).clone();
+        gc.with_physics_world(|w| {
+            w.set_if_changed(
+                EntityId::resources(),
+                input_binding_overrides(),
+                overrides.clone(),
+            )
+            .unwrap();
+        });
+    });
+
+    use_runtime_message::<messages::WindowGamepadButton>(hooks, move |world, event| {
+        process_input(world, has_focus, |input, _| {
+            let Ok(button) = GamepadButton::from_str(&event.button) else {
+                return;
+            };
+            if let Some(gamepad) = input.gamepads.get_mut(&event.gamepad) {
+                if event.pressed {
+                    gamepad.buttons.insert(button);
+                } else {
+                    gamepad.buttons.remove(&button);
+                }
+            }
+        });
+    });
+
+    use_runtime_message::<messages::WindowGamepadAxis>(hooks, move |world, event| {
+        process_input(world, has_focus, |input, _| {
+            let Ok(axis) = GamepadAxis::from_str(&event.axis) else {
+                return;
+            };
+            if let Some(gamepad) = input.gamepads.get_mut(&event.gamepad) {
+                gamepad.set_axis(axis, event.value);
+            }
+        });
+    });
+
     fn process_input(
         ui_world: &World,
         has_focus: bool,
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use ambient_ecs::{components, Description, Name, Resource, World};
 use ambient_native_std::math::interpolate;
 use glam::{uvec2, vec2, UVec2, Vec2, Vec3, Vec3Swizzles};
@@ -59,6 +61,16 @@ pub enum WindowCtl {
     ShowCursor(bool),
     SetTitle(String),
     SetFullscreen(bool),
+    /// Rumbles a gamepad. `strong` and `weak` are the strengths of the low- and high-frequency
+    /// motors, from 0 to 1.
+    RumbleGamepad {
+        gamepad: u32,
+        strong: f32,
+        weak: f32,
+        duration: Duration,
+    },
+    /// Makes the first touch on the window move the cursor and hold down the left mouse button.
+    SetTouchMouseEmulation(bool),
     ExitProcess(ExitStatus),
 }
 
@@ -9,7 +9,13 @@ use ambient_core::{
     player::local_user_id,
     runtime, timing,
 };
-use ambient_ecs::{query, World};
+use ambient_ecs::{
+    generated::wasm::components::{
+        is_module_on_server, module_fuel_consumed, module_host_calls, module_message_names,
+        module_message_run_times, module_name, module_run_time,
+    },
+    query, World,
+};
 use ambient_element::{
     consume_context, element_component, use_frame, use_state, use_state_with, Element,
     ElementComponentExt, Hooks,
@@ -304,6 +310,10 @@ pub fn Debugger(hooks: &mut Hooks, get_state: GetDebuggerState) -> Element {
                         get_state: get_state.clone(),
                     }
                     .el(),
+                    ModuleProfiler {
+                        get_state: get_state.clone(),
+                    }
+                    .el(),
                     // Button::new("Dump Internal UI World", {
                     //     move |world| {
                     //         dump_world_hierarchy_to_tmp_file(world);
@@ -438,3 +448,81 @@ fn ShaderDebug(hooks: &mut Hooks, get_state: GetDebuggerState) -> Element {
     }
     .el()
 }
+
+#[derive(Debug, Clone)]
+struct ModuleProfile {
+    name: String,
+    on_server: bool,
+    run_time: f32,
+    fuel_consumed: u64,
+    host_calls: u64,
+    slowest_message: Option<(String, f32)>,
+}
+
+/// Lists the modules that spent the most time running over the last second.
+#[element_component]
+fn ModuleProfiler(hooks: &mut Hooks, get_state: GetDebuggerState) -> Element {
+    let (show, set_show) = use_state(hooks, false);
+    let (profiles, set_profiles) = use_state(hooks, Vec::<ModuleProfile>::new());
+
+    use_frame(hooks, {
+        let get_state = get_state.clone();
+        move |_| {
+            if !show {
+                return;
+            }
+            let mut profiles = Vec::new();
+            get_state(&mut |_, _, world| {
+                for (id, (name, run_time)) in
+                    query((module_name(), module_run_time())).iter(world, None)
+                {
+                    let slowest_message = world
+                        .get_ref(id, module_message_names())
+                        .ok()
+                        .and_then(|names| names.first().cloned())
+                        .zip(
+                            world
+                                .get_ref(id, module_message_run_times())
+                                .ok()
+                                .and_then(|times| times.first().copied()),
+                        );
+                    profiles.push(ModuleProfile {
+                        name: name.clone(),
+                        on_server: world.has_component(id, is_module_on_server()),
+                        run_time: *run_time,
+                        fuel_consumed: world.get(id, module_fuel_consumed()).unwrap_or_default(),
+                        host_calls: world.get(id, module_host_calls()).unwrap_or_default(),
+                        slowest_message,
+                    });
+                }
+            });
+            profiles.sort_by(|a, b| b.run_time.total_cmp(&a.run_time));
+            set_profiles(profiles);
+        }
+    });
+
+    Dropdown {
+        content: Button::new("Module Profiler", move |_| set_show(!show))
+            .toggled(show)
+            .el(),
+        dropdown: FlowColumn::el(profiles.iter().map(|profile| {
+            let side = if profile.on_server {
+                "server"
+            } else {
+                "client"
+            };
+            let slowest = profile
+                .slowest_message
+                .as_ref()
+                .map(|(name, time)| format!(", slowest: {name} {time:.2} ms"))
+                .unwrap_or_default();
+            Text::el(format!(
+                "{} ({side}): {:.2} ms/s, {} fuel, {} host calls{slowest}",
+                profile.name, profile.run_time, profile.fuel_consumed, profile.host_calls
+            ))
+        }))
+        .with_background(Color::rgba(0., 0., 0., 0.9).into()),
+        show,
+    }
+    .el()
+}
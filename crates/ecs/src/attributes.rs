@@ -238,6 +238,72 @@ where
     }
 }
 
+/// Predict the component on the client for entities owned by the local player
+///
+/// Values from the server are reconciled with the local prediction instead of replacing it.
+/// Only has an effect on `Networked` components.
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Predicted;
+impl ComponentAttribute for Predicted {}
+impl<T: ComponentValue> AttributeConstructor<T, ()> for Predicted {
+    fn construct(store: &mut AttributeStore, _: ()) {
+        store.set(Self)
+    }
+}
+
+/// Interpolate the component on the client between the values received from the server
+///
+/// The component is rendered slightly behind the server, and extrapolated when updates are late.
+/// Only has an effect on `Networked` components of entities that are not owned by the local player.
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interpolated;
+impl ComponentAttribute for Interpolated {}
+impl<T: ComponentValue> AttributeConstructor<T, ()> for Interpolated {
+    fn construct(store: &mut AttributeStore, _: ()) {
+        store.set(Self)
+    }
+}
+
+/// The component is a color
+///
+/// Colors are sent to clients with half precision, which is more than enough to display them.
+/// Only has an effect on `Networked` components with float or vector values.
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Color;
+impl ComponentAttribute for Color {}
+impl<T: ComponentValue> AttributeConstructor<T, ()> for Color {
+    fn construct(store: &mut AttributeStore, _: ()) {
+        store.set(Self)
+    }
+}
+
+/// How urgently changes to the component are sent to clients
+///
+/// When the changes to send exceed a player's bandwidth budget, each pending change accumulates
+/// its component's `priority` every tick until it is sent, and the changes with the highest
+/// accumulated priority are sent first. Components without this attribute have a priority of 1.
+/// If `max_rate` is set, changes to the component are sent at most that many times per second
+/// per entity. Only has an effect on `Networked` components.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NetworkPriority {
+    pub priority: f32,
+    pub max_rate: Option<f32>,
+}
+impl Default for NetworkPriority {
+    fn default() -> Self {
+        Self {
+            priority: 1.,
+            max_rate: None,
+        }
+    }
+}
+impl ComponentAttribute for NetworkPriority {}
+impl<T: ComponentValue> AttributeConstructor<T, (f32, Option<f32>)> for NetworkPriority {
+    fn construct(store: &mut AttributeStore, (priority, max_rate): (f32, Option<f32>)) {
+        store.set(Self { priority, max_rate })
+    }
+}
+
 pub(crate) struct ComponentPath(pub String);
 impl ComponentAttribute for ComponentPath {}
 
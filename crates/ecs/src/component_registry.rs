@@ -44,6 +44,7 @@ pub struct ExternalComponentDesc {
     pub name: Option<String>,
     pub description: Option<String>,
     pub attributes: ExternalComponentAttributes,
+    pub network_priority: Option<NetworkPriority>,
 }
 
 impl From<&PrimitiveComponent> for ExternalComponentDesc {
@@ -54,6 +55,7 @@ impl From<&PrimitiveComponent> for ExternalComponentDesc {
             name: pc.desc.attribute::<Name>().map(|n| n.0.clone()),
             description: pc.desc.attribute::<Description>().map(|n| n.0.clone()),
             attributes: ExternalComponentAttributes::from_existing_component(pc.desc),
+            network_priority: pc.desc.attribute::<NetworkPriority>().map(|p| *p),
         }
     }
 }
@@ -118,7 +120,10 @@ define_external_component_attribute! {
         networked: Networked,
         resource: Resource,
         store: Store,
-        maybe_resource: MaybeResource
+        maybe_resource: MaybeResource,
+        predicted: Predicted,
+        interpolated: Interpolated,
+        color: Color
     },
     special: {
         enum_: Enum
@@ -151,6 +156,7 @@ impl ComponentRegistry {
                 desc.name.as_deref(),
                 desc.description.as_deref(),
                 desc.attributes,
+                desc.network_priority,
             );
         }
 
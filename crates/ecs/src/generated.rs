@@ -21,8 +21,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -37,8 +37,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -53,8 +53,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -62,15 +62,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("audio" , { # [doc = "**Is audio player**: The entity is an audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is audio player"] , Description ["The entity is an audio player."]] is_audio_player : () , # [doc = "**Is spatial audio player**: The entity is a spatial audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is spatial audio player"] , Description ["The entity is a spatial audio player."]] is_spatial_audio_player : () , # [doc = "**Spatial audio emitter**: The entity is a spatial audio emitter.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio emitter"] , Description ["The entity is a spatial audio emitter."]] spatial_audio_emitter : EntityId , # [doc = "**Spatial audio listener**: The entity is a spatial audio listener.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio listener"] , Description ["The entity is a spatial audio listener."]] spatial_audio_listener : EntityId , # [doc = "**Looping**: Whether or not the audio should loop.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Looping"] , Description ["Whether or not the audio should loop.\n"]] looping : bool , # [doc = "**One pole low pass filter**: With this component, the audio will be filtered with a one pole low pass filter.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["One pole low pass filter"] , Description ["With this component, the audio will be filtered with a one pole low pass filter.\n"]] onepole_lpf : f32 , # [doc = "**Playing sound**: The entity with this comp is a playing sound.\n\nWe can attach other components to it to control the sound parameters.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Playing sound"] , Description ["The entity with this comp is a playing sound.\nWe can attach other components to it to control the sound parameters.\n"]] playing_sound : () , # [doc = "**Amplitude**: The amplitude of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Amplitude"] , Description ["The amplitude of the audio.\n"]] amplitude : f32 , # [doc = "**Panning**: The panning of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Panning"] , Description ["The panning of the audio.\n"]] panning : f32 , # [doc = "**Low_pass filter**: Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Low_pass filter"] , Description ["Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] lpf : Vec2 , # [doc = "**High_pass filter**: High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["High_pass filter"] , Description ["High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] hpf : Vec2 , # [doc = "**Audio URL**: The URL of the assets.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio URL"] , Description ["The URL of the assets.\n"]] audio_url : String , # [doc = "**Trigger at this frame**: The system will watch for this component and PLAY the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Trigger at this frame"] , Description ["The system will watch for this component and PLAY the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] play_now : () , # [doc = "**Stop at this frame**: The system will watch for this component and STOP the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Stop at this frame"] , Description ["The system will watch for this component and STOP the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] stop_now : () , });
+                components ! ("audio" , { # [doc = "**Is audio player**: The entity is an audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is audio player"] , Description ["The entity is an audio player."]] is_audio_player : () , # [doc = "**Is spatial audio player**: The entity is a spatial audio player.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is spatial audio player"] , Description ["The entity is a spatial audio player."]] is_spatial_audio_player : () , # [doc = "**Spatial audio emitter**: The entity is a spatial audio emitter.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio emitter"] , Description ["The entity is a spatial audio emitter."]] spatial_audio_emitter : EntityId , # [doc = "**Spatial audio listener**: The entity is a spatial audio listener.\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Spatial audio listener"] , Description ["The entity is a spatial audio listener."]] spatial_audio_listener : EntityId , # [doc = "**Looping**: Whether or not the audio should loop.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Looping"] , Description ["Whether or not the audio should loop.\n"]] looping : bool , # [doc = "**Audio streaming**: If true, the audio is decoded from the asset cache while it plays, instead of being loaded into memory first.\n\nUse this for long sounds, such as music and ambience. Not supported on the web, where the audio is loaded into memory.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio streaming"] , Description ["If true, the audio is decoded from the asset cache while it plays, instead of being loaded into memory first.\nUse this for long sounds, such as music and ambience. Not supported on the web, where the audio is loaded into memory.\n"]] audio_streaming : bool , # [doc = "**One pole low pass filter**: With this component, the audio will be filtered with a one pole low pass filter.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["One pole low pass filter"] , Description ["With this component, the audio will be filtered with a one pole low pass filter.\n"]] onepole_lpf : f32 , # [doc = "**Playing sound**: The entity with this comp is a playing sound.\n\nWe can attach other components to it to control the sound parameters.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Playing sound"] , Description ["The entity with this comp is a playing sound.\nWe can attach other components to it to control the sound parameters.\n"]] playing_sound : () , # [doc = "**Amplitude**: The amplitude of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Amplitude"] , Description ["The amplitude of the audio.\n"]] amplitude : f32 , # [doc = "**Panning**: The panning of the audio.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Panning"] , Description ["The panning of the audio.\n"]] panning : f32 , # [doc = "**Low_pass filter**: Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Low_pass filter"] , Description ["Low pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] lpf : Vec2 , # [doc = "**High_pass filter**: High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["High_pass filter"] , Description ["High pass filter. The first value is the cutoff frequency, the second is the bandwidth.\n"]] hpf : Vec2 , # [doc = "**Audio URL**: The URL of the assets.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio URL"] , Description ["The URL of the assets.\n"]] audio_url : String , # [doc = "**Trigger at this frame**: The system will watch for this component and PLAY the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Trigger at this frame"] , Description ["The system will watch for this component and PLAY the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] play_now : () , # [doc = "**Stop at this frame**: The system will watch for this component and STOP the audio at this frame,\n\nusing the other components as parameters.\n\nThen set it back to false.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Stop at this frame"] , Description ["The system will watch for this component and STOP the audio at this frame,\nusing the other components as parameters.\nThen set it back to false.\n"]] stop_now : () , # [doc = "**Audio bus**: On an audio player, the bus that its sounds are played on, such as `music`, `sfx`, `voice` or `ui`. Sounds are played on the `sfx` bus if this is not set.\n\nOn an entity with `is_audio_bus`, the bus that the entity controls. The `master` bus is the bus that all other buses are mixed into.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus"] , Description ["On an audio player, the bus that its sounds are played on, such as `music`, `sfx`, `voice` or `ui`. Sounds are played on the `sfx` bus if this is not set.\nOn an entity with `is_audio_bus`, the bus that the entity controls. The `master` bus is the bus that all other buses are mixed into.\n"]] audio_bus : String , # [doc = "**Is audio bus**: The entity controls the audio bus named by its `audio_bus` component with its other `audio_bus_*` components.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is audio bus"] , Description ["The entity controls the audio bus named by its `audio_bus` component with its other `audio_bus_*` components.\n"]] is_audio_bus : () , # [doc = "**Audio bus volume**: The volume of the audio bus, which is multiplied with the volume that the player chose in their settings.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus volume"] , Description ["The volume of the audio bus, which is multiplied with the volume that the player chose in their settings.\n"]] audio_bus_volume : f32 , # [doc = "**Audio bus muted**: Whether or not the audio bus is muted.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus muted"] , Description ["Whether or not the audio bus is muted.\n"]] audio_bus_muted : bool , # [doc = "**Audio bus high pass**: The cutoff frequency of a high pass filter applied to the audio bus, in Hz. It is applied before `audio_bus_low_pass`.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus high pass"] , Description ["The cutoff frequency of a high pass filter applied to the audio bus, in Hz. It is applied before `audio_bus_low_pass`.\n"]] audio_bus_high_pass : f32 , # [doc = "**Audio bus low pass**: The cutoff frequency of a low pass filter applied to the audio bus, in Hz.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus low pass"] , Description ["The cutoff frequency of a low pass filter applied to the audio bus, in Hz.\n"]] audio_bus_low_pass : f32 , # [doc = "**Audio bus ducked by**: The name of the bus that ducks this audio bus: while it is playing, the volume of this bus is lowered to `audio_bus_duck_gain`.\n\nFor example, the `music` bus can be ducked by the `voice` bus so that dialogue can be heard over the music.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus ducked by"] , Description ["The name of the bus that ducks this audio bus: while it is playing, the volume of this bus is lowered to `audio_bus_duck_gain`.\nFor example, the `music` bus can be ducked by the `voice` bus so that dialogue can be heard over the music.\n"]] audio_bus_ducked_by : String , # [doc = "**Audio bus duck gain**: The gain that the audio bus is lowered to while it is ducked, from 0 to 1. Defaults to 0.3.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus duck gain"] , Description ["The gain that the audio bus is lowered to while it is ducked, from 0 to 1. Defaults to 0.3.\n"]] audio_bus_duck_gain : f32 , # [doc = "**Audio bus duck attack**: How long it takes to lower the volume of the audio bus when it is ducked, in seconds. Defaults to 0.05.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus duck attack"] , Description ["How long it takes to lower the volume of the audio bus when it is ducked, in seconds. Defaults to 0.05.\n"]] audio_bus_duck_attack : f32 , # [doc = "**Audio bus duck release**: How long it takes to restore the volume of the audio bus after it is no longer ducked, in seconds. Defaults to 0.5.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio bus duck release"] , Description ["How long it takes to restore the volume of the audio bus after it is no longer ducked, in seconds. Defaults to 0.5.\n"]] audio_bus_duck_release : f32 , # [doc = "**Is reverb zone**: The entity is a spherical reverb zone, centered on its position with a radius of `reverb_zone_radius`.\n\nWhile the spatial audio listener is inside the zone, spatial sounds are reverberated with the `reverb_*` components of the zone. If the listener is inside several zones, the smallest one is used.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Is reverb zone"] , Description ["The entity is a spherical reverb zone, centered on its position with a radius of `reverb_zone_radius`.\nWhile the spatial audio listener is inside the zone, spatial sounds are reverberated with the `reverb_*` components of the zone. If the listener is inside several zones, the smallest one is used.\n"]] is_reverb_zone : () , # [doc = "**Reverb zone radius**: The radius of the reverb zone.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Reverb zone radius"] , Description ["The radius of the reverb zone.\n"]] reverb_zone_radius : f32 , # [doc = "**Reverb room size**: How large the room of the reverb zone sounds, from 0 to 1. Larger rooms have longer reverb tails. Defaults to 0.5.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Reverb room size"] , Description ["How large the room of the reverb zone sounds, from 0 to 1. Larger rooms have longer reverb tails. Defaults to 0.5.\n"]] reverb_room_size : f32 , # [doc = "**Reverb damping**: How quickly the high frequencies of the reverb are absorbed, from 0 to 1. Defaults to 0.5.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Reverb damping"] , Description ["How quickly the high frequencies of the reverb are absorbed, from 0 to 1. Defaults to 0.5.\n"]] reverb_damping : f32 , # [doc = "**Reverb wet**: The level of the reverb that is added to spatial sounds, from 0 to 1. Defaults to 0.3.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Reverb wet"] , Description ["The level of the reverb that is added to spatial sounds, from 0 to 1. Defaults to 0.3.\n"]] reverb_wet : f32 , # [doc = "**Audio occluder**: The entity blocks sound. Spatial sounds that are behind its bounding box, as seen from the listener, are muffled.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio occluder"] , Description ["The entity blocks sound. Spatial sounds that are behind its bounding box, as seen from the listener, are muffled.\n"]] audio_occluder : () , # [doc = "**Audio occlusion**: How much the spatial sound emitted by this entity is blocked from the listener by occluders, from 0 (not at all) to 1 (fully). Partial values mean that the sound is obstructed, such as by the edge of a wall.\n\nThis is set by the runtime.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio occlusion"] , Description ["How much the spatial sound emitted by this entity is blocked from the listener by occluders, from 0 (not at all) to 1 (fully). Partial values mean that the sound is obstructed, such as by the edge of a wall.\nThis is set by the runtime.\n"]] audio_occlusion : f32 , # [doc = "**Audio occlusion low pass**: On a spatial audio player, the cutoff frequency of the low pass filter applied to its sounds when they are fully occluded, in Hz. Defaults to 800.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio occlusion low pass"] , Description ["On a spatial audio player, the cutoff frequency of the low pass filter applied to its sounds when they are fully occluded, in Hz. Defaults to 800.\n"]] audio_occlusion_low_pass : f32 , # [doc = "**Audio occlusion gain**: On a spatial audio player, the gain of its sounds when they are fully occluded, from 0 to 1. Defaults to 0.4.\n\n\n\n*Attributes*: MaybeResource, Debuggable"] @ [MaybeResource , Debuggable , Name ["Audio occlusion gain"] , Description ["On a spatial audio player, the gain of its sounds when they are fully occluded, from 0 to 1. Defaults to 0.4.\n"]] audio_occlusion_gain : f32 , });
             }
         }
         pub mod camera {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -85,8 +85,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -101,8 +101,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -117,8 +117,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -126,7 +126,7 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("input" , { # [doc = "**Mouse over entity**: The entity the mouse is currently over.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Mouse over entity"] , Description ["The entity the mouse is currently over."]] mouse_over_entity : EntityId , # [doc = "**Mouse over distance**: This distance to the entity that the mouse is currently over.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Mouse over distance"] , Description ["This distance to the entity that the mouse is currently over."]] mouse_over_distance : f32 , # [doc = "**Mouse over**: The number of mouse cursors that are currently over this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Mouse over"] , Description ["The number of mouse cursors that are currently over this entity."]] is_mouse_over : u32 , # [doc = "**Mouse pickable max**: This entity can be clicked by the mouse, and this component defines the max AABB bound of the click area.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Mouse pickable max"] , Description ["This entity can be clicked by the mouse, and this component defines the max AABB bound of the click area."]] mouse_pickable_max : Vec3 , # [doc = "**Mouse pickable min**: This entity can be clicked by the mouse, and this component defines the min AABB bound of the click area.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Mouse pickable min"] , Description ["This entity can be clicked by the mouse, and this component defines the min AABB bound of the click area."]] mouse_pickable_min : Vec3 , });
+                components ! ("input" , { # [doc = "**Mouse over entity**: The entity the mouse is currently over.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Mouse over entity"] , Description ["The entity the mouse is currently over."]] mouse_over_entity : EntityId , # [doc = "**Mouse over distance**: This distance to the entity that the mouse is currently over.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Mouse over distance"] , Description ["This distance to the entity that the mouse is currently over."]] mouse_over_distance : f32 , # [doc = "**Mouse over**: The number of mouse cursors that are currently over this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Mouse over"] , Description ["The number of mouse cursors that are currently over this entity."]] is_mouse_over : u32 , # [doc = "**Mouse pickable max**: This entity can be clicked by the mouse, and this component defines the max AABB bound of the click area.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Mouse pickable max"] , Description ["This entity can be clicked by the mouse, and this component defines the max AABB bound of the click area."]] mouse_pickable_max : Vec3 , # [doc = "**Mouse pickable min**: This entity can be clicked by the mouse, and this component defines the min AABB bound of the click area.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Mouse pickable min"] , Description ["This entity can be clicked by the mouse, and this component defines the min AABB bound of the click area."]] mouse_pickable_min : Vec3 , # [doc = "**Touch IDs**: The IDs of the touches that started on this entity and have not ended yet. Entities with `mouse_pickable_min` and `mouse_pickable_max` capture the touches that start on them, even if they move off of them.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Touch IDs"] , Description ["The IDs of the touches that started on this entity and have not ended yet. Entities with `mouse_pickable_min` and `mouse_pickable_max` capture the touches that start on them, even if they move off of them."]] touch_ids : Vec :: < u64 > , # [doc = "**Touch positions**: The positions of the touches in `touch_ids`, in the same order, in pixels from the top left of the window.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Touch positions"] , Description ["The positions of the touches in `touch_ids`, in the same order, in pixels from the top left of the window."]] touch_positions : Vec :: < Vec2 > , # [doc = "**Input action ID**: The ID of this input action, as declared in the `actions` section of its package's manifest.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Input action ID"] , Description ["The ID of this input action, as declared in the `actions` section of its package's manifest."]] input_action_id : String , # [doc = "**Input action package**: The package that declared this input action.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Input action package"] , Description ["The package that declared this input action."]] input_action_package : EntityId , # [doc = "**Input action bindings**: The default bindings of this input action, like `Key:Space` or `Gamepad:South`. Players can override them through their settings.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Input action bindings"] , Description ["The default bindings of this input action, like `Key:Space` or `Gamepad:South`. Players can override them through their settings."]] input_action_bindings : Vec :: < String > , # [doc = "**Input action pressed**: Whether or not any of the bindings of this input action are currently pressed. Only available on the client.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Input action pressed"] , Description ["Whether or not any of the bindings of this input action are currently pressed. Only available on the client."]] input_action_pressed : bool , # [doc = "**Input axis ID**: The ID of this input axis, as declared in the `axes` section of its package's manifest.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Input axis ID"] , Description ["The ID of this input axis, as declared in the `axes` section of its package's manifest."]] input_axis_id : String , # [doc = "**Input axis package**: The package that declared this input axis.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Input axis package"] , Description ["The package that declared this input axis."]] input_axis_package : EntityId , # [doc = "**Input axis positive bindings**: The default bindings that move this input axis towards 1. Players can override them through their settings.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Input axis positive bindings"] , Description ["The default bindings that move this input axis towards 1. Players can override them through their settings."]] input_axis_positive_bindings : Vec :: < String > , # [doc = "**Input axis negative bindings**: The default bindings that move this input axis towards -1. Players can override them through their settings.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Input axis negative bindings"] , Description ["The default bindings that move this input axis towards -1. Players can override them through their settings."]] input_axis_negative_bindings : Vec :: < String > , # [doc = "**Input axis value**: The current value of this input axis, from -1 to 1. Only available on the client.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Input axis value"] , Description ["The current value of this input axis, from -1 to 1. Only available on the client."]] input_axis_value : f32 , });
             }
             #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
             #[doc = r" and with other modules."]
@@ -212,8 +212,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -525,8 +525,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -541,8 +541,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -550,15 +550,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("network" , { # [doc = "**Is remote entity**: If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server).\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is remote entity"] , Description ["If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server)."]] is_remote_entity : () , # [doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is persistent resources"] , Description ["If attached, this entity contains global resources that are persisted to disk and synchronized to clients."]] is_persistent_resources : () , # [doc = "**Is synchronized resources**: If attached, this entity contains global resources that are synchronized to clients, but not persisted.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is synchronized resources"] , Description ["If attached, this entity contains global resources that are synchronized to clients, but not persisted."]] is_synced_resources : () , # [doc = "**No sync**: If attached, this entity will not be synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["No sync"] , Description ["If attached, this entity will not be synchronized to clients."]] no_sync : () , });
+                components ! ("network" , { # [doc = "**Bandwidth budget**: Server-side. The number of bytes per second of component changes to send to each player; unlimited if not set.\n\nCan be set as a resource to apply to all players, or attached to a player entity to override it for that player.\n\nWhen the changes exceed the budget, the changes with the highest accumulated `NetworkPriority` are sent first, and the rest are delayed.\n\n*Attributes*: Debuggable, MaybeResource"] @ [Debuggable , MaybeResource , Name ["Bandwidth budget"] , Description ["Server-side. The number of bytes per second of component changes to send to each player; unlimited if not set.\nCan be set as a resource to apply to all players, or attached to a player entity to override it for that player.\nWhen the changes exceed the budget, the changes with the highest accumulated `NetworkPriority` are sent first, and the rest are delayed."]] bandwidth_budget : u32 , # [doc = "**Input sequence**: The sequence number of the last message sent from this client to the server.\n\nEvery message sent to the server is numbered, and the server acknowledges them with `input_sequence_ack`.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Input sequence"] , Description ["The sequence number of the last message sent from this client to the server.\nEvery message sent to the server is numbered, and the server acknowledges them with `input_sequence_ack`."]] input_sequence : u64 , # [doc = "**Input sequence acknowledgement**: Attached to player entities. The sequence number of the last message from the player's client that has been processed by the server.\n\nThe client uses this to reconcile its `Predicted` components with the values from the server.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Input sequence acknowledgement"] , Description ["Attached to player entities. The sequence number of the last message from the player's client that has been processed by the server.\nThe client uses this to reconcile its `Predicted` components with the values from the server."]] input_sequence_ack : u64 , # [doc = "**Interpolate transform**: If attached, the `translation`, `rotation` and `scale` of this entity are interpolated on the client, as if they had the `Interpolated` attribute.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Interpolate transform"] , Description ["If attached, the `translation`, `rotation` and `scale` of this entity are interpolated on the client, as if they had the `Interpolated` attribute."]] interpolate_transform : () , # [doc = "**Interpolation delay**: Client-side resource. How far behind the most recent updates from the server `Interpolated` components are rendered, in seconds.\n\nHigher values make interpolation more robust to late and lost updates, at the cost of latency. Defaults to 0.1 seconds.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Interpolation delay"] , Description ["Client-side resource. How far behind the most recent updates from the server `Interpolated` components are rendered, in seconds.\nHigher values make interpolation more robust to late and lost updates, at the cost of latency. Defaults to 0.1 seconds."]] interpolation_delay : f32 , # [doc = "**Interpolation extrapolation limit**: Client-side resource. How far past the most recent update from the server `Interpolated` components may be extrapolated when updates are late, in seconds.\n\nDefaults to 0.25 seconds. Set to 0 to disable extrapolation.\n\n*Attributes*: Debuggable, Resource"] @ [Debuggable , Resource , Name ["Interpolation extrapolation limit"] , Description ["Client-side resource. How far past the most recent update from the server `Interpolated` components may be extrapolated when updates are late, in seconds.\nDefaults to 0.25 seconds. Set to 0 to disable extrapolation."]] interpolation_extrapolation_limit : f32 , # [doc = "**Server frame time**: Synced resource. The `game_time` of the server's most recent frame. Clients interpolate `Interpolated` components on the server's timeline, using this to tell when each update was produced.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Server frame time"] , Description ["Synced resource. The `game_time` of the server's most recent frame. Clients interpolate `Interpolated` components on the server's timeline, using this to tell when each update was produced."]] server_frame_time : Duration , # [doc = "**Is remote entity**: If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server).\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is remote entity"] , Description ["If attached, this entity was not spawned locally (e.g. if this is the client, it was spawned by the server)."]] is_remote_entity : () , # [doc = "**Is persistent resources**: If attached, this entity contains global resources that are persisted to disk and synchronized to clients.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is persistent resources"] , Description ["If attached, this entity contains global resources that are persisted to disk and synchronized to clients."]] is_persistent_resources : () , # [doc = "**Is synchronized resources**: If attached, this entity contains global resources that are synchronized to clients, but not persisted.\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Is synchronized resources"] , Description ["If attached, this entity contains global resources that are synchronized to clients, but not persisted."]] is_synced_resources : () , # [doc = "**No sync**: If attached, this entity will not be synchronized to clients.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["No sync"] , Description ["If attached, this entity will not be synchronized to clients."]] no_sync : () , # [doc = "**Relevance radius**: If attached, this entity will only be synchronized to players whose viewpoint is within this distance of it.\n\nThe viewpoint of a player is the position of their active camera on the server if there is one, or the position of the player entity otherwise.\n\nEntities are spawned and despawned on the client as they enter and leave the radius.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Relevance radius"] , Description ["If attached, this entity will only be synchronized to players whose viewpoint is within this distance of it.\nThe viewpoint of a player is the position of their active camera on the server if there is one, or the position of the player entity otherwise.\nEntities are spawned and despawned on the client as they enter and leave the radius."]] relevance_radius : f32 , # [doc = "**Relevance team**: If attached, this entity will only be synchronized to players whose player entity has the same `relevance_team`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Relevance team"] , Description ["If attached, this entity will only be synchronized to players whose player entity has the same `relevance_team`."]] relevance_team : u32 , # [doc = "**Relevance user IDs**: If attached, this entity will only be synchronized to the players with these user IDs.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Relevance user IDs"] , Description ["If attached, this entity will only be synchronized to the players with these user IDs."]] relevance_user_ids : Vec :: < String > , });
             }
         }
         pub mod package {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -715,8 +715,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -724,15 +724,350 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("physics" , { # [doc = "**Angular velocity**: Angular velocity (radians/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's angular velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Angular velocity"] , Description ["Angular velocity (radians/second) of this entity in the physics scene.\nUpdating this component will update the entity's angular velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead."]] angular_velocity : Vec3 , # [doc = "**Cube collider**: If attached, this entity will have a cube physics collider.\n\n`x, y, z` is the size of the cube.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cube collider"] , Description ["If attached, this entity will have a cube physics collider.\n`x, y, z` is the size of the cube."]] cube_collider : Vec3 , # [doc = "**Character controller height**: The height of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller height"] , Description ["The height of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider."]] character_controller_height : f32 , # [doc = "**Character controller radius**: The radius of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller radius"] , Description ["The radius of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider."]] character_controller_radius : f32 , # [doc = "**Collider from URL**: This entity will load its physics collider from the URL.\n\nThe value is the URL to load from.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider from URL"] , Description ["This entity will load its physics collider from the URL.\nThe value is the URL to load from."]] collider_from_url : String , # [doc = "**Collider loaded**: This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`).\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider loaded"] , Description ["This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`)."]] collider_loaded : () , # [doc = "**Collider loads**: Contains all colliders that were loaded in this physics tick.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Collider loads"] , Description ["Contains all colliders that were loaded in this physics tick."]] collider_loads : Vec :: < EntityId > , # [doc = "**Contact offset**: Contact offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Contact offset"] , Description ["Contact offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene."]] contact_offset : f32 , # [doc = "**Density**: The density of this entity.\n\nThis is used to update the `mass` when the entity is rescaled.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Density"] , Description ["The density of this entity.\nThis is used to update the `mass` when the entity is rescaled."]] density : f32 , # [doc = "**Dynamic**: If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Dynamic"] , Description ["If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static."]] dynamic : bool , # [doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Kinematic"] , Description ["If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally."]] kinematic : () , # [doc = "**Linear velocity**: Linear velocity (meters/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's linear velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Linear velocity"] , Description ["Linear velocity (meters/second) of this entity in the physics scene.\nUpdating this component will update the entity's linear velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead."]] linear_velocity : Vec3 , # [doc = "**Make physics static**: All physics objects will be made static when loaded.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Make physics static"] , Description ["All physics objects will be made static when loaded."]] make_physics_static : bool , # [doc = "**Mass**: The mass of this entity, measured in kilograms.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Mass"] , Description ["The mass of this entity, measured in kilograms."]] mass : f32 , # [doc = "**Physics controlled**: If attached, this entity will be controlled by physics.\n\nNote that this requires the entity to have a collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Physics controlled"] , Description ["If attached, this entity will be controlled by physics.\nNote that this requires the entity to have a collider."]] physics_controlled : () , # [doc = "**Plane collider**: If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Plane collider"] , Description ["If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead."]] plane_collider : () , # [doc = "**Rest offset**: Rest offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rest offset"] , Description ["Rest offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene."]] rest_offset : f32 , # [doc = "**Sphere collider**: If attached, this entity will have a sphere physics collider.\n\nThe value corresponds to the radius of the sphere.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sphere collider"] , Description ["If attached, this entity will have a sphere physics collider.\nThe value corresponds to the radius of the sphere."]] sphere_collider : f32 , # [doc = "**Unit mass**: The mass of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit mass"] , Description ["The mass of a character/unit."]] unit_mass : f32 , # [doc = "**Unit velocity**: The velocity of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit velocity"] , Description ["The velocity of a character/unit."]] unit_velocity : Vec3 , # [doc = "**Unit yaw**: The yaw of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit yaw"] , Description ["The yaw of a character/unit."]] unit_yaw : f32 , # [doc = "**Visualize collider**: If attached, the collider will be rendered.\n\n\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n\n\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Visualize collider"] , Description ["If attached, the collider will be rendered.\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n"]] visualize_collider : () , });
+                components ! ("physics" , { # [doc = "**Angular velocity**: Angular velocity (radians/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's angular velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Angular velocity"] , Description ["Angular velocity (radians/second) of this entity in the physics scene.\nUpdating this component will update the entity's angular velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like improper physics or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying an impulse using `physics` functions instead."]] angular_velocity : Vec3 , # [doc = "**Articulation bind ids**: The bind ids (see `bind_id`) of the bones of this entity's model that an articulation should be built from.\n\nEach bone becomes a link with a capsule collider reaching to its child links, and is attached to the closest ancestor bone in this list with a spherical joint. Exactly one of the bones must have no ancestor in the list; it becomes the root link. Otherwise, the articulation is not built and `articulation_error` is set.\n\n\n\nChanging this component will rebuild the articulation.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Articulation bind ids"] , Description ["The bind ids (see `bind_id`) of the bones of this entity's model that an articulation should be built from.\nEach bone becomes a link with a capsule collider reaching to its child links, and is attached to the closest ancestor bone in this list with a spherical joint. Exactly one of the bones must have no ancestor in the list; it becomes the root link. Otherwise, the articulation is not built and `articulation_error` is set.\n\nChanging this component will rebuild the articulation."]] articulation_bind_ids : Vec :: < String > , # [doc = "**Articulation blend**: How much the pose of the articulation's bones is driven by the physics simulation instead of their animation.\n\nAt `0`, the bones are fully animated and the articulation follows the animation. At `1`, the bones are fully simulated (i.e. a ragdoll). Values in between blend between the two, which can be used for hit reactions.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Articulation blend"] , Description ["How much the pose of the articulation's bones is driven by the physics simulation instead of their animation.\nAt `0`, the bones are fully animated and the articulation follows the animation. At `1`, the bones are fully simulated (i.e. a ragdoll). Values in between blend between the two, which can be used for hit reactions."]] articulation_blend : f32 , # [doc = "**Articulation drive damping**: The damping of the drives that pull each joint of the articulation towards the animated pose.\n\nCan be set on a bone entity to override the value on the articulation entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Articulation drive damping"] , Description ["The damping of the drives that pull each joint of the articulation towards the animated pose.\nCan be set on a bone entity to override the value on the articulation entity."]] articulation_drive_damping : f32 , # [doc = "**Articulation drive stiffness**: The stiffness of the drives that pull each joint of the articulation towards the animated pose. If not present, the joints are not driven.\n\nCan be set on a bone entity to override the value on the articulation entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Articulation drive stiffness"] , Description ["The stiffness of the drives that pull each joint of the articulation towards the animated pose. If not present, the joints are not driven.\nCan be set on a bone entity to override the value on the articulation entity."]] articulation_drive_stiffness : f32 , # [doc = "**Articulation error**: The reason the articulation could not be built from `articulation_bind_ids`, e.g. because more than one of the bones has no ancestor in the list.\n\nIt is rebuilt when `articulation_bind_ids` changes.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Articulation error"] , Description ["The reason the articulation could not be built from `articulation_bind_ids`, e.g. because more than one of the bones has no ancestor in the list.\nIt is rebuilt when `articulation_bind_ids` changes."]] articulation_error : String , # [doc = "**Articulation link radius**: The radius (in meters) of the capsule collider of this bone's articulation link. Defaults to `0.05` if not present.\n\nSet on the bone entity before the articulation is built.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Articulation link radius"] , Description ["The radius (in meters) of the capsule collider of this bone's articulation link. Defaults to `0.05` if not present.\nSet on the bone entity before the articulation is built."]] articulation_link_radius : f32 , # [doc = "**Articulation swing limit**: The swing limit (in radians) of the joint connecting this bone's articulation link to its parent link, as the maximum angle around the Y and Z axes.\n\nIf not present, the swing is free. Set on the bone entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Articulation swing limit"] , Description ["The swing limit (in radians) of the joint connecting this bone's articulation link to its parent link, as the maximum angle around the Y and Z axes.\nIf not present, the swing is free. Set on the bone entity."]] articulation_swing_limit : Vec2 , # [doc = "**Articulation twist limit**: The lower and upper twist limit (in radians) of the joint connecting this bone's articulation link to its parent link, around the X axis.\n\nIf not present, the twist is free. Set on the bone entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Articulation twist limit"] , Description ["The lower and upper twist limit (in radians) of the joint connecting this bone's articulation link to its parent link, around the X axis.\nIf not present, the twist is free. Set on the bone entity."]] articulation_twist_limit : Vec2 , # [doc = "**Cube collider**: If attached, this entity will have a cube physics collider.\n\n`x, y, z` is the size of the cube.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cube collider"] , Description ["If attached, this entity will have a cube physics collider.\n`x, y, z` is the size of the cube."]] cube_collider : Vec3 , # [doc = "**Character controller height**: The height of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller height"] , Description ["The height of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_radius`, it will be given a physical character collider."]] character_controller_height : f32 , # [doc = "**Character controller radius**: The radius of the physics character controller attached to this entity.\n\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Character controller radius"] , Description ["The radius of the physics character controller attached to this entity.\nIf an entity has both this and a `character_controller_height`, it will be given a physical character collider."]] character_controller_radius : f32 , # [doc = "**Collider from URL**: This entity will load its physics collider from the URL.\n\nThe value is the URL to load from.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider from URL"] , Description ["This entity will load its physics collider from the URL.\nThe value is the URL to load from."]] collider_from_url : String , # [doc = "**Collider loaded**: This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`).\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collider loaded"] , Description ["This component is automatically attached to an entity once the collider has been loaded (through e.g. `collider_from_url`)."]] collider_loaded : () , # [doc = "**Collider loads**: Contains all colliders that were loaded in this physics tick.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Collider loads"] , Description ["Contains all colliders that were loaded in this physics tick."]] collider_loads : Vec :: < EntityId > , # [doc = "**Collision group**: The collision groups this entity's colliders belong to, as a bitmask. Defaults to `1` if not present.\n\nTwo colliders only collide if each one's group shares a bit with the other's `collision_mask`. Scene queries with a collision mask only hit colliders whose group shares a bit with it.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collision group"] , Description ["The collision groups this entity's colliders belong to, as a bitmask. Defaults to `1` if not present.\nTwo colliders only collide if each one's group shares a bit with the other's `collision_mask`. Scene queries with a collision mask only hit colliders whose group shares a bit with it."]] collision_group : u32 , # [doc = "**Collision mask**: The collision groups this entity's colliders collide with, as a bitmask. Defaults to all groups if not present.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Collision mask"] , Description ["The collision groups this entity's colliders collide with, as a bitmask. Defaults to all groups if not present."]] collision_mask : u32 , # [doc = "**Contact offset**: Contact offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Contact offset"] , Description ["Contact offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene."]] contact_offset : f32 , # [doc = "**Density**: The density of this entity.\n\nThis is used to update the `mass` when the entity is rescaled.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Density"] , Description ["The density of this entity.\nThis is used to update the `mass` when the entity is rescaled."]] density : f32 , # [doc = "**Dynamic**: If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Dynamic"] , Description ["If this is true, the entity will be dynamic (i.e. be able to move). Otherwise, it will be static."]] dynamic : bool , # [doc = "**Heightfield collider**: If attached, this entity will have a heightfield physics collider, which is a cheap and accurate collider for terrain.\n\nThe value is the height of each sample, row by row: the height at `(x, y)` is at index `y * size.x + x`, where `size` is `heightfield_collider_size`.\n\nThe samples are `heightfield_collider_cell_size` apart along the X and Y axes, starting at the entity's origin.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Heightfield collider"] , Description ["If attached, this entity will have a heightfield physics collider, which is a cheap and accurate collider for terrain.\nThe value is the height of each sample, row by row: the height at `(x, y)` is at index `y * size.x + x`, where `size` is `heightfield_collider_size`.\nThe samples are `heightfield_collider_cell_size` apart along the X and Y axes, starting at the entity's origin."]] heightfield_collider : Vec :: < f32 > , # [doc = "**Heightfield collider cell size**: The distance (in meters) between two samples of the heightfield collider. Defaults to `1` if not present.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Heightfield collider cell size"] , Description ["The distance (in meters) between two samples of the heightfield collider. Defaults to `1` if not present."]] heightfield_collider_cell_size : f32 , # [doc = "**Heightfield collider from URL**: If attached, this entity will have a heightfield physics collider loaded from the heightmap image at the URL.\n\nEach pixel becomes a sample, with its brightness (from `0` to `1`) multiplied by `heightfield_collider_height_scale` as the height. The image is laid out as seen from above, with its top towards +Y.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Heightfield collider from URL"] , Description ["If attached, this entity will have a heightfield physics collider loaded from the heightmap image at the URL.\nEach pixel becomes a sample, with its brightness (from `0` to `1`) multiplied by `heightfield_collider_height_scale` as the height. The image is laid out as seen from above, with its top towards +Y."]] heightfield_collider_from_url : String , # [doc = "**Heightfield collider height scale**: A multiplier for the heights of the heightfield collider. Defaults to `1` if not present.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Heightfield collider height scale"] , Description ["A multiplier for the heights of the heightfield collider. Defaults to `1` if not present."]] heightfield_collider_height_scale : f32 , # [doc = "**Heightfield collider size**: The number of samples of the heightfield collider along the X and Y axes. Required by `heightfield_collider`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Heightfield collider size"] , Description ["The number of samples of the heightfield collider along the X and Y axes. Required by `heightfield_collider`."]] heightfield_collider_size : UVec2 , # [doc = "**Is trigger**: If attached, this entity's collider is a trigger volume: it does not physically collide with anything, but sends `TriggerEnter` and `TriggerExit` messages when other colliders enter or leave it.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Is trigger"] , Description ["If attached, this entity's collider is a trigger volume: it does not physically collide with anything, but sends `TriggerEnter` and `TriggerExit` messages when other colliders enter or leave it."]] is_trigger : () , # [doc = "**Joint break force**: The maximum force (in newtons) this joint can withstand before breaking.\n\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint break force"] , Description ["The maximum force (in newtons) this joint can withstand before breaking.\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent."]] joint_break_force : f32 , # [doc = "**Joint break torque**: The maximum torque (in newton-meters) this joint can withstand before breaking.\n\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint break torque"] , Description ["The maximum torque (in newton-meters) this joint can withstand before breaking.\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent."]] joint_break_torque : f32 , # [doc = "**Joint broken**: This component is automatically attached to a joint entity once the joint has broken.\n\nRemoving it will recreate the joint.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint broken"] , Description ["This component is automatically attached to a joint entity once the joint has broken.\nRemoving it will recreate the joint."]] joint_broken : () , # [doc = "**Joint drive damping**: The damping of the joint's drive.\n\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this is the damping of the spring.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive damping"] , Description ["The damping of the joint's drive.\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this is the damping of the spring."]] joint_drive_damping : f32 , # [doc = "**Joint drive stiffness**: The stiffness of the joint's drive.\n\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this enables a spring with this stiffness between the minimum and maximum distance.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive stiffness"] , Description ["The stiffness of the joint's drive.\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this enables a spring with this stiffness between the minimum and maximum distance."]] joint_drive_stiffness : f32 , # [doc = "**Joint drive target**: The target pose of `joint_frame1` relative to `joint_frame0` that a `D6` joint's drives will try to reach.\n\nOnly used by `D6` joints.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive target"] , Description ["The target pose of `joint_frame1` relative to `joint_frame0` that a `D6` joint's drives will try to reach.\nOnly used by `D6` joints."]] joint_drive_target : Mat4 , # [doc = "**Joint drive velocity**: If attached, a `Revolute` joint will be driven at this angular velocity (radians/second) around its axis, like a motor.\n\nOnly used by `Revolute` joints.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint drive velocity"] , Description ["If attached, a `Revolute` joint will be driven at this angular velocity (radians/second) around its axis, like a motor.\nOnly used by `Revolute` joints."]] joint_drive_velocity : f32 , # [doc = "**Joint entity 0**: The first entity connected by this joint.\n\nIf this is `EntityId::null()`, the joint is attached to the world frame.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint entity 0"] , Description ["The first entity connected by this joint.\nIf this is `EntityId::null()`, the joint is attached to the world frame."]] joint_entity0 : EntityId , # [doc = "**Joint entity 1**: The second entity connected by this joint.\n\nIf this is `EntityId::null()`, the joint is attached to the world frame.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint entity 1"] , Description ["The second entity connected by this joint.\nIf this is `EntityId::null()`, the joint is attached to the world frame."]] joint_entity1 : EntityId , # [doc = "**Joint frame 0**: The frame of the joint relative to `joint_entity0` (or the world, if it is null).\n\nThe joint's axis is the X axis of this frame.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint frame 0"] , Description ["The frame of the joint relative to `joint_entity0` (or the world, if it is null).\nThe joint's axis is the X axis of this frame."]] joint_frame0 : Mat4 , # [doc = "**Joint frame 1**: The frame of the joint relative to `joint_entity1` (or the world, if it is null).\n\nThe joint's axis is the X axis of this frame.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint frame 1"] , Description ["The frame of the joint relative to `joint_entity1` (or the world, if it is null).\nThe joint's axis is the X axis of this frame."]] joint_frame1 : Mat4 , # [doc = "**Joint limit**: If attached, the joint's motion will be limited. The meaning of `x, y` depends on the `joint_type`:\n\n- `Revolute`: the lower and upper angle (radians) around the joint axis.\n\n- `Spherical`: the angles (radians) of the limit cone around the Y and Z axes.\n\n- `Prismatic`: the lower and upper distance (meters) along the joint axis.\n\n- `Distance`: the minimum and maximum distance (meters) between the two frames.\n\n- `D6`: the lower and upper twist angle (radians). Only used if `joint_motion_twist` is `Limited`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint limit"] , Description ["If attached, the joint's motion will be limited. The meaning of `x, y` depends on the `joint_type`:\n- `Revolute`: the lower and upper angle (radians) around the joint axis.\n- `Spherical`: the angles (radians) of the limit cone around the Y and Z axes.\n- `Prismatic`: the lower and upper distance (meters) along the joint axis.\n- `Distance`: the minimum and maximum distance (meters) between the two frames.\n- `D6`: the lower and upper twist angle (radians). Only used if `joint_motion_twist` is `Limited`."]] joint_limit : Vec2 , # [doc = "**Joint linear limit**: The maximum distance (meters) between the two frames of a `D6` joint along any `Limited` linear axis.\n\nOnly used by `D6` joints.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint linear limit"] , Description ["The maximum distance (meters) between the two frames of a `D6` joint along any `Limited` linear axis.\nOnly used by `D6` joints."]] joint_linear_limit : f32 , # [doc = "**Joint motion swing 1**: The motion allowed around the Y axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint motion swing 1"] , Description ["The motion allowed around the Y axis of a `D6` joint. Defaults to `Locked`."]] joint_motion_swing1 : crate :: generated :: raw :: ambient_core :: physics :: types :: JointMotion , # [doc = "**Joint motion swing 2**: The motion allowed around the Z axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint motion swing 2"] , Description ["The motion allowed around the Z axis of a `D6` joint. Defaults to `Locked`."]] joint_motion_swing2 : crate :: generated :: raw :: ambient_core :: physics :: types :: JointMotion , # [doc = "**Joint motion twist**: The motion allowed around the X axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint motion twist"] , Description ["The motion allowed around the X axis of a `D6` joint. Defaults to `Locked`."]] joint_motion_twist : crate :: generated :: raw :: ambient_core :: physics :: types :: JointMotion , # [doc = "**Joint motion X**: The motion allowed along the X axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint motion X"] , Description ["The motion allowed along the X axis of a `D6` joint. Defaults to `Locked`."]] joint_motion_x : crate :: generated :: raw :: ambient_core :: physics :: types :: JointMotion , # [doc = "**Joint motion Y**: The motion allowed along the Y axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint motion Y"] , Description ["The motion allowed along the Y axis of a `D6` joint. Defaults to `Locked`."]] joint_motion_y : crate :: generated :: raw :: ambient_core :: physics :: types :: JointMotion , # [doc = "**Joint motion Z**: The motion allowed along the Z axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint motion Z"] , Description ["The motion allowed along the Z axis of a `D6` joint. Defaults to `Locked`."]] joint_motion_z : crate :: generated :: raw :: ambient_core :: physics :: types :: JointMotion , # [doc = "**Joint swing limit**: The angles (radians) of the swing limit cone around the Y and Z axes of a `D6` joint.\n\nOnly used if `joint_motion_swing1` or `joint_motion_swing2` is `Limited`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint swing limit"] , Description ["The angles (radians) of the swing limit cone around the Y and Z axes of a `D6` joint.\nOnly used if `joint_motion_swing1` or `joint_motion_swing2` is `Limited`."]] joint_swing_limit : Vec2 , # [doc = "**Joint type**: If attached, this entity is a physics joint connecting `joint_entity0` and `joint_entity1`.\n\nThe joint is created once the connected entities have physics actors, and is released when this entity is despawned.\n\n*Attributes*: Debuggable, Networked, Store, Enum"] @ [Debuggable , Networked , Store , Enum , Name ["Joint type"] , Description ["If attached, this entity is a physics joint connecting `joint_entity0` and `joint_entity1`.\nThe joint is created once the connected entities have physics actors, and is released when this entity is despawned."]] joint_type : crate :: generated :: raw :: ambient_core :: physics :: types :: JointType , # [doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Kinematic"] , Description ["If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally."]] kinematic : () , # [doc = "**Linear velocity**: Linear velocity (meters/second) of this entity in the physics scene.\n\nUpdating this component will update the entity's linear velocity in the physics scene.\n\n\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\n\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Linear velocity"] , Description ["Linear velocity (meters/second) of this entity in the physics scene.\nUpdating this component will update the entity's linear velocity in the physics scene.\n\nNote that changing this component will forcibly set the velocity; changing the velocity every frame may lead to unexpected behavior, like gravity not working or collisions failing.\n\nIf you need to adjust the velocity each frame, consider applying a force using `physics` functions instead."]] linear_velocity : Vec3 , # [doc = "**Make physics static**: All physics objects will be made static when loaded.\n\n*Attributes*: Debuggable, Networked, Resource, Store"] @ [Debuggable , Networked , Resource , Store , Name ["Make physics static"] , Description ["All physics objects will be made static when loaded."]] make_physics_static : bool , # [doc = "**Mass**: The mass of this entity, measured in kilograms.\n\n*Attributes*: Debuggable, Networked, Store\n\n*Suggested Default*: 1.0"] @ [Debuggable , Networked , Store , Name ["Mass"] , Description ["The mass of this entity, measured in kilograms."]] mass : f32 , # [doc = "**Physics controlled**: If attached, this entity will be controlled by physics.\n\nNote that this requires the entity to have a collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Physics controlled"] , Description ["If attached, this entity will be controlled by physics.\nNote that this requires the entity to have a collider."]] physics_controlled : () , # [doc = "**Plane collider**: If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Plane collider"] , Description ["If attached, this entity will have a plane physics collider. A plane is an infinite, flat surface. If you need a bounded flat surface, consider using a cube collider instead."]] plane_collider : () , # [doc = "**Report contacts**: If attached, `ContactBegin` and `ContactEnd` messages will be sent when this entity's collider starts or stops touching another collider.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Report contacts"] , Description ["If attached, `ContactBegin` and `ContactEnd` messages will be sent when this entity's collider starts or stops touching another collider."]] report_contacts : () , # [doc = "**Rest offset**: Rest offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rest offset"] , Description ["Rest offset (in meters) of this entity in the physics scene.\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene."]] rest_offset : f32 , # [doc = "**Sphere collider**: If attached, this entity will have a sphere physics collider.\n\nThe value corresponds to the radius of the sphere.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sphere collider"] , Description ["If attached, this entity will have a sphere physics collider.\nThe value corresponds to the radius of the sphere."]] sphere_collider : f32 , # [doc = "**Unit mass**: The mass of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit mass"] , Description ["The mass of a character/unit."]] unit_mass : f32 , # [doc = "**Unit velocity**: The velocity of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit velocity"] , Description ["The velocity of a character/unit."]] unit_velocity : Vec3 , # [doc = "**Unit yaw**: The yaw of a character/unit.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Unit yaw"] , Description ["The yaw of a character/unit."]] unit_yaw : f32 , # [doc = "**Visualize collider**: If attached, the collider will be rendered.\n\n\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n\n\n\n*Attributes*: Debuggable, Networked"] @ [Debuggable , Networked , Name ["Visualize collider"] , Description ["If attached, the collider will be rendered.\n\n**Note**: this will continuously overwrite the `local_gizmos` component.\n"]] visualize_collider : () , });
+            }
+            #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
+            #[doc = r" and with other modules."]
+            pub mod messages {
+                use crate::{Entity, EntityId};
+                use ambient_package_rt::message_serde::{
+                    Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                };
+                use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactBegin**: Sent when two colliders start touching, if either of them has `report_contacts`. The points and normals are in world space."]
+                pub struct ContactBegin {
+                    pub entity0: EntityId,
+                    pub entity1: EntityId,
+                    pub points: Vec<Vec3>,
+                    pub normals: Vec<Vec3>,
+                    pub impulse: Vec3,
+                }
+                impl ContactBegin {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        entity0: impl Into<EntityId>,
+                        entity1: impl Into<EntityId>,
+                        points: impl Into<Vec<Vec3>>,
+                        normals: impl Into<Vec<Vec3>>,
+                        impulse: impl Into<Vec3>,
+                    ) -> Self {
+                        Self {
+                            entity0: entity0.into(),
+                            entity1: entity1.into(),
+                            points: points.into(),
+                            normals: normals.into(),
+                            impulse: impulse.into(),
+                        }
+                    }
+                }
+                impl Message for ContactBegin {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactBegin"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.entity0.serialize_message_part(&mut output)?;
+                        self.entity1.serialize_message_part(&mut output)?;
+                        self.points.serialize_message_part(&mut output)?;
+                        self.normals.serialize_message_part(&mut output)?;
+                        self.impulse.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            entity0: EntityId::deserialize_message_part(&mut input)?,
+                            entity1: EntityId::deserialize_message_part(&mut input)?,
+                            points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            impulse: Vec3::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactBegin {}
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactEnd**: Sent when two colliders stop touching, if either of them has `report_contacts`."]
+                pub struct ContactEnd {
+                    pub entity0: EntityId,
+                    pub entity1: EntityId,
+                }
+                impl ContactEnd {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(entity0: impl Into<EntityId>, entity1: impl Into<EntityId>) -> Self {
+                        Self {
+                            entity0: entity0.into(),
+                            entity1: entity1.into(),
+                        }
+                    }
+                }
+                impl Message for ContactEnd {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactEnd"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.entity0.serialize_message_part(&mut output)?;
+                        self.entity1.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            entity0: EntityId::deserialize_message_part(&mut input)?,
+                            entity1: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactEnd {}
+                #[derive(Clone, Debug)]
+                #[doc = "**TriggerEnter**: Sent when a collider enters a trigger volume (see `is_trigger`)."]
+                pub struct TriggerEnter {
+                    pub trigger: EntityId,
+                    pub other: EntityId,
+                }
+                impl TriggerEnter {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(trigger: impl Into<EntityId>, other: impl Into<EntityId>) -> Self {
+                        Self {
+                            trigger: trigger.into(),
+                            other: other.into(),
+                        }
+                    }
+                }
+                impl Message for TriggerEnter {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::TriggerEnter"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.trigger.serialize_message_part(&mut output)?;
+                        self.other.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            trigger: EntityId::deserialize_message_part(&mut input)?,
+                            other: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for TriggerEnter {}
+                #[derive(Clone, Debug)]
+                #[doc = "**TriggerExit**: Sent when a collider leaves a trigger volume (see `is_trigger`)."]
+                pub struct TriggerExit {
+                    pub trigger: EntityId,
+                    pub other: EntityId,
+                }
+                impl TriggerExit {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(trigger: impl Into<EntityId>, other: impl Into<EntityId>) -> Self {
+                        Self {
+                            trigger: trigger.into(),
+                            other: other.into(),
+                        }
+                    }
+                }
+                impl Message for TriggerExit {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::TriggerExit"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.trigger.serialize_message_part(&mut output)?;
+                        self.other.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            trigger: EntityId::deserialize_message_part(&mut input)?,
+                            other: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for TriggerExit {}
+                #[derive(Clone, Debug)]
+                #[doc = "**JointBreak**: Sent when a physics joint breaks."]
+                pub struct JointBreak {
+                    pub joint: EntityId,
+                    pub entity0: EntityId,
+                    pub entity1: EntityId,
+                }
+                impl JointBreak {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        joint: impl Into<EntityId>,
+                        entity0: impl Into<EntityId>,
+                        entity1: impl Into<EntityId>,
+                    ) -> Self {
+                        Self {
+                            joint: joint.into(),
+                            entity0: entity0.into(),
+                            entity1: entity1.into(),
+                        }
+                    }
+                }
+                impl Message for JointBreak {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::JointBreak"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.joint.serialize_message_part(&mut output)?;
+                        self.entity0.serialize_message_part(&mut output)?;
+                        self.entity1.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            joint: EntityId::deserialize_message_part(&mut input)?,
+                            entity0: EntityId::deserialize_message_part(&mut input)?,
+                            entity1: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for JointBreak {}
+            }
+            #[doc = r" Auto-generated type definitions."]
+            pub mod types {
+                use ambient_package_rt::message_serde::*;
+                use serde;
+                #[derive(
+                    Copy,
+                    Clone,
+                    Debug,
+                    PartialEq,
+                    Eq,
+                    serde :: Serialize,
+                    serde :: Deserialize,
+                    Default,
+                )]
+                #[serde(crate = "self::serde")]
+                #[doc = "**JointType**: The type of a physics joint."]
+                pub enum JointType {
+                    #[default]
+                    #[doc = "Locks all relative motion between the two entities."]
+                    Fixed,
+                    #[doc = "Allows rotation around the joint axis, like a hinge."]
+                    Revolute,
+                    #[doc = "Allows rotation around all axes, like a ball-and-socket."]
+                    Spherical,
+                    #[doc = "Allows translation along the joint axis, like a slider."]
+                    Prismatic,
+                    #[doc = "Keeps the distance between the two entities within a range."]
+                    Distance,
+                    #[doc = "Configurable joint with per-axis motion, limits and drives."]
+                    D6,
+                }
+                impl crate::EnumComponent for JointType {
+                    fn to_u32(&self) -> u32 {
+                        match self {
+                            Self::Fixed => JointType::Fixed as u32,
+                            Self::Revolute => JointType::Revolute as u32,
+                            Self::Spherical => JointType::Spherical as u32,
+                            Self::Prismatic => JointType::Prismatic as u32,
+                            Self::Distance => JointType::Distance as u32,
+                            Self::D6 => JointType::D6 as u32,
+                        }
+                    }
+                    fn from_u32(value: u32) -> Option<Self> {
+                        if value == JointType::Fixed as u32 {
+                            return Some(Self::Fixed);
+                        }
+                        if value == JointType::Revolute as u32 {
+                            return Some(Self::Revolute);
+                        }
+                        if value == JointType::Spherical as u32 {
+                            return Some(Self::Spherical);
+                        }
+                        if value == JointType::Prismatic as u32 {
+                            return Some(Self::Prismatic);
+                        }
+                        if value == JointType::Distance as u32 {
+                            return Some(Self::Distance);
+                        }
+                        if value == JointType::D6 as u32 {
+                            return Some(Self::D6);
+                        }
+                        None
+                    }
+                }
+                impl MessageSerde for JointType {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        crate::EnumComponent::to_u32(self).serialize_message_part(output)
+                    }
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        crate::EnumComponent::from_u32(u32::deserialize_message_part(input)?)
+                            .ok_or(MessageSerdeError::InvalidValue)
+                    }
+                }
+                #[derive(
+                    Copy,
+                    Clone,
+                    Debug,
+                    PartialEq,
+                    Eq,
+                    serde :: Serialize,
+                    serde :: Deserialize,
+                    Default,
+                )]
+                #[serde(crate = "self::serde")]
+                #[doc = "**JointMotion**: The motion allowed along or around an axis of a `D6` joint."]
+                pub enum JointMotion {
+                    #[default]
+                    #[doc = "The axis is locked."]
+                    Locked,
+                    #[doc = "The axis is limited by the joint's limits."]
+                    Limited,
+                    #[doc = "The axis is free."]
+                    Free,
+                }
+                impl crate::EnumComponent for JointMotion {
+                    fn to_u32(&self) -> u32 {
+                        match self {
+                            Self::Locked => JointMotion::Locked as u32,
+                            Self::Limited => JointMotion::Limited as u32,
+                            Self::Free => JointMotion::Free as u32,
+                        }
+                    }
+                    fn from_u32(value: u32) -> Option<Self> {
+                        if value == JointMotion::Locked as u32 {
+                            return Some(Self::Locked);
+                        }
+                        if value == JointMotion::Limited as u32 {
+                            return Some(Self::Limited);
+                        }
+                        if value == JointMotion::Free as u32 {
+                            return Some(Self::Free);
+                        }
+                        None
+                    }
+                }
+                impl MessageSerde for JointMotion {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        crate::EnumComponent::to_u32(self).serialize_message_part(output)
+                    }
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        crate::EnumComponent::from_u32(u32::deserialize_message_part(input)?)
+                            .ok_or(MessageSerdeError::InvalidValue)
+                    }
+                }
             }
         }
         pub mod player {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -747,8 +1082,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -763,8 +1098,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -779,8 +1114,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -795,8 +1130,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -804,15 +1139,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("rect" , { # [doc = "**Background color**: Background color of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Background color"] , Description ["Background color of an entity with a `rect` component."]] background_color : Vec4 , # [doc = "**Background URL**: URL to an image asset.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Background URL"] , Description ["URL to an image asset."]] background_url : String , # [doc = "**Border color**: Border color of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Border color"] , Description ["Border color of an entity with a `rect` component."]] border_color : Vec4 , # [doc = "**Border radius**: Radius for each corner of an entity with a `rect` component.\n\n`x` = top-left, `y` = top-right, `z` = bottom-left, `w` = bottom-right.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Border radius"] , Description ["Radius for each corner of an entity with a `rect` component.\n`x` = top-left, `y` = top-right, `z` = bottom-left, `w` = bottom-right."]] border_radius : Vec4 , # [doc = "**Border thickness**: Border thickness of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Border thickness"] , Description ["Border thickness of an entity with a `rect` component."]] border_thickness : f32 , # [doc = "**Pixel Line from**: Start point of a pixel sized line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Pixel Line from"] , Description ["Start point of a pixel sized line."]] pixel_line_from : Vec3 , # [doc = "**Pixel Line to**: End point of a pixel sized line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Pixel Line to"] , Description ["End point of a pixel sized line."]] pixel_line_to : Vec3 , # [doc = "**Line from**: Start point of a line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Line from"] , Description ["Start point of a line."]] line_from : Vec3 , # [doc = "**Line to**: End point of a line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Line to"] , Description ["End point of a line."]] line_to : Vec3 , # [doc = "**Line width**: Width of line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Line width"] , Description ["Width of line."]] line_width : f32 , # [doc = "**Rect**: If attached to an entity, the entity will be converted to a UI rectangle, with optionally rounded corners and borders.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rect"] , Description ["If attached to an entity, the entity will be converted to a UI rectangle, with optionally rounded corners and borders."]] rect : () , # [doc = "**Size from background image**: Resize this rect based on the size of the background image.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Size from background image"] , Description ["Resize this rect based on the size of the background image."]] size_from_background_image : () , });
+                components ! ("rect" , { # [doc = "**Background color**: Background color of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store, Color"] @ [Debuggable , Networked , Store , Color , Name ["Background color"] , Description ["Background color of an entity with a `rect` component."]] background_color : Vec4 , # [doc = "**Background URL**: URL to an image asset.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Background URL"] , Description ["URL to an image asset."]] background_url : String , # [doc = "**Border color**: Border color of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store, Color"] @ [Debuggable , Networked , Store , Color , Name ["Border color"] , Description ["Border color of an entity with a `rect` component."]] border_color : Vec4 , # [doc = "**Border radius**: Radius for each corner of an entity with a `rect` component.\n\n`x` = top-left, `y` = top-right, `z` = bottom-left, `w` = bottom-right.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Border radius"] , Description ["Radius for each corner of an entity with a `rect` component.\n`x` = top-left, `y` = top-right, `z` = bottom-left, `w` = bottom-right."]] border_radius : Vec4 , # [doc = "**Border thickness**: Border thickness of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Border thickness"] , Description ["Border thickness of an entity with a `rect` component."]] border_thickness : f32 , # [doc = "**Pixel Line from**: Start point of a pixel sized line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Pixel Line from"] , Description ["Start point of a pixel sized line."]] pixel_line_from : Vec3 , # [doc = "**Pixel Line to**: End point of a pixel sized line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Pixel Line to"] , Description ["End point of a pixel sized line."]] pixel_line_to : Vec3 , # [doc = "**Line from**: Start point of a line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Line from"] , Description ["Start point of a line."]] line_from : Vec3 , # [doc = "**Line to**: End point of a line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Line to"] , Description ["End point of a line."]] line_to : Vec3 , # [doc = "**Line width**: Width of line.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Line width"] , Description ["Width of line."]] line_width : f32 , # [doc = "**Rect**: If attached to an entity, the entity will be converted to a UI rectangle, with optionally rounded corners and borders.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Rect"] , Description ["If attached to an entity, the entity will be converted to a UI rectangle, with optionally rounded corners and borders."]] rect : () , # [doc = "**Size from background image**: Resize this rect based on the size of the background image.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Size from background image"] , Description ["Resize this rect based on the size of the background image."]] size_from_background_image : () , });
             }
         }
         pub mod rendering {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -820,15 +1155,15 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("rendering" , { # [doc = "**Cast shadows**: If attached, this entity will cast shadows.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cast shadows"] , Description ["If attached, this entity will cast shadows."]] cast_shadows : () , # [doc = "**Color**: This entity will be tinted with the specified color if the color is not black.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Color"] , Description ["This entity will be tinted with the specified color if the color is not black."]] color : Vec4 , # [doc = "**Double-sided**: If attached, this controls whether or not the entity will be rendered with double-sided rendering. If not attached, the decision will fall back to the material.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Double-sided"] , Description ["If attached, this controls whether or not the entity will be rendered with double-sided rendering. If not attached, the decision will fall back to the material."]] double_sided : bool , # [doc = "**Fog color**: The color of the fog for this `sun`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Fog color"] , Description ["The color of the fog for this `sun`."]] fog_color : Vec3 , # [doc = "**Fog density**: The density of the fog for this `sun`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Fog density"] , Description ["The density of the fog for this `sun`."]] fog_density : f32 , # [doc = "**Fog height fall-off**: The height at which the fog will fall off (i.e. stop being visible) for this `sun`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Fog height fall-off"] , Description ["The height at which the fog will fall off (i.e. stop being visible) for this `sun`."]] fog_height_falloff : f32 , # [doc = "**Joint Matrices**: Contains the matrices for each joint of this skinned mesh.\n\nThis should be used in combination with `joints`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint Matrices"] , Description ["Contains the matrices for each joint of this skinned mesh.\nThis should be used in combination with `joints`."]] joint_matrices : Vec :: < Mat4 > , # [doc = "**Joints**: Contains the joints that comprise this skinned mesh.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joints"] , Description ["Contains the joints that comprise this skinned mesh."]] joints : Vec :: < EntityId > , # [doc = "**Light ambient**: The ambient light color of the `sun`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Light ambient"] , Description ["The ambient light color of the `sun`."]] light_ambient : Vec3 , # [doc = "**Light diffuse**: The diffuse light color of the `sun`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Light diffuse"] , Description ["The diffuse light color of the `sun`."]] light_diffuse : Vec3 , # [doc = "**Outline**: If attached, this entity will be rendered with an outline with the color specified.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Outline"] , Description ["If attached, this entity will be rendered with an outline with the color specified."]] outline : Vec4 , # [doc = "**Outline (recursive)**: If attached, this entity and all of its children will be rendered with an outline with the color specified.\n\nYou do not need to attach `outline` if you have attached `outline_recursive`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Outline (recursive)"] , Description ["If attached, this entity and all of its children will be rendered with an outline with the color specified.\nYou do not need to attach `outline` if you have attached `outline_recursive`."]] outline_recursive : Vec4 , # [doc = "**Overlay**: If attached, this entity will be rendered with an overlay.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Overlay"] , Description ["If attached, this entity will be rendered with an overlay."]] overlay : () , # [doc = "**PBR material from URL**: Load a PBR material from the URL and attach it to this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["PBR material from URL"] , Description ["Load a PBR material from the URL and attach it to this entity."]] pbr_material_from_url : String , # [doc = "**Sky**: Add a realistic skybox to the scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sky"] , Description ["Add a realistic skybox to the scene."]] sky : () , # [doc = "**Sun**: Marks this entity as a sun (i.e. its rotation will be used to control the global light direction).\n\nThe entity with the highest `sun` value takes precedence.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sun"] , Description ["Marks this entity as a sun (i.e. its rotation will be used to control the global light direction).\nThe entity with the highest `sun` value takes precedence."]] sun : f32 , # [doc = "**Transparency group**: Controls when this transparent object will be rendered. Transparent objects are sorted by `(transparency_group, z-depth)`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Transparency group"] , Description ["Controls when this transparent object will be rendered. Transparent objects are sorted by `(transparency_group, z-depth)`."]] transparency_group : i32 , # [doc = "**Water**: Add a realistic water plane to this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Water"] , Description ["Add a realistic water plane to this entity."]] water : () , # [doc = "**Decal material from URL**: Load a Decal material from the URL and attach it to this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Decal material from URL"] , Description ["Load a Decal material from the URL and attach it to this entity."]] decal_from_url : String , # [doc = "**Scissors**: Apply a scissors test to this entity (anything outside the rect will be hidden).\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Scissors"] , Description ["Apply a scissors test to this entity (anything outside the rect will be hidden)."]] scissors : UVec4 , # [doc = "**Scissors (recursive)**: If attached, this entity and all of its children will be rendered with an scissor with the rect specified.\n\nYou do not need to attach `scissors` if you have attached `scissors_recursive`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Scissors (recursive)"] , Description ["If attached, this entity and all of its children will be rendered with an scissor with the rect specified.\nYou do not need to attach `scissors` if you have attached `scissors_recursive`."]] scissors_recursive : UVec4 , # [doc = "**Local bounding AABB min**: The minimum point of the local AABB of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Local bounding AABB min"] , Description ["The minimum point of the local AABB of this entity."]] local_bounding_aabb_min : Vec3 , # [doc = "**Local bounding AABB max**: The maximum point of the local AABB of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Local bounding AABB max"] , Description ["The maximum point of the local AABB of this entity."]] local_bounding_aabb_max : Vec3 , # [doc = "**World bounding AABB min**: The minimum point of the world AABB of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["World bounding AABB min"] , Description ["The minimum point of the world AABB of this entity."]] world_bounding_aabb_min : Vec3 , # [doc = "**World bounding AABB max**: The maximum point of the world AABB of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["World bounding AABB max"] , Description ["The maximum point of the world AABB of this entity."]] world_bounding_aabb_max : Vec3 , # [doc = "**World bounding sphere center**: The center of the world bounding sphere of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["World bounding sphere center"] , Description ["The center of the world bounding sphere of this entity."]] world_bounding_sphere_center : Vec3 , # [doc = "**World bounding sphere radius**: The radius of the world bounding sphere of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["World bounding sphere radius"] , Description ["The radius of the world bounding sphere of this entity."]] world_bounding_sphere_radius : f32 , });
+                components ! ("rendering" , { # [doc = "**Cast shadows**: If attached, this entity will cast shadows.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Cast shadows"] , Description ["If attached, this entity will cast shadows."]] cast_shadows : () , # [doc = "**Color**: This entity will be tinted with the specified color if the color is not black.\n\n*Attributes*: Debuggable, Networked, Store, Color"] @ [Debuggable , Networked , Store , Color , Name ["Color"] , Description ["This entity will be tinted with the specified color if the color is not black."]] color : Vec4 , # [doc = "**Double-sided**: If attached, this controls whether or not the entity will be rendered with double-sided rendering. If not attached, the decision will fall back to the material.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Double-sided"] , Description ["If attached, this controls whether or not the entity will be rendered with double-sided rendering. If not attached, the decision will fall back to the material."]] double_sided : bool , # [doc = "**Fog color**: The color of the fog for this `sun`.\n\n*Attributes*: Debuggable, Networked, Store, Color"] @ [Debuggable , Networked , Store , Color , Name ["Fog color"] , Description ["The color of the fog for this `sun`."]] fog_color : Vec3 , # [doc = "**Fog density**: The density of the fog for this `sun`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Fog density"] , Description ["The density of the fog for this `sun`."]] fog_density : f32 , # [doc = "**Fog height fall-off**: The height at which the fog will fall off (i.e. stop being visible) for this `sun`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Fog height fall-off"] , Description ["The height at which the fog will fall off (i.e. stop being visible) for this `sun`."]] fog_height_falloff : f32 , # [doc = "**Joint Matrices**: Contains the matrices for each joint of this skinned mesh.\n\nThis should be used in combination with `joints`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joint Matrices"] , Description ["Contains the matrices for each joint of this skinned mesh.\nThis should be used in combination with `joints`."]] joint_matrices : Vec :: < Mat4 > , # [doc = "**Joints**: Contains the joints that comprise this skinned mesh.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Joints"] , Description ["Contains the joints that comprise this skinned mesh."]] joints : Vec :: < EntityId > , # [doc = "**Light ambient**: The ambient light color of the `sun`.\n\n*Attributes*: Debuggable, Networked, Store, Color"] @ [Debuggable , Networked , Store , Color , Name ["Light ambient"] , Description ["The ambient light color of the `sun`."]] light_ambient : Vec3 , # [doc = "**Light diffuse**: The diffuse light color of the `sun`.\n\n*Attributes*: Debuggable, Networked, Store, Color"] @ [Debuggable , Networked , Store , Color , Name ["Light diffuse"] , Description ["The diffuse light color of the `sun`."]] light_diffuse : Vec3 , # [doc = "**Outline**: If attached, this entity will be rendered with an outline with the color specified.\n\n*Attributes*: Debuggable, Networked, Store, Color"] @ [Debuggable , Networked , Store , Color , Name ["Outline"] , Description ["If attached, this entity will be rendered with an outline with the color specified."]] outline : Vec4 , # [doc = "**Outline (recursive)**: If attached, this entity and all of its children will be rendered with an outline with the color specified.\n\nYou do not need to attach `outline` if you have attached `outline_recursive`.\n\n*Attributes*: Debuggable, Networked, Store, Color"] @ [Debuggable , Networked , Store , Color , Name ["Outline (recursive)"] , Description ["If attached, this entity and all of its children will be rendered with an outline with the color specified.\nYou do not need to attach `outline` if you have attached `outline_recursive`."]] outline_recursive : Vec4 , # [doc = "**Overlay**: If attached, this entity will be rendered with an overlay.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Overlay"] , Description ["If attached, this entity will be rendered with an overlay."]] overlay : () , # [doc = "**PBR material from URL**: Load a PBR material from the URL and attach it to this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["PBR material from URL"] , Description ["Load a PBR material from the URL and attach it to this entity."]] pbr_material_from_url : String , # [doc = "**Sky**: Add a realistic skybox to the scene.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sky"] , Description ["Add a realistic skybox to the scene."]] sky : () , # [doc = "**Sun**: Marks this entity as a sun (i.e. its rotation will be used to control the global light direction).\n\nThe entity with the highest `sun` value takes precedence.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Sun"] , Description ["Marks this entity as a sun (i.e. its rotation will be used to control the global light direction).\nThe entity with the highest `sun` value takes precedence."]] sun : f32 , # [doc = "**Transparency group**: Controls when this transparent object will be rendered. Transparent objects are sorted by `(transparency_group, z-depth)`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Transparency group"] , Description ["Controls when this transparent object will be rendered. Transparent objects are sorted by `(transparency_group, z-depth)`."]] transparency_group : i32 , # [doc = "**Water**: Add a realistic water plane to this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Water"] , Description ["Add a realistic water plane to this entity."]] water : () , # [doc = "**Decal material from URL**: Load a Decal material from the URL and attach it to this entity.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Decal material from URL"] , Description ["Load a Decal material from the URL and attach it to this entity."]] decal_from_url : String , # [doc = "**Scissors**: Apply a scissors test to this entity (anything outside the rect will be hidden).\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Scissors"] , Description ["Apply a scissors test to this entity (anything outside the rect will be hidden)."]] scissors : UVec4 , # [doc = "**Scissors (recursive)**: If attached, this entity and all of its children will be rendered with an scissor with the rect specified.\n\nYou do not need to attach `scissors` if you have attached `scissors_recursive`.\n\n*Attributes*: Debuggable, Networked, Store"] @ [Debuggable , Networked , Store , Name ["Scissors (recursive)"] , Description ["If attached, this entity and all of its children will be rendered with an scissor with the rect specified.\nYou do not need to attach `scissors` if you have attached `scissors_recursive`."]] scissors_recursive : UVec4 , # [doc = "**Local bounding AABB min**: The minimum point of the local AABB of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Local bounding AABB min"] , Description ["The minimum point of the local AABB of this entity."]] local_bounding_aabb_min : Vec3 , # [doc = "**Local bounding AABB max**: The maximum point of the local AABB of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["Local bounding AABB max"] , Description ["The maximum point of the local AABB of this entity."]] local_bounding_aabb_max : Vec3 , # [doc = "**World bounding AABB min**: The minimum point of the world AABB of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["World bounding AABB min"] , Description ["The minimum point of the world AABB of this entity."]] world_bounding_aabb_min : Vec3 , # [doc = "**World bounding AABB max**: The maximum point of the world AABB of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["World bounding AABB max"] , Description ["The maximum point of the world AABB of this entity."]] world_bounding_aabb_max : Vec3 , # [doc = "**World bounding sphere center**: The center of the world bounding sphere of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["World bounding sphere center"] , Description ["The center of the world bounding sphere of this entity."]] world_bounding_sphere_center : Vec3 , # [doc = "**World bounding sphere radius**: The radius of the world bounding sphere of this entity.\n\n*Attributes*: Debuggable"] @ [Debuggable , Name ["World bounding sphere radius"] , Description ["The radius of the world bounding sphere of this entity."]] world_bounding_sphere_radius : f32 , });
             }
         }
         pub mod text {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -934,8 +1269,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -950,8 +1285,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -1008,8 +1343,8 @@ mod raw {
             #[doc = r" Auto-generated component definitions."]
             pub mod components {
                 use crate::{
-                    components, Debuggable, Description, EntityId, Enum, MaybeResource, Name,
-                    Networked, Resource, Store,
+                    components, Color, Debuggable, Description, EntityId, Enum, Interpolated,
+                    MaybeResource, Name, NetworkPriority, Networked, Predicted, Resource, Store,
                 };
                 use ambient_shared_types::{
                     ProceduralMaterialHandle, ProceduralMeshHandle, ProceduralSamplerHandle,
@@ -1017,7 +1352,7 @@ mod raw {
                 };
                 use glam::{Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
                 use std::time::Duration;
-                components ! ("wasm" , { # [doc = "**Is module**: A module.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Is module"] , Description ["A module."]] is_module : () , # [doc = "**Is module on server**: Whether or not this module is on the server.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Is module on server"] , Description ["Whether or not this module is on the server."]] is_module_on_server : () , # [doc = "**Bytecode from URL**: Asset URL for the bytecode of a WASM component.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Bytecode from URL"] , Description ["Asset URL for the bytecode of a WASM component."]] bytecode_from_url : String , # [doc = "**Module enabled**: Whether or not this module is enabled.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Module enabled"] , Description ["Whether or not this module is enabled."]] module_enabled : bool , # [doc = "**Module name**: The name of this module.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Module name"] , Description ["The name of this module."]] module_name : String , # [doc = "**Package reference**: The package that this module belongs to.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Package reference"] , Description ["The package that this module belongs to."]] package_ref : EntityId , });
+                components ! ("wasm" , { # [doc = "**Is module**: A module.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Is module"] , Description ["A module."]] is_module : () , # [doc = "**Is module on server**: Whether or not this module is on the server.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Is module on server"] , Description ["Whether or not this module is on the server."]] is_module_on_server : () , # [doc = "**Bytecode from URL**: Asset URL for the bytecode of a WASM component.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Bytecode from URL"] , Description ["Asset URL for the bytecode of a WASM component."]] bytecode_from_url : String , # [doc = "**Module enabled**: Whether or not this module is enabled.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Module enabled"] , Description ["Whether or not this module is enabled."]] module_enabled : bool , # [doc = "**Module name**: The name of this module.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Module name"] , Description ["The name of this module."]] module_name : String , # [doc = "**Package reference**: The package that this module belongs to.\n\n*Attributes*: Networked, Store, Debuggable"] @ [Networked , Store , Debuggable , Name ["Package reference"] , Description ["The package that this module belongs to."]] package_ref : EntityId , # [doc = "**Module run time**: The time in milliseconds that this module spent handling messages over the last second. Updated every second.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module run time"] , Description ["The time in milliseconds that this module spent handling messages over the last second. Updated every second."]] module_run_time : f32 , # [doc = "**Module fuel consumed**: The fuel that this module consumed over the last second, which is roughly the number of WebAssembly instructions it executed. Updated every second. Fuel is only counted for server modules that have a fuel limit, and is 0 otherwise.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module fuel consumed"] , Description ["The fuel that this module consumed over the last second, which is roughly the number of WebAssembly instructions it executed. Updated every second. Fuel is only counted for server modules that have a fuel limit, and is 0 otherwise."]] module_fuel_consumed : u64 , # [doc = "**Module host calls**: The number of calls that this module made to the runtime over the last second. Updated every second; always 0 on the web.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module host calls"] , Description ["The number of calls that this module made to the runtime over the last second. Updated every second; always 0 on the web."]] module_host_calls : u64 , # [doc = "**Module message names**: The messages that this module handled over the last second, from slowest to fastest. The time spent handling each message is in `module_message_run_times` at the same index.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module message names"] , Description ["The messages that this module handled over the last second, from slowest to fastest. The time spent handling each message is in `module_message_run_times` at the same index."]] module_message_names : Vec :: < String > , # [doc = "**Module message run times**: The time in milliseconds that this module spent handling each of the messages in `module_message_names` over the last second.\n\n*Attributes*: Networked, Debuggable"] @ [Networked , Debuggable , Name ["Module message run times"] , Description ["The time in milliseconds that this module spent handling each of the messages in `module_message_names` over the last second."]] module_message_run_times : Vec :: < f32 > , });
             }
         }
         #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
@@ -1149,6 +1484,111 @@ mod raw {
                 }
             }
             #[derive(Clone, Debug)]
+            #[doc = "**WorldRestore**: Sent to a server module after its first `ModuleLoad` if the world was restored from a save when the server started. Contains the package version that made the save, and the components that could not be restored because they no longer exist or their type changed, so that the module can migrate the restored state."]
+            pub struct WorldRestore {
+                pub saved_version: String,
+                pub skipped_components: Vec<String>,
+            }
+            impl WorldRestore {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    saved_version: impl Into<String>,
+                    skipped_components: impl Into<Vec<String>>,
+                ) -> Self {
+                    Self {
+                        saved_version: saved_version.into(),
+                        skipped_components: skipped_components.into(),
+                    }
+                }
+            }
+            impl Message for WorldRestore {
+                fn id() -> &'static str {
+                    "ambient_core::WorldRestore"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.saved_version.serialize_message_part(&mut output)?;
+                    self.skipped_components
+                        .serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        saved_version: String::deserialize_message_part(&mut input)?,
+                        skipped_components: Vec::<String>::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WorldRestore {}
+            #[derive(Clone, Debug)]
+            #[doc = "**TimerFire**: Sent to a module when one of its timers fires. Contains the name the timer was started with."]
+            pub struct TimerFire {
+                pub name: String,
+            }
+            impl TimerFire {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(name: impl Into<String>) -> Self {
+                    Self { name: name.into() }
+                }
+            }
+            impl Message for TimerFire {
+                fn id() -> &'static str {
+                    "ambient_core::TimerFire"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.name.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        name: String::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for TimerFire {}
+            #[derive(Clone, Debug)]
+            #[doc = "**PredictInput**: Sent to client modules after every update from the server, once for each message the client has sent to the server that the server has not acknowledged yet, oldest first. The `Predicted` components of the local player's entities have been reset to the server's values, so the module should apply the effect of the message to them again, as it did when it sent it. Contains the sequence number, name and data of the message."]
+            pub struct PredictInput {
+                pub sequence: u64,
+                pub name: String,
+                pub data: Vec<u8>,
+            }
+            impl PredictInput {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    sequence: impl Into<u64>,
+                    name: impl Into<String>,
+                    data: impl Into<Vec<u8>>,
+                ) -> Self {
+                    Self {
+                        sequence: sequence.into(),
+                        name: name.into(),
+                        data: data.into(),
+                    }
+                }
+            }
+            impl Message for PredictInput {
+                fn id() -> &'static str {
+                    "ambient_core::PredictInput"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.sequence.serialize_message_part(&mut output)?;
+                    self.name.serialize_message_part(&mut output)?;
+                    self.data.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        sequence: u64::deserialize_message_part(&mut input)?,
+                        name: String::deserialize_message_part(&mut input)?,
+                        data: Vec::<u8>::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for PredictInput {}
+            #[derive(Clone, Debug)]
             #[doc = "**ModuleUnload**: Sent to a module when it unloads."]
             pub struct ModuleUnload;
             impl ModuleUnload {
@@ -1453,6 +1893,170 @@ mod raw {
             }
             impl RuntimeMessage for WindowCursorLockChange {}
             #[derive(Clone, Debug)]
+            #[doc = "**WindowGamepadConnection**: Sent when a gamepad is connected or disconnected."]
+            pub struct WindowGamepadConnection {
+                pub gamepad: u32,
+                pub name: String,
+                pub connected: bool,
+            }
+            impl WindowGamepadConnection {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    gamepad: impl Into<u32>,
+                    name: impl Into<String>,
+                    connected: impl Into<bool>,
+                ) -> Self {
+                    Self {
+                        gamepad: gamepad.into(),
+                        name: name.into(),
+                        connected: connected.into(),
+                    }
+                }
+            }
+            impl Message for WindowGamepadConnection {
+                fn id() -> &'static str {
+                    "ambient_core::WindowGamepadConnection"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.gamepad.serialize_message_part(&mut output)?;
+                    self.name.serialize_message_part(&mut output)?;
+                    self.connected.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        gamepad: u32::deserialize_message_part(&mut input)?,
+                        name: String::deserialize_message_part(&mut input)?,
+                        connected: bool::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WindowGamepadConnection {}
+            #[derive(Clone, Debug)]
+            #[doc = "**WindowGamepadButton**: Sent when a gamepad button is pressed or released."]
+            pub struct WindowGamepadButton {
+                pub gamepad: u32,
+                pub button: String,
+                pub pressed: bool,
+            }
+            impl WindowGamepadButton {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    gamepad: impl Into<u32>,
+                    button: impl Into<String>,
+                    pressed: impl Into<bool>,
+                ) -> Self {
+                    Self {
+                        gamepad: gamepad.into(),
+                        button: button.into(),
+                        pressed: pressed.into(),
+                    }
+                }
+            }
+            impl Message for WindowGamepadButton {
+                fn id() -> &'static str {
+                    "ambient_core::WindowGamepadButton"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.gamepad.serialize_message_part(&mut output)?;
+                    self.button.serialize_message_part(&mut output)?;
+                    self.pressed.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        gamepad: u32::deserialize_message_part(&mut input)?,
+                        button: String::deserialize_message_part(&mut input)?,
+                        pressed: bool::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WindowGamepadButton {}
+            #[derive(Clone, Debug)]
+            #[doc = "**WindowGamepadAxis**: Sent when a gamepad axis changes, after the deadzone has been applied."]
+            pub struct WindowGamepadAxis {
+                pub gamepad: u32,
+                pub axis: String,
+                pub value: f32,
+            }
+            impl WindowGamepadAxis {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    gamepad: impl Into<u32>,
+                    axis: impl Into<String>,
+                    value: impl Into<f32>,
+                ) -> Self {
+                    Self {
+                        gamepad: gamepad.into(),
+                        axis: axis.into(),
+                        value: value.into(),
+                    }
+                }
+            }
+            impl Message for WindowGamepadAxis {
+                fn id() -> &'static str {
+                    "ambient_core::WindowGamepadAxis"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.gamepad.serialize_message_part(&mut output)?;
+                    self.axis.serialize_message_part(&mut output)?;
+                    self.value.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        gamepad: u32::deserialize_message_part(&mut input)?,
+                        axis: String::deserialize_message_part(&mut input)?,
+                        value: f32::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WindowGamepadAxis {}
+            #[derive(Clone, Debug)]
+            #[doc = "**WindowTouch**: Sent when a finger touches, moves on, or is lifted from the window. The phase is one of `Started`, `Moved`, `Ended` or `Cancelled`, and the position is in pixels from the top left of the window."]
+            pub struct WindowTouch {
+                pub id: u64,
+                pub phase: String,
+                pub position: Vec2,
+            }
+            impl WindowTouch {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    id: impl Into<u64>,
+                    phase: impl Into<String>,
+                    position: impl Into<Vec2>,
+                ) -> Self {
+                    Self {
+                        id: id.into(),
+                        phase: phase.into(),
+                        position: position.into(),
+                    }
+                }
+            }
+            impl Message for WindowTouch {
+                fn id() -> &'static str {
+                    "ambient_core::WindowTouch"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.id.serialize_message_part(&mut output)?;
+                    self.phase.serialize_message_part(&mut output)?;
+                    self.position.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        id: u64::deserialize_message_part(&mut input)?,
+                        phase: String::deserialize_message_part(&mut input)?,
+                        position: Vec2::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WindowTouch {}
+            #[derive(Clone, Debug)]
             #[doc = "**HttpResponse**: Sent when an HTTP response is received."]
             pub struct HttpResponse {
                 pub response_id: u64,
@@ -6,7 +6,7 @@ use paste::paste;
 
 use crate::{
     AttributeConstructor, AttributeStore, ComponentDesc, ComponentRegistry, ComponentVTable,
-    Description, EntityId, ExternalComponentAttributes, Name,
+    Description, EntityId, ExternalComponentAttributes, Name, NetworkPriority,
 };
 
 use ambient_shared_types::primitive_component_definitions;
@@ -17,7 +17,7 @@ use ambient_shared_types::{
 
 // implementation
 macro_rules! build_attribute_registration {
-    ($type:ty, $store:ident, $name:ident, $description:ident, $attributes:ident) => {{
+    ($type:ty, $store:ident, $name:ident, $description:ident, $attributes:ident, $network_priority:ident) => {{
         if let Some(name) = $name {
             <Name as AttributeConstructor<$type, _>>::construct(&mut $store, &name);
         }
@@ -25,6 +25,12 @@ macro_rules! build_attribute_registration {
             <Description as AttributeConstructor<$type, _>>::construct(&mut $store, &description);
         }
         $attributes.construct_for_store::<$type>(&mut $store);
+        if let Some(NetworkPriority { priority, max_rate }) = $network_priority {
+            <NetworkPriority as AttributeConstructor<$type, _>>::construct(
+                &mut $store,
+                (priority, max_rate),
+            );
+        }
 
         static VTABLE: &ComponentVTable<$type> = &ComponentVTable::construct_external();
         unsafe { VTABLE.erase() }
@@ -112,18 +118,18 @@ macro_rules! make_primitive_component {
                     }
                 }
 
-                pub(crate) fn register(&self, reg: &mut ComponentRegistry, path: &str, name: Option<&str>, description: Option<&str>, attributes: ExternalComponentAttributes) {
+                pub(crate) fn register(&self, reg: &mut ComponentRegistry, path: &str, name: Option<&str>, description: Option<&str>, attributes: ExternalComponentAttributes, network_priority: Option<NetworkPriority>) {
                     let mut store = AttributeStore::new();
                     let vtable = match self {
                         $(
                             PrimitiveComponentType::$value => {
-                                build_attribute_registration!($type, store, name, description, attributes)
+                                build_attribute_registration!($type, store, name, description, attributes, network_priority)
                             },
                             PrimitiveComponentType::[< Vec $value >] => {
-                                build_attribute_registration!(Vec<$type>, store, name, description, attributes)
+                                build_attribute_registration!(Vec<$type>, store, name, description, attributes, network_priority)
                             },
                             PrimitiveComponentType::[< Option $value >] => {
-                                build_attribute_registration!(Option<$type>, store, name, description, attributes)
+                                build_attribute_registration!(Option<$type>, store, name, description, attributes, network_priority)
                             },
                         )*
                     };
@@ -191,6 +191,11 @@ impl<'a> IntoIterator for &'a WorldDiff {
 pub struct FrozenWorldDiff {
     changes: Arc<[WorldChange]>,
 }
+impl FrozenWorldDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
 impl From<WorldDiff> for FrozenWorldDiff {
     fn from(diff: WorldDiff) -> Self {
         Self {
@@ -254,7 +259,7 @@ impl WorldStreamFilter {
             .filter(|&comp| (self.component_filter)(comp, WorldStreamCompEvent::Init))
             .collect_vec()
     }
-    fn read_entity_components(&self, world: &World, id: EntityId) -> Vec<ComponentEntry> {
+    pub fn read_entity_components(&self, world: &World, id: EntityId) -> Vec<ComponentEntry> {
         self.get_entity_components(world, id)
             .into_iter()
             .map(|comp| world.get_entry(id, comp).unwrap())
@@ -68,6 +68,7 @@ pub async fn rpc_select(args: ServerRpcArgs, (method, mode): (SelectMethod, Sele
                     RaycastFilter {
                         entities: Some(ArchetypeFilter::new().incl(selectable())),
                         collider_type: None,
+                        collision_mask: None,
                     },
                     ray,
                 ) {
@@ -509,6 +509,7 @@ impl ElementComponent for EditorTerrainMode {
                     filter: RaycastFilter {
                         entities: Some(ArchetypeFilter::new().incl(terrain_world_cell())),
                         collider_type: Some(ColliderScene::Physics),
+                        collision_mask: None,
                     },
                     layer,
                     brush,
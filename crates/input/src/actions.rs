@@ -0,0 +1,388 @@
+//! Input actions and axes, which packages declare in their manifest with default bindings.
+//!
+//! The server spawns an entity for each of them, and the client resolves their bindings against the
+//! player's raw input every frame. The player can override the bindings of any action, or of either
+//! direction of any axis; these overrides are identified by the package ID and a slot, which is the
+//! ID of the action or [axis_slot] for the axis.
+use std::{collections::BTreeMap, str::FromStr};
+
+use ambient_ecs::{
+    components,
+    generated::{
+        input::components::{
+            input_action_bindings, input_action_id, input_action_package, input_action_pressed,
+            input_axis_id, input_axis_negative_bindings, input_axis_package,
+            input_axis_positive_bindings, input_axis_value,
+        },
+        package::components::id as package_id,
+    },
+    query, Component, ComponentValue, Debuggable, EntityId, Resource, SystemGroup, World,
+};
+use ambient_shared_types::InputBinding;
+
+use crate::{player_raw_input, PlayerRawInput};
+
+components!("input", {
+    @[Debuggable, Resource]
+    input_binding_overrides: InputBindingOverrides,
+});
+
+/// The bindings that the player uses instead of the defaults declared by packages, by package ID
+/// and slot.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct InputBindingOverrides(BTreeMap<String, BTreeMap<String, Vec<InputBinding>>>);
+impl InputBindingOverrides {
+    /// Parses the overrides from their persisted form, skipping (and logging) any invalid bindings.
+    pub fn parse(raw: &BTreeMap<String, BTreeMap<String, Vec<String>>>) -> Self {
+        Self(
+            raw.iter()
+                .map(|(package_id, slots)| {
+                    let slots = slots
+                        .iter()
+                        .map(|(slot, bindings)| {
+                            let bindings = bindings
+                                .iter()
+                                .filter_map(|binding| match InputBinding::from_str(binding) {
+                                    Ok(binding) => Some(binding),
+                                    Err(err) => {
+                                        tracing::warn!(
+                                            "Ignoring input binding for {package_id}/{slot}: {err}"
+                                        );
+                                        None
+                                    }
+                                })
+                                .collect();
+                            (slot.clone(), bindings)
+                        })
+                        .collect();
+                    (package_id.clone(), slots)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the overrides in their persisted form.
+    pub fn to_raw(&self) -> BTreeMap<String, BTreeMap<String, Vec<String>>> {
+        self.0
+            .iter()
+            .map(|(package_id, slots)| {
+                let slots = slots
+                    .iter()
+                    .map(|(slot, bindings)| {
+                        (
+                            slot.clone(),
+                            bindings.iter().map(|b| b.to_string()).collect(),
+                        )
+                    })
+                    .collect();
+                (package_id.clone(), slots)
+            })
+            .collect()
+    }
+
+    pub fn get(&self, package_id: &str, slot: &str) -> Option<&[InputBinding]> {
+        self.0
+            .get(package_id)
+            .and_then(|slots| slots.get(slot))
+            .map(|bindings| bindings.as_slice())
+    }
+
+    pub fn set(&mut self, package_id: &str, slot: &str, bindings: Vec<InputBinding>) {
+        self.0
+            .entry(package_id.to_string())
+            .or_default()
+            .insert(slot.to_string(), bindings);
+    }
+
+    /// Goes back to the default bindings for the slot.
+    pub fn reset(&mut self, package_id: &str, slot: &str) {
+        if let Some(slots) = self.0.get_mut(package_id) {
+            slots.remove(slot);
+            if slots.is_empty() {
+                self.0.remove(package_id);
+            }
+        }
+    }
+}
+
+/// The slot that overrides the bindings of one direction of an axis, e.g. `move_forward.positive`.
+pub fn axis_slot(axis_id: &str, positive: bool) -> String {
+    format!(
+        "{axis_id}.{}",
+        if positive { "positive" } else { "negative" }
+    )
+}
+
+/// Returns how far the binding is pressed, from 0 to 1. Gamepad axes range from -1 to 1, and the
+/// axis that is furthest from its center is used if there are several gamepads.
+pub fn binding_value(input: &PlayerRawInput, binding: InputBinding) -> f32 {
+    let pressed = |pressed: bool| if pressed { 1.0 } else { 0.0 };
+    match binding {
+        InputBinding::Key(key) => pressed(input.keys.contains(&key)),
+        InputBinding::Mouse(button) => pressed(input.mouse_buttons.contains(&button)),
+        InputBinding::Gamepad(button) => pressed(
+            input
+                .gamepads
+                .values()
+                .any(|gamepad| gamepad.buttons.contains(&button)),
+        ),
+        InputBinding::GamepadAxis(axis) => input
+            .gamepads
+            .values()
+            .map(|gamepad| gamepad.axis(axis))
+            .fold(0.0, |a: f32, b| if b.abs() > a.abs() { b } else { a }),
+    }
+}
+
+/// Returns the bindings of a slot, which are the player's overrides if there are any, and the
+/// defaults declared by the package otherwise.
+pub fn resolve_bindings(
+    world: &World,
+    package: EntityId,
+    slot: &str,
+    defaults: &[String],
+) -> Vec<InputBinding> {
+    let overrides = world
+        .get_ref(package, package_id())
+        .ok()
+        .and_then(|package_id| {
+            world
+                .resource(input_binding_overrides())
+                .get(package_id, slot)
+        });
+
+    match overrides {
+        Some(bindings) => bindings.to_vec(),
+        // The defaults were validated when the package's manifest was parsed
+        None => defaults
+            .iter()
+            .filter_map(|binding| InputBinding::from_str(binding).ok())
+            .collect(),
+    }
+}
+
+/// Updates the state of the input actions and axes from the player's raw input.
+pub fn client_systems() -> SystemGroup {
+    SystemGroup::new(
+        "input/actions",
+        vec![
+            query((
+                input_action_id(),
+                input_action_package(),
+                input_action_bindings(),
+            ))
+            .to_system(|q, world, qs, _| {
+                let input = world.resource(player_raw_input());
+                let actions = q
+                    .iter(world, qs)
+                    .map(|(id, (action, &package, defaults))| {
+                        let pressed = resolve_bindings(world, package, action, defaults)
+                            .into_iter()
+                            .any(|binding| binding_value(input, binding) > 0.5);
+                        (id, pressed)
+                    })
+                    .collect::<Vec<_>>();
+
+                for (id, pressed) in actions {
+                    set_or_add(world, id, input_action_pressed(), pressed);
+                }
+            }),
+            query((
+                input_axis_id(),
+                input_axis_package(),
+                input_axis_positive_bindings(),
+                input_axis_negative_bindings(),
+            ))
+            .to_system(|q, world, qs, _| {
+                let input = world.resource(player_raw_input());
+                let axes = q
+                    .iter(world, qs)
+                    .map(|(id, (axis, &package, positive, negative))| {
+                        let sum = |is_positive: bool, defaults: &[String]| -> f32 {
+                            resolve_bindings(
+                                world,
+                                package,
+                                &axis_slot(axis, is_positive),
+                                defaults,
+                            )
+                            .into_iter()
+                            .map(|binding| binding_value(input, binding))
+                            .sum()
+                        };
+                        let value = sum(true, positive) - sum(false, negative);
+                        (id, value.clamp(-1.0, 1.0))
+                    })
+                    .collect::<Vec<_>>();
+
+                for (id, value) in axes {
+                    set_or_add(world, id, input_axis_value(), value);
+                }
+            }),
+        ],
+    )
+}
+
+fn set_or_add<T: ComponentValue + PartialEq>(
+    world: &mut World,
+    id: EntityId,
+    component: Component<T>,
+    value: T,
+) {
+    if world.has_component(id, component) {
+        world.set_if_changed(id, component, value).ok();
+    } else {
+        world.add_component(id, component, value).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::{Entity, WorldContext};
+    use ambient_shared_types::{GamepadAxis, GamepadButton, MouseButton, VirtualKeyCode};
+
+    use super::*;
+    use crate::gamepad::GamepadState;
+
+    #[test]
+    fn binding_value_reads_keys_buttons_and_axes() {
+        let mut input = PlayerRawInput::default();
+        input.keys.insert(VirtualKeyCode::Space);
+        input.mouse_buttons.insert(MouseButton::Left);
+
+        let mut first = GamepadState::new("First".to_string());
+        first.buttons.insert(GamepadButton::South);
+        first.left_stick.y = 0.25;
+        let mut second = GamepadState::new("Second".to_string());
+        second.left_stick.y = -0.75;
+        input.gamepads.insert(0, first);
+        input.gamepads.insert(1, second);
+
+        assert_eq!(
+            binding_value(&input, InputBinding::Key(VirtualKeyCode::Space)),
+            1.0
+        );
+        assert_eq!(
+            binding_value(&input, InputBinding::Key(VirtualKeyCode::W)),
+            0.0
+        );
+        assert_eq!(
+            binding_value(&input, InputBinding::Mouse(MouseButton::Left)),
+            1.0
+        );
+        assert_eq!(
+            binding_value(&input, InputBinding::Mouse(MouseButton::Right)),
+            0.0
+        );
+        assert_eq!(
+            binding_value(&input, InputBinding::Gamepad(GamepadButton::South)),
+            1.0
+        );
+        assert_eq!(
+            binding_value(&input, InputBinding::Gamepad(GamepadButton::East)),
+            0.0
+        );
+
+        // The gamepad whose stick is furthest from the center wins, even if it is negative
+        assert_eq!(
+            binding_value(&input, InputBinding::GamepadAxis(GamepadAxis::LeftStickY)),
+            -0.75
+        );
+        assert_eq!(
+            binding_value(&input, InputBinding::GamepadAxis(GamepadAxis::RightStickX)),
+            0.0
+        );
+        assert_eq!(
+            binding_value(
+                &PlayerRawInput::default(),
+                InputBinding::GamepadAxis(GamepadAxis::LeftStickY)
+            ),
+            0.0
+        );
+    }
+
+    #[test]
+    fn resolve_bindings_prefers_the_players_overrides() {
+        ambient_ecs::init_components();
+        crate::init_all_components();
+        let mut world = World::new("actions_test", WorldContext::Client);
+        world
+            .add_components(world.resource_entity(), crate::resources())
+            .unwrap();
+        let package = Entity::new()
+            .with(package_id(), "pkg".to_string())
+            .spawn(&mut world);
+        let defaults = ["Key:Space".to_string(), "Gamepad:South".to_string()];
+
+        assert_eq!(
+            resolve_bindings(&world, package, "jump", &defaults),
+            vec![
+                InputBinding::Key(VirtualKeyCode::Space),
+                InputBinding::Gamepad(GamepadButton::South)
+            ]
+        );
+
+        world.resource_mut(input_binding_overrides()).set(
+            "pkg",
+            "jump",
+            vec![InputBinding::Key(VirtualKeyCode::J)],
+        );
+        assert_eq!(
+            resolve_bindings(&world, package, "jump", &defaults),
+            vec![InputBinding::Key(VirtualKeyCode::J)]
+        );
+        // Overrides only apply to their own slot and package
+        assert_eq!(
+            resolve_bindings(&world, package, "run", &["Key:LShift".to_string()]),
+            vec![InputBinding::Key(VirtualKeyCode::LShift)]
+        );
+        let other_package = Entity::new()
+            .with(package_id(), "other".to_string())
+            .spawn(&mut world);
+        assert_eq!(
+            resolve_bindings(&world, other_package, "jump", &defaults).len(),
+            2
+        );
+
+        // Overriding a slot with no bindings unbinds it, while resetting it restores the defaults
+        world
+            .resource_mut(input_binding_overrides())
+            .set("pkg", "jump", vec![]);
+        assert!(resolve_bindings(&world, package, "jump", &defaults).is_empty());
+        world
+            .resource_mut(input_binding_overrides())
+            .reset("pkg", "jump");
+        assert_eq!(
+            resolve_bindings(&world, package, "jump", &defaults).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn overrides_survive_being_persisted() {
+        let mut overrides = InputBindingOverrides::default();
+        overrides.set(
+            "pkg",
+            &axis_slot("move_forward", true),
+            vec![
+                InputBinding::Key(VirtualKeyCode::Up),
+                InputBinding::GamepadAxis(GamepadAxis::LeftStickY),
+            ],
+        );
+        let mut raw = overrides.to_raw();
+        assert_eq!(
+            raw["pkg"]["move_forward.positive"],
+            vec!["Key:Up".to_string(), "GamepadAxis:LeftStickY".to_string()]
+        );
+
+        raw.get_mut("pkg")
+            .unwrap()
+            .insert("jump".to_string(), vec!["Key:NotAKey".to_string()]);
+        let parsed = InputBindingOverrides::parse(&raw);
+        assert_eq!(
+            parsed.get("pkg", "move_forward.positive"),
+            overrides.get("pkg", "move_forward.positive")
+        );
+        // Invalid bindings are skipped rather than discarding the slot
+        assert_eq!(parsed.get("pkg", "jump"), Some(&[][..]));
+    }
+}
@@ -0,0 +1,631 @@
+//! Gamepad input, which is read with gilrs on both the native and the web client.
+//!
+//! Gamepads are identified by the id that gilrs assigns to them, which stays the same if a
+//! gamepad is disconnected and reconnected.
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    time::Duration,
+};
+
+use ambient_ecs::{
+    components, generated::messages, world_events, Debuggable, Resource, System, World,
+    WorldEventsExt,
+};
+pub use ambient_shared_types::{GamepadAxis, GamepadButton};
+use gilrs::{
+    ev::filter::{axis_dpad_to_button, Filter},
+    ff::{BaseEffect, BaseEffectType, EffectBuilder, Repeat, Replay, Ticks},
+    Axis, Button, EventType, GamepadId, Gilrs, GilrsBuilder,
+};
+use glam::{vec2, Vec2};
+use serde::{Deserialize, Serialize};
+use winit::event::Event;
+
+/// The state of a connected gamepad.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct GamepadState {
+    pub name: String,
+    pub buttons: HashSet<GamepadButton>,
+    /// From -1 to 1 on both axes, with up being positive Y.
+    pub left_stick: Vec2,
+    /// From -1 to 1 on both axes, with up being positive Y.
+    pub right_stick: Vec2,
+    /// From 0 to 1.
+    pub left_trigger: f32,
+    /// From 0 to 1.
+    pub right_trigger: f32,
+}
+impl GamepadState {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_axis(&mut self, axis: GamepadAxis, value: f32) {
+        match axis {
+            GamepadAxis::LeftStickX => self.left_stick.x = value,
+            GamepadAxis::LeftStickY => self.left_stick.y = value,
+            GamepadAxis::RightStickX => self.right_stick.x = value,
+            GamepadAxis::RightStickY => self.right_stick.y = value,
+            GamepadAxis::LeftTrigger => self.left_trigger = value,
+            GamepadAxis::RightTrigger => self.right_trigger = value,
+        }
+    }
+
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        match axis {
+            GamepadAxis::LeftStickX => self.left_stick.x,
+            GamepadAxis::LeftStickY => self.left_stick.y,
+            GamepadAxis::RightStickX => self.right_stick.x,
+            GamepadAxis::RightStickY => self.right_stick.y,
+            GamepadAxis::LeftTrigger => self.left_trigger,
+            GamepadAxis::RightTrigger => self.right_trigger,
+        }
+    }
+
+    /// Releases all buttons and centers all axes.
+    pub fn clear(&mut self) {
+        *self = Self::new(std::mem::take(&mut self.name));
+    }
+}
+
+/// How far the sticks and triggers have to move before they register, from 0 to 1.
+///
+/// Inputs past the deadzone are rescaled, so that they still cover the full range.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GamepadDeadzones {
+    /// The deadzone of the sticks, which is applied to the distance of the stick from its center
+    pub stick: f32,
+    pub trigger: f32,
+}
+impl Default for GamepadDeadzones {
+    fn default() -> Self {
+        Self {
+            stick: 0.15,
+            trigger: 0.05,
+        }
+    }
+}
+
+/// A request to rumble a gamepad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GamepadRumble {
+    pub gamepad: u32,
+    /// The strength of the low-frequency motor, from 0 to 1
+    pub strong: f32,
+    /// The strength of the high-frequency motor, from 0 to 1
+    pub weak: f32,
+    pub duration: Duration,
+}
+
+components!("input", {
+    @[Debuggable, Resource]
+    gamepad_deadzones: GamepadDeadzones,
+    /// The names of the connected gamepads
+    @[Debuggable, Resource]
+    connected_gamepads: BTreeMap<u32, String>,
+    @[Debuggable, Resource]
+    pending_gamepad_rumbles: Vec<GamepadRumble>,
+});
+
+/// How often the gamepad thread polls gilrs for new events.
+#[cfg(not(target_os = "unknown"))]
+const POLL_INTERVAL: Duration = Duration::from_millis(4);
+
+/// The gamepad input that gilrs reports to the [GamepadSystem].
+#[derive(Debug, Clone, PartialEq)]
+enum GamepadEvent {
+    Connected {
+        gamepad: u32,
+        name: String,
+    },
+    Disconnected {
+        gamepad: u32,
+    },
+    Button {
+        gamepad: u32,
+        button: GamepadButton,
+        pressed: bool,
+    },
+    /// The raw value of an axis, before the deadzones are applied
+    Axis {
+        gamepad: u32,
+        axis: GamepadAxis,
+        value: f32,
+    },
+}
+
+/// Sends the input of the connected gamepads as runtime messages, once per frame.
+///
+/// The gilrs context is not thread-safe, so it lives on its own thread and reports to the system
+/// over a channel, like the cursor lock does. On the web, where there are no threads, it is
+/// polled by the system instead.
+pub struct GamepadSystem {
+    events_rx: flume::Receiver<GamepadEvent>,
+    rumbles_tx: flume::Sender<GamepadRumble>,
+    /// The axis values before the deadzones are applied
+    raw_axes: HashMap<(u32, GamepadAxis), f32>,
+    /// The axis values that were last sent
+    axes: HashMap<(u32, GamepadAxis), f32>,
+}
+
+impl GamepadSystem {
+    pub fn new() -> Self {
+        let (events_tx, events_rx) = flume::unbounded();
+        let (rumbles_tx, rumbles_rx) = flume::unbounded();
+        spawn_gilrs(events_tx, rumbles_rx);
+        Self::from_channels(events_rx, rumbles_tx)
+    }
+
+    fn from_channels(
+        events_rx: flume::Receiver<GamepadEvent>,
+        rumbles_tx: flume::Sender<GamepadRumble>,
+    ) -> Self {
+        Self {
+            events_rx,
+            rumbles_tx,
+            raw_axes: HashMap::new(),
+            axes: HashMap::new(),
+        }
+    }
+
+    fn connect(&mut self, world: &mut World, gamepad: u32, name: String) {
+        world
+            .resource_mut(connected_gamepads())
+            .insert(gamepad, name.clone());
+        world
+            .resource_mut(world_events())
+            .add_message(messages::WindowGamepadConnection::new(gamepad, name, true));
+    }
+
+    fn disconnect(&mut self, world: &mut World, gamepad: u32) {
+        self.raw_axes.retain(|(g, _), _| *g != gamepad);
+        self.axes.retain(|(g, _), _| *g != gamepad);
+
+        if let Some(name) = world.resource_mut(connected_gamepads()).remove(&gamepad) {
+            world
+                .resource_mut(world_events())
+                .add_message(messages::WindowGamepadConnection::new(gamepad, name, false));
+        }
+    }
+
+    fn set_axis(&mut self, world: &mut World, gamepad: u32, axis: GamepadAxis, raw: f32) {
+        self.raw_axes.insert((gamepad, axis), raw);
+
+        let deadzones = *world.resource(gamepad_deadzones());
+        match axis {
+            GamepadAxis::LeftStickX | GamepadAxis::LeftStickY => {
+                let stick = (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY);
+                self.set_stick(world, gamepad, stick, deadzones.stick);
+            }
+            GamepadAxis::RightStickX | GamepadAxis::RightStickY => {
+                let stick = (GamepadAxis::RightStickX, GamepadAxis::RightStickY);
+                self.set_stick(world, gamepad, stick, deadzones.stick);
+            }
+            GamepadAxis::LeftTrigger | GamepadAxis::RightTrigger => {
+                let value = apply_trigger_deadzone(raw, deadzones.trigger);
+                self.send_axis(world, gamepad, axis, value);
+            }
+        }
+    }
+
+    /// The deadzone of a stick depends on both of its axes, so they are updated together.
+    fn set_stick(
+        &mut self,
+        world: &mut World,
+        gamepad: u32,
+        (x_axis, y_axis): (GamepadAxis, GamepadAxis),
+        deadzone: f32,
+    ) {
+        let raw = |axis| self.raw_axes.get(&(gamepad, axis)).copied().unwrap_or(0.0);
+        let stick = apply_stick_deadzone(vec2(raw(x_axis), raw(y_axis)), deadzone);
+
+        self.send_axis(world, gamepad, x_axis, stick.x);
+        self.send_axis(world, gamepad, y_axis, stick.y);
+    }
+
+    fn send_axis(&mut self, world: &mut World, gamepad: u32, axis: GamepadAxis, value: f32) {
+        if self.axes.insert((gamepad, axis), value) == Some(value) {
+            return;
+        }
+
+        world
+            .resource_mut(world_events())
+            .add_message(messages::WindowGamepadAxis::new(
+                gamepad,
+                axis.to_string(),
+                value,
+            ));
+    }
+}
+
+impl std::fmt::Debug for GamepadSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GamepadSystem")
+            .field("axes", &self.axes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for GamepadSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System<Event<'static, ()>> for GamepadSystem {
+    fn run(&mut self, world: &mut World, event: &Event<'static, ()>) {
+        if !matches!(event, Event::MainEventsCleared) {
+            return;
+        }
+
+        // If gilrs is not available, there is nothing to rumble
+        for rumble in std::mem::take(world.resource_mut(pending_gamepad_rumbles())) {
+            self.rumbles_tx.send(rumble).ok();
+        }
+
+        #[cfg(target_os = "unknown")]
+        poll_gilrs();
+
+        for event in self.events_rx.drain() {
+            match event {
+                GamepadEvent::Connected { gamepad, name } => self.connect(world, gamepad, name),
+                GamepadEvent::Disconnected { gamepad } => self.disconnect(world, gamepad),
+                GamepadEvent::Button {
+                    gamepad,
+                    button,
+                    pressed,
+                } => {
+                    world.resource_mut(world_events()).add_message(
+                        messages::WindowGamepadButton::new(gamepad, button.to_string(), pressed),
+                    );
+                }
+                GamepadEvent::Axis {
+                    gamepad,
+                    axis,
+                    value,
+                } => self.set_axis(world, gamepad, axis, value),
+            }
+        }
+    }
+}
+
+/// Owns the gilrs context, and translates its events for the [GamepadSystem].
+struct GilrsPoller {
+    gilrs: Gilrs,
+    /// The gamepads that have been reported as connected
+    connected: HashSet<GamepadId>,
+    /// The effects that are playing. Dropping an effect stops it.
+    rumbles: HashMap<u32, gilrs::ff::Effect>,
+    events_tx: flume::Sender<GamepadEvent>,
+    rumbles_rx: flume::Receiver<GamepadRumble>,
+}
+
+impl GilrsPoller {
+    fn new(
+        events_tx: flume::Sender<GamepadEvent>,
+        rumbles_rx: flume::Receiver<GamepadRumble>,
+    ) -> Option<Self> {
+        match GilrsBuilder::new().with_default_filters(false).build() {
+            Ok(gilrs) => Some(Self {
+                gilrs,
+                connected: HashSet::new(),
+                rumbles: HashMap::new(),
+                events_tx,
+                rumbles_rx,
+            }),
+            Err(err) => {
+                tracing::warn!("Gamepads are not available: {err}");
+                None
+            }
+        }
+    }
+
+    /// Handles everything that happened since the last poll. Returns false once the
+    /// [GamepadSystem] is gone.
+    fn poll(&mut self) -> bool {
+        let rumbles = self.rumbles_rx.drain().collect::<Vec<_>>();
+        for rumble in rumbles {
+            self.rumble(rumble);
+        }
+
+        let mut events = Vec::new();
+
+        // The gamepads that were connected before startup do not produce connection events
+        let gamepads = self.gilrs.gamepads().map(|(id, _)| id).collect::<Vec<_>>();
+        for id in gamepads {
+            events.extend(self.connect(id));
+        }
+
+        while let Some(event) = self
+            .gilrs
+            .next_event()
+            .filter_ev(&axis_dpad_to_button, &mut self.gilrs)
+        {
+            let gamepad = gamepad_index(event.id);
+
+            match event.event {
+                EventType::Connected => events.extend(self.connect(event.id)),
+                EventType::Disconnected => {
+                    self.connected.remove(&event.id);
+                    self.rumbles.remove(&gamepad);
+                    events.push(GamepadEvent::Disconnected { gamepad });
+                }
+                EventType::ButtonPressed(button, _) | EventType::ButtonReleased(button, _) => {
+                    let Some(button) = gamepad_button(button) else {
+                        continue;
+                    };
+                    let pressed = matches!(event.event, EventType::ButtonPressed(..));
+                    events.push(GamepadEvent::Button {
+                        gamepad,
+                        button,
+                        pressed,
+                    });
+                }
+                EventType::ButtonChanged(button, value, _) => {
+                    let axis = match button {
+                        Button::LeftTrigger2 => GamepadAxis::LeftTrigger,
+                        Button::RightTrigger2 => GamepadAxis::RightTrigger,
+                        _ => continue,
+                    };
+                    events.push(GamepadEvent::Axis {
+                        gamepad,
+                        axis,
+                        value,
+                    });
+                }
+                EventType::AxisChanged(axis, value, _) => {
+                    let axis = match axis {
+                        Axis::LeftStickX => GamepadAxis::LeftStickX,
+                        Axis::LeftStickY => GamepadAxis::LeftStickY,
+                        Axis::RightStickX => GamepadAxis::RightStickX,
+                        Axis::RightStickY => GamepadAxis::RightStickY,
+                        _ => continue,
+                    };
+                    events.push(GamepadEvent::Axis {
+                        gamepad,
+                        axis,
+                        value,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        !self.rumbles_rx.is_disconnected()
+            && events
+                .into_iter()
+                .all(|event| self.events_tx.send(event).is_ok())
+    }
+
+    fn connect(&mut self, id: GamepadId) -> Option<GamepadEvent> {
+        if !self.connected.insert(id) {
+            return None;
+        }
+
+        Some(GamepadEvent::Connected {
+            gamepad: gamepad_index(id),
+            name: self.gilrs.gamepad(id).name().to_string(),
+        })
+    }
+
+    fn rumble(&mut self, rumble: GamepadRumble) {
+        // Replacing the effect that is playing stops it
+        self.rumbles.remove(&rumble.gamepad);
+        if rumble.duration.is_zero() || (rumble.strong <= 0.0 && rumble.weak <= 0.0) {
+            return;
+        }
+
+        let Some((id, _)) = self
+            .gilrs
+            .gamepads()
+            .find(|(id, _)| gamepad_index(*id) == rumble.gamepad)
+        else {
+            return;
+        };
+
+        let ticks = Ticks::from_ms(rumble.duration.as_millis().min(u32::MAX as u128) as u32);
+        let scheduling = Replay {
+            play_for: ticks,
+            ..Default::default()
+        };
+        let magnitude = |strength: f32| (strength.clamp(0.0, 1.0) * u16::MAX as f32) as u16;
+
+        let effect = EffectBuilder::new()
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Strong {
+                    magnitude: magnitude(rumble.strong),
+                },
+                scheduling,
+                ..Default::default()
+            })
+            .add_effect(BaseEffect {
+                kind: BaseEffectType::Weak {
+                    magnitude: magnitude(rumble.weak),
+                },
+                scheduling,
+                ..Default::default()
+            })
+            .gamepads(&[id])
+            .repeat(Repeat::For(ticks))
+            .finish(&mut self.gilrs);
+
+        // Not all gamepads and platforms support rumble, and it is not essential
+        match effect.and_then(|effect| effect.play().map(|_| effect)) {
+            Ok(effect) => {
+                self.rumbles.insert(rumble.gamepad, effect);
+            }
+            Err(err) => tracing::debug!("Failed to rumble gamepad {}: {err}", rumble.gamepad),
+        }
+    }
+}
+
+#[cfg(not(target_os = "unknown"))]
+fn spawn_gilrs(events_tx: flume::Sender<GamepadEvent>, rumbles_rx: flume::Receiver<GamepadRumble>) {
+    let thread = std::thread::Builder::new()
+        .name("gamepads".to_string())
+        .spawn(move || {
+            let Some(mut poller) = GilrsPoller::new(events_tx, rumbles_rx) else {
+                return;
+            };
+            while poller.poll() {
+                std::thread::sleep(POLL_INTERVAL);
+            }
+        });
+    if let Err(err) = thread {
+        tracing::warn!("Failed to start the gamepad thread: {err}");
+    }
+}
+
+#[cfg(target_os = "unknown")]
+thread_local! {
+    static GILRS_POLLERS: std::cell::RefCell<Vec<GilrsPoller>> = Default::default();
+}
+
+#[cfg(target_os = "unknown")]
+fn spawn_gilrs(events_tx: flume::Sender<GamepadEvent>, rumbles_rx: flume::Receiver<GamepadRumble>) {
+    if let Some(poller) = GilrsPoller::new(events_tx, rumbles_rx) {
+        GILRS_POLLERS.with(|pollers| pollers.borrow_mut().push(poller));
+    }
+}
+
+/// Polls gilrs from the main thread, as the web client has no other threads to run it on.
+#[cfg(target_os = "unknown")]
+fn poll_gilrs() {
+    GILRS_POLLERS.with(|pollers| pollers.borrow_mut().retain_mut(|poller| poller.poll()));
+}
+
+fn gamepad_index(id: GamepadId) -> u32 {
+    usize::from(id) as u32
+}
+
+fn gamepad_button(button: Button) -> Option<GamepadButton> {
+    Some(match button {
+        Button::South => GamepadButton::South,
+        Button::East => GamepadButton::East,
+        Button::North => GamepadButton::North,
+        Button::West => GamepadButton::West,
+        Button::LeftTrigger => GamepadButton::LeftBumper,
+        Button::RightTrigger => GamepadButton::RightBumper,
+        Button::LeftTrigger2 => GamepadButton::LeftTrigger,
+        Button::RightTrigger2 => GamepadButton::RightTrigger,
+        Button::Select => GamepadButton::Select,
+        Button::Start => GamepadButton::Start,
+        Button::Mode => GamepadButton::Mode,
+        Button::LeftThumb => GamepadButton::LeftStick,
+        Button::RightThumb => GamepadButton::RightStick,
+        Button::DPadUp => GamepadButton::DPadUp,
+        Button::DPadDown => GamepadButton::DPadDown,
+        Button::DPadLeft => GamepadButton::DPadLeft,
+        Button::DPadRight => GamepadButton::DPadRight,
+        Button::C | Button::Z | Button::Unknown => return None,
+    })
+}
+
+/// Applies a radial deadzone, so that the direction of the stick is preserved near the center.
+fn apply_stick_deadzone(stick: Vec2, deadzone: f32) -> Vec2 {
+    let deadzone = deadzone.clamp(0.0, 0.99);
+    let length = stick.length();
+    if length <= deadzone {
+        return Vec2::ZERO;
+    }
+
+    stick / length * ((length.min(1.0) - deadzone) / (1.0 - deadzone))
+}
+
+fn apply_trigger_deadzone(value: f32, deadzone: f32) -> f32 {
+    let deadzone = deadzone.clamp(0.0, 0.99);
+    if value <= deadzone {
+        return 0.0;
+    }
+
+    ((value - deadzone) / (1.0 - deadzone)).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stick_deadzone_is_radial_and_rescaled() {
+        assert_eq!(apply_stick_deadzone(vec2(0.1, 0.1), 0.2), Vec2::ZERO);
+        assert_eq!(apply_stick_deadzone(vec2(0.2, 0.0), 0.2), Vec2::ZERO);
+
+        // The direction is kept, and the edge of the deadzone maps to 0
+        let stick = apply_stick_deadzone(vec2(0.0, -0.6), 0.2);
+        assert!((stick - vec2(0.0, -0.5)).length() < 1e-6, "{stick}");
+        let stick = apply_stick_deadzone(vec2(0.3, 0.4), 0.0);
+        assert!((stick - vec2(0.3, 0.4)).length() < 1e-6, "{stick}");
+
+        // Sticks that report more than full deflection are clamped
+        let stick = apply_stick_deadzone(vec2(1.2, 0.0), 0.2);
+        assert!((stick - vec2(1.0, 0.0)).length() < 1e-6, "{stick}");
+    }
+
+    #[test]
+    fn trigger_deadzone_is_rescaled() {
+        assert_eq!(apply_trigger_deadzone(0.0, 0.1), 0.0);
+        assert_eq!(apply_trigger_deadzone(0.1, 0.1), 0.0);
+        assert!((apply_trigger_deadzone(0.55, 0.1) - 0.5).abs() < 1e-6);
+        assert_eq!(apply_trigger_deadzone(1.0, 0.1), 1.0);
+        assert_eq!(apply_trigger_deadzone(1.5, 0.1), 1.0);
+
+        // A deadzone of 1 would never register anything
+        assert!(apply_trigger_deadzone(1.0, 1.0) > 0.0);
+    }
+
+    #[test]
+    fn system_tracks_connections_and_forwards_rumbles() {
+        ambient_ecs::init_components();
+        crate::init_all_components();
+        let mut world = World::new("gamepad_test", ambient_ecs::WorldContext::Client);
+        world
+            .add_components(
+                world.resource_entity(),
+                crate::resources().with(world_events(), Default::default()),
+            )
+            .unwrap();
+
+        let (events_tx, events_rx) = flume::unbounded();
+        let (rumbles_tx, rumbles_rx) = flume::unbounded();
+        let mut system = GamepadSystem::from_channels(events_rx, rumbles_tx);
+
+        events_tx
+            .send(GamepadEvent::Connected {
+                gamepad: 3,
+                name: "Pad".to_string(),
+            })
+            .unwrap();
+        let rumble = GamepadRumble {
+            gamepad: 3,
+            strong: 1.0,
+            weak: 0.5,
+            duration: Duration::from_millis(100),
+        };
+        world.resource_mut(pending_gamepad_rumbles()).push(rumble);
+        system.run(&mut world, &Event::MainEventsCleared);
+
+        assert_eq!(
+            world.resource(connected_gamepads()).get(&3),
+            Some(&"Pad".to_string())
+        );
+        assert_eq!(rumbles_rx.drain().collect::<Vec<_>>(), vec![rumble]);
+        assert!(world.resource(pending_gamepad_rumbles()).is_empty());
+
+        events_tx
+            .send(GamepadEvent::Axis {
+                gamepad: 3,
+                axis: GamepadAxis::LeftTrigger,
+                value: 0.5,
+            })
+            .unwrap();
+        events_tx
+            .send(GamepadEvent::Disconnected { gamepad: 3 })
+            .unwrap();
+        system.run(&mut world, &Event::MainEventsCleared);
+        assert!(world.resource(connected_gamepads()).is_empty());
+        assert!(system.axes.is_empty());
+    }
+}
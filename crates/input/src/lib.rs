@@ -1,9 +1,10 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use ambient_ecs::{
     components, generated::messages, world_events, Debuggable, Entity, FnSystem, Resource, System,
     SystemGroup, WorldEventsExt,
 };
+use ambient_shared_types::TouchGestures;
 use glam::{vec2, Vec2};
 use serde::{Deserialize, Serialize};
 use winit::event::ModifiersState;
@@ -12,7 +13,10 @@ pub use winit::event::{
     WindowEvent,
 };
 
+pub mod actions;
+pub mod gamepad;
 pub mod picking;
+pub mod touch;
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct PlayerRawInput {
@@ -21,6 +25,14 @@ pub struct PlayerRawInput {
     pub mouse_delta: Vec2,
     pub mouse_wheel: f32,
     pub mouse_buttons: HashSet<ambient_shared_types::MouseButton>,
+    /// The connected gamepads, by their id
+    pub gamepads: BTreeMap<u32, gamepad::GamepadState>,
+    /// The touches on the screen, by their id
+    pub touches: BTreeMap<u64, touch::TouchState>,
+    /// How far the touches moved together since the last frame
+    pub touch_pan: Vec2,
+    /// How much the distance between the first two touches changed since the last frame
+    pub touch_pinch: f32,
 }
 impl PlayerRawInput {
     pub fn clear(&mut self) {
@@ -28,6 +40,33 @@ impl PlayerRawInput {
         self.mouse_delta = vec2(0.0, 0.0);
         self.mouse_wheel = 0.0;
         self.mouse_buttons.clear();
+        for gamepad in self.gamepads.values_mut() {
+            gamepad.clear();
+        }
+        self.touches.clear();
+        self.touch_pan = vec2(0.0, 0.0);
+        self.touch_pinch = 0.0;
+    }
+
+    /// Replaces the touches with their current positions, and recognizes the gestures that they
+    /// made since this was last called.
+    pub fn set_touches(&mut self, touches: &BTreeMap<u64, Vec2>) {
+        let previous = self
+            .touches
+            .iter()
+            .map(|(id, touch)| (*id, touch.position))
+            .collect::<BTreeMap<_, _>>();
+        let gestures = TouchGestures::between(&previous, touches);
+
+        self.touches = touches
+            .iter()
+            .map(|(id, &position)| {
+                let delta = previous.get(id).map_or(Vec2::ZERO, |p| position - *p);
+                (*id, touch::TouchState { position, delta })
+            })
+            .collect();
+        self.touch_pan = gestures.pan;
+        self.touch_pinch = gestures.pinch;
     }
 }
 
@@ -42,11 +81,21 @@ components!("input", {
 
 pub fn init_all_components() {
     picking::init_components();
+    gamepad::init_components();
+    actions::init_components();
+    touch::init_components();
     init_components();
 }
 
 pub fn event_systems() -> SystemGroup<Event<'static, ()>> {
-    SystemGroup::new("inputs", vec![Box::new(InputSystem::new())])
+    SystemGroup::new(
+        "inputs",
+        vec![
+            Box::new(InputSystem::new()),
+            Box::new(gamepad::GamepadSystem::new()),
+            Box::new(touch::TouchSystem::new()),
+        ],
+    )
 }
 
 pub fn cursor_lock_system(cursor_lock_rx: flume::Receiver<bool>) -> Box<dyn System + Send + Sync> {
@@ -63,6 +112,12 @@ pub fn resources() -> Entity {
     Entity::new()
         .with(player_raw_input(), Default::default())
         .with(player_prev_raw_input(), Default::default())
+        .with(gamepad::gamepad_deadzones(), Default::default())
+        .with(gamepad::connected_gamepads(), Default::default())
+        .with(gamepad::pending_gamepad_rumbles(), Default::default())
+        .with(actions::input_binding_overrides(), Default::default())
+        .with(touch::touches(), Default::default())
+        .with(touch::touch_mouse_emulation(), false)
 }
 
 #[derive(Debug)]
@@ -188,3 +243,32 @@ pub struct MouseInput {
     pub state: ElementState,
     pub button: MouseButton,
 }
+
+#[cfg(test)]
+mod tests {
+    use glam::vec2;
+
+    use super::*;
+
+    #[test]
+    fn set_touches_tracks_deltas_and_gestures() {
+        let mut input = PlayerRawInput::default();
+        input.set_touches(&BTreeMap::from([(1, vec2(0., 0.)), (2, vec2(10., 0.))]));
+        assert_eq!(input.touches[&1].delta, Vec2::ZERO);
+        assert_eq!(input.touch_pan, Vec2::ZERO);
+        assert_eq!(input.touch_pinch, 0.);
+
+        input.set_touches(&BTreeMap::from([(1, vec2(-5., 2.)), (2, vec2(15., 2.))]));
+        assert_eq!(input.touches[&1].position, vec2(-5., 2.));
+        assert_eq!(input.touches[&1].delta, vec2(-5., 2.));
+        assert_eq!(input.touches[&2].delta, vec2(5., 2.));
+        assert_eq!(input.touch_pan, vec2(0., 2.));
+        assert_eq!(input.touch_pinch, 10.);
+
+        // Lifting a finger removes the touch without making the gestures jump
+        input.set_touches(&BTreeMap::from([(2, vec2(15., 2.))]));
+        assert!(!input.touches.contains_key(&1));
+        assert_eq!(input.touch_pan, Vec2::ZERO);
+        assert_eq!(input.touch_pinch, 0.);
+    }
+}
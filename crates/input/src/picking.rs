@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashMap};
+
 use ambient_core::{
     camera::{clip_position_to_world_ray, get_active_camera},
     player::{local_user_id, user_id},
@@ -8,13 +10,16 @@ use ambient_core::{
 use ambient_ecs::{
     components,
     generated::input::{
-        components::{mouse_over_distance, mouse_over_entity},
+        components::{mouse_over_distance, mouse_over_entity, touch_ids, touch_positions},
         messages::MouseOverChanged,
     },
-    query, world_events, Debuggable, Entity, EntityId, SystemGroup, WorldEventsExt,
+    query, world_events, Debuggable, Entity, EntityId, Resource, SystemGroup, World,
+    WorldEventsExt,
 };
 use ambient_native_std::shapes::{RayIntersectable, AABB};
-use glam::Vec2;
+use glam::{UVec2, Vec2};
+
+use crate::touch::touches;
 
 pub use ambient_ecs::generated::input::components::{
     is_mouse_over, mouse_pickable_max, mouse_pickable_min,
@@ -24,12 +29,17 @@ components!("input", {
 
     @[Debuggable]
     mouse_pickable: AABB,
+    /// The entities that captured the touches on the window, by touch id. This is null for
+    /// touches that did not start on a pickable entity.
+    @[Debuggable, Resource]
+    touch_targets: BTreeMap<u64, EntityId>,
 });
 
 pub fn resources() -> Entity {
     Entity::new()
         .with(mouse_over_entity(), EntityId::null())
         .with(mouse_over_distance(), f32::MAX)
+        .with(touch_targets(), Default::default())
 }
 
 pub fn frame_systems() -> SystemGroup {
@@ -49,42 +59,15 @@ pub fn frame_systems() -> SystemGroup {
             }),
             query((window_logical_size(), cursor_position())).to_system(|q, world, qs, _| {
                 for (id, (window_size, mouse_position)) in q.collect_cloned(world, qs) {
-                    let mut mouse_origin =
-                        -Vec2::ONE + (mouse_position / window_size.as_vec2()) * 2.;
-                    mouse_origin.y = -mouse_origin.y;
-                    let camera = match get_active_camera(
-                        world,
-                        ui_scene(),
-                        world
-                            .get_ref(id, user_id())
-                            .ok()
-                            .or_else(|| world.resource_opt(local_user_id())),
-                    ) {
-                        Some(cam) => cam,
-                        None => return,
+                    let Some((intersecting_entity, intersecting_dist)) =
+                        pick(world, id, window_size, mouse_position)
+                    else {
+                        return;
                     };
-                    let ray =
-                        clip_position_to_world_ray(world, camera, mouse_origin).unwrap_or_default();
 
                     let prev_intersecting_entity =
                         world.get(id, mouse_over_entity()).unwrap_or_default();
 
-                    let mut intersecting_entity = EntityId::null();
-                    let mut intersecting_dist = 0.;
-                    for (id2, (pickable, local_to_world)) in
-                        query((mouse_pickable(), local_to_world())).iter(world, None)
-                    {
-                        if local_to_world.is_nan() {
-                            continue;
-                        }
-                        let ray = ray.transform(local_to_world.inverse());
-                        if let Some(dist) = pickable.ray_intersect(ray) {
-                            if intersecting_entity.is_null() || dist < intersecting_dist {
-                                intersecting_entity = id2;
-                                intersecting_dist = dist;
-                            }
-                        }
-                    }
                     if prev_intersecting_entity != intersecting_entity {
                         if !prev_intersecting_entity.is_null() {
                             if let Ok(prev_mouse_over) =
@@ -129,6 +112,99 @@ pub fn frame_systems() -> SystemGroup {
                     }
                 }
             }),
+            query((window_logical_size(), touches())).to_system(|q, world, qs, _| {
+                for (id, (window_size, touches)) in q.collect_cloned(world, qs) {
+                    let mut targets = world.resource(touch_targets()).clone();
+                    if touches.is_empty() && targets.is_empty() {
+                        continue;
+                    }
+
+                    // Touches are captured by the entity they start on, like pointers in browsers
+                    targets.retain(|touch, _| touches.contains_key(touch));
+                    for (&touch, &position) in &touches {
+                        if !targets.contains_key(&touch) {
+                            let target = pick(world, id, window_size, position)
+                                .map_or(EntityId::null(), |(target, _)| target);
+                            targets.insert(touch, target);
+                        }
+                    }
+
+                    let mut touches_by_target = HashMap::<EntityId, (Vec<u64>, Vec<Vec2>)>::new();
+                    for (touch, target) in &targets {
+                        if !target.is_null() && world.exists(*target) {
+                            let (ids, positions) = touches_by_target.entry(*target).or_default();
+                            ids.push(*touch);
+                            positions.push(touches[touch]);
+                        }
+                    }
+
+                    let released = query(touch_ids())
+                        .iter(world, None)
+                        .map(|(target, _)| target)
+                        .filter(|target| !touches_by_target.contains_key(target))
+                        .collect::<Vec<_>>();
+                    for target in released {
+                        world.remove_component(target, touch_ids()).unwrap();
+                        world.remove_component(target, touch_positions()).unwrap();
+                    }
+                    for (target, (ids, positions)) in touches_by_target {
+                        // Only touch the components when they change, so that change queries on
+                        // them are not triggered every frame
+                        if world.has_component(target, touch_ids()) {
+                            world.set_if_changed(target, touch_ids(), ids).unwrap();
+                            world
+                                .set_if_changed(target, touch_positions(), positions)
+                                .unwrap();
+                        } else {
+                            world.add_component(target, touch_ids(), ids).unwrap();
+                            world
+                                .add_component(target, touch_positions(), positions)
+                                .unwrap();
+                        }
+                    }
+
+                    *world.resource_mut(touch_targets()) = targets;
+                }
+            }),
         ],
     )
 }
+
+/// Returns the pickable entity under the position on the window, and its distance from the
+/// camera, or a null entity if there is none. Returns `None` if there is no camera to pick with.
+fn pick(
+    world: &World,
+    id: EntityId,
+    window_size: UVec2,
+    position: Vec2,
+) -> Option<(EntityId, f32)> {
+    let mut origin = -Vec2::ONE + (position / window_size.as_vec2()) * 2.;
+    origin.y = -origin.y;
+    let camera = get_active_camera(
+        world,
+        ui_scene(),
+        world
+            .get_ref(id, user_id())
+            .ok()
+            .or_else(|| world.resource_opt(local_user_id())),
+    )?;
+    let ray = clip_position_to_world_ray(world, camera, origin).unwrap_or_default();
+
+    let mut intersecting_entity = EntityId::null();
+    let mut intersecting_dist = 0.;
+    for (id, (pickable, local_to_world)) in
+        query((mouse_pickable(), local_to_world())).iter(world, None)
+    {
+        if local_to_world.is_nan() {
+            continue;
+        }
+        let ray = ray.transform(local_to_world.inverse());
+        if let Some(dist) = pickable.ray_intersect(ray) {
+            if intersecting_entity.is_null() || dist < intersecting_dist {
+                intersecting_entity = id;
+                intersecting_dist = dist;
+            }
+        }
+    }
+    Some((intersecting_entity, intersecting_dist))
+}
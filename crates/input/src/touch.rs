@@ -0,0 +1,142 @@
+//! Touch input, which winit provides on touchscreens and in mobile browsers.
+//!
+//! Touches are identified by the id that winit assigns to them, which is unique among the
+//! touches that are on the screen at the same time, but may be reused afterwards.
+use std::collections::{BTreeMap, HashSet};
+
+use ambient_core::window::{cursor_position, window_scale_factor};
+use ambient_ecs::{
+    components, generated::messages, world_events, Debuggable, Resource, System, World,
+    WorldEventsExt,
+};
+use ambient_shared_types::{MouseButton, TouchPhase};
+use glam::{vec2, Vec2};
+use serde::{Deserialize, Serialize};
+use winit::event::{Event, WindowEvent};
+
+/// The state of a touch on the screen.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TouchState {
+    /// In pixels from the top left of the window.
+    pub position: Vec2,
+    /// The movement of the touch since the last frame.
+    pub delta: Vec2,
+}
+
+components!("input", {
+    /// The positions of the touches on the window, by their id. Touches that ended are kept until
+    /// the next frame, so that taps are seen even if they start and end within the same frame.
+    @[Debuggable, Resource]
+    touches: BTreeMap<u64, Vec2>,
+    /// When enabled, the first touch on the window also moves the cursor and holds down the left
+    /// mouse button, so that content made for the mouse can be used on touchscreens.
+    @[Debuggable, Resource]
+    touch_mouse_emulation: bool,
+});
+
+/// Tracks the touches on the window, and sends them as runtime messages.
+#[derive(Debug, Default)]
+pub struct TouchSystem {
+    /// The touches that ended since the last frame
+    ended: HashSet<u64>,
+    /// The touches that ended before the last frame, which are removed this frame
+    ended_previously: HashSet<u64>,
+    /// The touch that is emulating the mouse
+    emulating: Option<u64>,
+}
+
+impl TouchSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn touch(&mut self, world: &mut World, id: u64, phase: TouchPhase, position: Vec2) {
+        match phase {
+            TouchPhase::Started | TouchPhase::Moved => {
+                // The id may be reused by a new touch before the old one was removed
+                self.ended.remove(&id);
+                self.ended_previously.remove(&id);
+                world.resource_mut(touches()).insert(id, position);
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                if let Some(previous) = world.resource_mut(touches()).get_mut(&id) {
+                    *previous = position;
+                    self.ended.insert(id);
+                }
+            }
+        }
+
+        world
+            .resource_mut(world_events())
+            .add_message(messages::WindowTouch::new(id, phase.to_string(), position));
+
+        if *world.resource(touch_mouse_emulation()) {
+            self.emulate_mouse(world, id, phase, position);
+        } else {
+            self.emulating = None;
+        }
+    }
+
+    fn emulate_mouse(&mut self, world: &mut World, id: u64, phase: TouchPhase, position: Vec2) {
+        if phase == TouchPhase::Started && self.emulating.is_none() {
+            self.emulating = Some(id);
+        }
+        if self.emulating != Some(id) {
+            return;
+        }
+
+        let delta = match phase {
+            // The cursor jumps to where the touch started, which is not a movement of the mouse
+            TouchPhase::Started => Vec2::ZERO,
+            _ => position - *world.resource(cursor_position()),
+        };
+        *world.resource_mut(cursor_position()) = position;
+        if delta != Vec2::ZERO {
+            world
+                .resource_mut(world_events())
+                .add_message(messages::WindowMouseMotion::new(delta));
+        }
+
+        let pressed = match phase {
+            TouchPhase::Started => true,
+            TouchPhase::Moved => return,
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                self.emulating = None;
+                false
+            }
+        };
+        world
+            .resource_mut(world_events())
+            .add_message(messages::WindowMouseInput::new(pressed, MouseButton::Left));
+    }
+}
+
+impl System<Event<'static, ()>> for TouchSystem {
+    fn run(&mut self, world: &mut World, event: &Event<'static, ()>) {
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::Touch(touch),
+                ..
+            } => {
+                let scale_factor = *world.resource(window_scale_factor());
+                let position =
+                    vec2(touch.location.x as f32, touch.location.y as f32) / scale_factor as f32;
+                let phase = match touch.phase {
+                    winit::event::TouchPhase::Started => TouchPhase::Started,
+                    winit::event::TouchPhase::Moved => TouchPhase::Moved,
+                    winit::event::TouchPhase::Ended => TouchPhase::Ended,
+                    winit::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+                };
+                self.touch(world, touch.id, phase, position);
+            }
+            Event::MainEventsCleared => {
+                let positions = world.resource_mut(touches());
+                for id in self.ended_previously.drain() {
+                    positions.remove(&id);
+                }
+                std::mem::swap(&mut self.ended, &mut self.ended_previously);
+            }
+            _ => {}
+        }
+    }
+}
@@ -48,6 +48,15 @@ pub type PlatformSendStream = crate::webtransport::SendStream;
 #[cfg(target_os = "unknown")]
 pub type PlatformRecvStream = crate::webtransport::RecvStream;
 
+/// A unidirectional stream from the server, as passed to a [UniStreamHandlers] handler.
+///
+/// Usually a [PlatformRecvStream], but read from memory when the session is being recorded or
+/// replayed.
+#[cfg(not(target_os = "unknown"))]
+pub type ClientRecvStream = crate::DynRecv;
+#[cfg(target_os = "unknown")]
+pub type ClientRecvStream = std::pin::Pin<Box<dyn tokio::io::AsyncRead>>;
+
 type BiStreamHandler = Arc<
     dyn Fn(
             &mut World,
@@ -59,7 +68,7 @@ type BiStreamHandler = Arc<
         + Send,
 >;
 type UniStreamHandler = Arc<
-    dyn Fn(&mut World, AssetCache, PlatformRecvStream) -> PlatformBoxFuture<'static, ()>
+    dyn Fn(&mut World, AssetCache, ClientRecvStream) -> PlatformBoxFuture<'static, ()>
         + Sync
         + Send,
 >;
@@ -240,6 +249,10 @@ pub struct NetworkStats {
     pub bytes_received: u64,
     pub packets_sent: u64,
     pub packets_lost: u64,
+    /// World diff bytes received per second
+    pub diff_bytes_received: u64,
+    /// World diff bytes received per second, once decompressed
+    pub diff_bytes_decompressed: u64,
 }
 
 impl Display for NetworkStats {
@@ -247,11 +260,13 @@ impl Display for NetworkStats {
         let loss = self.packets_lost as f32 / self.packets_sent as f32;
         write!(
             f,
-            "{:?} ms rtt, {}/s out, {}/s in, {:.03} loss",
+            "{:?} ms rtt, {}/s out, {}/s in, {:.03} loss, {}/s diffs ({}/s decompressed)",
             self.latency_ms,
             to_byte_unit(self.bytes_sent),
             to_byte_unit(self.bytes_received),
             if loss.is_nan() { 0.0 } else { loss },
+            to_byte_unit(self.diff_bytes_received),
+            to_byte_unit(self.diff_bytes_decompressed),
         )
     }
 }
@@ -26,6 +26,8 @@ use glam::{vec2, Mat4, Vec2, Vec3, Vec3Swizzles};
 use ambient_core::player::{is_player, user_id};
 use tracing::debug_span;
 
+use crate::interpolation;
+
 components!("rendering", {
     game_screen_render_target: Arc<RenderTarget>,
 });
@@ -70,6 +72,7 @@ impl ClientGameState {
             "game",
             vec![
                 Box::new(client_systems),
+                Box::new(interpolation::systems()),
                 Box::new(world_instance_systems(true)),
                 Box::new(audio_systems()),
             ],
@@ -2,11 +2,14 @@
 use std::collections::HashMap;
 
 use ambient_ecs::{
-    with_component_registry, ComponentDesc, ComponentEntry, ComponentRegistry, Entity, EntityId,
-    External, ExternalComponentDesc, Serializable, WorldChange, WorldDiff,
+    with_component_registry, Color, ComponentDesc, ComponentEntry, ComponentRegistry, Entity,
+    EntityId, External, ExternalComponentDesc, Serializable, WorldChange, WorldDiff,
 };
 use bincode::Options;
 use bytes::Bytes;
+use glam::{Quat, Vec2, Vec3, Vec4};
+use half::f16;
+use itertools::Itertools;
 use serde::{
     ser::{SerializeSeq, SerializeTuple},
     Deserialize, Deserializer,
@@ -91,6 +94,8 @@ enum WorldChangeTag {
     AddComponents = 2,
     RemoveComponents = 3,
     SetComponents = 4,
+    /// `SetComponents` with values encoded with a `ValueEncoding`
+    SetComponentsEncoded = 5,
 }
 impl TryFrom<u8> for WorldChangeTag {
     type Error = ();
@@ -102,6 +107,7 @@ impl TryFrom<u8> for WorldChangeTag {
             2 => Ok(Self::AddComponents),
             3 => Ok(Self::RemoveComponents),
             4 => Ok(Self::SetComponents),
+            5 => Ok(Self::SetComponentsEncoded),
             _ => Err(()),
         }
     }
@@ -125,6 +131,9 @@ impl<'a> From<&NetworkedWorldChange<'a>> for WorldChangeTag {
             NetworkedWorldChange::AddComponents(_, _) => WorldChangeTag::AddComponents,
             NetworkedWorldChange::RemoveComponents(_, _) => WorldChangeTag::RemoveComponents,
             NetworkedWorldChange::SetComponents(_, _) => WorldChangeTag::SetComponents,
+            NetworkedWorldChange::SetComponentsEncoded(_, _) => {
+                WorldChangeTag::SetComponentsEncoded
+            }
         }
     }
 }
@@ -246,6 +255,16 @@ impl From<&ComponentDesc> for UnknownComponent {
 /// `ComponentEntry` and `ComponentDesc` are serialized differently compared to the textual representation. Instead of
 /// serializing the full component path, `DiffSerializer` serializes only the internal component index (`u32`).
 ///
+/// ## Value encoding
+///
+/// With `with_value_encoding(true)`, values in `SetComponents` changes that can be sent more compactly are moved to a
+/// `SetComponentsEncoded` change for the same entity, which follows it. Its data is a collection of `EncodedValue`s:
+/// - rotations (`Quat`) and colors are quantized, see `ValueEncoding` for details
+/// - floats and float vectors are XORed with the last value sent for that entity and component, which is usually
+///   close to the new value, and written as variable-length integers
+///
+/// Deserialization always supports encoded values, and keeps track of the last values it received for the latter.
+///
 /// ## Example
 ///
 /// ```
@@ -281,6 +300,8 @@ impl From<&ComponentDesc> for UnknownComponent {
 #[derive(Clone, Default)]
 pub struct DiffSerializer {
     known_component_paths: HashMap<u32, String>,
+    encode_values: bool,
+    baselines: Baselines,
 }
 
 impl std::fmt::Debug for DiffSerializer {
@@ -289,10 +310,16 @@ impl std::fmt::Debug for DiffSerializer {
     }
 }
 impl DiffSerializer {
+    /// Enables the compact encoding of values when serializing; see [DiffSerializer] for details.
+    pub fn with_value_encoding(mut self, enabled: bool) -> Self {
+        self.encode_values = enabled;
+        self
+    }
+
     fn serialize_parts(
         &mut self,
         unknown_components: HashMap<u32, UnknownComponent>,
-        diff: &NetworkedWorldDiff,
+        diff: &impl serde::Serialize,
     ) -> Result<Bytes, bincode::Error> {
         // serialize unknown components so that deserialize can map idx to component path
         let mut buffer = bincode_options().serialize(&unknown_components)?;
@@ -329,8 +356,83 @@ impl DiffSerializer {
     pub fn serialize(&mut self, diff: &WorldDiff) -> Result<Bytes, bincode::Error> {
         // get all component that we haven't seen before
         let unknown_components = self.collect_unknown_components(diff.changes.iter());
+        if !self.encode_values {
+            // serialize everything
+            return self.serialize_parts(unknown_components, &NetworkedWorldDiff(diff));
+        }
+
+        // split out the values that can be encoded, in order as they depend on the previous values
+        let encoded = diff
+            .changes
+            .iter()
+            .map(|change| {
+                let encoded = match change {
+                    WorldChange::SetComponents(id, entity) => self.encode_entity(*id, entity),
+                    _ => None,
+                };
+                self.baselines.update(change);
+                encoded
+            })
+            .collect_vec();
+        let changes = diff
+            .changes
+            .iter()
+            .zip(&encoded)
+            .flat_map(|(change, encoded)| match (change, encoded) {
+                (WorldChange::SetComponents(id, _), Some((rest, values))) => (!rest.is_empty())
+                    .then(|| NetworkedWorldChange::SetComponents(id.0, NetworkedEntity(rest)))
+                    .into_iter()
+                    .chain([NetworkedWorldChange::SetComponentsEncoded(id.0, values)])
+                    .collect_vec(),
+                _ => vec![NetworkedWorldChange::from(change)],
+            })
+            .collect_vec();
         // serialize everything
-        self.serialize_parts(unknown_components, &NetworkedWorldDiff(diff))
+        self.serialize_parts(unknown_components, &NetworkedWorldChanges(&changes))
+    }
+
+    /// Splits the values of `entity` into the ones that are sent as-is and the encoded ones.
+    /// Returns `None` if no values can be encoded.
+    fn encode_entity(&self, id: EntityId, entity: &Entity) -> Option<(Entity, Vec<EncodedValue>)> {
+        let mut rest = Entity::new();
+        let mut values = Vec::new();
+        for entry in entity.iter() {
+            let index = entry.desc().index();
+            match encode_value(entry, self.baselines.get(id, index)) {
+                Some((encoding, data)) => values.push(EncodedValue {
+                    index,
+                    encoding,
+                    data,
+                }),
+                None => rest.set_entry(entry.clone()),
+            }
+        }
+        (!values.is_empty()).then_some((rest, values))
+    }
+
+    fn decode_entity(
+        &self,
+        id: EntityId,
+        values: Vec<EncodedValue>,
+    ) -> Result<Entity, bincode::Error> {
+        values
+            .into_iter()
+            .map(|value| -> Result<ComponentEntry, bincode::Error> {
+                let desc = component_desc(&self.known_component_paths, value.index)?;
+                decode_value(
+                    desc,
+                    value.encoding,
+                    &value.data,
+                    self.baselines.get(id, value.index),
+                )
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!(
+                        "Failed to decode {:?} value of {:?}",
+                        value.encoding, desc
+                    ))
+                })
+            })
+            .collect()
     }
 
     fn collect_unknown_components<'a, I>(&self, changes: I) -> HashMap<u32, UnknownComponent>
@@ -382,10 +484,29 @@ impl DiffSerializer {
                 .map(|(k, v)| (k, v.into_path())),
         );
         // deserialize the actual changes
-        deserializer.deserialize_seq(NetworkedChangesVisitor::from(&*self))
+        let received = deserializer.deserialize_seq(NetworkedChangesVisitor::from(&*self))?;
+        // decode the encoded values, in order as they depend on the previous values
+        let mut changes = Vec::with_capacity(received.len());
+        for change in received {
+            let change = match change {
+                ReceivedChange::Change(change) => change,
+                ReceivedChange::Encoded(id, values) => {
+                    WorldChange::SetComponents(id, self.decode_entity(id, values)?)
+                }
+            };
+            self.baselines.update(&change);
+            changes.push(change);
+        }
+        Ok(WorldDiff { changes })
     }
 }
 
+/// A change as it is read from the wire, before its encoded values are decoded
+enum ReceivedChange {
+    Change(WorldChange),
+    Encoded(EntityId, Vec<EncodedValue>),
+}
+
 #[derive(Clone, Copy, Debug)]
 struct NetworkedChangesVisitor<'a> {
     known_component_paths: &'a HashMap<u32, String>,
@@ -398,7 +519,7 @@ impl<'a> From<&'a DiffSerializer> for NetworkedChangesVisitor<'a> {
     }
 }
 impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangesVisitor<'a> {
-    type Value = WorldDiff;
+    type Value = Vec<ReceivedChange>;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         formatter.write_str("struct WorldDiff")
@@ -416,7 +537,7 @@ impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangesVisitor<'a> {
         while let Some(change) = seq.next_element_seed(NetworkedChangeVisitor::from(self))? {
             changes.push(change)
         }
-        Ok(WorldDiff { changes })
+        Ok(changes)
     }
 }
 
@@ -425,7 +546,7 @@ struct NetworkedChangeVisitor<'a> {
     known_component_paths: &'a HashMap<u32, String>,
 }
 impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangeVisitor<'a> {
-    type Value = WorldChange;
+    type Value = ReceivedChange;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         formatter.write_str("enum WorldChange")
@@ -438,7 +559,7 @@ impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangeVisitor<'a> {
         // WorldChange is encoded as a tuple of 3 elements:
         // 1. tag
         // 2. id
-        // 3. change specific data (either Entity, Vec<ComponentDesc> or 0u8 for Despawn)
+        // 3. change specific data (either Entity, Vec<ComponentDesc>, Vec<EncodedValue> or 0u8 for Despawn)
 
         let tag = seq
             .next_element_seed(WorldChangeTagVisitor)?
@@ -447,7 +568,7 @@ impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangeVisitor<'a> {
             .next_element_seed(NetworkedEntityIdVisitor)?
             .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
 
-        Ok(match tag {
+        Ok(ReceivedChange::Change(match tag {
             WorldChangeTag::Spawn => {
                 let entity = seq
                     .next_element_seed(NetworkedEntityVisitor::from(self))?
@@ -479,11 +600,17 @@ impl<'a, 'de> serde::de::Visitor<'de> for NetworkedChangeVisitor<'a> {
                     .ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
                 WorldChange::SetComponents(id, entity)
             }
-        })
+            WorldChangeTag::SetComponentsEncoded => {
+                let values = seq
+                    .next_element::<Vec<EncodedValue>>()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
+                return Ok(ReceivedChange::Encoded(id, values));
+            }
+        }))
     }
 }
 impl<'a, 'de> serde::de::DeserializeSeed<'de> for NetworkedChangeVisitor<'a> {
-    type Value = WorldChange;
+    type Value = ReceivedChange;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
@@ -492,7 +619,7 @@ impl<'a, 'de> serde::de::DeserializeSeed<'de> for NetworkedChangeVisitor<'a> {
         // WorldChange is encoded as a tuple of 3 elements:
         // 1. tag
         // 2. id
-        // 3. change specific data (either Entity, Vec<ComponentDesc> or 0u8 for Despawn)
+        // 3. change specific data (either Entity, Vec<ComponentDesc>, Vec<EncodedValue> or 0u8 for Despawn)
         deserializer.deserialize_tuple(3, self)
     }
 }
@@ -607,6 +734,24 @@ impl<'a, 'de> serde::de::DeserializeSeed<'de> for NetworkedComponentDescsVisitor
     }
 }
 
+fn component_desc<E: serde::de::Error>(
+    known_component_paths: &HashMap<u32, String>,
+    index: u32,
+) -> Result<ComponentDesc, E> {
+    match known_component_paths.get(&index) {
+        Some(path) => {
+            let component = with_component_registry(|r| r.get_by_path(path));
+            match component {
+                Some(desc) => Ok(desc),
+                None => Err(E::custom(format!(
+                    "Encountered unknown networked component: {index} {path:?}"
+                ))),
+            }
+        }
+        None => Err(E::custom(format!("Unknown component index {}", index))),
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 struct NetworkedComponentDescVisitor<'a> {
     known_component_paths: &'a HashMap<u32, String>,
@@ -622,21 +767,7 @@ impl<'a, 'de> serde::de::Visitor<'de> for NetworkedComponentDescVisitor<'a> {
     where
         E: serde::de::Error,
     {
-        match self.known_component_paths.get(&v) {
-            Some(path) => {
-                let component = with_component_registry(|r| r.get_by_path(path));
-                match component {
-                    Some(desc) => Ok(desc),
-                    None => Err(serde::de::Error::custom(format!(
-                        "Encountered unknown networked component: {v} {path:?}"
-                    ))),
-                }
-            }
-            None => Err(serde::de::Error::custom(format!(
-                "Unknown component index {}",
-                v
-            ))),
-        }
+        component_desc(self.known_component_paths, v)
     }
 }
 impl<'a, 'de> serde::de::DeserializeSeed<'de> for NetworkedComponentDescVisitor<'a> {
@@ -706,6 +837,21 @@ impl<'a> serde::Serialize for NetworkedWorldDiff<'a> {
     }
 }
 
+#[derive(Clone, Copy, Debug)]
+struct NetworkedWorldChanges<'a>(&'a [NetworkedWorldChange<'a>]);
+impl<'a> serde::Serialize for NetworkedWorldChanges<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for change in self.0.iter() {
+            seq.serialize_element(change)?;
+        }
+        seq.end()
+    }
+}
+
 #[derive(Clone, Debug)]
 enum NetworkedWorldChange<'a> {
     Spawn(u128, NetworkedEntity<'a>),
@@ -713,6 +859,7 @@ enum NetworkedWorldChange<'a> {
     AddComponents(u128, NetworkedEntity<'a>),
     RemoveComponents(u128, Vec<NetworkedComponentDesc>),
     SetComponents(u128, NetworkedEntity<'a>),
+    SetComponentsEncoded(u128, &'a [EncodedValue]),
 }
 impl<'a> NetworkedWorldChange<'a> {
     fn id(&self) -> u128 {
@@ -721,13 +868,16 @@ impl<'a> NetworkedWorldChange<'a> {
             | NetworkedWorldChange::Despawn(id)
             | NetworkedWorldChange::AddComponents(id, _)
             | NetworkedWorldChange::RemoveComponents(id, _)
-            | NetworkedWorldChange::SetComponents(id, _) => *id,
+            | NetworkedWorldChange::SetComponents(id, _)
+            | NetworkedWorldChange::SetComponentsEncoded(id, _) => *id,
         }
     }
 
     fn entity(&self) -> Option<NetworkedEntity<'a>> {
         match self {
-            NetworkedWorldChange::Despawn(_) | NetworkedWorldChange::RemoveComponents(_, _) => None,
+            NetworkedWorldChange::Despawn(_)
+            | NetworkedWorldChange::RemoveComponents(_, _)
+            | NetworkedWorldChange::SetComponentsEncoded(_, _) => None,
             NetworkedWorldChange::Spawn(_, e)
             | NetworkedWorldChange::AddComponents(_, e)
             | NetworkedWorldChange::SetComponents(_, e) => Some(*e),
@@ -763,7 +913,7 @@ impl<'a> serde::Serialize for NetworkedWorldChange<'a> {
         // WorldChange is encoded as a tuple of 3 elements:
         // 1. tag
         // 2. id
-        // 3. change specific data (either Entity, Vec<ComponentDesc> or 0u8 for Despawn)
+        // 3. change specific data (either Entity, Vec<ComponentDesc>, Vec<EncodedValue> or 0u8 for Despawn)
         let mut seq = serializer.serialize_tuple(3)?;
         seq.serialize_element(&WorldChangeTag::from(self))?;
         seq.serialize_element(&self.id())?;
@@ -771,6 +921,8 @@ impl<'a> serde::Serialize for NetworkedWorldChange<'a> {
             seq.serialize_element(&e)?;
         } else if let NetworkedWorldChange::RemoveComponents(_, components) = self {
             seq.serialize_element(components)?;
+        } else if let NetworkedWorldChange::SetComponentsEncoded(_, values) = self {
+            seq.serialize_element(values)?;
         } else if let NetworkedWorldChange::Despawn(_) = self {
             seq.serialize_element(&0u8)?;
         } else {
@@ -818,6 +970,276 @@ impl<'a> serde::Serialize for NetworkedComponentEntry<'a> {
 #[derive(Clone, Copy, Debug, serde::Serialize)]
 struct NetworkedComponentDesc(u32);
 
+/// How the data of an `EncodedValue` is encoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+enum ValueEncoding {
+    /// A unit `Quat` as the index of its largest component (`u8`), followed by the other three components (`i16`),
+    /// which are always within ±1/√2. The largest component is recomputed from the others. Lossy.
+    QuantizedRotation,
+    /// A `Vec3` or `Vec4` color as half-precision floats. Lossy, but keeps HDR values.
+    QuantizedColor,
+    /// The bits of each float XORed with the last value sent for the component, as variable-length integers.
+    /// Lossless, and only used when smaller than the value itself.
+    Delta,
+}
+
+/// A component value in a `SetComponentsEncoded` change
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct EncodedValue {
+    /// Internal component index
+    index: u32,
+    encoding: ValueEncoding,
+    data: Vec<u8>,
+}
+
+/// The last values that were sent for the components that can be delta encoded
+#[derive(Clone, Debug, Default)]
+struct Baselines(HashMap<EntityId, HashMap<u32, Vec<f32>>>);
+impl Baselines {
+    fn get(&self, id: EntityId, index: u32) -> Option<&[f32]> {
+        Some(self.0.get(&id)?.get(&index)?.as_slice())
+    }
+
+    fn update(&mut self, change: &WorldChange) {
+        match change {
+            WorldChange::Spawn(id, entity)
+            | WorldChange::AddComponents(id, entity)
+            | WorldChange::SetComponents(id, entity) => {
+                for entry in entity.iter() {
+                    if is_color(entry.desc()) {
+                        continue;
+                    }
+                    if let Some(lanes) = float_lanes(entry) {
+                        self.0
+                            .entry(*id)
+                            .or_default()
+                            .insert(entry.desc().index(), lanes);
+                    }
+                }
+            }
+            WorldChange::Despawn(id) => {
+                self.0.remove(id);
+            }
+            WorldChange::RemoveComponents(id, components) => {
+                if let Some(baselines) = self.0.get_mut(id) {
+                    for desc in components {
+                        baselines.remove(&desc.index());
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn is_color(desc: ComponentDesc) -> bool {
+    desc.has_attribute::<Color>()
+}
+
+fn float_lanes(entry: &ComponentEntry) -> Option<Vec<f32>> {
+    if let Some(value) = entry.try_downcast_ref::<f32>() {
+        Some(vec![*value])
+    } else if let Some(value) = entry.try_downcast_ref::<Vec2>() {
+        Some(value.to_array().to_vec())
+    } else if let Some(value) = entry.try_downcast_ref::<Vec3>() {
+        Some(value.to_array().to_vec())
+    } else {
+        entry
+            .try_downcast_ref::<Vec4>()
+            .map(|value| value.to_array().to_vec())
+    }
+}
+
+fn from_float_lanes(desc: ComponentDesc, lanes: &[f32]) -> Option<ComponentEntry> {
+    Some(match *lanes {
+        [x] if desc.is::<f32>() => ComponentEntry::from_raw_parts(desc, x),
+        [x, y] if desc.is::<Vec2>() => ComponentEntry::from_raw_parts(desc, Vec2::new(x, y)),
+        [x, y, z] if desc.is::<Vec3>() => ComponentEntry::from_raw_parts(desc, Vec3::new(x, y, z)),
+        [x, y, z, w] if desc.is::<Vec4>() => {
+            ComponentEntry::from_raw_parts(desc, Vec4::new(x, y, z, w))
+        }
+        _ => return None,
+    })
+}
+
+/// Scales the three smallest components of a unit quaternion to the range of `i16`
+const ROTATION_SCALE: f32 = i16::MAX as f32 * std::f32::consts::SQRT_2;
+
+fn encode_value(
+    entry: &ComponentEntry,
+    baseline: Option<&[f32]>,
+) -> Option<(ValueEncoding, Vec<u8>)> {
+    if let Some(rotation) = entry.try_downcast_ref::<Quat>() {
+        if !rotation.is_normalized() {
+            return None;
+        }
+        let mut components = rotation.to_array();
+        let largest = (0..4)
+            .max_by(|&a, &b| components[a].abs().total_cmp(&components[b].abs()))
+            .unwrap();
+        // q and -q are the same rotation, so the largest component can always be positive
+        if components[largest] < 0. {
+            components = components.map(|c| -c);
+        }
+        let mut data = vec![largest as u8];
+        for (_, c) in components.iter().enumerate().filter(|(i, _)| *i != largest) {
+            data.extend(((c * ROTATION_SCALE).round() as i16).to_le_bytes());
+        }
+        return Some((ValueEncoding::QuantizedRotation, data));
+    }
+
+    let lanes = float_lanes(entry)?;
+    if is_color(entry.desc()) {
+        let data = lanes
+            .iter()
+            .flat_map(|&x| f16::from_f32(x).to_le_bytes())
+            .collect();
+        return Some((ValueEncoding::QuantizedColor, data));
+    }
+
+    let baseline = baseline.filter(|baseline| baseline.len() == lanes.len())?;
+    let mut data = Vec::new();
+    for (x, base) in lanes.iter().zip(baseline) {
+        write_varint(&mut data, x.to_bits() ^ base.to_bits());
+    }
+    // the encoding and the length of the data take up 2 bytes
+    (data.len() + 2 < lanes.len() * 4).then_some((ValueEncoding::Delta, data))
+}
+
+fn decode_value(
+    desc: ComponentDesc,
+    encoding: ValueEncoding,
+    data: &[u8],
+    baseline: Option<&[f32]>,
+) -> Option<ComponentEntry> {
+    match encoding {
+        ValueEncoding::QuantizedRotation => {
+            let (&largest, rest) = data.split_first()?;
+            let largest = largest as usize;
+            if !desc.is::<Quat>() || largest > 3 || rest.len() != 6 {
+                return None;
+            }
+            let mut others = rest
+                .chunks_exact(2)
+                .map(|c| i16::from_le_bytes([c[0], c[1]]) as f32 / ROTATION_SCALE);
+            let mut components = [0.; 4];
+            for (i, c) in components.iter_mut().enumerate() {
+                if i != largest {
+                    *c = others.next()?;
+                }
+            }
+            let sum_of_squares: f32 = components.iter().map(|c| c * c).sum();
+            components[largest] = (1. - sum_of_squares).max(0.).sqrt();
+            Some(ComponentEntry::from_raw_parts(
+                desc,
+                Quat::from_array(components).normalize(),
+            ))
+        }
+        ValueEncoding::QuantizedColor => {
+            if data.len() % 2 != 0 {
+                return None;
+            }
+            let lanes = data
+                .chunks_exact(2)
+                .map(|c| f16::from_le_bytes([c[0], c[1]]).to_f32())
+                .collect_vec();
+            from_float_lanes(desc, &lanes)
+        }
+        ValueEncoding::Delta => {
+            let mut data = data;
+            let lanes = baseline?
+                .iter()
+                .map(|base| Some(f32::from_bits(base.to_bits() ^ read_varint(&mut data)?)))
+                .collect::<Option<Vec<_>>>()?;
+            if !data.is_empty() {
+                return None;
+            }
+            from_float_lanes(desc, &lanes)
+        }
+    }
+}
+
+/// Writes `value` in LEB128, 7 bits per byte
+fn write_varint(data: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        data.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    data.push(value as u8);
+}
+
+fn read_varint(data: &mut &[u8]) -> Option<u32> {
+    let mut value = 0u32;
+    for shift in (0..32).step_by(7) {
+        let (&byte, rest) = data.split_first()?;
+        *data = rest;
+        value |= ((byte & 0x7f) as u32) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Compression of whole serialized diffs, negotiated when a client connects.
+///
+/// Compressed messages are prefixed with the compression that was used (`u8`), as small messages, or messages that
+/// don't compress well, are sent uncompressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[repr(u8)]
+pub enum DiffCompression {
+    #[default]
+    None = 0,
+    Lz4 = 1,
+}
+impl DiffCompression {
+    /// The compressions supported by this version, in order of preference
+    pub const SUPPORTED: &'static [Self] = &[Self::Lz4];
+
+    /// Messages smaller than this are not worth compressing
+    const MIN_SIZE: usize = 128;
+
+    /// Picks the preferred compression out of the ones supported by a client
+    pub fn negotiate(client_supported: &[Self]) -> Self {
+        Self::SUPPORTED
+            .iter()
+            .copied()
+            .find(|compression| client_supported.contains(compression))
+            .unwrap_or_default()
+    }
+
+    /// Compresses a message serialized by `DiffSerializer`
+    pub fn compress(self, message: &[u8]) -> Bytes {
+        let compressed = match self {
+            Self::Lz4 if message.len() >= Self::MIN_SIZE => {
+                Some(lz4_flex::compress_prepend_size(message))
+            }
+            _ => None,
+        };
+        let (compression, data) = match &compressed {
+            Some(compressed) if compressed.len() < message.len() => (self, compressed.as_slice()),
+            _ => (Self::None, message),
+        };
+        let mut buffer = Vec::with_capacity(data.len() + 1);
+        buffer.push(compression as u8);
+        buffer.extend_from_slice(data);
+        buffer.into()
+    }
+
+    /// Decompresses a message compressed with [Self::compress]
+    pub fn decompress(message: Bytes) -> anyhow::Result<Bytes> {
+        let Some((&compression, data)) = message.split_first() else {
+            anyhow::bail!("Empty diff message");
+        };
+        if compression == Self::None as u8 {
+            Ok(message.slice(1..))
+        } else if compression == Self::Lz4 as u8 {
+            Ok(lz4_flex::decompress_size_prepended(data)?.into())
+        } else {
+            anyhow::bail!("Unknown diff compression {compression}")
+        }
+    }
+}
+
 macro_rules! impl_from_kcp {
     ($source:ty, $target:ty) => {
         impl<'a> From<$source> for $target {
@@ -865,6 +1287,12 @@ mod tests {
         float: f32,
         @[Serializable]
         counter: usize,
+        @[Serializable]
+        position: Vec3,
+        @[Serializable]
+        orientation: Quat,
+        @[Serializable, Color]
+        tint: Vec4,
     });
 
     fn assert_same_diffs<'a, 'b, A, B>(a: A, b: B)
@@ -1033,4 +1461,109 @@ mod tests {
             assert_eq!(WorldChangeTag::try_from(tag as u8).unwrap(), tag);
         }
     }
+
+    #[test]
+    fn encoded_values_pass_through_serialization() {
+        // Arrange
+        init_components();
+        let id = EntityId::new();
+        let spawn = WorldDiff {
+            changes: vec![WorldChange::Spawn(
+                id,
+                Entity::new()
+                    .with(text(), "foo".to_string())
+                    .with(position(), Vec3::new(10.0, 2.0, -3.5))
+                    .with(orientation(), Quat::IDENTITY),
+            )],
+        };
+        let rotation = Quat::from_euler(glam::EulerRot::XYZ, 0.3, -1.2, 2.0);
+        let set = WorldDiff {
+            changes: vec![WorldChange::SetComponents(
+                id,
+                Entity::new()
+                    .with(text(), "bar".to_string())
+                    .with(position(), Vec3::new(10.001, 2.0, -3.5))
+                    .with(orientation(), rotation),
+            )],
+        };
+        let mut serializer = DiffSerializer::default().with_value_encoding(true);
+        let mut plain_serializer = DiffSerializer::default();
+        let mut deserializer = DiffSerializer::default();
+
+        // Act
+        let spawn_bytes = serializer.serialize(&spawn).unwrap();
+        let set_bytes = serializer.serialize(&set).unwrap();
+        plain_serializer.serialize(&spawn).unwrap();
+        let plain_set_bytes = plain_serializer.serialize(&set).unwrap();
+        let received_spawn = deserializer.deserialize(spawn_bytes).unwrap();
+        let received_set = deserializer.deserialize(set_bytes.clone()).unwrap();
+
+        // Assert
+        assert!(set_bytes.len() < plain_set_bytes.len());
+        assert_same_diffs(&spawn, &received_spawn);
+        let mut received = Entity::new();
+        for change in &received_set.changes {
+            let WorldChange::SetComponents(received_id, entity) = change else {
+                panic!("Expected only SetComponents changes, got {change:?}");
+            };
+            assert_eq!(*received_id, id);
+            received.merge(entity.clone());
+        }
+        assert_eq!(received.get_ref(text()), Some(&"bar".to_string()));
+        // delta encoding is lossless
+        assert_eq!(received.get(position()), Some(Vec3::new(10.001, 2.0, -3.5)));
+        // quantized rotations are not, but they're close
+        assert!(received.get(orientation()).unwrap().angle_between(rotation) < 1e-3);
+    }
+
+    #[test]
+    fn colors_are_quantized() {
+        // Arrange
+        init_components();
+        let id = EntityId::new();
+        let entity = Entity::new()
+            .with(tint(), Vec4::new(0.25, 0.5, 0.75, 1.0))
+            .with(position(), Vec3::ONE);
+        let diff = WorldDiff {
+            changes: vec![WorldChange::SetComponents(id, entity.clone())],
+        };
+        let mut serializer = DiffSerializer::default().with_value_encoding(true);
+
+        // Act
+        let tint_encoding = encode_value(entity.get_entry(tint()).unwrap(), None);
+        let position_encoding = encode_value(entity.get_entry(position()).unwrap(), None);
+        let bytes = serializer.serialize(&diff).unwrap();
+        let received = DiffSerializer::default().deserialize(bytes).unwrap();
+
+        // Assert
+        // only components with the `Color` attribute are stored with half precision
+        assert!(matches!(
+            tint_encoding,
+            Some((ValueEncoding::QuantizedColor, data)) if data.len() == 8
+        ));
+        assert!(position_encoding.is_none());
+        // these values are exactly representable with half precision
+        assert_same_diffs(&diff, &received);
+    }
+
+    #[test]
+    fn compression_round_trips() {
+        let small = Bytes::from_static(b"small");
+        let large = Bytes::from(b"large and repetitive ".repeat(100));
+
+        for message in [small.clone(), large.clone()] {
+            let compressed = DiffCompression::Lz4.compress(&message);
+            assert_eq!(DiffCompression::decompress(compressed).unwrap(), message);
+        }
+        assert_eq!(DiffCompression::Lz4.compress(&small).len(), small.len() + 1);
+        assert!(DiffCompression::Lz4.compress(&large).len() < large.len());
+        assert_eq!(
+            DiffCompression::negotiate(&[DiffCompression::None]),
+            DiffCompression::None
+        );
+        assert_eq!(
+            DiffCompression::negotiate(DiffCompression::SUPPORTED),
+            DiffCompression::Lz4
+        );
+    }
 }
@@ -0,0 +1,436 @@
+//! Client-side interpolation of `Interpolated` components on remote entities.
+//!
+//! Instead of being applied as soon as they arrive, the values the server sends for these
+//! components are buffered with the server time they were produced at (`server_frame_time`).
+//! Every frame, the components are set to their value `interpolation_delay` behind the estimated
+//! current server time, interpolated between the two buffered values around that time. If no newer
+//! value has arrived in time, the last two values are extrapolated for up to
+//! `interpolation_extrapolation_limit`.
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use ambient_core::{
+    game_time,
+    transform::{rotation, scale, translation},
+};
+use ambient_ecs::{
+    components, ComponentDesc, ComponentEntry, Entity, EntityId, FnSystem, Interpolated, Networked,
+    Resource, SystemGroup, World, WorldChange, WorldDiff,
+};
+use ambient_sys::time::Instant;
+use glam::{Quat, Vec2, Vec3, Vec4};
+use itertools::Itertools;
+
+pub use ambient_ecs::generated::network::components::{
+    interpolate_transform, interpolation_delay, interpolation_extrapolation_limit,
+    server_frame_time,
+};
+
+use crate::{prediction::is_local, ServerWorldExt};
+
+const DEFAULT_DELAY: f32 = 0.1;
+const DEFAULT_EXTRAPOLATION_LIMIT: f32 = 0.25;
+/// How quickly the [ServerClock] follows updates that arrive later than expected
+const CLOCK_SMOOTHING: f64 = 0.02;
+
+/// The values received for a component, and the server time they were produced at, oldest first.
+type Samples = VecDeque<(Duration, ComponentEntry)>;
+
+/// Estimates the current time of the server from the times at which its updates arrive.
+#[derive(Debug, Clone)]
+pub struct ServerClock {
+    /// The local time the clock was started at
+    origin: Instant,
+    /// The estimated server time at `origin`, in seconds
+    server_time_at_origin: f64,
+}
+
+impl ServerClock {
+    fn new(now: Instant, server_time: Duration) -> Self {
+        Self {
+            origin: now,
+            server_time_at_origin: server_time.as_secs_f64(),
+        }
+    }
+
+    /// Updates the estimate with an update from `server_time` that was received `now`.
+    ///
+    /// The update that took the least time to arrive gives the best estimate, so the clock jumps
+    /// ahead to updates that arrive early, and only slowly falls back for updates that arrive late,
+    /// in case the latency has increased.
+    fn update(&mut self, now: Instant, server_time: Duration) {
+        let estimate = server_time.as_secs_f64() - now.duration_since(self.origin).as_secs_f64();
+        if estimate > self.server_time_at_origin {
+            self.server_time_at_origin = estimate;
+        } else {
+            self.server_time_at_origin += (estimate - self.server_time_at_origin) * CLOCK_SMOOTHING;
+        }
+    }
+
+    /// The estimated time of the server at `now`.
+    pub fn server_time(&self, now: Instant) -> Duration {
+        let seconds = self.server_time_at_origin + now.duration_since(self.origin).as_secs_f64();
+        Duration::from_secs_f64(seconds.max(0.))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InterpolationBuffer {
+    samples: Samples,
+    /// Set once the component has been set to its final value, until a new value is received.
+    settled: bool,
+}
+
+components!("network::interpolation", {
+    /// The buffered values of the interpolated components of remote entities.
+    @[Resource]
+    interpolation_buffers: HashMap<(EntityId, ComponentDesc), InterpolationBuffer>,
+    /// The client's estimate of the server's time, which interpolation follows.
+    @[Resource]
+    server_clock: ServerClock,
+});
+
+/// The server time that a diff was produced at, if it includes it.
+fn diff_server_time(diff: &WorldDiff) -> Option<Duration> {
+    diff.changes.iter().rev().find_map(|change| match change {
+        WorldChange::Spawn(_, data)
+        | WorldChange::AddComponents(_, data)
+        | WorldChange::SetComponents(_, data) => data.get(server_frame_time()),
+        _ => None,
+    })
+}
+
+fn is_interpolated(world: &World, id: EntityId, data: &Entity, desc: ComponentDesc) -> bool {
+    if !desc.has_attribute::<Networked>() || is_local(world, id) {
+        return false;
+    }
+    if desc.has_attribute::<Interpolated>() {
+        return true;
+    }
+    let transform = [translation().desc(), rotation().desc(), scale().desc()];
+    transform.contains(&desc)
+        && (data.contains(interpolate_transform())
+            || world.has_component(id, interpolate_transform()))
+}
+
+/// Buffers the values of the interpolated components in a diff from the server.
+///
+/// Values for components the entity already has are removed from the diff, and are applied by
+/// [systems] instead. Must be called before the diff is applied to the world.
+pub fn buffer_diff(world: &mut World, diff: &mut WorldDiff) {
+    let now = Instant::now();
+    let server_time = match (
+        diff_server_time(diff),
+        world.resource_mut_opt(server_clock()),
+    ) {
+        (Some(server_time), Some(clock)) => {
+            clock.update(now, server_time);
+            server_time
+        }
+        (Some(server_time), None) => {
+            world.add_resource(server_clock(), ServerClock::new(now, server_time));
+            server_time
+        }
+        (None, Some(clock)) => clock.server_time(now),
+        (None, None) => Duration::ZERO,
+    };
+
+    let mut received = Vec::new();
+    let mut removed = Vec::new();
+
+    for change in &mut diff.changes {
+        match change {
+            WorldChange::Spawn(id, data) | WorldChange::AddComponents(id, data) => {
+                received.extend(
+                    data.iter()
+                        .filter(|entry| is_interpolated(world, *id, data, entry.desc()))
+                        .map(|entry| (*id, entry.clone())),
+                );
+            }
+            WorldChange::SetComponents(id, data) => {
+                let interpolated = data
+                    .iter()
+                    .map(|entry| entry.desc())
+                    .filter(|&desc| {
+                        is_interpolated(world, *id, data, desc) && world.has_component(*id, desc)
+                    })
+                    .collect_vec();
+                for desc in interpolated {
+                    received.extend(data.remove_raw(desc).map(|entry| (*id, entry)));
+                }
+            }
+            WorldChange::RemoveComponents(id, descs) => {
+                removed.extend(descs.iter().map(|&desc| (*id, Some(desc))));
+            }
+            WorldChange::Despawn(id) => removed.push((*id, None)),
+        }
+    }
+
+    if received.is_empty() && removed.is_empty() {
+        return;
+    }
+    if world.resource_opt(interpolation_buffers()).is_none() {
+        world.add_resource(interpolation_buffers(), Default::default());
+    }
+    let buffers = world.resource_mut(interpolation_buffers());
+    for (id, desc) in removed {
+        buffers.retain(|&(buffered_id, buffered_desc), _| {
+            buffered_id != id || desc.is_some_and(|desc| desc != buffered_desc)
+        });
+    }
+    for (id, entry) in received {
+        let buffer = buffers.entry((id, entry.desc())).or_default();
+        buffer.samples.push_back((server_time, entry));
+        buffer.settled = false;
+    }
+}
+
+/// Updates `server_frame_time` on the server every frame, so that clients know when the updates
+/// they receive were produced.
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "network/interpolation/server",
+        vec![Box::new(FnSystem::new(|world, _| {
+            let time = *world.resource(game_time());
+            if let Some(id) = world.synced_resource_entity() {
+                world.add_component(id, server_frame_time(), time).ok();
+            }
+        }))],
+    )
+}
+
+/// Sets the interpolated components of remote entities to their buffered values.
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "network/interpolation",
+        vec![Box::new(FnSystem::new(|world, _| {
+            profiling::scope!("interpolation");
+            let seconds = |value: Option<&f32>, default: f32| {
+                Duration::from_secs_f32(value.copied().unwrap_or(default).max(0.))
+            };
+            let delay = seconds(world.resource_opt(interpolation_delay()), DEFAULT_DELAY);
+            let extrapolation_limit = seconds(
+                world.resource_opt(interpolation_extrapolation_limit()),
+                DEFAULT_EXTRAPOLATION_LIMIT,
+            );
+            let Some(server_time) = world
+                .resource_opt(server_clock())
+                .map(|clock| clock.server_time(Instant::now()))
+            else {
+                return;
+            };
+            let time = server_time.saturating_sub(delay);
+
+            let Some(buffers) = world.resource_mut_opt(interpolation_buffers()) else {
+                return;
+            };
+            let values = buffers
+                .iter_mut()
+                .filter(|(_, buffer)| !buffer.settled)
+                .filter_map(|(&(id, _), buffer)| {
+                    let (value, settled) = sample(&mut buffer.samples, time, extrapolation_limit)?;
+                    buffer.settled = settled;
+                    Some((id, value))
+                })
+                .collect_vec();
+
+            for (id, entry) in values {
+                if world.exists(id) {
+                    world.set_entry(id, entry).ok();
+                }
+            }
+        }))],
+    )
+}
+
+/// Returns the value of `samples` at the server time `time`, and whether it will stay at that
+/// value until a new sample is received. Drops the samples that are no longer needed.
+fn sample(
+    samples: &mut Samples,
+    time: Duration,
+    extrapolation_limit: Duration,
+) -> Option<(ComponentEntry, bool)> {
+    // The index of the last sample that is due
+    let Some(index) = samples
+        .iter()
+        .rposition(|(sample_time, _)| *sample_time <= time)
+    else {
+        return samples.front().map(|(_, entry)| (entry.clone(), false));
+    };
+    // Keep one sample before the current one to extrapolate from
+    samples.drain(..index.saturating_sub(1));
+    let index = index.min(1);
+
+    let previous = index.checked_sub(1).and_then(|index| samples.get(index));
+    match (previous, &samples[index], samples.get(index + 1)) {
+        // Interpolate towards the next sample
+        (_, (from_time, from), Some((to_time, to))) => {
+            let elapsed = time - *from_time;
+            let t = fraction(elapsed, *to_time - *from_time);
+            Some((mix(from, to, t), false))
+        }
+        // Extrapolate past the last sample
+        (Some((from_time, from)), (to_time, to), None) => {
+            let elapsed = time - *to_time;
+            let settled = elapsed >= extrapolation_limit;
+            let t = 1. + fraction(elapsed.min(extrapolation_limit), *to_time - *from_time);
+            Some((mix(from, to, t), settled))
+        }
+        (None, (_, value), None) => Some((value.clone(), true)),
+    }
+}
+
+fn fraction(elapsed: Duration, span: Duration) -> f32 {
+    if span.is_zero() {
+        1.
+    } else {
+        elapsed.as_secs_f32() / span.as_secs_f32()
+    }
+}
+
+/// Blends from `from` (at 0) to `to` (at 1); `t` may be past 1 to extrapolate.
+///
+/// Values that can't be blended switch from `from` to `to` at 1.
+fn mix(from: &ComponentEntry, to: &ComponentEntry, t: f32) -> ComponentEntry {
+    let desc = to.desc();
+    macro_rules! mix_linear {
+        ($($ty:ty),*) => {
+            $(
+                if let (Some(from), Some(to)) = (
+                    from.try_downcast_ref::<$ty>(),
+                    to.try_downcast_ref::<$ty>(),
+                ) {
+                    return ComponentEntry::from_raw_parts(desc, *from + (*to - *from) * t);
+                }
+            )*
+        };
+    }
+    mix_linear!(Vec2, Vec3, Vec4);
+
+    if let (Some(from), Some(to)) = (from.try_downcast_ref::<f32>(), to.try_downcast_ref::<f32>()) {
+        return ComponentEntry::from_raw_parts(desc, from + (to - from) * t);
+    }
+    if let (Some(from), Some(to)) = (from.try_downcast_ref::<f64>(), to.try_downcast_ref::<f64>()) {
+        return ComponentEntry::from_raw_parts(desc, from + (to - from) * t as f64);
+    }
+    if let (Some(from), Some(to)) = (
+        from.try_downcast_ref::<Quat>(),
+        to.try_downcast_ref::<Quat>(),
+    ) {
+        return ComponentEntry::from_raw_parts(desc, from.slerp(*to, t).normalize());
+    }
+
+    if t < 1. {
+        from.clone()
+    } else {
+        to.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    components!("test", {
+        @[Networked, Interpolated]
+        value: f32,
+    });
+
+    fn init() {
+        ambient_ecs::init_components();
+        crate::init_all_components();
+        init_components();
+    }
+
+    fn seconds(seconds: f32) -> Duration {
+        Duration::from_secs_f32(seconds)
+    }
+
+    fn samples(values: &[(f32, f32)]) -> Samples {
+        values
+            .iter()
+            .map(|&(time, v)| (seconds(time), ComponentEntry::new(self::value(), v)))
+            .collect()
+    }
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{a} != {b}");
+    }
+
+    fn value_at(samples: &mut Samples, time: f32) -> (f32, bool) {
+        let (entry, settled) = sample(samples, seconds(time), seconds(0.1)).unwrap();
+        (*entry.try_downcast_ref::<f32>().unwrap(), settled)
+    }
+
+    #[test]
+    fn samples_are_interpolated_on_the_server_timeline() {
+        init();
+        let mut samples = samples(&[(1.0, 0.0), (1.1, 1.0), (1.2, 3.0)]);
+
+        // Before the first sample, the first value is used
+        assert_eq!(value_at(&mut samples, 0.5), (0.0, false));
+
+        let (value, settled) = value_at(&mut samples, 1.05);
+        assert_close(value, 0.5);
+        assert!(!settled);
+
+        let (value, _) = value_at(&mut samples, 1.15);
+        assert_close(value, 2.0);
+    }
+
+    #[test]
+    fn samples_are_extrapolated_up_to_the_limit() {
+        init();
+        let mut samples = samples(&[(1.0, 0.0), (1.1, 1.0)]);
+
+        let (value, settled) = value_at(&mut samples, 1.15);
+        assert_close(value, 1.5);
+        assert!(!settled);
+
+        let (value, settled) = value_at(&mut samples, 2.0);
+        assert_close(value, 2.0);
+        assert!(settled);
+    }
+
+    #[test]
+    fn server_clock_follows_the_fastest_updates() {
+        let start = Instant::now();
+        let mut clock = ServerClock::new(start, seconds(10.0));
+        assert_eq!(clock.server_time(start + seconds(1.0)), seconds(11.0));
+
+        // An update that arrives earlier than expected moves the clock ahead at once
+        clock.update(start + seconds(1.0), seconds(11.5));
+        assert_eq!(clock.server_time(start + seconds(1.0)), seconds(11.5));
+
+        // An update that arrives late only moves it back slightly
+        clock.update(start + seconds(2.0), seconds(11.5));
+        let time = clock.server_time(start + seconds(2.0)).as_secs_f64();
+        assert!((time - (12.5 - CLOCK_SMOOTHING)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn diffs_are_buffered_at_their_server_time() {
+        init();
+        let mut world = World::new("test", ambient_ecs::WorldContext::Client);
+        let resources = Entity::new()
+            .with(server_frame_time(), seconds(5.0))
+            .spawn(&mut world);
+        let id = Entity::new().with(self::value(), 0.0).spawn(&mut world);
+
+        let mut diff = WorldDiff::new()
+            .set(resources, server_frame_time(), seconds(5.1))
+            .set(id, self::value(), 1.0);
+        buffer_diff(&mut world, &mut diff);
+
+        // The interpolated value is removed from the diff, and buffered instead
+        match &diff.changes[1] {
+            WorldChange::SetComponents(_, data) => assert!(!data.contains(self::value())),
+            _ => unreachable!(),
+        }
+        let buffer = &world.resource(interpolation_buffers())[&(id, self::value().desc())];
+        assert_eq!(buffer.samples[0].0, seconds(5.1));
+        assert!(world.resource_opt(server_clock()).is_some());
+    }
+}
@@ -22,7 +22,13 @@ pub mod client_game_state;
 pub mod codec;
 pub mod diff_serialization;
 pub mod hooks;
+pub mod interpolation;
+pub mod persistence;
+pub mod prediction;
+pub mod priority;
 pub mod proto;
+pub mod recording;
+pub mod relevance;
 pub mod rpc;
 pub mod serialization;
 pub mod server;
@@ -54,6 +60,11 @@ pub fn init_all_components() {
     client::init_components();
     server::init_components();
     client_game_state::init_components();
+    relevance::init_components();
+    prediction::init_components();
+    persistence::init_components();
+    priority::init_components();
+    interpolation::init_components();
 }
 
 pub trait ServerWorldExt {
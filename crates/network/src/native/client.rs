@@ -6,6 +6,7 @@ use crate::{
         client::{ClientProtoState, SharedClientGameState},
         ClientRequest,
     },
+    recording::{RecordedEvent, SessionRecorder},
     server::RpcArgs,
     stream::{FramedRecvStream, FramedSendStream, RawFramedRecvStream},
     NetworkError,
@@ -34,6 +35,7 @@ use rand::Rng;
 use rustls::Certificate;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
     sync::Arc,
     time::Duration,
 };
@@ -74,6 +76,8 @@ pub struct ClientView {
     pub cert: Option<Vec<u8>>,
     pub user_id: String,
     pub fail_on_version_mismatch: bool,
+    /// Records the session to this file, for playing back with a [super::replay::ReplayView]
+    pub record: Option<PathBuf>,
     pub systems_and_resources: Cb<dyn Fn() -> (SystemGroup, Entity) + Sync + Send>,
     pub on_loaded: LoadedFunc,
     pub create_rpc_registry: Cb<dyn Fn() -> RpcRegistry<RpcArgs> + Sync + Send>,
@@ -86,6 +90,7 @@ impl ElementComponent for ClientView {
             server_addr,
             user_id,
             fail_on_version_mismatch,
+            record,
             systems_and_resources,
             create_rpc_registry,
             on_loaded,
@@ -154,6 +159,13 @@ impl ElementComponent for ClientView {
             let local_resources = world_instance_resources(AppResources::from_world(ui_world))
                 .with(game_screen_render_target(), render_target.0.clone());
             let task = async move {
+                let recorder = record
+                    .map(|path| {
+                        tracing::info!("Recording the session to {}", path.display());
+                        SessionRecorder::create(&path, &user_id)
+                    })
+                    .transpose()?;
+
                 let conn = open_connection(server_addr.clone(), cert.map(Certificate))
                     .await
                     .with_context(|| {
@@ -220,6 +232,7 @@ impl ElementComponent for ClientView {
                     },
                     // game_state,
                     control_rx,
+                    recorder,
                 )
                 .await?;
 
@@ -284,7 +297,14 @@ async fn handle_connection(
         + Send
         + Sync,
     control_rx: flume::Receiver<Control>,
+    recorder: Option<SessionRecorder>,
 ) -> anyhow::Result<()> {
+    let record = |event: RecordedEvent| {
+        if let Some(recorder) = &recorder {
+            recorder.record(&event);
+        }
+    };
+
     let mut request_send = FramedSendStream::new(conn.open_uni().await?);
 
     // Accept the diff and stat stream
@@ -294,7 +314,7 @@ async fn handle_connection(
     tracing::info!("Attempting to connect using {user_id:?}");
 
     request_send
-        .send(ClientRequest::Connect(user_id.clone()))
+        .send(ClientRequest::connect(user_id.clone()))
         .await?;
 
     let mut client = ClientProtoState::Pending(user_id.clone());
@@ -303,7 +323,9 @@ async fn handle_connection(
 
     while client.is_pending() {
         if let Some(frame) = push_recv.next().await {
-            client.process_push(assets, fail_on_version_mismatch, frame?)?;
+            let frame = frame?;
+            record(RecordedEvent::Push(frame.clone()));
+            client.process_push(assets, fail_on_version_mismatch, frame)?;
         }
     }
 
@@ -344,7 +366,9 @@ async fn handle_connection(
     while let ClientProtoState::Connected(connected) = &mut client {
         tokio::select! {
             Some(frame) = push_recv.next() => {
-                client.process_push(assets, fail_on_version_mismatch, frame?)?;
+                let frame = frame?;
+                record(RecordedEvent::Push(frame.clone()));
+                client.process_push(assets, fail_on_version_mismatch, frame)?;
             }
             _ = stats_timer.tick() => {
                 let stats = conn.stats();
@@ -355,7 +379,8 @@ async fn handle_connection(
                     bytes_received: (stats.udp_rx.bytes - prev_stats.udp_rx.bytes) / stats_interval,
                     packets_sent: (stats.path.sent_packets - prev_stats.path.sent_packets),
                     packets_lost: (stats.path.lost_packets - prev_stats.path.lost_packets),
-                });
+                    ..Default::default()
+                }, stats_interval);
 
                 prev_stats = stats;
             }
@@ -371,16 +396,19 @@ async fn handle_connection(
             }
 
             Ok(datagram) = conn.read_datagram() => {
+                record(RecordedEvent::Datagram(datagram.clone()));
                 connected.process_datagram(&shared_client_state, datagram)?;
             }
             Ok((send, recv)) = conn.accept_bi() => {
                 connected.process_bi(&shared_client_state, send, recv);
             }
             Ok(recv) = conn.accept_uni() => {
-                connected.process_uni(&shared_client_state, recv);
+                connected.process_uni(&shared_client_state, Box::pin(recv), recorder.clone());
             }
             Some(diff) = diff_stream.next() => {
-                connected.process_diff(&shared_client_state, diff?)?;
+                let diff = diff?;
+                record(RecordedEvent::Diff(diff.clone()));
+                connected.process_diff(&shared_client_state, diff)?;
             }
         }
     }
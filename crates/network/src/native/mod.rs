@@ -4,6 +4,7 @@
 pub mod client;
 pub mod client_connection;
 pub mod common;
+pub mod replay;
 pub mod server;
 mod webtransport;
 
@@ -0,0 +1,435 @@
+use std::{io::Cursor, sync::Arc, time::Duration};
+
+use ambient_app::{window_title, world_instance_resources, AppResources};
+use ambient_core::{asset_cache, gpu};
+use ambient_ecs::{
+    generated::network::components::is_remote_entity, query, world_events, Entity, SystemGroup,
+};
+use ambient_element::{
+    consume_context, element_component, provide_context, use_frame, use_ref_with, use_state,
+    use_state_with, use_task, Element, ElementComponent, ElementComponentExt, Hooks,
+};
+use ambient_native_std::{asset_cache::AssetCache, cb, Cb};
+use ambient_renderer::RenderTarget;
+use ambient_rpc::RpcRegistry;
+use ambient_sys::time::Instant;
+use ambient_ui_native::{
+    layout::{docking, Docking},
+    margin, padding, space_between_items, Borders, Button, Centered, Dock, FlowColumn, FlowRow,
+    Slider, StylesExt, Text, Throbber, STREET,
+};
+use bytes::Bytes;
+use futures::{future::BoxFuture, StreamExt};
+use glam::uvec2;
+use itertools::Itertools;
+use parking_lot::Mutex;
+
+use crate::{
+    client::{ClientState, GameClientRenderTarget, LoadedFunc, NetworkTransport},
+    client_game_state::{game_screen_render_target, ClientGameState},
+    proto::client::{ClientProtoState, SharedClientGameState},
+    recording::{RecordedEvent, Recording},
+    server::RpcArgs,
+    NetworkError,
+};
+
+/// How often the replay is advanced.
+const TICK: Duration = Duration::from_millis(10);
+
+/// Plays back a [Recording] of a client session.
+///
+/// The recorded world diffs, datagrams and streams are fed to the client world in the same way
+/// as they would be when connected to a server, with controls to pause the playback and to seek
+/// within the recording. The client can't send anything, as there is no server to send it to.
+#[derive(Debug, Clone)]
+pub struct ReplayView {
+    pub recording: Arc<Recording>,
+    pub systems_and_resources: Cb<dyn Fn() -> (SystemGroup, Entity) + Sync + Send>,
+    pub on_loaded: LoadedFunc,
+    pub create_rpc_registry: Cb<dyn Fn() -> RpcRegistry<RpcArgs> + Sync + Send>,
+    pub inner: Element,
+}
+
+impl ElementComponent for ReplayView {
+    fn render(self: Box<Self>, hooks: &mut Hooks) -> Element {
+        let Self {
+            recording,
+            systems_and_resources,
+            on_loaded,
+            create_rpc_registry,
+            inner,
+        } = *self;
+
+        let gpu = hooks.world.resource(gpu()).clone();
+
+        provide_context(hooks, || {
+            GameClientRenderTarget(Arc::new(RenderTarget::new(&gpu, uvec2(1, 1), None)))
+        });
+
+        let (render_target, _) = consume_context::<GameClientRenderTarget>(hooks).unwrap();
+
+        let assets = hooks.world.resource(asset_cache()).clone();
+
+        let ((control_tx, control_rx), _) = use_state_with(hooks, |_| flume::unbounded());
+        let status = use_ref_with(hooks, |_| ReplayStatus::default());
+
+        let (client_state, set_client_state) = use_state(hooks, None as Option<ClientState>);
+
+        // Run game logic
+        {
+            let gpu = gpu.clone();
+            let render_target = render_target.clone();
+            let world_event_reader = Mutex::new(hooks.world.resource(world_events()).reader());
+
+            let client_state = client_state.clone();
+            use_frame(hooks, move |app_world| {
+                if let Some(client_state) = &client_state {
+                    let mut game_state = client_state.game_state.lock();
+                    // Pipe events from app world to game world
+                    for (_, event) in world_event_reader
+                        .lock()
+                        .iter(app_world.resource(world_events()))
+                    {
+                        game_state
+                            .world
+                            .resource_mut(world_events())
+                            .add_event(event.clone());
+                    }
+
+                    game_state.on_frame(&gpu, &render_target.0);
+                }
+            });
+        }
+
+        let (window_title_state, set_window_title) = use_state(hooks, "Ambient".to_string());
+        *hooks.world.resource_mut(window_title()) = window_title_state;
+
+        let (err, set_error) = use_state(hooks, None);
+
+        {
+            let status = status.clone();
+            use_task(hooks, move |ui_world| {
+                let local_resources = world_instance_resources(AppResources::from_world(ui_world))
+                    .with(game_screen_render_target(), render_target.0.clone());
+                let task = async move {
+                    let mut player = ReplayPlayer::new(recording.clone());
+                    let main_package_name = player.connect(&assets)?;
+                    set_window_title(format!("{main_package_name} (replay)"));
+
+                    let user_id = recording.user_id().to_string();
+                    let (systems, resources) = systems_and_resources();
+                    let resources = local_resources
+                        .with(ambient_core::player::local_user_id(), user_id.clone())
+                        .with_merge(resources);
+
+                    let game_state = ClientGameState::new(
+                        &gpu,
+                        assets.clone(),
+                        user_id.clone(),
+                        systems,
+                        resources,
+                    );
+
+                    let client_state = ClientState::new(
+                        Arc::new(ReplayTransport),
+                        Arc::new(create_rpc_registry()),
+                        Arc::new(Mutex::new(game_state)),
+                        user_id,
+                    );
+
+                    let game_state = client_state.game_state.clone();
+                    let cleanup = {
+                        let game_state = &mut game_state.lock();
+                        game_state.world.add_resource(
+                            crate::client::client_state(),
+                            Some(client_state.clone()),
+                        );
+
+                        (on_loaded)(&client_state, game_state)?
+                    };
+                    scopeguard::defer!(cleanup());
+
+                    set_client_state(Some(client_state));
+
+                    let mut timer = tokio::time::interval(TICK);
+                    let mut last_tick = Instant::now();
+                    let mut control_rx = control_rx.into_stream();
+
+                    loop {
+                        tokio::select! {
+                            _ = timer.tick() => {
+                                let now = Instant::now();
+                                player.advance(&game_state, &assets, now.duration_since(last_tick))?;
+                                last_tick = now;
+                            }
+                            control = control_rx.next() => {
+                                match control {
+                                    Some(ReplayControl::TogglePlaying) => player.playing = !player.playing,
+                                    Some(ReplayControl::Seek(position)) => player.seek(&game_state, &assets, position)?,
+                                    None => break,
+                                }
+                            }
+                        }
+
+                        *status.lock() = player.status();
+                    }
+
+                    Ok(()) as anyhow::Result<()>
+                };
+
+                async move {
+                    if let Err(err) = task.await {
+                        tracing::error!("Replay failed: {:?}", err);
+                        set_error(Some(format!("{err:?}")));
+                    }
+                }
+            });
+        }
+
+        if let Some(err) = err {
+            return Dock(vec![Text::el("Error").header_style(), Text::el(err)]).el();
+        }
+
+        if let Some(client_state) = &client_state {
+            provide_context(hooks, || client_state.clone());
+            hooks
+                .world
+                .add_resource(crate::client::client_state(), Some(client_state.clone()));
+
+            Dock::el([
+                ReplayControls::el(status, control_tx).with(docking(), Docking::Bottom),
+                inner,
+            ])
+        } else {
+            Centered(vec![FlowColumn::el([FlowRow::el([
+                Text::el("Loading replay"),
+                Throbber.el(),
+            ])])])
+            .el()
+        }
+    }
+}
+
+#[element_component]
+fn ReplayControls(
+    hooks: &mut Hooks,
+    status: Arc<Mutex<ReplayStatus>>,
+    control_tx: flume::Sender<ReplayControl>,
+) -> Element {
+    let (current, set_current) = use_state(hooks, status.lock().clone());
+    {
+        let current = current.clone();
+        use_frame(hooks, move |_| {
+            let status = status.lock().clone();
+            if status != current {
+                set_current(status);
+            }
+        });
+    }
+
+    FlowRow::el([
+        Button::new(if current.playing { "Pause" } else { "Play" }, {
+            let control_tx = control_tx.clone();
+            move |_| {
+                control_tx.send(ReplayControl::TogglePlaying).ok();
+            }
+        })
+        .el(),
+        Text::el(format!(
+            "{} / {}",
+            format_time(current.position),
+            format_time(current.duration)
+        )),
+        Slider {
+            value: current.position.as_secs_f32(),
+            on_change: Some(cb(move |value| {
+                control_tx
+                    .send(ReplayControl::Seek(Duration::from_secs_f32(value.max(0.))))
+                    .ok();
+            })),
+            min: 0.,
+            max: current.duration.as_secs_f32(),
+            width: 400.,
+            logarithmic: false,
+            round: Some(1),
+            suffix: Some(" s"),
+        }
+        .el(),
+    ])
+    .floating_panel()
+    .with(space_between_items(), STREET)
+    .with(margin(), Borders::even(STREET).into())
+    .with(padding(), Borders::even(STREET).into())
+}
+
+fn format_time(time: Duration) -> String {
+    let seconds = time.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[derive(Debug, Clone)]
+enum ReplayControl {
+    TogglePlaying,
+    Seek(Duration),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ReplayStatus {
+    position: Duration,
+    duration: Duration,
+    playing: bool,
+}
+
+/// Feeds the events of a recording to a client world as the playback position moves.
+struct ReplayPlayer {
+    recording: Arc<Recording>,
+    client: ClientProtoState,
+    /// The index of the next event to apply
+    next_event: usize,
+    position: Duration,
+    playing: bool,
+}
+
+impl ReplayPlayer {
+    fn new(recording: Arc<Recording>) -> Self {
+        Self {
+            client: ClientProtoState::Pending(recording.user_id().to_string()),
+            recording,
+            next_event: 0,
+            position: Duration::ZERO,
+            playing: true,
+        }
+    }
+
+    /// Applies the start of the recording, up to the client having connected, and returns the
+    /// name of the main package.
+    fn connect(&mut self, assets: &AssetCache) -> anyhow::Result<String> {
+        let recording = self.recording.clone();
+        while self.client.is_pending() {
+            let Some((time, event)) = recording.events().get(self.next_event) else {
+                anyhow::bail!("The recording ends before the client connected");
+            };
+            self.next_event += 1;
+            self.position = *time;
+            if let RecordedEvent::Push(push) = event {
+                self.client.process_push(assets, false, push.clone())?;
+            }
+        }
+
+        match &self.client {
+            ClientProtoState::Connected(connected) => Ok(connected.main_package_name.clone()),
+            _ => anyhow::bail!("The recorded connection was denied"),
+        }
+    }
+
+    fn status(&self) -> ReplayStatus {
+        ReplayStatus {
+            position: self.position,
+            duration: self.recording.duration(),
+            playing: self.playing,
+        }
+    }
+
+    /// Moves the playback `elapsed` further along if playing, and applies everything up to it.
+    fn advance(
+        &mut self,
+        state: &SharedClientGameState,
+        assets: &AssetCache,
+        elapsed: Duration,
+    ) -> anyhow::Result<()> {
+        if self.playing {
+            let duration = self.recording.duration();
+            self.position = (self.position + elapsed).min(duration);
+            self.playing = self.position < duration;
+        }
+        self.apply_until(state, assets, self.position, true)
+    }
+
+    /// Moves the playback to `position`.
+    ///
+    /// Datagrams and streams are only handled during playback, as the messages they carry can't
+    /// be skipped over meaningfully; only the world diffs are applied when seeking.
+    fn seek(
+        &mut self,
+        state: &SharedClientGameState,
+        assets: &AssetCache,
+        position: Duration,
+    ) -> anyhow::Result<()> {
+        let position = position.min(self.recording.duration());
+        if position < self.position {
+            self.rewind(state);
+        }
+        self.position = position;
+        self.apply_until(state, assets, position, false)
+    }
+
+    /// Removes everything received from the recording, so that it can be applied from the start.
+    fn rewind(&mut self, state: &SharedClientGameState) {
+        let mut gs = state.lock();
+        let remote_entities = query(is_remote_entity())
+            .iter(&gs.world, None)
+            .map(|(id, _)| id)
+            .collect_vec();
+        for id in remote_entities {
+            gs.world.despawn(id);
+        }
+
+        self.client = ClientProtoState::Pending(self.recording.user_id().to_string());
+        self.next_event = 0;
+    }
+
+    fn apply_until(
+        &mut self,
+        state: &SharedClientGameState,
+        assets: &AssetCache,
+        position: Duration,
+        handle_messages: bool,
+    ) -> anyhow::Result<()> {
+        let recording = self.recording.clone();
+        while let Some((time, event)) = recording.events().get(self.next_event) {
+            if *time > position {
+                break;
+            }
+            self.next_event += 1;
+
+            match (event, &mut self.client) {
+                (RecordedEvent::Push(push), _) => {
+                    self.client.process_push(assets, false, push.clone())?
+                }
+                (RecordedEvent::Diff(diff), ClientProtoState::Connected(connected)) => {
+                    connected.process_diff(state, diff.clone())?
+                }
+                (RecordedEvent::Datagram(data), ClientProtoState::Connected(connected))
+                    if handle_messages =>
+                {
+                    connected.process_datagram(state, data.clone())?
+                }
+                (RecordedEvent::UniStream(data), ClientProtoState::Connected(connected))
+                    if handle_messages =>
+                {
+                    connected.process_uni(state, Box::pin(Cursor::new(data.clone())), None)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The transport of a replayed session, which has no server to send anything to.
+struct ReplayTransport;
+
+impl NetworkTransport for ReplayTransport {
+    fn request_bi(&self, _id: u32, _data: Bytes) -> BoxFuture<Result<Bytes, NetworkError>> {
+        Box::pin(async { Err(NetworkError::ConnectionClosed) })
+    }
+
+    fn request_uni(&self, _id: u32, _data: Bytes) -> BoxFuture<Result<(), NetworkError>> {
+        Box::pin(async { Err(NetworkError::ConnectionClosed) })
+    }
+
+    fn send_datagram(&self, _id: u32, _data: Bytes) -> BoxFuture<Result<(), NetworkError>> {
+        Box::pin(async { Err(NetworkError::ConnectionClosed) })
+    }
+}
@@ -329,6 +329,7 @@ async fn handle_quinn_connection(
     tokio::spawn(handle_diffs(
         FramedSendStream::new(conn.open_uni().await?),
         diffs_rx,
+        server.diff_compression(),
     ));
 
     let mut server = scopeguard::guard(server, |mut server| {
@@ -124,6 +124,7 @@ async fn handle_webtransport_session(
     tokio::spawn(handle_diffs(
         FramedSendStream::new(conn.open_uni(sid).await?),
         diffs_rx,
+        server.diff_compression(),
     ));
 
     let mut server = scopeguard::guard(server, |mut server| {
@@ -0,0 +1,401 @@
+//! Saving of the server world to disk, and restoring it when the server starts.
+//!
+//! Only components with the [Store] attribute are saved, from all entities except those marked
+//! with `dont_store`. The `Store` components of the persistent resources entity are saved
+//! separately, and restored onto the persistent resources entity of the new world; all other
+//! entities are restored with their original ids, so that references between them stay valid.
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use ambient_core::runtime;
+use ambient_ecs::{
+    components, dont_store, query, DeserEntityDataWithWarnings, Entity, EntityId, FnSystem,
+    Resource, Store, SystemGroup, World,
+};
+use ambient_sys::time::Instant;
+use anyhow::Context;
+use itertools::Itertools;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::{server::ShutdownEvent, ServerWorldExt};
+
+components!("network::persistence", {
+    /// Where and how often the world is saved. The world is only persisted if this is set.
+    @[Resource]
+    world_save_settings: WorldSaveSettings,
+    /// When the world was last saved.
+    @[Resource]
+    last_world_save: Instant,
+    /// Set if the world was restored from a save when the server started.
+    @[Resource]
+    restored_world: RestoredWorld,
+    /// Writes the saves of the world to disk.
+    @[Resource]
+    world_save_writer: Arc<SaveWriter>,
+});
+
+/// The version of the save file format, which is unrelated to the version of the package.
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone)]
+pub struct WorldSaveSettings {
+    pub path: PathBuf,
+    /// How often to save the world; if not set, the world is only saved on shutdown and on request
+    pub interval: Option<Duration>,
+    /// The version of the package being run, which is recorded in the save
+    pub package_version: String,
+}
+
+/// What was restored from a save, for packages to migrate from.
+#[derive(Debug, Clone)]
+pub struct RestoredWorld {
+    /// The version of the package that made the save
+    pub saved_version: String,
+    /// The paths of the saved components that could not be restored, because they no longer
+    /// exist or their type changed
+    pub skipped_components: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct WorldSave {
+    format_version: u32,
+    package_version: String,
+    persistent_resources: Entity,
+    entities: Vec<(EntityId, Entity)>,
+}
+
+/// Writes saves to disk in the order they were captured in, so that a save that was written in
+/// the background never replaces a newer one.
+#[derive(Default)]
+pub struct SaveWriter {
+    /// The number of saves captured so far
+    captured: AtomicU64,
+    /// The number of the newest save on disk
+    written: Mutex<u64>,
+}
+impl SaveWriter {
+    fn next(&self) -> u64 {
+        self.captured.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Writes save number `index` to `path`, unless a newer save has already been written.
+    fn write(&self, index: u64, path: &Path, save: &WorldSave) -> anyhow::Result<()> {
+        let mut written = self.written.lock();
+        if *written > index {
+            return Ok(());
+        }
+
+        // Write to a temporary file first, so that the previous save survives a failed write
+        let data = serde_json::to_vec(save)?;
+        let temp_path = path.with_extension("tmp");
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&temp_path, data)
+            .with_context(|| format!("Failed to write {}", temp_path.display()))?;
+        std::fs::rename(&temp_path, path)
+            .with_context(|| format!("Failed to replace {}", path.display()))?;
+        *written = index;
+
+        tracing::debug!(
+            "Saved {} entities to {}",
+            save.entities.len(),
+            path.display()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct LoadedWorldSave {
+    format_version: u32,
+    package_version: String,
+    persistent_resources: DeserEntityDataWithWarnings,
+    entities: Vec<(EntityId, DeserEntityDataWithWarnings)>,
+}
+
+/// Saves the `Store` components of `world` to the path of its [world_save_settings], and waits
+/// for the save to be written.
+pub fn save_world(world: &mut World) -> anyhow::Result<()> {
+    let (writer, index, path, save) = capture_world(world)?;
+    writer.write(index, &path, &save)
+}
+
+/// Captures the `Store` components of `world`, and writes them to the path of its
+/// [world_save_settings] in the background, so that serializing and writing a large world does
+/// not hold up the frame. Errors while writing are logged.
+pub fn save_world_in_background(world: &mut World) -> anyhow::Result<()> {
+    let (writer, index, path, save) = capture_world(world)?;
+    world.resource(runtime()).spawn_blocking(move || {
+        if let Err(err) = writer.write(index, &path, &save) {
+            tracing::error!("Failed to save the world: {err:?}");
+        }
+    });
+    Ok(())
+}
+
+fn capture_world(world: &mut World) -> anyhow::Result<(Arc<SaveWriter>, u64, PathBuf, WorldSave)> {
+    let settings = world
+        .resource_opt(world_save_settings())
+        .context("The world is not persisted; start the server with `--world-save <file>`")?;
+
+    let persistent_resources_id = world.persisted_resource_entity();
+    let resource_entity = world.resource_entity();
+    let entities = query(())
+        .excl(dont_store())
+        .iter(world, None)
+        .map(|(id, _)| id)
+        .filter(|id| Some(*id) != persistent_resources_id && *id != resource_entity)
+        .map(|id| (id, stored_components(world, id)))
+        .filter(|(_, entity)| !entity.is_empty())
+        .collect_vec();
+    let save = WorldSave {
+        format_version: FORMAT_VERSION,
+        package_version: settings.package_version.clone(),
+        persistent_resources: persistent_resources_id
+            .map(|id| stored_components(world, id))
+            .unwrap_or_default(),
+        entities,
+    };
+    let path = settings.path.clone();
+
+    let writer = match world.resource_opt(world_save_writer()) {
+        Some(writer) => writer.clone(),
+        None => {
+            let writer = Arc::new(SaveWriter::default());
+            world.add_resource(world_save_writer(), writer.clone());
+            writer
+        }
+    };
+    world.add_resource(last_world_save(), Instant::now());
+    Ok((writer.clone(), writer.next(), path, save))
+}
+
+/// Restores the world saved at the path of the [world_save_settings] of `world`, if it exists.
+///
+/// This has to happen after the components of all packages have been registered, so that their
+/// values can be restored.
+pub fn restore_world(world: &mut World) -> anyhow::Result<()> {
+    let Some(path) = world
+        .resource_opt(world_save_settings())
+        .map(|settings| settings.path.clone())
+    else {
+        return Ok(());
+    };
+    world.add_resource(last_world_save(), Instant::now());
+
+    let data = match std::fs::read(&path) {
+        Ok(data) => data,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            tracing::info!("No saved world at {}, starting afresh", path.display());
+            return Ok(());
+        }
+        Err(err) => {
+            return Err(err).with_context(|| format!("Failed to read {}", path.display()));
+        }
+    };
+    let save =
+        parse_save(&data).with_context(|| format!("Invalid world save {}", path.display()))?;
+
+    let mut skipped_components = Vec::new();
+    let mut restore = |entity: DeserEntityDataWithWarnings| {
+        for (_, component, err) in entity.warnings.warnings {
+            tracing::warn!("Failed to restore saved component {component}: {err}");
+            skipped_components.push(component);
+        }
+        entity.entity
+    };
+
+    let persistent_resources = restore(save.persistent_resources);
+    if let Some(id) = world.persisted_resource_entity() {
+        world.add_components(id, persistent_resources)?;
+    }
+
+    let entity_count = save.entities.len();
+    for (id, entity) in save.entities {
+        let entity = restore(entity);
+        if world.exists(id) {
+            world.add_components(id, entity)?;
+        } else {
+            world.spawn_with_id(id, entity);
+        }
+    }
+
+    skipped_components.sort();
+    skipped_components.dedup();
+    tracing::info!(
+        "Restored {entity_count} entities from {}, saved by version {}",
+        path.display(),
+        save.package_version
+    );
+    world.add_resource(
+        restored_world(),
+        RestoredWorld {
+            saved_version: save.package_version,
+            skipped_components,
+        },
+    );
+    Ok(())
+}
+
+fn parse_save(data: &[u8]) -> anyhow::Result<LoadedWorldSave> {
+    let save: LoadedWorldSave = serde_json::from_slice(data)?;
+    anyhow::ensure!(
+        save.format_version == FORMAT_VERSION,
+        "Unsupported save format version {} (expected {FORMAT_VERSION})",
+        save.format_version
+    );
+    Ok(save)
+}
+
+fn stored_components(world: &World, id: EntityId) -> Entity {
+    world
+        .get_components(id)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|desc| desc.has_attribute::<Store>())
+        .filter_map(|desc| world.get_entry(id, desc).ok())
+        .collect()
+}
+
+/// Saves the world every [WorldSaveSettings::interval].
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "network/persistence",
+        vec![Box::new(FnSystem::new(|world, _| {
+            let Some(interval) = world
+                .resource_opt(world_save_settings())
+                .and_then(|settings| settings.interval)
+            else {
+                return;
+            };
+            let due = world.resource_opt(last_world_save()).map_or(true, |last| {
+                Instant::now().duration_since(*last) >= interval
+            });
+            if due {
+                if let Err(err) = save_world_in_background(world) {
+                    tracing::error!("Failed to save the world: {err:?}");
+                    // Try again at the next interval rather than every frame
+                    world.add_resource(last_world_save(), Instant::now());
+                }
+            }
+        }))],
+    )
+}
+
+/// Saves the world when the server shuts down, waiting for the save to be written.
+pub fn on_shutdown_systems() -> SystemGroup<ShutdownEvent> {
+    SystemGroup::new(
+        "network/persistence/on_shutdown",
+        vec![Box::new(FnSystem::new(|world, _| {
+            if world.resource_opt(world_save_settings()).is_some() {
+                if let Err(err) = save_world(world) {
+                    tracing::error!("Failed to save the world on shutdown: {err:?}");
+                }
+            }
+        }))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use ambient_ecs::{components, Networked, WorldContext};
+
+    use super::*;
+    use crate::is_persistent_resources;
+
+    components!("test", {
+        @[Networked, Store]
+        score: u32,
+        @[Networked]
+        velocity: f32,
+    });
+
+    fn server_world(path: &Path) -> World {
+        let mut world = World::new("test", WorldContext::Server);
+        world.add_resource(
+            world_save_settings(),
+            WorldSaveSettings {
+                path: path.to_path_buf(),
+                interval: None,
+                package_version: "1.0.0".to_string(),
+            },
+        );
+        Entity::new()
+            .with(is_persistent_resources(), ())
+            .spawn(&mut world);
+        world
+    }
+
+    #[test]
+    fn stored_components_survive_a_restart() {
+        ambient_ecs::init_components();
+        crate::init_all_components();
+        init_components();
+        let path = std::env::temp_dir().join(format!(
+            "ambient_world_save_test_{}.json",
+            std::process::id()
+        ));
+
+        let mut world = server_world(&path);
+        let resources = world.persisted_resource_entity().unwrap();
+        world.add_component(resources, score(), 100).unwrap();
+        let player = Entity::new()
+            .with(score(), 5)
+            .with(velocity(), 2.)
+            .spawn(&mut world);
+        Entity::new()
+            .with(score(), 7)
+            .with(dont_store(), ())
+            .spawn(&mut world);
+        save_world(&mut world).unwrap();
+
+        let mut world = server_world(&path);
+        restore_world(&mut world).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let resources = world.persisted_resource_entity().unwrap();
+        assert_eq!(world.get(resources, score()), Ok(100));
+        assert_eq!(world.get(player, score()), Ok(5));
+        assert!(!world.has_component(player, velocity()));
+        assert_eq!(query(score()).iter(&world, None).count(), 2);
+        let restored = world.resource(restored_world());
+        assert_eq!(restored.saved_version, "1.0.0");
+        assert!(restored.skipped_components.is_empty());
+    }
+
+    #[test]
+    fn older_saves_do_not_replace_newer_ones() {
+        ambient_ecs::init_components();
+        crate::init_all_components();
+        init_components();
+        let path = std::env::temp_dir().join(format!(
+            "ambient_world_save_order_test_{}.json",
+            std::process::id()
+        ));
+
+        let mut world = server_world(&path);
+        let player = Entity::new().with(score(), 1).spawn(&mut world);
+        let (writer, older, _, older_save) = capture_world(&mut world).unwrap();
+        world.set(player, score(), 2).unwrap();
+        let (_, newer, _, newer_save) = capture_world(&mut world).unwrap();
+
+        // The background write of the older save finishes last
+        writer.write(newer, &path, &newer_save).unwrap();
+        writer.write(older, &path, &older_save).unwrap();
+
+        let mut world = server_world(&path);
+        restore_world(&mut world).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(world.get(player, score()), Ok(2));
+    }
+}
@@ -0,0 +1,459 @@
+//! Client-side prediction of `Predicted` components on the entities owned by the local player.
+//!
+//! Every message the client sends to the server is numbered (`input_sequence`), and the server
+//! acknowledges the last one it has processed on the player entity (`input_sequence_ack`).
+//!
+//! Clients can register prediction systems with [add_prediction_system], which apply the effect
+//! of a message to the local player's entities. They are run as soon as a message is sent, and
+//! the messages the server has not acknowledged yet are kept. When values from the server arrive,
+//! the `Predicted` components are reset to them, and the unacknowledged messages are replayed
+//! through the prediction systems on top of them. Client modules take part in this by
+//! subscribing to the `PredictInput` message.
+//!
+//! Without prediction systems, the client records the predicted values of its entities whenever
+//! it sends a message, and the changes it made since its first unacknowledged message are
+//! replayed on top of the server's values instead.
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    sync::Arc,
+};
+
+use ambient_core::player::{get_by_user_id, local_user_id, user_id};
+use ambient_ecs::{
+    components, query, ComponentDesc, ComponentEntry, Entity, EntityId, Networked, Predicted,
+    Resource, Serializable, World, WorldChange, WorldDiff,
+};
+use bincode::Options;
+use glam::{Quat, Vec2, Vec3, Vec4};
+
+pub use ambient_ecs::generated::network::components::{input_sequence, input_sequence_ack};
+
+use crate::diff_serialization::bincode_options;
+
+type PredictedValues = HashMap<(EntityId, ComponentDesc), ComponentEntry>;
+
+/// A message the client has sent to the server.
+#[derive(Debug, Clone)]
+pub struct PredictedInput {
+    /// The `input_sequence` of the message
+    pub sequence: u64,
+    pub name: String,
+    pub data: Vec<u8>,
+    /// Whether the message is being replayed on top of values from the server, rather than
+    /// having just been sent
+    pub replayed: bool,
+}
+
+/// Applies the effect of a message sent to the server to the local player's entities.
+///
+/// Must only depend on the message and the state of the world, as it is run again for every
+/// update from the server until the message has been acknowledged.
+pub type PredictionSystem = Arc<dyn Fn(&mut World, &PredictedInput) + Sync + Send>;
+
+components!("network::prediction", {
+    /// The predicted values of the local player's entities at the time each unacknowledged
+    /// message was sent.
+    @[Resource]
+    prediction_history: BTreeMap<u64, PredictedValues>,
+    /// The systems that predict the effect of the messages sent to the server.
+    @[Resource]
+    prediction_systems: Vec<PredictionSystem>,
+    /// The messages sent to the server that it has not acknowledged yet, oldest first.
+    @[Resource]
+    pending_inputs: VecDeque<PredictedInput>,
+    /// The most recent values the server sent for the `Predicted` components of the local
+    /// player's entities, which the pending inputs are replayed on top of.
+    @[Resource]
+    server_values: PredictedValues,
+});
+
+/// Adds a system that predicts the effect of the messages the client sends to the server.
+pub fn add_prediction_system(world: &mut World, system: PredictionSystem) {
+    if world.resource_opt(prediction_systems()).is_none() {
+        world.add_resource(prediction_systems(), Default::default());
+    }
+    world.resource_mut(prediction_systems()).push(system);
+}
+
+/// Removes a system added with [add_prediction_system].
+pub fn remove_prediction_system(world: &mut World, system: &PredictionSystem) {
+    if let Some(systems) = world.resource_mut_opt(prediction_systems()) {
+        systems.retain(|other| !same_system(other, system));
+    }
+}
+
+/// Returns true if `system` has been added with [add_prediction_system].
+pub fn has_prediction_system(world: &World, system: &PredictionSystem) -> bool {
+    world
+        .resource_opt(prediction_systems())
+        .is_some_and(|systems| systems.iter().any(|other| same_system(other, system)))
+}
+
+/// Compares the systems by address only, as the vtables of the same closure can differ.
+fn same_system(a: &PredictionSystem, b: &PredictionSystem) -> bool {
+    std::ptr::eq(Arc::as_ptr(a) as *const (), Arc::as_ptr(b) as *const ())
+}
+
+fn uses_prediction_systems(world: &World) -> bool {
+    world
+        .resource_opt(prediction_systems())
+        .is_some_and(|systems| !systems.is_empty())
+}
+
+fn run_prediction_systems(world: &mut World, input: &PredictedInput) {
+    for system in world.resource(prediction_systems()).clone() {
+        system(world, input);
+    }
+}
+
+fn is_predicted(desc: ComponentDesc) -> bool {
+    desc.has_attribute::<Predicted>() && desc.has_attribute::<Networked>()
+}
+
+pub(crate) fn is_local(world: &World, id: EntityId) -> bool {
+    match (
+        world.resource_opt(local_user_id()),
+        world.get_ref(id, user_id()),
+    ) {
+        (Some(local), Ok(owner)) => local == owner,
+        _ => false,
+    }
+}
+
+fn predicted_values(world: &World) -> PredictedValues {
+    query(user_id())
+        .iter(world, None)
+        .filter(|(id, _)| is_local(world, *id))
+        .flat_map(|(id, _)| {
+            world
+                .get_components(id)
+                .unwrap()
+                .into_iter()
+                .filter(|&desc| is_predicted(desc))
+                .map(move |desc| ((id, desc), world.get_entry(id, desc).unwrap()))
+        })
+        .collect()
+}
+
+/// Numbers a message that is about to be sent to the server and predicts its effect. Returns
+/// the sequence number of the message.
+pub fn next_input_sequence(world: &mut World, name: &str, data: &[u8]) -> u64 {
+    let sequence = world
+        .resource_opt(input_sequence())
+        .copied()
+        .unwrap_or_default()
+        + 1;
+    world.add_resource(input_sequence(), sequence);
+
+    if uses_prediction_systems(world) {
+        let input = PredictedInput {
+            sequence,
+            name: name.to_string(),
+            data: data.to_vec(),
+            replayed: false,
+        };
+        run_prediction_systems(world, &input);
+        if world.resource_opt(pending_inputs()).is_none() {
+            world.add_resource(pending_inputs(), Default::default());
+        }
+        world.resource_mut(pending_inputs()).push_back(input);
+        return sequence;
+    }
+
+    let values = predicted_values(world);
+    if !values.is_empty() {
+        if world.resource_opt(prediction_history()).is_none() {
+            world.add_resource(prediction_history(), Default::default());
+        }
+        world
+            .resource_mut(prediction_history())
+            .insert(sequence, values);
+    }
+    sequence
+}
+
+/// Acknowledges that the message `sequence` from `user_id` has been received by the server.
+///
+/// The message will be processed by the next server frame, so the acknowledgement reaches the
+/// client together with the state that results from it.
+pub fn acknowledge_input(world: &mut World, user_id: &str, sequence: u64) {
+    let Some(id) = get_by_user_id(world, user_id) else {
+        return;
+    };
+    if world.get(id, input_sequence_ack()).unwrap_or_default() < sequence {
+        world.add_component(id, input_sequence_ack(), sequence).ok();
+    }
+}
+
+/// Reconciles the `Predicted` components in a diff from the server with the local prediction.
+///
+/// Must be called before the diff is applied to the world, and followed by [replay_inputs] after
+/// it has been applied.
+pub fn reconcile(world: &mut World, diff: &mut WorldDiff) {
+    let ack = diff
+        .changes
+        .iter()
+        .find_map(|change| match change {
+            WorldChange::SetComponents(id, data) | WorldChange::AddComponents(id, data)
+                if is_local(world, *id) =>
+            {
+                data.get(input_sequence_ack())
+            }
+            _ => None,
+        })
+        .or_else(|| {
+            let local = world.resource_opt(local_user_id())?;
+            world
+                .get(get_by_user_id(world, local)?, input_sequence_ack())
+                .ok()
+        })
+        .unwrap_or_default();
+
+    // Kept even without prediction systems, so that systems that are added later have values to
+    // replay on top of
+    record_server_values(world, diff);
+
+    if uses_prediction_systems(world) {
+        if let Some(pending) = world.resource_mut_opt(pending_inputs()) {
+            pending.retain(|input| input.sequence > ack);
+        }
+        return;
+    }
+
+    let Some(history) = world.resource_mut_opt(prediction_history()) else {
+        return;
+    };
+    // The server's values already include the effects of the acknowledged messages
+    *history = history.split_off(&(ack + 1));
+    let Some(predicted) = history.values().next().cloned() else {
+        return;
+    };
+
+    for change in &mut diff.changes {
+        let WorldChange::SetComponents(id, data) = change else {
+            continue;
+        };
+        let replayed = data
+            .iter()
+            .filter_map(|entry| {
+                let predicted = predicted.get(&(*id, entry.desc()))?;
+                let current = world.get_entry(*id, entry.desc()).ok()?;
+                replay(entry, predicted, &current)
+            })
+            .collect::<Vec<_>>();
+        for entry in replayed {
+            data.set_entry(entry);
+        }
+    }
+}
+
+/// Keeps the values of the `Predicted` components of the local player's entities in `diff`.
+fn record_server_values(world: &mut World, diff: &WorldDiff) {
+    let is_local = |id: EntityId, data: &Entity| match (
+        world.resource_opt(local_user_id()),
+        data.get_ref(user_id())
+            .or(world.get_ref(id, user_id()).ok()),
+    ) {
+        (Some(local), Some(owner)) => local == owner,
+        _ => false,
+    };
+
+    let mut received = Vec::new();
+    let mut removed = Vec::new();
+    for change in &diff.changes {
+        match change {
+            WorldChange::Spawn(id, data)
+            | WorldChange::AddComponents(id, data)
+            | WorldChange::SetComponents(id, data) => {
+                if is_local(*id, data) {
+                    received.extend(
+                        data.iter()
+                            .filter(|entry| is_predicted(entry.desc()))
+                            .map(|entry| ((*id, entry.desc()), entry.clone())),
+                    );
+                }
+            }
+            WorldChange::RemoveComponents(id, descs) => {
+                removed.extend(descs.iter().map(|&desc| (*id, Some(desc))));
+            }
+            WorldChange::Despawn(id) => removed.push((*id, None)),
+        }
+    }
+
+    if world.resource_opt(server_values()).is_none() {
+        world.add_resource(server_values(), Default::default());
+    }
+    let values = world.resource_mut(server_values());
+    for (id, desc) in removed {
+        values.retain(|&(value_id, value_desc), _| {
+            value_id != id || desc.is_some_and(|desc| desc != value_desc)
+        });
+    }
+    values.extend(received);
+}
+
+/// Resets the `Predicted` components of the local player's entities to the most recent values
+/// from the server, and replays the messages the server has not acknowledged yet through the
+/// prediction systems.
+///
+/// Does nothing if there are no prediction systems.
+pub fn replay_inputs(world: &mut World) {
+    if !uses_prediction_systems(world) {
+        return;
+    }
+    let values = world
+        .resource_opt(server_values())
+        .cloned()
+        .unwrap_or_default();
+    for ((id, _), entry) in values {
+        if world.has_component(id, entry.desc()) {
+            world.set_entry(id, entry).ok();
+        }
+    }
+
+    let pending = world
+        .resource_opt(pending_inputs())
+        .cloned()
+        .unwrap_or_default();
+    for input in pending {
+        run_prediction_systems(
+            world,
+            &PredictedInput {
+                replayed: true,
+                ..input
+            },
+        );
+    }
+}
+
+/// Replays the change from `predicted` to `current` on top of the server's `value`.
+///
+/// Values that can't be added together are kept at the local prediction until the server has
+/// acknowledged the messages that changed them.
+fn replay(
+    value: &ComponentEntry,
+    predicted: &ComponentEntry,
+    current: &ComponentEntry,
+) -> Option<ComponentEntry> {
+    let desc = value.desc();
+    macro_rules! replay_additive {
+        ($($ty:ty),*) => {
+            $(
+                if let (Some(value), Some(predicted), Some(current)) = (
+                    value.try_downcast_ref::<$ty>(),
+                    predicted.try_downcast_ref::<$ty>(),
+                    current.try_downcast_ref::<$ty>(),
+                ) {
+                    return Some(ComponentEntry::from_raw_parts(
+                        desc,
+                        *value + (*current - *predicted),
+                    ));
+                }
+            )*
+        };
+    }
+    replay_additive!(f32, f64, Vec2, Vec3, Vec4);
+
+    if let (Some(value), Some(predicted), Some(current)) = (
+        value.try_downcast_ref::<Quat>(),
+        predicted.try_downcast_ref::<Quat>(),
+        current.try_downcast_ref::<Quat>(),
+    ) {
+        return Some(ComponentEntry::from_raw_parts(
+            desc,
+            (*current * predicted.inverse() * *value).normalize(),
+        ));
+    }
+
+    let serialize = |entry: &ComponentEntry| {
+        let ser = entry.attribute::<Serializable>()?;
+        bincode_options().serialize(ser.serialize(entry)).ok()
+    };
+    (serialize(predicted) != serialize(current)).then(|| current.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::WorldContext;
+
+    use super::*;
+
+    components!("test", {
+        @[Networked, Predicted]
+        position: f32,
+    });
+
+    fn init() -> (World, EntityId) {
+        ambient_ecs::init_components();
+        crate::init_all_components();
+        init_components();
+        let mut world = World::new("test", WorldContext::Client);
+        world.add_resource(local_user_id(), "alice".to_string());
+        let player = Entity::new()
+            .with(user_id(), "alice".to_string())
+            .with(position(), 0.)
+            .with(input_sequence_ack(), 0)
+            .spawn(&mut world);
+        (world, player)
+    }
+
+    /// Applies a diff from the server that sets the position and acknowledges `ack`.
+    fn receive(world: &mut World, player: EntityId, value: f32, ack: u64) {
+        let mut diff =
+            WorldDiff::new()
+                .set(player, position(), value)
+                .set(player, input_sequence_ack(), ack);
+        reconcile(world, &mut diff);
+        diff.apply(world, Entity::new());
+        replay_inputs(world);
+    }
+
+    #[test]
+    fn unacknowledged_inputs_are_replayed_through_prediction_systems() {
+        let (mut world, player) = init();
+        add_prediction_system(
+            &mut world,
+            Arc::new(move |world, input| {
+                if input.name == "move" {
+                    let step = input.data[0] as f32;
+                    *world.get_mut(player, position()).unwrap() += step;
+                }
+            }),
+        );
+
+        for step in [1, 2, 3] {
+            next_input_sequence(&mut world, "move", &[step]);
+        }
+        assert_eq!(world.get(player, position()).unwrap(), 6.);
+
+        // The server has only processed the first message, and moved the player a little further
+        receive(&mut world, player, 1.5, 1);
+        assert_eq!(world.get(player, position()).unwrap(), 6.5);
+        assert_eq!(world.resource(pending_inputs()).len(), 2);
+
+        // An update that doesn't include the position still replays on the server's value
+        let mut diff = WorldDiff::new().set(player, input_sequence_ack(), 2);
+        reconcile(&mut world, &mut diff);
+        diff.apply(&mut world, Entity::new());
+        replay_inputs(&mut world);
+        assert_eq!(world.get(player, position()).unwrap(), 4.5);
+
+        receive(&mut world, player, 6.5, 3);
+        assert_eq!(world.get(player, position()).unwrap(), 6.5);
+        assert!(world.resource(pending_inputs()).is_empty());
+    }
+
+    #[test]
+    fn changes_are_replayed_on_top_of_server_values_without_prediction_systems() {
+        let (mut world, player) = init();
+
+        next_input_sequence(&mut world, "move", &[]);
+        world.set(player, position(), 2.).unwrap();
+
+        receive(&mut world, player, 0.5, 0);
+        assert_eq!(world.get(player, position()).unwrap(), 2.5);
+
+        receive(&mut world, player, 2., 1);
+        assert_eq!(world.get(player, position()).unwrap(), 2.);
+    }
+}
@@ -0,0 +1,316 @@
+//! Prioritization of the component changes sent to each player.
+//!
+//! Spawns, despawns and added or removed components are always sent right away. Changes to the
+//! values of components are held back while they would exceed their component's
+//! [NetworkPriority] `max_rate` or the player's `bandwidth_budget`. Every tick a change is held
+//! back, it accumulates its component's priority, and the changes with the highest accumulated
+//! priority are sent first once there is room for them. Only the latest value of a held-back
+//! component is sent.
+use std::collections::{HashMap, HashSet};
+
+use ambient_ecs::{
+    components, ComponentDesc, ComponentEntry, Entity, EntityId, FrozenWorldDiff, NetworkPriority,
+    Serializable, World, WorldChange, WorldDiff,
+};
+use ambient_sys::time::Instant;
+use bincode::Options;
+use itertools::Itertools;
+
+pub use ambient_ecs::generated::network::components::bandwidth_budget;
+
+use crate::diff_serialization::bincode_options;
+
+components!("network::priority", {
+    /// The component changes that are waiting to be sent to this player.
+    player_diff_scheduler: DiffScheduler,
+});
+
+/// How many seconds worth of budget can be saved up while there is nothing to send.
+const MAX_BURST: f32 = 0.25;
+/// Roughly how many bytes the entity and component of a change take up on top of its value.
+const CHANGE_OVERHEAD: usize = 4;
+
+#[derive(Debug, Clone)]
+struct PendingChange {
+    entry: ComponentEntry,
+    priority: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DiffScheduler {
+    pending: HashMap<(EntityId, ComponentDesc), PendingChange>,
+    /// When rate-limited components were last sent, until they can be sent again
+    last_sent: HashMap<(EntityId, ComponentDesc), Instant>,
+    /// How many bytes can be sent; negative once more than the budget allowed has been sent
+    allowance: f32,
+    last_schedule: Option<Instant>,
+}
+
+impl DiffScheduler {
+    /// Returns the changes to send to the player now: the shape changes of `diff`, followed by
+    /// the changes to component values that are due, from `diff` or held back from before.
+    ///
+    /// `budget` is in bytes per second; without one, only `max_rate` holds changes back.
+    pub fn schedule(
+        &mut self,
+        diff: &FrozenWorldDiff,
+        budget: Option<u32>,
+        now: Instant,
+    ) -> FrozenWorldDiff {
+        let elapsed = self
+            .last_schedule
+            .replace(now)
+            .map(|last| now.duration_since(last))
+            .unwrap_or_default();
+
+        if budget.is_none() && self.pending.is_empty() && !has_rate_limited_changes(diff) {
+            return diff.clone();
+        }
+
+        let mut shape_changes = Vec::new();
+        let mut despawned = HashSet::new();
+        for change in diff {
+            match change {
+                WorldChange::SetComponents(id, entity) => {
+                    for entry in entity.iter() {
+                        self.pending
+                            .entry((*id, entry.desc()))
+                            .and_modify(|pending| pending.entry = entry.clone())
+                            .or_insert_with(|| PendingChange {
+                                entry: entry.clone(),
+                                priority: 0.,
+                            });
+                    }
+                    continue;
+                }
+                // The values sent with these supersede any held-back changes
+                WorldChange::Spawn(id, entity) | WorldChange::AddComponents(id, entity) => {
+                    for entry in entity.iter() {
+                        self.pending.remove(&(*id, entry.desc()));
+                    }
+                }
+                WorldChange::RemoveComponents(id, descs) => {
+                    for desc in descs {
+                        self.pending.remove(&(*id, *desc));
+                        self.last_sent.remove(&(*id, *desc));
+                    }
+                }
+                WorldChange::Despawn(id) => {
+                    despawned.insert(*id);
+                }
+            }
+            shape_changes.push(change.clone());
+        }
+        if !despawned.is_empty() {
+            self.pending.retain(|(id, _), _| !despawned.contains(id));
+            self.last_sent.retain(|(id, _), _| !despawned.contains(id));
+        }
+
+        if let Some(budget) = budget {
+            let budget = budget as f32;
+            self.allowance =
+                (self.allowance + budget * elapsed.as_secs_f32()).min(budget * MAX_BURST);
+            self.allowance -= shape_changes.iter().map(change_size).sum::<usize>() as f32;
+        }
+
+        self.last_sent.retain(|&(_, desc), last_sent| {
+            is_rate_limited(
+                network_priority(desc),
+                now.duration_since(*last_sent).as_secs_f32(),
+            )
+        });
+
+        let mut due = Vec::new();
+        for (key, pending) in &mut self.pending {
+            pending.priority += network_priority(key.1).priority;
+            if !self.last_sent.contains_key(key) {
+                due.push((*key, pending.priority));
+            }
+        }
+        due.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        let mut set_changes = HashMap::<EntityId, Entity>::new();
+        for (key, _) in due {
+            if budget.is_some() && self.allowance <= 0. {
+                break;
+            }
+            let pending = self.pending.remove(&key).unwrap();
+            if budget.is_some() {
+                self.allowance -= entry_size(&pending.entry) as f32;
+            }
+            if network_priority(key.1).max_rate.is_some() {
+                self.last_sent.insert(key, now);
+            }
+            set_changes
+                .entry(key.0)
+                .or_default()
+                .set_entry(pending.entry);
+        }
+
+        let changes = shape_changes
+            .into_iter()
+            .chain(
+                set_changes
+                    .into_iter()
+                    .map(|(id, entity)| WorldChange::SetComponents(id, entity)),
+            )
+            .collect_vec();
+        WorldDiff { changes }.into()
+    }
+
+    /// The number of component changes that are being held back.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+/// Schedules `diff` for sending to `player`, within the player's `bandwidth_budget` or the
+/// `bandwidth_budget` resource.
+pub fn schedule_diff(
+    world: &mut World,
+    player: EntityId,
+    diff: &FrozenWorldDiff,
+) -> FrozenWorldDiff {
+    let budget = world
+        .get(player, bandwidth_budget())
+        .ok()
+        .or_else(|| world.resource_opt(bandwidth_budget()).copied());
+    if !world.has_component(player, player_diff_scheduler()) {
+        world
+            .add_component(player, player_diff_scheduler(), Default::default())
+            .ok();
+    }
+    match world.get_mut(player, player_diff_scheduler()) {
+        Ok(scheduler) => scheduler.schedule(diff, budget, Instant::now()),
+        Err(_) => diff.clone(),
+    }
+}
+
+fn network_priority(desc: ComponentDesc) -> NetworkPriority {
+    desc.attribute::<NetworkPriority>()
+        .map(|priority| *priority)
+        .unwrap_or_default()
+}
+
+/// Whether a component can't be sent again yet, `elapsed` seconds after it was last sent.
+fn is_rate_limited(priority: NetworkPriority, elapsed: f32) -> bool {
+    priority
+        .max_rate
+        .is_some_and(|max_rate| elapsed * max_rate < 1.)
+}
+
+fn has_rate_limited_changes(diff: &FrozenWorldDiff) -> bool {
+    diff.into_iter().any(|change| match change {
+        WorldChange::SetComponents(_, entity) => entity
+            .iter()
+            .any(|entry| network_priority(entry.desc()).max_rate.is_some()),
+        _ => false,
+    })
+}
+
+/// Estimates how many bytes a change takes up once serialized.
+fn change_size(change: &WorldChange) -> usize {
+    match change {
+        WorldChange::Spawn(_, entity)
+        | WorldChange::AddComponents(_, entity)
+        | WorldChange::SetComponents(_, entity) => {
+            CHANGE_OVERHEAD + entity.iter().map(entry_size).sum::<usize>()
+        }
+        WorldChange::RemoveComponents(_, descs) => CHANGE_OVERHEAD * (1 + descs.len()),
+        WorldChange::Despawn(_) => CHANGE_OVERHEAD,
+    }
+}
+
+fn entry_size(entry: &ComponentEntry) -> usize {
+    let value_size = entry
+        .attribute::<Serializable>()
+        .and_then(|ser| bincode_options().serialized_size(ser.serialize(entry)).ok())
+        .unwrap_or_default();
+    CHANGE_OVERHEAD + value_size as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use ambient_ecs::{components, Networked};
+
+    use super::*;
+
+    components!("test", {
+        @[Networked]
+        health: f32,
+        @[Networked, NetworkPriority[(10., None)]]
+        ammo: u32,
+        @[Networked, NetworkPriority[(1., Some(2.))]]
+        emote: String,
+    });
+
+    fn set(id: EntityId, entity: Entity) -> FrozenWorldDiff {
+        WorldDiff {
+            changes: vec![WorldChange::SetComponents(id, entity)],
+        }
+        .into()
+    }
+
+    fn sent_components(diff: &FrozenWorldDiff) -> Vec<ComponentDesc> {
+        diff.into_iter()
+            .flat_map(|change| match change {
+                WorldChange::SetComponents(_, entity) => {
+                    entity.iter().map(|entry| entry.desc()).collect_vec()
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn higher_priorities_are_sent_first() {
+        init_components();
+        let id = EntityId::new();
+        let start = Instant::now();
+        let mut scheduler = DiffScheduler::default();
+        // Only enough budget for one change per tick
+        let budget = Some(100);
+        let tick = Duration::from_millis(50);
+
+        // The first tick has no budget saved up yet
+        let diff = set(id, Entity::new().with(health(), 1.).with(ammo(), 30));
+        let sent = scheduler.schedule(&diff, budget, start);
+        assert!(sent.is_empty());
+
+        let sent = scheduler.schedule(&WorldDiff::new().into(), budget, start + tick);
+        assert_eq!(sent_components(&sent), vec![ammo().desc()]);
+        assert_eq!(scheduler.pending_count(), 1);
+
+        let sent = scheduler.schedule(&WorldDiff::new().into(), budget, start + tick * 2);
+        assert_eq!(sent_components(&sent), vec![health().desc()]);
+        assert_eq!(scheduler.pending_count(), 0);
+    }
+
+    #[test]
+    fn max_rate_holds_back_changes() {
+        init_components();
+        let id = EntityId::new();
+        let start = Instant::now();
+        let mut scheduler = DiffScheduler::default();
+        let emote_diff = |value: &str| set(id, Entity::new().with(emote(), value.to_string()));
+
+        let sent = scheduler.schedule(&emote_diff("wave"), None, start);
+        assert_eq!(sent_components(&sent), vec![emote().desc()]);
+
+        let sent = scheduler.schedule(
+            &emote_diff("dance"),
+            None,
+            start + Duration::from_millis(100),
+        );
+        assert!(sent.is_empty());
+
+        let sent = scheduler.schedule(&emote_diff("sit"), None, start + Duration::from_millis(600));
+        let changes = sent.into_iter().collect_vec();
+        let [WorldChange::SetComponents(_, entity)] = changes.as_slice() else {
+            panic!("Expected a single SetComponents change");
+        };
+        assert_eq!(entity.get_ref(emote()), Some(&"sit".to_string()));
+    }
+}
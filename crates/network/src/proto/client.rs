@@ -14,13 +14,14 @@ use tracing::{debug_span, Instrument};
 use crate::{
     bytes_ext::BufExt,
     client::{
-        bi_stream_handlers, datagram_handlers, uni_stream_handlers, PlatformRecvStream,
-        PlatformSendStream,
+        bi_stream_handlers, datagram_handlers, uni_stream_handlers, ClientRecvStream,
+        PlatformRecvStream, PlatformSendStream,
     },
     client_game_state::ClientGameState,
-    diff_serialization::DiffSerializer,
-    log_task_result,
+    diff_serialization::{DiffCompression, DiffSerializer},
+    interpolation, log_task_result, prediction,
     proto::*,
+    recording::{RecordedEvent, SessionRecorder},
 };
 
 /// The client logic handler in a connected state
@@ -30,6 +31,9 @@ use crate::{
 pub(crate) struct ConnectedClient {
     diff_serializer: DiffSerializer,
     pub main_package_name: String,
+    /// Bytes of world diffs received since the last stats update, as received and once decompressed
+    diff_bytes_received: u64,
+    diff_bytes_decompressed: u64,
 }
 
 #[derive(Debug)]
@@ -59,6 +63,14 @@ impl ClientProtoState {
     ) -> anyhow::Result<()> {
         match (frame, &self) {
             (ServerPush::ServerInfo(server_info), Self::Pending(_user_id)) => {
+                // Unlike a version mismatch, the client can't work with a different protocol
+                anyhow::ensure!(
+                    server_info.protocol_version == PROTOCOL_VERSION,
+                    "Client protocol version does not match server protocol version.\n\nServer protocol version: {}\nClient protocol version: {}",
+                    server_info.protocol_version,
+                    PROTOCOL_VERSION
+                );
+
                 let current_version = ambient_version().to_string();
 
                 if server_info.version != current_version {
@@ -81,6 +93,8 @@ impl ClientProtoState {
                 *self = Self::Connected(ConnectedClient {
                     diff_serializer: Default::default(),
                     main_package_name: server_info.main_package_name,
+                    diff_bytes_received: 0,
+                    diff_bytes_decompressed: 0,
                 });
 
                 Ok(())
@@ -96,14 +110,23 @@ impl ClientProtoState {
         }
     }
 
+    /// Updates the client network stats, which cover the last `interval` seconds
     #[cfg(not(target_os = "unknown"))]
     pub fn process_client_stats(
         &mut self,
         state: &SharedClientGameState,
-        stats: crate::client::NetworkStats,
+        mut stats: crate::client::NetworkStats,
+        interval: u64,
     ) {
         use crate::client::client_network_stats;
 
+        if let Self::Connected(connected) = self {
+            stats.diff_bytes_received =
+                std::mem::take(&mut connected.diff_bytes_received) / interval;
+            stats.diff_bytes_decompressed =
+                std::mem::take(&mut connected.diff_bytes_decompressed) / interval;
+        }
+
         let mut gs = state.lock();
         tracing::debug!(?stats, "Client network stats");
         gs.world.add_resource(client_network_stats(), stats);
@@ -133,10 +156,16 @@ impl ConnectedClient {
         state: &SharedClientGameState,
         diff: Bytes,
     ) -> anyhow::Result<()> {
-        let diff = self.diff_serializer.deserialize(diff)?;
+        self.diff_bytes_received += diff.len() as u64;
+        let diff = DiffCompression::decompress(diff)?;
+        self.diff_bytes_decompressed += diff.len() as u64;
+        let mut diff = self.diff_serializer.deserialize(diff)?;
         let mut gs = state.lock();
+        prediction::reconcile(&mut gs.world, &mut diff);
+        interpolation::buffer_diff(&mut gs.world, &mut diff);
         tracing::trace!(diff=?diff.len(), "Applying diff");
         diff.apply(&mut gs.world, Entity::new().with(is_remote_entity(), ()));
+        prediction::replay_inputs(&mut gs.world);
         Ok(())
     }
 
@@ -166,11 +195,25 @@ impl ConnectedClient {
     }
 
     /// Processes a server initiated unidirectional stream
-    pub fn process_uni(&mut self, state: &SharedClientGameState, mut recv: PlatformRecvStream) {
+    ///
+    /// When recording, the stream is read to its end and recorded before it is handled.
+    pub fn process_uni(
+        &mut self,
+        state: &SharedClientGameState,
+        mut recv: ClientRecvStream,
+        recorder: Option<SessionRecorder>,
+    ) {
         let state = state.clone();
 
         let task = log_task_result(
             async move {
+                if let Some(recorder) = recorder {
+                    let mut data = Vec::new();
+                    recv.read_to_end(&mut data).await?;
+                    recorder.record(&RecordedEvent::UniStream(data.clone().into()));
+                    recv = Box::pin(std::io::Cursor::new(data));
+                }
+
                 let id = recv.read_u32().await?;
 
                 // The handler is returned to avoid holding the lock while the handler is running
@@ -1,6 +1,18 @@
 use ambient_core::main_package_name;
 use ambient_native_std::{ambient_version, asset_url::AbsAssetUrl};
 
+use crate::diff_serialization::DiffCompression;
+
+/// The version of the messages exchanged between the client and the server.
+///
+/// Bumped whenever their format changes incompatibly; clients refuse to connect to servers with a
+/// different version.
+///
+/// - 1: Messages from modules are prefixed with their `input_sequence`.
+/// - 2: World diffs can be compressed and have their values encoded, as negotiated by
+///   [ClientRequest::ConnectWithProtocol].
+pub const PROTOCOL_VERSION: u32 = 2;
+
 pub mod client;
 pub mod server;
 
@@ -8,12 +20,35 @@ pub mod server;
 /// Request sent by the client to the server
 pub enum ClientRequest {
     /// Connect to the server with the specified user id
+    ///
+    /// Only sent by clients that predate [PROTOCOL_VERSION], which the server refuses.
     Connect(String),
     /// Client wants to disconnect
     Disconnect,
+    /// Connect to the server with the specified user id and protocol version, supporting the
+    /// specified compressions for the world diffs
+    ///
+    /// Added after the other requests, so that older servers fail to decode it instead of
+    /// misreading it.
+    ConnectWithProtocol {
+        user_id: String,
+        protocol_version: u32,
+        diff_compressions: Vec<DiffCompression>,
+    },
 }
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+impl ClientRequest {
+    /// The request a client of this version connects with.
+    pub fn connect(user_id: String) -> Self {
+        Self::ConnectWithProtocol {
+            user_id,
+            protocol_version: PROTOCOL_VERSION,
+            diff_compressions: DiffCompression::SUPPORTED.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 /// Frame used by the server to send information to the client
 pub enum ServerPush {
     ServerInfo(ServerInfo),
@@ -35,6 +70,10 @@ pub struct ServerInfo {
     /// Defaults to the version of the crate.
     /// TODO: use semver
     pub version: String,
+
+    /// The [PROTOCOL_VERSION] of the server. Clients that predate it ignore it, as it is the last
+    /// field.
+    pub protocol_version: u32,
 }
 
 impl ServerInfo {
@@ -49,6 +88,55 @@ impl ServerInfo {
             main_package_name: world.resource(main_package_name()).clone(),
             content_base_url,
             version: ambient_version().to_string(),
+            protocol_version: PROTOCOL_VERSION,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clients_that_predate_protocol_versions_can_read_server_info() {
+        #[derive(serde::Deserialize)]
+        struct OldServerInfo {
+            main_package_name: String,
+            #[allow(dead_code)]
+            content_base_url: AbsAssetUrl,
+            version: String,
+        }
+
+        let info = ServerInfo {
+            main_package_name: "Test".to_string(),
+            content_base_url: "http://localhost:8999/".parse().unwrap(),
+            version: ambient_version().to_string(),
+            protocol_version: PROTOCOL_VERSION,
+        };
+        let old: OldServerInfo = bincode::deserialize(&bincode::serialize(&info).unwrap()).unwrap();
+        assert_eq!(old.main_package_name, "Test");
+        assert_eq!(old.version, info.version);
+    }
+
+    #[test]
+    fn connect_requests_from_older_clients_are_recognized() {
+        #[derive(serde::Serialize)]
+        enum OldClientRequest {
+            Connect(String),
+            #[allow(dead_code)]
+            Disconnect,
         }
+
+        let bytes = bincode::serialize(&OldClientRequest::Connect("alice".to_string())).unwrap();
+        let request: ClientRequest = bincode::deserialize(&bytes).unwrap();
+        assert!(matches!(request, ClientRequest::Connect(user_id) if user_id == "alice"));
+
+        let bytes = bincode::serialize(&ClientRequest::connect("alice".to_string())).unwrap();
+        let request: ClientRequest = bincode::deserialize(&bytes).unwrap();
+        assert!(matches!(
+            request,
+            ClientRequest::ConnectWithProtocol { protocol_version, .. }
+                if protocol_version == PROTOCOL_VERSION
+        ));
     }
 }
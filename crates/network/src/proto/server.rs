@@ -15,9 +15,10 @@ use uuid::Uuid;
 use crate::{
     bytes_ext::BufExt,
     client::NetworkTransport,
-    diff_serialization::{DiffSerializer, WorldDiffDeduplicator},
+    diff_serialization::{DiffCompression, DiffSerializer, WorldDiffDeduplicator},
     log_network_result, log_task_result,
     proto::ServerPush,
+    relevance::{filter_initial_diff, hidden_entities, player_hidden_entities},
     server::{
         bi_stream_handlers, create_player_entity_data, datagram_handlers, uni_stream_handlers,
     },
@@ -25,7 +26,7 @@ use crate::{
     stream,
 };
 
-use super::ClientRequest;
+use super::{ClientRequest, PROTOCOL_VERSION};
 
 /// The server can be in multiple states depending on what has been received from the client.
 ///
@@ -49,12 +50,15 @@ pub struct ConnectedClient {
     /// Currently a random friendly_id generated by the client
     user_id: Arc<str>,
     pub control_rx: flume::r#async::RecvStream<'static, ServerPush>,
+    /// The compression negotiated for the world diffs sent to the client
+    pub diff_compression: DiffCompression,
 }
 
 impl std::fmt::Debug for ConnectedClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ConnectedClient")
             .field("user_id", &self.user_id)
+            .field("diff_compression", &self.diff_compression)
             .finish()
     }
 }
@@ -116,13 +120,33 @@ impl ServerProtoState {
                 tracing::debug!("Client is disconnected, ignoring control frame");
                 Ok(())
             }
-            (ClientRequest::Connect(user_id), Self::PendingConnection) => {
+            (
+                ClientRequest::ConnectWithProtocol {
+                    user_id,
+                    protocol_version,
+                    diff_compressions,
+                },
+                Self::PendingConnection,
+            ) => {
+                anyhow::ensure!(
+                    protocol_version == PROTOCOL_VERSION,
+                    "Client {user_id:?} uses protocol version {protocol_version}, but the server uses {PROTOCOL_VERSION}"
+                );
                 // Connect the user
                 tracing::debug!("User connected");
-                self.process_connect(data, user_id);
+                let diff_compression = DiffCompression::negotiate(&diff_compressions);
+                self.process_connect(data, user_id, diff_compression);
                 Ok(())
             }
-            (ClientRequest::Connect(_), Self::Connected(_)) => {
+            (ClientRequest::Connect(user_id), Self::PendingConnection) => {
+                anyhow::bail!(
+                    "Client {user_id:?} predates protocol versions, and can't connect to this server"
+                )
+            }
+            (
+                ClientRequest::Connect(..) | ClientRequest::ConnectWithProtocol { .. },
+                Self::Connected(_),
+            ) => {
                 tracing::warn!("Client already connected");
                 Ok(())
             }
@@ -134,7 +158,12 @@ impl ServerProtoState {
     }
 
     #[tracing::instrument(level = "debug")]
-    fn process_connect(&mut self, data: &ConnectionData, user_id: String) {
+    fn process_connect(
+        &mut self,
+        data: &ConnectionData,
+        user_id: String,
+        diff_compression: DiffCompression,
+    ) {
         let mut state = data.state.lock();
 
         let (control_tx, control_rx) = flume::unbounded();
@@ -157,9 +186,6 @@ impl ServerProtoState {
 
         let diff = data.world_stream_filter.initial_diff(&instance.world);
 
-        log_result!(data.diff_tx.send(diff.into()));
-        tracing::debug!("[{}] Init diff sent", user_id);
-
         let entity_data = create_player_entity_data(
             data.conn.clone(),
             user_id.clone(),
@@ -167,7 +193,7 @@ impl ServerProtoState {
             data.connection_id,
         );
 
-        if let Some(old_player) = old_player {
+        let id = if let Some(old_player) = old_player {
             old_player.control_tx.send(ServerPush::Disconnect).ok();
 
             let id = get_by_user_id(&instance.world, &user_id).unwrap();
@@ -175,14 +201,29 @@ impl ServerProtoState {
             instance.world.add_components(id, entity_data).unwrap();
 
             tracing::debug!(user_id, ?id, "Player reconnected");
+            id
         } else {
             let id = instance.spawn_player(entity_data);
             tracing::debug!(user_id, ?id, "Player connected");
-        }
+            id
+        };
+
+        // Only send the entities that are relevant to the player; the rest are spawned once they
+        // become relevant
+        let hidden = hidden_entities(&instance.world, &data.world_stream_filter, id);
+        let diff = filter_initial_diff(diff, &hidden);
+        instance
+            .world
+            .add_component(id, player_hidden_entities(), hidden)
+            .unwrap();
+
+        log_result!(data.diff_tx.send(diff.into()));
+        tracing::debug!("[{}] Init diff sent", user_id);
 
         *self = Self::Connected(ConnectedClient {
             user_id: user_id.into(),
             control_rx: control_rx.into_stream(),
+            diff_compression,
         });
     }
 
@@ -216,6 +257,14 @@ impl ServerProtoState {
         *self = Self::Disconnected;
     }
 
+    /// The compression negotiated for the world diffs sent to the client, if connected
+    pub fn diff_compression(&self) -> DiffCompression {
+        match self {
+            Self::Connected(client) => client.diff_compression,
+            _ => DiffCompression::None,
+        }
+    }
+
     /// Returns `true` if the server state is [`Connected`].
     ///
     /// [`Connected`]: ServerState::Connected
@@ -361,6 +410,7 @@ pub async fn handle_stats<S>(
 pub async fn handle_diffs<S>(
     stream: stream::FramedSendStream<WorldDiff, S>,
     diffs_rx: flume::Receiver<FrozenWorldDiff>,
+    compression: DiffCompression,
 ) where
     S: Unpin + AsyncWrite,
 {
@@ -375,7 +425,7 @@ pub async fn handle_diffs<S>(
     }
 
     let mut deduplicator = WorldDiffDeduplicator::default();
-    let mut serializer = DiffSerializer::default();
+    let mut serializer = DiffSerializer::default().with_value_encoding(true);
     #[cfg(debug_assertions)]
     let mut deserializer = DiffSerializer::default();
 
@@ -450,6 +500,10 @@ pub async fn handle_diffs<S>(
                 );
             }
 
+            let uncompressed_bytes = msg.len();
+            let msg = compression.compress(&msg);
+            tracing::trace!(?compression, uncompressed_bytes, bytes = msg.len());
+
             let span = tracing::debug_span!("send_world_diff");
             sending_future = Some(Box::pin(async move {
                 stream
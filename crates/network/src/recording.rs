@@ -0,0 +1,258 @@
+//! Recording of client sessions, for playing them back later with `ambient replay`.
+//!
+//! A recording starts with a header, followed by one frame for every push, diff, datagram and
+//! unidirectional stream the client received from the server, along with the time it was received
+//! relative to the start of the recording. Frames are length-prefixed and flushed as they are
+//! written, so a recording that was cut short is still readable up to its last complete frame.
+use std::{
+    fmt::Debug,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::Arc,
+    time::Duration,
+};
+
+use ambient_native_std::asset_url::AbsAssetUrl;
+use ambient_sys::time::Instant;
+use anyhow::Context;
+use bytes::Bytes;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::proto::ServerPush;
+
+const MAGIC: &[u8; 8] = b"AMBREC\0\0";
+/// Bumped along with [crate::proto::PROTOCOL_VERSION], as recordings contain the messages of the
+/// protocol
+const VERSION: u32 = 3;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordingHeader {
+    version: u32,
+    user_id: String,
+}
+
+/// Something the client received from the server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RecordedEvent {
+    Push(ServerPush),
+    /// A world diff, as it was received
+    Diff(Bytes),
+    /// A datagram, starting with the id of its handler
+    Datagram(Bytes),
+    /// All of the data of a unidirectional stream, starting with the id of its handler
+    UniStream(Bytes),
+}
+
+/// Writes the events received by a client to a recording.
+#[derive(Clone)]
+pub struct SessionRecorder {
+    file: Arc<Mutex<BufWriter<File>>>,
+    start: Instant,
+}
+
+impl Debug for SessionRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionRecorder")
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SessionRecorder {
+    /// Creates a recording at `path` of the session of `user_id`, replacing any existing file.
+    pub fn create(path: &Path, user_id: &str) -> anyhow::Result<Self> {
+        let file = File::create(path)
+            .with_context(|| format!("Failed to create recording {}", path.display()))?;
+        let mut file = BufWriter::new(file);
+
+        let header = bincode::serialize(&RecordingHeader {
+            version: VERSION,
+            user_id: user_id.to_string(),
+        })?;
+        file.write_all(MAGIC)?;
+        write_frame(&mut file, &header)?;
+
+        Ok(Self {
+            file: Arc::new(Mutex::new(file)),
+            start: Instant::now(),
+        })
+    }
+
+    /// Appends `event` to the recording, timestamped with the current time.
+    ///
+    /// Failing to write is logged rather than returned, as it should not end the session.
+    pub fn record(&self, event: &RecordedEvent) {
+        let time = Instant::now().duration_since(self.start);
+        if let Err(err) = self.write(time, event) {
+            tracing::warn!("Failed to record network event: {err:?}");
+        }
+    }
+
+    fn write(&self, time: Duration, event: &RecordedEvent) -> anyhow::Result<()> {
+        let frame = bincode::serialize(&(time.as_micros() as u64, event))?;
+        write_frame(&mut *self.file.lock(), &frame)
+    }
+}
+
+fn write_frame(file: &mut BufWriter<File>, frame: &[u8]) -> anyhow::Result<()> {
+    file.write_all(&(frame.len() as u32).to_le_bytes())?;
+    file.write_all(frame)?;
+    file.flush()?;
+    Ok(())
+}
+
+/// A recording of a client session, loaded into memory.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    user_id: String,
+    events: Vec<(Duration, RecordedEvent)>,
+}
+
+impl Recording {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read(path)
+            .with_context(|| format!("Failed to read recording {}", path.display()))?;
+        Self::parse(&data).with_context(|| format!("Invalid recording {}", path.display()))
+    }
+
+    pub fn parse(data: &[u8]) -> anyhow::Result<Self> {
+        let data = data
+            .strip_prefix(MAGIC.as_slice())
+            .context("Not an Ambient recording")?;
+        let (header, mut data) = read_frame(data).context("Missing recording header")?;
+        let header: RecordingHeader = bincode::deserialize(header)?;
+        anyhow::ensure!(
+            header.version == VERSION,
+            "Unsupported recording version {} (expected {VERSION})",
+            header.version
+        );
+
+        let mut events = Vec::new();
+        while !data.is_empty() {
+            let Some((frame, rest)) = read_frame(data) else {
+                tracing::warn!("Recording ends with an incomplete frame, ignoring it");
+                break;
+            };
+            let (time, event): (u64, RecordedEvent) = bincode::deserialize(frame)?;
+            events.push((Duration::from_micros(time), event));
+            data = rest;
+        }
+
+        Ok(Self {
+            user_id: header.user_id,
+            events,
+        })
+    }
+
+    /// The user the session was recorded as.
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    /// The recorded events, in the order they were received, along with when they were received.
+    pub fn events(&self) -> &[(Duration, RecordedEvent)] {
+        &self.events
+    }
+
+    /// The content URL the server provided, which the package's assets are loaded from.
+    pub fn content_base_url(&self) -> Option<&AbsAssetUrl> {
+        self.events.iter().find_map(|(_, event)| match event {
+            RecordedEvent::Push(ServerPush::ServerInfo(info)) => Some(&info.content_base_url),
+            _ => None,
+        })
+    }
+
+    /// Loads the package's assets from `url` instead of the content URL the server provided, e.g.
+    /// because the server that was recorded served them itself and is no longer running.
+    pub fn set_content_base_url(&mut self, url: AbsAssetUrl) {
+        for (_, event) in &mut self.events {
+            if let RecordedEvent::Push(ServerPush::ServerInfo(info)) = event {
+                info.content_base_url = url.clone();
+            }
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.events
+            .last()
+            .map(|(time, _)| *time)
+            .unwrap_or_default()
+    }
+}
+
+fn read_frame(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let len = u32::from_le_bytes(data.get(..4)?.try_into().unwrap()) as usize;
+    let frame = data.get(4..4 + len)?;
+    Some((frame, &data[4 + len..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recordings_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "ambient_recording_test_{}.ambrec",
+            std::process::id()
+        ));
+
+        let recorder = SessionRecorder::create(&path, "user").unwrap();
+        recorder.record(&RecordedEvent::Push(ServerPush::Disconnect));
+        recorder.record(&RecordedEvent::Diff(Bytes::from_static(b"diff")));
+        recorder.record(&RecordedEvent::Datagram(Bytes::from_static(b"datagram")));
+        drop(recorder);
+
+        // A frame cut short by a crash is skipped
+        let mut data = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        data.extend_from_slice(&[8, 0, 0, 0, 1]);
+
+        let recording = Recording::parse(&data).unwrap();
+        assert_eq!(recording.user_id(), "user");
+        let events = recording.events();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(
+            events[0].1,
+            RecordedEvent::Push(ServerPush::Disconnect)
+        ));
+        assert!(matches!(&events[1].1, RecordedEvent::Diff(diff) if diff == "diff"));
+        assert!(events.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(recording.duration(), events[2].0);
+    }
+
+    #[test]
+    fn content_base_url_can_be_overridden() {
+        let info = crate::proto::ServerInfo {
+            main_package_name: "Test".to_string(),
+            content_base_url: "http://localhost:8999/content/".parse().unwrap(),
+            version: "0.0.0".to_string(),
+            protocol_version: crate::proto::PROTOCOL_VERSION,
+        };
+        let mut recording = Recording {
+            user_id: "user".to_string(),
+            events: vec![
+                (
+                    Duration::ZERO,
+                    RecordedEvent::Push(ServerPush::ServerInfo(info)),
+                ),
+                (
+                    Duration::from_millis(10),
+                    RecordedEvent::Diff(Bytes::from_static(b"diff")),
+                ),
+            ],
+        };
+        assert_eq!(
+            recording.content_base_url().unwrap().to_string(),
+            "http://localhost:8999/content/"
+        );
+
+        recording.set_content_base_url("https://example.com/build/".parse().unwrap());
+        assert_eq!(
+            recording.content_base_url().unwrap().to_string(),
+            "https://example.com/build/"
+        );
+    }
+}
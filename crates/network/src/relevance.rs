@@ -0,0 +1,300 @@
+//! Per-player relevance filtering of the entities that are synchronized to clients.
+//!
+//! Entities that are not relevant to a player are not sent to their client; they are spawned
+//! on the client once they become relevant, and despawned once they stop being relevant.
+use std::{collections::HashSet, sync::Arc};
+
+use ambient_core::{
+    camera::get_active_camera,
+    hierarchy::parent,
+    main_scene,
+    player::user_id,
+    transform::{local_to_world, translation},
+};
+use ambient_ecs::{
+    components, query, EntityId, FrozenWorldDiff, Resource, World, WorldChange, WorldDiff,
+    WorldStreamFilter,
+};
+use glam::Vec3;
+use itertools::Itertools;
+
+pub use ambient_ecs::generated::network::components::{
+    relevance_radius, relevance_team, relevance_user_ids,
+};
+
+/// Returns true if the entity (second argument) should be synchronized to the player entity
+/// (first argument).
+pub type RelevanceFilter = Arc<dyn Fn(&World, EntityId, EntityId) -> bool + Sync + Send>;
+
+components!("network::relevance", {
+    /// Replaces [default_relevance] as the filter that decides which entities are synchronized
+    /// to each player.
+    @[Resource]
+    relevance_filter: RelevanceFilter,
+    /// The entities that are currently not synchronized to this player's client.
+    player_hidden_entities: HashSet<EntityId>,
+});
+
+/// The default relevance filter, which respects `relevance_radius`, `relevance_team` and
+/// `relevance_user_ids`.
+pub fn default_relevance(world: &World, player: EntityId, entity: EntityId) -> bool {
+    if entity == player {
+        return true;
+    }
+    if let Ok(user_ids) = world.get_ref(entity, relevance_user_ids()) {
+        match world.get_ref(player, user_id()) {
+            Ok(player_user_id) if user_ids.contains(player_user_id) => {}
+            _ => return false,
+        }
+    }
+    if let Ok(team) = world.get(entity, relevance_team()) {
+        if world.get(player, relevance_team()).ok() != Some(team) {
+            return false;
+        }
+    }
+    if let Ok(radius) = world.get(entity, relevance_radius()) {
+        // Without a viewpoint there's nothing to measure against, so the entity stays relevant
+        if let (Some(viewpoint), Some(position)) = (
+            player_viewpoint(world, player),
+            world_position(world, entity),
+        ) {
+            if viewpoint.distance_squared(position) > radius * radius {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// The position the distance to an entity is measured from for `relevance_radius`.
+pub fn player_viewpoint(world: &World, player: EntityId) -> Option<Vec3> {
+    let player_user_id = world.get_ref(player, user_id()).ok();
+    get_active_camera(world, main_scene(), player_user_id)
+        .and_then(|camera| world_position(world, camera))
+        .or_else(|| world_position(world, player))
+}
+
+fn world_position(world: &World, id: EntityId) -> Option<Vec3> {
+    world
+        .get(id, local_to_world())
+        .map(|transform| transform.w_axis.truncate())
+        .or_else(|_| world.get(id, translation()))
+        .ok()
+}
+
+/// An entity is only relevant if it and all of its ancestors are relevant, so that clients
+/// never see an entity without its parent.
+fn is_relevant(
+    world: &World,
+    filter: &RelevanceFilter,
+    player: EntityId,
+    entity: EntityId,
+) -> bool {
+    let mut id = entity;
+    loop {
+        if !filter(world, player, id) {
+            return false;
+        }
+        match world.get(id, parent()) {
+            Ok(parent) if world.exists(parent) => id = parent,
+            _ => return true,
+        }
+    }
+}
+
+/// The default filter only hides entities that have a relevance component, or whose ancestors
+/// do, so without them there is no need to check every entity for every player.
+fn uses_relevance_components(world: &World) -> bool {
+    query(relevance_radius()).iter(world, None).next().is_some()
+        || query(relevance_team()).iter(world, None).next().is_some()
+        || query(relevance_user_ids())
+            .iter(world, None)
+            .next()
+            .is_some()
+}
+
+/// Returns the entities of the stream that should not be synchronized to `player`.
+pub fn hidden_entities(
+    world: &World,
+    stream_filter: &WorldStreamFilter,
+    player: EntityId,
+) -> HashSet<EntityId> {
+    let filter = match world.resource_opt(relevance_filter()) {
+        Some(filter) => filter.clone(),
+        None if uses_relevance_components(world) => Arc::new(default_relevance),
+        None => return HashSet::new(),
+    };
+    stream_filter
+        .all_entities(world)
+        .filter(|&id| !is_relevant(world, &filter, player, id))
+        .collect()
+}
+
+fn change_entity(change: &WorldChange) -> EntityId {
+    match change {
+        WorldChange::Spawn(id, _)
+        | WorldChange::Despawn(id)
+        | WorldChange::AddComponents(id, _)
+        | WorldChange::RemoveComponents(id, _)
+        | WorldChange::SetComponents(id, _) => *id,
+    }
+}
+
+/// Removes the entities in `hidden` from an initial diff.
+pub fn filter_initial_diff(mut diff: WorldDiff, hidden: &HashSet<EntityId>) -> WorldDiff {
+    diff.changes
+        .retain(|change| !hidden.contains(&change_entity(change)));
+    diff
+}
+
+/// Rewrites `diff` for a client that had `hidden_before` hidden from it, and should now have
+/// `hidden_now` hidden from it.
+///
+/// Entities that stopped being relevant are despawned, and entities that became relevant are
+/// spawned with their current components.
+pub fn filter_diff(
+    world: &World,
+    stream_filter: &WorldStreamFilter,
+    diff: &FrozenWorldDiff,
+    hidden_before: &HashSet<EntityId>,
+    hidden_now: &HashSet<EntityId>,
+) -> WorldDiff {
+    let spawned: HashSet<EntityId> = diff
+        .into_iter()
+        .filter_map(|change| match change {
+            WorldChange::Spawn(id, _) => Some(*id),
+            _ => None,
+        })
+        .collect();
+    let entered = stream_filter
+        .all_entities(world)
+        .filter(|id| hidden_before.contains(id) && !hidden_now.contains(id))
+        .collect_vec();
+
+    let left = hidden_now
+        .difference(hidden_before)
+        .filter(|id| !spawned.contains(id))
+        .map(|&id| WorldChange::Despawn(id));
+    let changes = diff.into_iter().filter(|change| {
+        let id = change_entity(change);
+        match change {
+            WorldChange::Despawn(_) => !hidden_before.contains(&id),
+            _ => !hidden_now.contains(&id) && !hidden_before.contains(&id),
+        }
+    });
+    let entered = entered
+        .into_iter()
+        .map(|id| WorldChange::Spawn(id, stream_filter.read_entity_components(world, id).into()));
+
+    WorldDiff {
+        changes: left.chain(changes.cloned()).chain(entered).collect_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_ecs::{ArchetypeFilter, Entity, Networked, WorldContext};
+
+    use super::*;
+
+    components!("test", {
+        @[Networked]
+        score: u32,
+    });
+
+    fn init() -> (World, WorldStreamFilter) {
+        ambient_ecs::init_components();
+        crate::init_all_components();
+        init_components();
+        let filter = WorldStreamFilter::new(ArchetypeFilter::new(), Arc::new(|_, _| true));
+        (World::new("test", WorldContext::Server), filter)
+    }
+
+    fn summary(diff: &WorldDiff) -> Vec<(&'static str, EntityId)> {
+        diff.changes
+            .iter()
+            .map(|change| {
+                let kind = match change {
+                    WorldChange::Spawn(..) => "spawn",
+                    WorldChange::Despawn(_) => "despawn",
+                    _ => "change",
+                };
+                (kind, change_entity(change))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn entities_are_only_hidden_by_relevance() {
+        let (mut world, filter) = init();
+        let player = Entity::new()
+            .with(user_id(), "alice".to_string())
+            .spawn(&mut world);
+        let entity = Entity::new().with(score(), 1).spawn(&mut world);
+        let child = Entity::new()
+            .with(score(), 2)
+            .with(parent(), entity)
+            .spawn(&mut world);
+        assert!(hidden_entities(&world, &filter, player).is_empty());
+
+        world
+            .add_component(entity, relevance_user_ids(), vec!["bob".to_string()])
+            .unwrap();
+        assert_eq!(
+            hidden_entities(&world, &filter, player),
+            HashSet::from([entity, child])
+        );
+    }
+
+    #[test]
+    fn initial_diffs_leave_out_hidden_entities() {
+        init();
+        let visible = EntityId::new();
+        let hidden = EntityId::new();
+        let diff = WorldDiff::new()
+            .set(visible, score(), 1)
+            .set(hidden, score(), 2);
+
+        let diff = filter_initial_diff(diff, &HashSet::from([hidden]));
+        assert_eq!(summary(&diff), vec![("change", visible)]);
+    }
+
+    #[test]
+    fn diffs_spawn_and_despawn_entities_as_their_relevance_changes() {
+        let (mut world, filter) = init();
+        let visible = Entity::new().with(score(), 1).spawn(&mut world);
+        let entering = Entity::new().with(score(), 2).spawn(&mut world);
+        let leaving = Entity::new().with(score(), 3).spawn(&mut world);
+        let hidden = Entity::new().with(score(), 4).spawn(&mut world);
+        let spawned_hidden = Entity::new().with(score(), 5).spawn(&mut world);
+
+        let mut diff = WorldDiff::new();
+        for id in [visible, entering, leaving, hidden] {
+            diff = diff.set(id, score(), 10);
+        }
+        diff.changes.push(WorldChange::Spawn(
+            spawned_hidden,
+            Entity::new().with(score(), 5),
+        ));
+
+        let diff = filter_diff(
+            &world,
+            &filter,
+            &diff.into(),
+            &HashSet::from([entering, hidden]),
+            &HashSet::from([leaving, hidden, spawned_hidden]),
+        );
+        assert_eq!(
+            summary(&diff),
+            vec![
+                ("despawn", leaving),
+                ("change", visible),
+                ("spawn", entering)
+            ]
+        );
+        match &diff.changes[2] {
+            WorldChange::Spawn(_, entity) => assert_eq!(entity.get(score()), Some(2)),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use std::{collections::HashMap, fmt::Debug, sync::Arc, time::Duration};
 
 use crate::{
-    client::NetworkTransport, proto::server::Player, DynRecv, DynSend, NetworkError,
-    RPC_BISTREAM_ID,
+    client::NetworkTransport,
+    priority::schedule_diff,
+    proto::server::Player,
+    relevance::{filter_diff, hidden_entities, player_hidden_entities},
+    DynRecv, DynSend, NetworkError, RPC_BISTREAM_ID,
 };
 use ambient_core::{
     app_start_time, name,
@@ -20,6 +23,7 @@ use ambient_rpc::RpcRegistry;
 use ambient_sys::time::Instant;
 use bytes::Bytes;
 use flume::Sender;
+use itertools::Itertools;
 use parking_lot::Mutex;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use uuid::Uuid;
@@ -133,16 +137,44 @@ impl WorldInstance {
         ambient_core::hierarchy::despawn_recursive(&mut self.world, id)
     }
     pub fn broadcast_diffs(&mut self) {
-        let diff = self.world_stream.next_diff(&self.world);
-        if diff.is_empty() {
-            return;
-        }
-        let diff: FrozenWorldDiff = diff.into();
+        let diff: FrozenWorldDiff = self.world_stream.next_diff(&self.world).into();
 
         profiling::scope!("Send MsgEntities");
 
-        for (_, (entity_stream,)) in query((player_entity_stream(),)).iter(&self.world, None) {
-            if let Err(err) = entity_stream.send(diff.clone()) {
+        let players = query((player_entity_stream(),))
+            .iter(&self.world, None)
+            .map(|(id, (entity_stream,))| (id, entity_stream.clone()))
+            .collect_vec();
+        for (id, entity_stream) in players {
+            let hidden_before = self
+                .world
+                .get_cloned(id, player_hidden_entities())
+                .unwrap_or_default();
+            let hidden_now = hidden_entities(&self.world, self.world_stream.filter(), id);
+
+            let diff = if hidden_before.is_empty() && hidden_now.is_empty() {
+                diff.clone()
+            } else {
+                filter_diff(
+                    &self.world,
+                    self.world_stream.filter(),
+                    &diff,
+                    &hidden_before,
+                    &hidden_now,
+                )
+                .into()
+            };
+            if hidden_before != hidden_now {
+                self.world
+                    .add_component(id, player_hidden_entities(), hidden_now)
+                    .unwrap();
+            }
+            let diff = schedule_diff(&mut self.world, id, &diff);
+            if diff.is_empty() {
+                continue;
+            }
+
+            if let Err(err) = entity_stream.send(diff) {
                 tracing::warn!("Failed to broadcast diff to player: {err:?}");
             }
         }
@@ -385,7 +385,7 @@ async fn handle_connection(
     tracing::debug!("Attempting to connect using {user_id:?}");
 
     request_send
-        .send(ClientRequest::Connect(user_id.clone()))
+        .send(ClientRequest::connect(user_id.clone()))
         .await?;
 
     let mut client = ClientProtoState::Pending(user_id.clone());
@@ -453,7 +453,7 @@ async fn handle_connection(
             }
 
             Some(Ok(recv)) = conn.accept_uni() => {
-                connected.process_uni(&shared_client_state, recv);
+                connected.process_uni(&shared_client_state, Box::pin(recv), None);
             }
 
             Some(diff) = diff_stream.next() => {
@@ -7,18 +7,24 @@ use std::{
 
 use ambient_cb::Cb;
 use ambient_ecs::{
-    components, generated::app::components::name as app_name,
-    generated::wasm::components::module_enabled, query, ComponentRegistry, Entity, EntityId,
-    ExternalComponentAttributes, ExternalComponentDesc, Networked, PrimitiveComponentType,
-    Resource, SystemGroup, World,
+    components, dont_store,
+    generated::app::components::{description as app_description, name as app_name},
+    generated::input::components::{
+        input_action_bindings, input_action_id, input_action_package, input_axis_id,
+        input_axis_negative_bindings, input_axis_package, input_axis_positive_bindings,
+    },
+    generated::wasm::components::module_enabled,
+    query, ComponentRegistry, Entity, EntityId, ExternalComponentAttributes, ExternalComponentDesc,
+    NetworkPriority, Networked, PrimitiveComponentType, Resource, SystemGroup, World,
 };
 use ambient_native_std::asset_url::AbsAssetUrl;
 use ambient_network::ServerWorldExt;
-use ambient_package::PackageContent;
+use ambient_package::{Manifest, PackageContent};
 use ambient_package_semantic::{
     Item, ItemId, ItemSource, LocalOrRemote, Package, PrimitiveType, RetrievableFile, Semantic,
     TypeInner,
 };
+use ambient_shared_types::InputBinding;
 
 pub use ambient_ecs::generated::package::components::*;
 use thiserror::Error;
@@ -235,6 +241,7 @@ fn sync_semantic_to_world(
         let mut entity = Entity::new()
             .with(app_name(), format!("Package {}", manifest.package.name))
             .with(self::is_package(), ())
+            .with(dont_store(), ())
             .with(self::enabled(), enabled)
             .with(self::id(), package_id.clone())
             .with(self::name(), manifest.package.name.clone())
@@ -256,6 +263,8 @@ fn sync_semantic_to_world(
             .unwrap()
             .insert(package_id.clone(), entity);
 
+        spawn_input_actions(world, entity, manifest);
+
         if let Some(metadata) = &package.build_metadata {
             let asset_url = AbsAssetUrl(base_asset_url.clone());
 
@@ -290,6 +299,47 @@ fn sync_semantic_to_world(
         .expect("main package was not spawned; this is likely a logical error"))
 }
 
+/// Spawns an entity for each of the input actions and axes declared by the package, so that
+/// clients can resolve them against the player's bindings.
+fn spawn_input_actions(world: &mut World, package: EntityId, manifest: &Manifest) {
+    fn to_strings(bindings: &[InputBinding]) -> Vec<String> {
+        bindings.iter().map(|b| b.to_string()).collect()
+    }
+
+    for (id, action) in &manifest.actions {
+        let mut entity = Entity::new()
+            .with(
+                app_name(),
+                action.name.clone().unwrap_or_else(|| id.to_string()),
+            )
+            .with(dont_store(), ())
+            .with(input_action_id(), id.to_string())
+            .with(input_action_package(), package)
+            .with(input_action_bindings(), to_strings(&action.bindings));
+        if let Some(description) = &action.description {
+            entity.set(app_description(), description.clone());
+        }
+        entity.spawn(world);
+    }
+
+    for (id, axis) in &manifest.axes {
+        let mut entity = Entity::new()
+            .with(
+                app_name(),
+                axis.name.clone().unwrap_or_else(|| id.to_string()),
+            )
+            .with(dont_store(), ())
+            .with(input_axis_id(), id.to_string())
+            .with(input_axis_package(), package)
+            .with(input_axis_positive_bindings(), to_strings(&axis.positive))
+            .with(input_axis_negative_bindings(), to_strings(&axis.negative));
+        if let Some(description) = &axis.description {
+            entity.set(app_description(), description.clone());
+        }
+        entity.spawn(world);
+    }
+}
+
 pub async fn add_to_semantic_and_register_components(
     semantic: &mut Semantic,
     url: &AbsAssetUrl,
@@ -404,6 +454,12 @@ fn all_defined_components(semantic: &Semantic) -> anyhow::Result<Vec<ExternalCom
                     attributes: ExternalComponentAttributes::from_iter(
                         attributes.iter().map(|s| s.as_str()),
                     ),
+                    network_priority: component.network_priority.map(|network_priority| {
+                        NetworkPriority {
+                            priority: network_priority.priority,
+                            max_rate: network_priority.max_rate,
+                        }
+                    }),
                 });
             }
             Ok(())
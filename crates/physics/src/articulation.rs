@@ -0,0 +1,451 @@
+use std::collections::HashMap;
+
+use ambient_core::{
+    hierarchy::parent,
+    transform::{local_to_world, rotation, translation},
+};
+use ambient_ecs::{components, query, Entity, EntityId, SystemGroup, World};
+use ambient_model::animation_binder;
+use glam::{vec3, EulerRot, Mat4, Quat, Vec3};
+use itertools::Itertools;
+use physxx::{
+    articulation_reduced_coordinate::{PxArticulationJointRef, PxArticulationRef},
+    PxArticulationAxis, PxArticulationDriveType, PxArticulationJointBase, PxArticulationJointType,
+    PxArticulationLinkRef, PxArticulationMotion, PxCapsuleGeometry, PxRigidActor, PxRigidBody,
+    PxShape, PxSphereGeometry, PxTransform, PxUserData,
+};
+
+use crate::{
+    collider::set_collision_filter,
+    main_physics_scene,
+    physx::{articulation_link, articulation_reduce_coordinate, physics},
+    wood_physics_material, PxShapeUserData, ALL_COLLISION_GROUPS, DEFAULT_COLLISION_GROUP,
+};
+
+pub use ambient_ecs::generated::physics::components::*;
+
+/// The radius of a link's capsule collider if the bone has no `articulation_link_radius`.
+const DEFAULT_LINK_RADIUS: f32 = 0.05;
+
+#[derive(Debug, Clone)]
+pub struct ArticulationLink {
+    pub bone: EntityId,
+    pub link: PxArticulationLinkRef,
+    /// The bone of the parent link, or `None` for the root link.
+    pub parent: Option<EntityId>,
+    /// The rotation of the joint frame relative to the parent link, i.e. the rotation of the
+    /// bone relative to its parent when the articulation was built.
+    pub rest_rotation: Quat,
+    /// The stiffness and damping last set on the drives of the joint to the parent link.
+    pub drive: Option<(f32, f32)>,
+    /// The twist and swing angles last set as the targets of the drives.
+    pub drive_target: Option<Vec3>,
+}
+
+components!("physics", {
+    /// The links of the articulation on this entity, with parents before their children.
+    articulation_links: Vec<ArticulationLink>,
+});
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/articulation/server",
+        vec![
+            query((articulation_reduce_coordinate(), articulation_links()))
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (_, (articulation, links)) in q.collect_cloned(world, qs) {
+                        release_px_articulation(world, articulation, &links);
+                        // The bones may outlive the articulation entity
+                        for link in &links {
+                            world.remove_component(link.bone, articulation_link()).ok();
+                        }
+                    }
+                }),
+            query((articulation_bind_ids().changed(),)).to_system(|q, world, qs, _| {
+                for (id, _) in q.collect_cloned(world, qs) {
+                    release_articulation(world, id);
+                    world.remove_component(id, articulation_error()).ok();
+                }
+            }),
+            // The bones may not be available until the model has finished loading, so this is
+            // retried until the articulation can be built
+            query((articulation_bind_ids(), animation_binder()))
+                .excl(articulation_reduce_coordinate())
+                .excl(articulation_error())
+                .to_system(|q, world, qs, _| {
+                    for (id, (bind_ids, binder)) in q.collect_cloned(world, qs) {
+                        match build_articulation(world, id, &bind_ids, &binder) {
+                            Ok(Some((articulation, links))) => {
+                                for link in &links {
+                                    world
+                                        .add_component(link.bone, articulation_link(), link.link)
+                                        .unwrap();
+                                }
+                                world
+                                    .add_components(
+                                        id,
+                                        Entity::new()
+                                            .with(articulation_reduce_coordinate(), articulation)
+                                            .with(articulation_links(), links),
+                                    )
+                                    .unwrap();
+                            }
+                            Ok(None) => {}
+                            Err(err) => {
+                                tracing::warn!("Failed to build articulation {id}: {err}");
+                                world.add_component(id, articulation_error(), err).unwrap();
+                            }
+                        }
+                    }
+                }),
+            query((articulation_reduce_coordinate(), articulation_links())).to_system(
+                |q, world, qs, _| {
+                    for (id, (articulation, mut links)) in q.collect_cloned(world, qs) {
+                        if update_articulation(world, id, articulation, &mut links) {
+                            world.set(id, articulation_links(), links).unwrap();
+                        }
+                    }
+                },
+            ),
+        ],
+    )
+}
+
+/// Returns the closest ancestor of `bone` that is in `bones`.
+fn find_parent_bone(world: &World, bone: EntityId, bones: &[EntityId]) -> Option<EntityId> {
+    let mut id = bone;
+    loop {
+        id = world.get(id, parent()).ok()?;
+        if bones.contains(&id) {
+            return Some(id);
+        }
+    }
+}
+
+fn bone_pose(world: &World, bone: EntityId) -> Option<(Vec3, Quat)> {
+    let (_, rotation, translation) = world
+        .get(bone, local_to_world())
+        .ok()?
+        .to_scale_rotation_translation();
+    Some((translation, rotation))
+}
+
+/// Builds an articulation from the bones of `bind_ids`. Returns `None` if the bones are not
+/// available (yet), and an error if they can't make up an articulation.
+fn build_articulation(
+    world: &World,
+    id: EntityId,
+    bind_ids: &[String],
+    binder: &HashMap<String, EntityId>,
+) -> Result<Option<(PxArticulationRef, Vec<ArticulationLink>)>, String> {
+    let Some(bones) = bind_ids
+        .iter()
+        .map(|bind_id| binder.get(bind_id).copied())
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(None);
+    };
+    if bones.is_empty() {
+        return Ok(None);
+    }
+    let Some(poses) = bones
+        .iter()
+        .map(|bone| bone_pose(world, *bone))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(None);
+    };
+    let poses: HashMap<EntityId, (Vec3, Quat)> = bones.iter().copied().zip(poses).collect();
+
+    let parents = bones
+        .iter()
+        .map(|bone| (*bone, find_parent_bone(world, *bone, &bones)))
+        .collect::<HashMap<_, _>>();
+    let roots = bind_ids
+        .iter()
+        .zip(&bones)
+        .filter(|(_, bone)| parents[*bone].is_none())
+        .map(|(bind_id, _)| bind_id.as_str())
+        .collect_vec();
+    if roots.len() > 1 {
+        return Err(format!(
+            "Exactly one bone must have no ancestor in articulation_bind_ids, but {} do: {}",
+            roots.len(),
+            roots.join(", ")
+        ));
+    }
+    let depth = |mut bone: EntityId| {
+        let mut depth = 0;
+        while let Some(parent) = parents[&bone] {
+            bone = parent;
+            depth += 1;
+        }
+        depth
+    };
+    let ordered = bones.iter().copied().sorted_by_key(|bone| depth(*bone));
+
+    let physics = world.resource(physics());
+    let material = world.resource(wood_physics_material());
+    let group = world
+        .get(id, collision_group())
+        .unwrap_or(DEFAULT_COLLISION_GROUP);
+    let mask = world
+        .get(id, collision_mask())
+        .unwrap_or(ALL_COLLISION_GROUPS);
+    let density = world.get(id, density()).unwrap_or(1.);
+
+    let articulation = PxArticulationRef::new(&physics.physics);
+    let mut links: Vec<ArticulationLink> = Vec::new();
+    for bone in ordered {
+        let (position, rotation) = poses[&bone];
+        let parent = parents[&bone];
+        let parent_link =
+            parent.map(|parent| links.iter().find(|link| link.bone == parent).unwrap().link);
+        let link = PxArticulationLinkRef::new(
+            &articulation,
+            parent_link.as_ref(),
+            &PxTransform::new(position, rotation),
+        );
+
+        // The collider reaches from the bone to the average position of its child links
+        let radius = world
+            .get(bone, articulation_link_radius())
+            .unwrap_or(DEFAULT_LINK_RADIUS);
+        let children = bones
+            .iter()
+            .filter(|child| parents[*child] == Some(bone))
+            .map(|child| poses[child].0)
+            .collect_vec();
+        let extent = if children.is_empty() {
+            Vec3::ZERO
+        } else {
+            rotation.inverse() * (children.iter().sum::<Vec3>() / children.len() as f32 - position)
+        };
+        let shape = if extent.length() > radius * 2. {
+            let shape = PxShape::new(
+                physics.physics,
+                &PxCapsuleGeometry::new(radius, extent.length() / 2. - radius),
+                &[material],
+                Some(true),
+                None,
+            );
+            shape.set_local_pose(&PxTransform::new(
+                extent / 2.,
+                Quat::from_rotation_arc(Vec3::X, extent.normalize()),
+            ));
+            shape
+        } else {
+            PxShape::new(
+                physics.physics,
+                &PxSphereGeometry::new(radius),
+                &[material],
+                Some(true),
+                None,
+            )
+        };
+        shape.set_user_data(PxShapeUserData {
+            entity: bone,
+            density,
+            ..Default::default()
+        });
+        set_collision_filter(&shape, group, mask);
+        link.attach_shape(&shape);
+        link.update_mass_and_inertia(vec![density], None, None);
+
+        let mut rest_rotation = Quat::IDENTITY;
+        if let Some(parent) = parent {
+            let (parent_position, parent_rotation) = poses[&parent];
+            rest_rotation = parent_rotation.inverse() * rotation;
+
+            let mut joint = PxArticulationJointRef::from_inbound_joint(&link).unwrap();
+            joint.set_joint_type(PxArticulationJointType::Spherical);
+            joint.set_parent_pose(&PxTransform::new(
+                parent_rotation.inverse() * (position - parent_position),
+                rest_rotation,
+            ));
+            joint.set_child_pose(&PxTransform::identity());
+            match world.get(bone, articulation_twist_limit()) {
+                Ok(limit) => {
+                    joint.set_motion(PxArticulationAxis::Twist, PxArticulationMotion::Limited);
+                    joint.set_limit(PxArticulationAxis::Twist, limit.x, limit.y);
+                }
+                Err(_) => joint.set_motion(PxArticulationAxis::Twist, PxArticulationMotion::Free),
+            }
+            match world.get(bone, articulation_swing_limit()) {
+                Ok(limit) => {
+                    joint.set_motion(PxArticulationAxis::Swing1, PxArticulationMotion::Limited);
+                    joint.set_motion(PxArticulationAxis::Swing2, PxArticulationMotion::Limited);
+                    joint.set_limit(PxArticulationAxis::Swing1, -limit.x, limit.x);
+                    joint.set_limit(PxArticulationAxis::Swing2, -limit.y, limit.y);
+                }
+                Err(_) => {
+                    joint.set_motion(PxArticulationAxis::Swing1, PxArticulationMotion::Free);
+                    joint.set_motion(PxArticulationAxis::Swing2, PxArticulationMotion::Free);
+                }
+            }
+        }
+
+        links.push(ArticulationLink {
+            bone,
+            link,
+            parent,
+            rest_rotation,
+            drive: None,
+            drive_target: None,
+        });
+    }
+    world
+        .resource(main_physics_scene())
+        .add_articulation(&articulation);
+
+    Ok(Some((articulation, links)))
+}
+
+fn release_px_articulation(
+    world: &World,
+    mut articulation: PxArticulationRef,
+    links: &[ArticulationLink],
+) {
+    for link in links {
+        for shape in link.link.get_shapes() {
+            shape.remove_user_data::<PxShapeUserData>();
+        }
+    }
+    world
+        .resource(main_physics_scene())
+        .remove_articulation(&articulation, true);
+    articulation.release();
+}
+
+fn release_articulation(world: &mut World, id: EntityId) {
+    let (Ok(articulation), Ok(links)) = (
+        world.get(id, articulation_reduce_coordinate()),
+        world.get_ref(id, articulation_links()).cloned(),
+    ) else {
+        return;
+    };
+    release_px_articulation(world, articulation, &links);
+    for link in &links {
+        world.remove_component(link.bone, articulation_link()).ok();
+    }
+    world.remove_component(id, articulation_links()).ok();
+    world
+        .remove_component(id, articulation_reduce_coordinate())
+        .ok();
+}
+
+/// Drives the articulation towards the animated pose, and blends the simulated pose into
+/// the bones according to `articulation_blend`. Returns true if the drives of `links` changed.
+fn update_articulation(
+    world: &mut World,
+    id: EntityId,
+    articulation: PxArticulationRef,
+    links: &mut [ArticulationLink],
+) -> bool {
+    let blend = world
+        .get(id, articulation_blend())
+        .unwrap_or_default()
+        .clamp(0., 1.);
+
+    let animated = links
+        .iter()
+        .filter_map(|link| Some((link.bone, bone_pose(world, link.bone)?)))
+        .collect::<HashMap<_, _>>();
+    if blend <= 0. {
+        // While the bones are fully animated, keep the articulation where the animation is
+        // so that it's in place once it starts being simulated
+        if let Some((position, rotation)) = animated.get(&links[0].bone) {
+            articulation.teleport_root_link(&PxTransform::new(*position, *rotation), true);
+        }
+    }
+
+    // Only the drives that changed are set, rather than every drive on every frame
+    let mut drives_changed = false;
+    for link in links.iter_mut() {
+        let Some(parent) = link.parent else {
+            continue;
+        };
+        let stiffness = world
+            .get(link.bone, articulation_drive_stiffness())
+            .or_else(|_| world.get(id, articulation_drive_stiffness()))
+            .ok();
+        let damping = world
+            .get(link.bone, articulation_drive_damping())
+            .or_else(|_| world.get(id, articulation_drive_damping()))
+            .unwrap_or_default();
+        let mut joint = PxArticulationJointRef::from_inbound_joint(&link.link).unwrap();
+        let axes = [
+            PxArticulationAxis::Twist,
+            PxArticulationAxis::Swing1,
+            PxArticulationAxis::Swing2,
+        ];
+        let drive = (stiffness.unwrap_or_default(), damping);
+        if link.drive != Some(drive) {
+            for axis in axes {
+                joint.set_drive(
+                    axis,
+                    drive.0,
+                    drive.1,
+                    f32::MAX,
+                    PxArticulationDriveType::Acceleration,
+                );
+            }
+            link.drive = Some(drive);
+            drives_changed = true;
+        }
+        if let (Some(_), Some((_, rotation)), Some((_, parent_rotation))) =
+            (stiffness, animated.get(&link.bone), animated.get(&parent))
+        {
+            let target = link.rest_rotation.inverse() * parent_rotation.inverse() * *rotation;
+            let (twist, swing1, swing2) = target.to_euler(EulerRot::XYZ);
+            let target = vec3(twist, swing1, swing2);
+            if link.drive_target != Some(target) {
+                for (axis, angle) in axes.into_iter().zip(target.to_array()) {
+                    joint.set_drive_target(axis, angle);
+                }
+                link.drive_target = Some(target);
+                drives_changed = true;
+            }
+        }
+    }
+
+    if blend <= 0. {
+        return drives_changed;
+    }
+    let mut blended: HashMap<EntityId, Mat4> = HashMap::new();
+    for link in links {
+        let Ok(animated) = world.get(link.bone, local_to_world()) else {
+            continue;
+        };
+        let (scale, animated_rotation, animated_position) =
+            animated.to_scale_rotation_translation();
+        let pose = link.link.get_global_pose();
+        let transform = Mat4::from_scale_rotation_translation(
+            scale,
+            animated_rotation.slerp(pose.rotation(), blend),
+            animated_position.lerp(pose.translation(), blend),
+        );
+        blended.insert(link.bone, transform);
+
+        // Bones between links keep their animated pose
+        let parent_transform = world.get(link.bone, parent()).ok().and_then(|parent| {
+            blended
+                .get(&parent)
+                .copied()
+                .or_else(|| world.get(parent, local_to_world()).ok())
+        });
+        let local = match parent_transform {
+            Some(parent_transform) => parent_transform.inverse() * transform,
+            None => transform,
+        };
+        let (_, local_rotation, local_position) = local.to_scale_rotation_translation();
+        world
+            .set_if_changed(link.bone, translation(), local_position)
+            .ok();
+        world
+            .set_if_changed(link.bone, rotation(), local_rotation)
+            .ok();
+    }
+    drives_changed
+}
@@ -13,19 +13,20 @@ use ambient_ecs::{
 use ambient_model::model_from_url;
 use ambient_native_std::{
     asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKeyExt},
-    asset_url::{AbsAssetUrl, ColliderAssetType, TypedAssetUrl},
-    download_asset::{AssetError, JsonFromUrl},
+    asset_url::{AbsAssetUrl, ColliderAssetType, ImageAssetType, TypedAssetUrl},
+    download_asset::{AssetError, BytesFromUrl, JsonFromUrl},
     events::EventDispatcher,
 };
 use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::try_join_all;
-use glam::{vec3, Mat4, Quat, Vec3};
+use glam::{uvec2, vec3, Mat3, Mat4, Quat, UVec2, Vec3};
 use itertools::Itertools;
 use physxx::{
     AsPxActor, AsPxRigidActor, PxActor, PxActorFlag, PxBase, PxBoxGeometry, PxControllerDesc,
-    PxControllerShapeDesc, PxConvexMeshGeometry, PxGeometry, PxMaterial, PxMeshScale,
-    PxPlaneGeometry, PxRigidActor, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef,
+    PxControllerFilters, PxControllerShapeDesc, PxConvexMeshGeometry, PxFilterData, PxGeometry,
+    PxHeightFieldDesc, PxHeightFieldGeometry, PxMaterial, PxMeshScale, PxPlaneGeometry,
+    PxQuantizedHeightFieldSamples, PxRigidActor, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef,
     PxRigidStaticRef, PxShape, PxShapeFlag, PxSphereGeometry, PxTransform, PxTriangleMeshGeometry,
     PxUserData,
 };
@@ -35,7 +36,9 @@ use crate::{
     main_controller_manager,
     mesh::{PhysxGeometry, PhysxGeometryFromUrl},
     physx::{character_controller, physics, physics_shape, rigid_actor, Physics},
+    snapshot::restored_from_snapshot,
     wood_physics_material, ColliderScene, PxActorUserData, PxShapeUserData, PxWoodMaterialKey,
+    ALL_COLLISION_GROUPS, COLLISION_FILTER_ASSIGNED, DEFAULT_COLLISION_GROUP,
 };
 
 pub use ambient_ecs::generated::physics::components::*;
@@ -141,6 +144,67 @@ pub fn server_systems() -> SystemGroup {
                         .unwrap();
                 }
             }),
+            query(heightfield_collider().changed())
+                .optional_changed(heightfield_collider_size())
+                .optional_changed(heightfield_collider_cell_size())
+                .optional_changed(heightfield_collider_height_scale())
+                .to_system(|q, world, qs, _| {
+                    for (id, heights) in changed_or_missing(q, world, qs, collider()) {
+                        let Ok(size) = world.get(id, heightfield_collider_size()) else {
+                            tracing::warn!(
+                                "Heightfield collider {id} has no heightfield_collider_size"
+                            );
+                            continue;
+                        };
+                        world
+                            .add_component(
+                                id,
+                                collider(),
+                                ColliderDef::HeightField {
+                                    size,
+                                    heights,
+                                    cell_size: world
+                                        .get(id, heightfield_collider_cell_size())
+                                        .unwrap_or(1.),
+                                    height_scale: world
+                                        .get(id, heightfield_collider_height_scale())
+                                        .unwrap_or(1.),
+                                },
+                            )
+                            .unwrap();
+                    }
+                }),
+            query(heightfield_collider_from_url().changed())
+                .optional_changed(heightfield_collider_cell_size())
+                .optional_changed(heightfield_collider_height_scale())
+                .to_system(|q, world, qs, _| {
+                    for (id, url) in changed_or_missing(q, world, qs, collider()) {
+                        match TypedAssetUrl::from_str(&url) {
+                            Ok(heightmap) => world
+                                .add_component(
+                                    id,
+                                    collider(),
+                                    ColliderDef::HeightFieldImage {
+                                        heightmap,
+                                        cell_size: world
+                                            .get(id, heightfield_collider_cell_size())
+                                            .unwrap_or(1.),
+                                        height_scale: world
+                                            .get(id, heightfield_collider_height_scale())
+                                            .unwrap_or(1.),
+                                    },
+                                )
+                                .unwrap(),
+                            Err(err) => {
+                                tracing::warn!(
+                                    "Failed to load heightfield collider from {}: {:?}",
+                                    url,
+                                    err
+                                )
+                            }
+                        }
+                    }
+                }),
             query(collider_from_url().changed()).to_system(|q, world, qs, _| {
                 for (id, url) in changed_or_missing(q, world, qs, collider()) {
                     tracing::debug!("Loading collider: {url:#?}");
@@ -218,6 +282,15 @@ pub fn server_systems() -> SystemGroup {
                             density: 1.,
                             ..Default::default()
                         });
+                        set_collision_filter(
+                            &actor.get_shapes()[0],
+                            world
+                                .get(id, collision_group())
+                                .unwrap_or(DEFAULT_COLLISION_GROUP),
+                            world
+                                .get(id, collision_mask())
+                                .unwrap_or(ALL_COLLISION_GROUPS),
+                        );
                         world
                             .add_components(
                                 id,
@@ -308,6 +381,7 @@ pub fn server_systems() -> SystemGroup {
             ))
             .optional_changed(collider_type())
             .optional_changed(kinematic())
+            .optional_changed(is_trigger())
             .to_system(|q, world, qs, _| {
                 let physics = world.resource(physics()).clone();
                 let force_static = world
@@ -367,7 +441,17 @@ pub fn server_systems() -> SystemGroup {
                         };
                         let coff = world.get(id, contact_offset()).ok();
                         let roff = world.get(id, rest_offset()).ok();
+                        let is_trigger = world.has_component(id, is_trigger());
                         for shape in shapes.iter_mut() {
+                            // A shape can't be both a simulation and a trigger shape, so the flag
+                            // being turned off has to be cleared first
+                            if is_trigger {
+                                shape.set_flag(PxShapeFlag::SIMULATION_SHAPE, false);
+                                shape.set_flag(PxShapeFlag::TRIGGER_SHAPE, true);
+                            } else {
+                                shape.set_flag(PxShapeFlag::TRIGGER_SHAPE, false);
+                                shape.set_flag(PxShapeFlag::SIMULATION_SHAPE, true);
+                            }
                             if !actor.attach_shape(shape) {
                                 tracing::error!("Failed to attach shape to entity {}", id);
                                 actor.as_actor().remove_user_data::<PxActorUserData>();
@@ -428,13 +512,96 @@ pub fn server_systems() -> SystemGroup {
                         }
                     };
                 for (id, (convex, concave)) in q.collect_cloned(world, qs) {
+                    // The actor already matches the entity's components
+                    if world.has_component(id, restored_from_snapshot()) {
+                        world
+                            .remove_component(id, restored_from_snapshot())
+                            .unwrap();
+                        continue;
+                    }
                     build_actor(world, id, convex, concave);
                 }
             }),
+            // `optional_changed` doesn't see the component being removed, so the shapes have to be
+            // turned back into simulation shapes here
+            query(())
+                .incl(is_trigger())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for id in q.collect_ids(world, qs) {
+                        let Ok(shapes) = world.get_ref(id, collider_shapes()) else {
+                            continue;
+                        };
+                        for shape in shapes {
+                            shape.set_flag(PxShapeFlag::TRIGGER_SHAPE, false);
+                            shape.set_flag(PxShapeFlag::SIMULATION_SHAPE, true);
+                        }
+                    }
+                }),
+            query((collider_shapes().changed(),))
+                .optional_changed(collision_group())
+                .optional_changed(collision_mask())
+                .to_system(|q, world, qs, _| {
+                    for (id, (shapes,)) in q.iter(world, qs) {
+                        let group = world
+                            .get(id, collision_group())
+                            .unwrap_or(DEFAULT_COLLISION_GROUP);
+                        let mask = world
+                            .get(id, collision_mask())
+                            .unwrap_or(ALL_COLLISION_GROUPS);
+                        let convex = world.get_ref(id, collider_shapes_convex()).ok();
+                        for shape in shapes.iter().chain(convex.into_iter().flatten()) {
+                            set_collision_filter(shape, group, mask);
+                        }
+                    }
+                }),
+            // Shapes created outside of the collider systems, e.g. terrain, still need filter data
+            // for collision masks in scene queries to be able to hit them
+            query((physics_shape().changed(),))
+                .excl(collider_shapes())
+                .optional_changed(collision_group())
+                .optional_changed(collision_mask())
+                .to_system(|q, world, qs, _| {
+                    for (id, (shape,)) in q.iter(world, qs) {
+                        set_collision_filter(
+                            shape,
+                            world
+                                .get(id, collision_group())
+                                .unwrap_or(DEFAULT_COLLISION_GROUP),
+                            world
+                                .get(id, collision_mask())
+                                .unwrap_or(ALL_COLLISION_GROUPS),
+                        );
+                    }
+                }),
         ],
     )
 }
 
+/// Assigns the collision `group` and `mask` used for simulation and scene query filtering to `shape`.
+pub fn set_collision_filter(shape: &PxShape, group: u32, mask: u32) {
+    shape.set_simulation_filter_data(&PxFilterData::new(
+        group,
+        mask,
+        COLLISION_FILTER_ASSIGNED,
+        0,
+    ));
+    shape.set_query_filter_data(&PxFilterData::new(group, 0, 0, 0));
+}
+
+/// Returns the filters that make the character controller of `id` only collide with colliders in
+/// the groups of its `collision_mask`.
+pub fn character_controller_filters(world: &World, id: EntityId) -> PxControllerFilters {
+    // Without filter data, the controller filters by the simulation filter data of its own shape,
+    // which would match its group rather than its mask. Filter data that is all zero doesn't
+    // filter at all.
+    let mask = match world.get(id, collision_mask()) {
+        Ok(ALL_COLLISION_GROUPS) | Err(_) => 0,
+        Ok(mask) => mask,
+    };
+    PxControllerFilters::with_filter_data(PxFilterData::new(mask, 0, 0, 0))
+}
+
 fn one_value() -> f32 {
     1.
 }
@@ -460,6 +627,25 @@ pub enum ColliderDef {
         center: Vec3,
     },
     Plane,
+    /// A heightfield with `size.x` by `size.y` samples, `cell_size` apart along the X and Y axes.
+    /// The height at `(x, y)` is `heights[y * size.x + x]`.
+    HeightField {
+        size: UVec2,
+        heights: Vec<f32>,
+        #[serde(default = "one_value")]
+        cell_size: f32,
+        #[serde(default = "one_value")]
+        height_scale: f32,
+    },
+    /// A heightfield with a sample per pixel of the heightmap image, with the brightness of the
+    /// pixel (from `0` to `1`) as the height. The image is laid out as seen from above.
+    HeightFieldImage {
+        heightmap: TypedAssetUrl<ImageAssetType>,
+        #[serde(default = "one_value")]
+        cell_size: f32,
+        #[serde(default = "one_value")]
+        height_scale: f32,
+    },
 }
 
 type ColliderSpawner = Box<dyn Fn(&Physics, Vec3) -> (Vec<PxShape>, Vec<PxShape>) + Sync + Send>;
@@ -473,6 +659,12 @@ impl ColliderDef {
                     .context("Failed to resolve")?
                     .into();
             }
+            ColliderDef::HeightFieldImage { heightmap, .. } => {
+                *heightmap = heightmap
+                    .resolve(base_url)
+                    .context("Failed to resolve")?
+                    .into();
+            }
             _ => {}
         }
         Ok(())
@@ -547,6 +739,39 @@ impl ColliderDef {
                 });
                 (vec![shape.clone()], vec![shape])
             })),
+            ColliderDef::HeightField {
+                size,
+                heights,
+                cell_size,
+                height_scale,
+            } => heightfield_spawner(size, &heights, cell_size, height_scale, material, density),
+            ColliderDef::HeightFieldImage {
+                heightmap,
+                cell_size,
+                height_scale,
+            } => {
+                let bytes = BytesFromUrl::new(heightmap.unwrap_abs(), true)
+                    .get(&assets)
+                    .await?;
+                let image = image::load_from_memory(&bytes)
+                    .context("Failed to decode heightmap")?
+                    .into_luma16();
+                let (width, height) = image.dimensions();
+                // Image rows go downwards, while heightfield rows go along the Y axis
+                let heights = (0..height)
+                    .rev()
+                    .flat_map(|y| (0..width).map(move |x| (x, y)))
+                    .map(|(x, y)| image.get_pixel(x, y).0[0] as f32 / u16::MAX as f32)
+                    .collect_vec();
+                heightfield_spawner(
+                    uvec2(width, height),
+                    &heights,
+                    cell_size,
+                    height_scale,
+                    material,
+                    density,
+                )
+            }
             ColliderDef::Asset { collider } => {
                 let collider = collider.unwrap_abs();
                 let collider_from_urls: Arc<ColliderFromUrls> =
@@ -566,6 +791,53 @@ impl ColliderDef {
     }
 }
 
+fn heightfield_spawner(
+    size: UVec2,
+    heights: &[f32],
+    cell_size: f32,
+    height_scale: f32,
+    material: PxMaterial,
+    density: f32,
+) -> Result<ColliderSpawner, AssetError> {
+    if size.x < 2 || size.y < 2 {
+        return Err(anyhow::anyhow!(
+            "Heightfield collider needs at least 2x2 samples. size={}",
+            size
+        )
+        .into());
+    }
+    if heights.len() != (size.x * size.y) as usize {
+        return Err(anyhow::anyhow!(
+            "Heightfield collider has {} heights, but its size is {}",
+            heights.len(),
+            size
+        )
+        .into());
+    }
+    let samples = Arc::new(PxQuantizedHeightFieldSamples::new_from_f32_array(heights));
+    // PhysX heightfields have their rows along X, columns along Z and heights along Y, so rotate
+    // them to have the rows along Y, columns along X and heights along Z
+    let rotation = Quat::from_mat3(&Mat3::from_cols(Vec3::Y, Vec3::Z, Vec3::X));
+    let offset = vec3(0., 0., samples.min_height * height_scale);
+    Ok(Box::new(move |physics, scale| {
+        let desc = PxHeightFieldDesc::new(size.y, size.x, &samples.samples);
+        let geometry = PxHeightFieldGeometry::new(
+            &mut physics.cooking.create_height_field(&physics.physics, &desc),
+            samples.height_scale * height_scale * scale.z,
+            cell_size * scale.y,
+            cell_size * scale.x,
+        );
+        let shape = PxShape::new(physics.physics, &geometry, &[&material], Some(true), None);
+        shape.set_local_pose(&PxTransform::new(offset * scale, rotation));
+        shape.set_user_data(PxShapeUserData {
+            entity: EntityId::null(),
+            density,
+            base_pose: Mat4::from_rotation_translation(rotation, offset),
+        });
+        (vec![shape.clone()], vec![shape])
+    }))
+}
+
 impl Default for ColliderDef {
     fn default() -> Self {
         Self::Sphere {
@@ -10,14 +10,15 @@ use glam::{vec3, Mat4, Vec3};
 use itertools::Itertools;
 use physxx::{
     AsPxActor, AsPxRigidActor, PxActor, PxActorRef, PxActorTypeFlag, PxBase, PxBoxGeometry,
-    PxConvexMeshGeometry, PxForceMode, PxJoint, PxMeshScale, PxOverlapCallback, PxPhysicsRef,
-    PxQueryFilterData, PxQueryFlag, PxRevoluteJointRef, PxRigidActor, PxRigidActorRef, PxRigidBody,
-    PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxSceneRef, PxShape, PxSphereGeometry,
-    PxTransform, PxTriangleMeshGeometry, PxUserData,
+    PxConvexMeshGeometry, PxForceMode, PxJoint, PxJointRef, PxMeshScale, PxOverlapCallback,
+    PxPhysicsRef, PxQueryFilterData, PxQueryFlag, PxRevoluteJointRef, PxRigidActor,
+    PxRigidActorRef, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxSceneRef,
+    PxShape, PxSphereGeometry, PxTransform, PxTriangleMeshGeometry, PxUserData,
 };
 
 use crate::{
     collider::{collider_shapes_convex, collider_type, kinematic},
+    joints::physics_joint,
     main_physics_scene,
     physx::{
         physics, physics_controlled, physics_shape, revolute_joint, rigid_actor, rigid_dynamic,
@@ -377,13 +378,7 @@ pub fn weld_two(world: &mut World, first: EntityId, second: EntityId) {
                 }
                 joint.set_local_pose(0, &PxTransform::new(translation, rotation));
             } else {
-                let entity = joint.get_user_data::<EntityId>().unwrap();
-                if joint.to_revolute_joint().is_some() {
-                    world.remove_component(entity, revolute_joint()).ok();
-                } else {
-                    unimplemented!()
-                }
-                joint.release();
+                release_welded_joint(world, joint);
             }
         } else if can_have_joint(&a0) {
             joint.set_actors(a0, Some(first_actor));
@@ -395,13 +390,7 @@ pub fn weld_two(world: &mut World, first: EntityId, second: EntityId) {
             }
             joint.set_local_pose(1, &PxTransform::new(translation, rotation));
         } else {
-            let entity = joint.get_user_data::<EntityId>().unwrap();
-            if joint.to_revolute_joint().is_some() {
-                world.remove_component(entity, revolute_joint()).ok();
-            } else {
-                unimplemented!()
-            }
-            joint.release();
+            release_welded_joint(world, joint);
         }
     }
     second_actor
@@ -430,6 +419,14 @@ pub fn weld_two(world: &mut World, first: EntityId, second: EntityId) {
     update_physics_controlled(world, first_actor);
 }
 
+fn release_welded_joint(world: &mut World, joint: PxJointRef) {
+    if let Some(entity) = joint.remove_user_data::<EntityId>() {
+        world.remove_component(entity, revolute_joint()).ok();
+        world.remove_component(entity, physics_joint()).ok();
+    }
+    joint.release();
+}
+
 pub fn unweld_multi(world: &World, selected: Vec<EntityId>) {
     let mut selected = selected
         .into_iter()
@@ -5,16 +5,20 @@ use ambient_ecs::{query, ArchetypeFilter, EntityId, World};
 use ambient_meshes::cuboid::CuboidMesh;
 use ambient_native_std::{asset_cache::SyncAssetKeyExt, mesh::Mesh, shapes::Ray};
 use ambient_network::server;
-use glam::Vec3;
+use glam::{Quat, Vec3};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use physxx::{
-    PxConvexFlag, PxConvexMesh, PxConvexMeshDesc, PxConvexMeshGeometry, PxOverlapCallback,
-    PxQueryFilterData, PxRaycastCallback, PxRigidActor, PxShape, PxTransform, PxUserData,
+    sweep::PxSweepHit, PxBoxGeometry, PxCapsuleGeometry, PxConvexFlag, PxConvexMesh,
+    PxConvexMeshDesc, PxConvexMeshGeometry, PxFilterData, PxGeometry, PxOverlapCallback,
+    PxQueryFilterData, PxRaycastCallback, PxRigidActor, PxShape, PxSphereGeometry, PxTransform,
+    PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{main_physics_scene, physx::PhysicsKey, ColliderScene, PxShapeUserData};
+use crate::{
+    main_physics_scene, physx::PhysicsKey, ColliderScene, PxShapeUserData, ALL_COLLISION_GROUPS,
+};
 
 pub fn get_entities_in_radius(world: &World, center: Vec3, radius: f32) -> Vec<EntityId> {
     query((translation(),))
@@ -29,17 +33,29 @@ pub fn get_entities_in_radius(world: &World, center: Vec3, radius: f32) -> Vec<E
         .collect_vec()
 }
 
-pub fn raycast_first(world: &World, ray: Ray) -> Option<(EntityId, f32)> {
-    raycast_first_px(world, ray).and_then(|(shape, dist)| {
+/// Returns the filter data for a scene query that only hits colliders in the groups of `collision_mask`.
+pub fn query_filter_data(collision_mask: u32) -> PxQueryFilterData {
+    let mut filter_data = PxQueryFilterData::new();
+    // Shapes that were never assigned a group have no query filter data, so only filter when needed
+    if collision_mask != ALL_COLLISION_GROUPS {
+        filter_data.set_data(PxFilterData::new(collision_mask, 0, 0, 0));
+    }
+    filter_data
+}
+
+pub fn raycast_first(world: &World, ray: Ray, collision_mask: u32) -> Option<(EntityId, f32)> {
+    raycast_first_px(world, ray, collision_mask).and_then(|(shape, dist)| {
         shape
             .get_user_data::<PxShapeUserData>()
             .map(|ud| (ud.entity, dist))
     })
 }
 
-fn raycast_first_px(world: &World, ray: Ray) -> Option<(PxShape, f32)> {
+fn raycast_first_px(world: &World, ray: Ray, collision_mask: u32) -> Option<(PxShape, f32)> {
     (0..3)
-        .filter_map(|i| raycast_first_collider_type_px(world, ColliderScene::from_usize(i), ray))
+        .filter_map(|i| {
+            raycast_first_collider_type_px(world, ColliderScene::from_usize(i), ray, collision_mask)
+        })
         .sorted_by_key(|x| OrderedFloat(x.1))
         .next()
 }
@@ -48,21 +64,25 @@ pub fn raycast_first_collider_type(
     world: &World,
     collider_type: ColliderScene,
     ray: Ray,
+    collision_mask: u32,
 ) -> Option<(EntityId, f32)> {
-    raycast_first_collider_type_px(world, collider_type, ray).and_then(|(shape, dist)| {
-        shape
-            .get_user_data::<PxShapeUserData>()
-            .map(|ud| (ud.entity, dist))
-    })
+    raycast_first_collider_type_px(world, collider_type, ray, collision_mask).and_then(
+        |(shape, dist)| {
+            shape
+                .get_user_data::<PxShapeUserData>()
+                .map(|ud| (ud.entity, dist))
+        },
+    )
 }
 pub fn raycast_first_collider_type_px(
     world: &World,
     collider_type: ColliderScene,
     ray: Ray,
+    collision_mask: u32,
 ) -> Option<(PxShape, f32)> {
     let mut hit = PxRaycastCallback::new(0);
     let scene = collider_type.get_scene(world);
-    let filter_data = PxQueryFilterData::new();
+    let filter_data = query_filter_data(collision_mask);
     if scene.raycast(ray.origin, ray.dir, f32::MAX, &mut hit, None, &filter_data) {
         let block = hit.block().unwrap();
         if let Some(shape) = block.shape {
@@ -72,8 +92,8 @@ pub fn raycast_first_collider_type_px(
     None
 }
 
-pub fn raycast(world: &World, ray: Ray) -> Vec<(EntityId, f32)> {
-    raycast_px(world, ray)
+pub fn raycast(world: &World, ray: Ray, collision_mask: u32) -> Vec<(EntityId, f32)> {
+    raycast_px(world, ray, collision_mask)
         .into_iter()
         .flat_map(|(shape, dist)| {
             shape
@@ -83,10 +103,11 @@ pub fn raycast(world: &World, ray: Ray) -> Vec<(EntityId, f32)> {
         .collect_vec()
 }
 
-fn raycast_px(world: &World, ray: Ray) -> Vec<(PxShape, f32)> {
+fn raycast_px(world: &World, ray: Ray, collision_mask: u32) -> Vec<(PxShape, f32)> {
     (0..3)
         .flat_map(|i| {
-            raycast_collider_type_px(world, ColliderScene::from_usize(i), ray).into_iter()
+            raycast_collider_type_px(world, ColliderScene::from_usize(i), ray, collision_mask)
+                .into_iter()
         })
         .sorted_by_key(|x| OrderedFloat(x.1))
         .collect_vec()
@@ -96,8 +117,9 @@ pub fn raycast_collider_type(
     world: &World,
     collider_type: ColliderScene,
     ray: Ray,
+    collision_mask: u32,
 ) -> Vec<(EntityId, f32)> {
-    raycast_collider_type_px(world, collider_type, ray)
+    raycast_collider_type_px(world, collider_type, ray, collision_mask)
         .into_iter()
         .filter_map(|(shape, dist)| {
             shape
@@ -110,10 +132,11 @@ pub fn raycast_collider_type_px(
     world: &World,
     collider_type: ColliderScene,
     ray: Ray,
+    collision_mask: u32,
 ) -> Vec<(PxShape, f32)> {
     let mut hit = PxRaycastCallback::new(100);
     let scene = collider_type.get_scene(world);
-    let filter_data = PxQueryFilterData::new();
+    let filter_data = query_filter_data(collision_mask);
     if scene.raycast(ray.origin, ray.dir, f32::MAX, &mut hit, None, &filter_data) {
         return hit
             .touches()
@@ -124,6 +147,202 @@ pub fn raycast_collider_type_px(
     Vec::new()
 }
 
+/// A volume used for [sweep] and [overlap] queries.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum QueryShape {
+    Sphere {
+        radius: f32,
+    },
+    Cuboid {
+        half_extents: Vec3,
+    },
+    /// A capsule extending along the local Z axis. `half_height` is the distance from the center
+    /// to the center of each of the hemispheres.
+    Capsule {
+        radius: f32,
+        half_height: f32,
+    },
+}
+impl QueryShape {
+    fn geometry(&self) -> Box<dyn PxGeometry> {
+        match *self {
+            QueryShape::Sphere { radius } => Box::new(PxSphereGeometry::new(radius)),
+            QueryShape::Cuboid { half_extents } => Box::new(PxBoxGeometry::new(
+                half_extents.x,
+                half_extents.y,
+                half_extents.z,
+            )),
+            QueryShape::Capsule {
+                radius,
+                half_height,
+            } => Box::new(PxCapsuleGeometry::new(radius, half_height)),
+        }
+    }
+    fn pose(&self, position: Vec3, rotation: Quat) -> PxTransform {
+        match self {
+            // PhysX capsules extend along the X axis, so we rotate them to be Z-up like the rest of the engine
+            QueryShape::Capsule { .. } => PxTransform::new(
+                position,
+                rotation * Quat::from_rotation_y(-std::f32::consts::FRAC_PI_2),
+            ),
+            _ => PxTransform::new(position, rotation),
+        }
+    }
+}
+
+/// Where a [sweep] hit.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShapeCastHit {
+    pub entity: EntityId,
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub distance: f32,
+}
+impl ShapeCastHit {
+    fn from_px(hit: PxSweepHit) -> Option<Self> {
+        let entity = hit.shape?.get_user_data::<PxShapeUserData>()?.entity;
+        Some(Self {
+            entity,
+            position: hit.position,
+            normal: hit.normal,
+            distance: hit.distance,
+        })
+    }
+}
+
+/// Sweeps `shape` from `position` along `direction` for up to `max_distance`, and returns all hits
+/// in all collider scenes, sorted by distance.
+///
+/// `direction` must be normalized.
+pub fn sweep(
+    world: &World,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    collision_mask: u32,
+) -> Vec<ShapeCastHit> {
+    (0..3)
+        .flat_map(|i| {
+            sweep_collider_type(
+                world,
+                ColliderScene::from_usize(i),
+                shape,
+                position,
+                rotation,
+                direction,
+                max_distance,
+                collision_mask,
+            )
+        })
+        .sorted_by_key(|hit| OrderedFloat(hit.distance))
+        .collect_vec()
+}
+
+/// Like [sweep], but only returns the closest hit.
+pub fn sweep_first(
+    world: &World,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    collision_mask: u32,
+) -> Option<ShapeCastHit> {
+    sweep(
+        world,
+        shape,
+        position,
+        rotation,
+        direction,
+        max_distance,
+        collision_mask,
+    )
+    .into_iter()
+    .next()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn sweep_collider_type(
+    world: &World,
+    collider_type: ColliderScene,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    collision_mask: u32,
+) -> Vec<ShapeCastHit> {
+    let scene = collider_type.get_scene(world);
+    let hit = scene.sweep(
+        shape.geometry().as_ref(),
+        &shape.pose(position, rotation),
+        direction,
+        max_distance,
+        query_filter_data(collision_mask),
+    );
+    hit.block()
+        .into_iter()
+        .chain(hit.touches())
+        .filter_map(ShapeCastHit::from_px)
+        .sorted_by_key(|hit| OrderedFloat(hit.distance))
+        .collect_vec()
+}
+
+/// Returns all entities whose colliders overlap `shape` placed at `position` with `rotation`, in all collider scenes.
+pub fn overlap(
+    world: &World,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    collision_mask: u32,
+) -> Vec<EntityId> {
+    (0..3)
+        .flat_map(|i| {
+            overlap_collider_type(
+                world,
+                ColliderScene::from_usize(i),
+                shape,
+                position,
+                rotation,
+                collision_mask,
+            )
+        })
+        .unique()
+        .collect_vec()
+}
+
+pub fn overlap_collider_type(
+    world: &World,
+    collider_type: ColliderScene,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    collision_mask: u32,
+) -> Vec<EntityId> {
+    let mut hit_call = PxOverlapCallback::new(1000);
+    let filter_data = query_filter_data(collision_mask);
+    let scene = collider_type.get_scene(world);
+    if scene.overlap(
+        shape.geometry().as_ref(),
+        shape.pose(position, rotation),
+        &mut hit_call,
+        &filter_data,
+    ) {
+        hit_call
+            .block()
+            .into_iter()
+            .chain(hit_call.touches())
+            .filter_map(|hit| hit.shape.get_user_data::<PxShapeUserData>())
+            .map(|ud| ud.entity)
+            .unique()
+            .collect_vec()
+    } else {
+        Vec::new()
+    }
+}
+
 pub fn intersect_frustum(world: &World, frustum_corners: &[Vec3; 8]) -> Vec<EntityId> {
     let mut hit_call = PxOverlapCallback::new(1000);
     let filter_data = PxQueryFilterData::new();
@@ -174,10 +393,11 @@ pub async fn rpc_pick(
 }
 
 pub fn raycast_filtered(world: &World, filter: RaycastFilter, ray: Ray) -> Option<(EntityId, f32)> {
+    let collision_mask = filter.collision_mask.unwrap_or(ALL_COLLISION_GROUPS);
     let hits = if let Some(collider_type) = filter.collider_type {
-        raycast_collider_type(world, collider_type, ray)
+        raycast_collider_type(world, collider_type, ray, collision_mask)
     } else {
-        raycast(world, ray)
+        raycast(world, ray, collision_mask)
     };
     if let Some(filter) = &filter.entities {
         hits.into_iter()
@@ -191,4 +411,6 @@ pub fn raycast_filtered(world: &World, filter: RaycastFilter, ray: Ray) -> Optio
 pub struct RaycastFilter {
     pub entities: Option<ArchetypeFilter>,
     pub collider_type: Option<ColliderScene>,
+    /// Only colliders in at least one of these collision groups will be hit.
+    pub collision_mask: Option<u32>,
 }
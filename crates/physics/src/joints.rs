@@ -0,0 +1,304 @@
+use ambient_ecs::{
+    components,
+    generated::physics::types::{JointMotion, JointType},
+    query, EntityId, Resource, SystemGroup, World,
+};
+use glam::{Mat4, Vec2};
+use itertools::Itertools;
+use physxx::{
+    AsPxJoint, PxAny, PxBase, PxConstraintFlags, PxD6Axis, PxD6Drive, PxD6JointDrive, PxD6JointRef,
+    PxD6Motion, PxDistanceJointFlag, PxDistanceJointRef, PxFixedJointRef, PxJoint,
+    PxJointAngularLimitPair, PxJointLimitCone, PxJointLinearLimit, PxJointLinearLimitPair,
+    PxJointRef, PxPhysicsRef, PxPrismaticJointFlag, PxPrismaticJointRef, PxRevoluteJointFlag,
+    PxRevoluteJointRef, PxRigidActorRef, PxSphericalJointFlag, PxSphericalJointRef, PxTransform,
+    PxUserData,
+};
+
+use crate::helpers::get_actor;
+
+pub use ambient_ecs::generated::physics::components::*;
+
+components!("physics", {
+    physics_joint: PxJointRef,
+    /// The joints that broke during this physics tick: `(joint, entity0, entity1)`.
+    @[Resource]
+    joint_breaks: Vec<(EntityId, EntityId, EntityId)>,
+});
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/joints/server",
+        vec![
+            query((physics_joint(),))
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    for (_, (joint,)) in q.iter(world, qs) {
+                        joint.remove_user_data::<EntityId>();
+                        joint.release();
+                    }
+                }),
+            // Release joints that no longer match their entity's configuration, i.e. the type
+            // was changed or one of the connected bodies was rebuilt or removed. They will be
+            // recreated below once possible.
+            query((
+                physics_joint(),
+                joint_type(),
+                joint_entity0(),
+                joint_entity1(),
+            ))
+            .to_system(|q, world, qs, _| {
+                let stale = q
+                    .iter(world, qs)
+                    .filter(|(_, (joint, ty, id0, id1))| {
+                        get_joint_type(**joint) != Some(**ty)
+                            || match (get_joint_actor(world, **id0), get_joint_actor(world, **id1))
+                            {
+                                (Some(a0), Some(a1)) => joint.get_actors() != (a0, a1),
+                                _ => true,
+                            }
+                    })
+                    .map(|(id, _)| id)
+                    .collect_vec();
+                for id in stale {
+                    release_joint(world, id);
+                }
+            }),
+            query((physics_joint(), joint_entity0(), joint_entity1()))
+                .excl(joint_broken())
+                .to_system(|q, world, qs, _| {
+                    let broken = q
+                        .iter(world, qs)
+                        .filter(|(_, (joint, _, _))| {
+                            joint
+                                .get_constraint_flags()
+                                .contains(PxConstraintFlags::BROKEN)
+                        })
+                        .map(|(id, (_, id0, id1))| (id, *id0, *id1))
+                        .collect_vec();
+                    for (id, id0, id1) in broken {
+                        release_joint(world, id);
+                        world.add_component(id, joint_broken(), ()).unwrap();
+                        world.resource_mut(joint_breaks()).push((id, id0, id1));
+                    }
+                }),
+            query((joint_type(), joint_entity0(), joint_entity1()))
+                .excl(physics_joint())
+                .excl(joint_broken())
+                .to_system(|q, world, qs, _| {
+                    for (id, (ty, id0, id1)) in q.collect_cloned(world, qs) {
+                        if let Some(joint) = create_joint(world, id, ty, id0, id1) {
+                            configure_joint(world, id, joint);
+                            world.add_component(id, physics_joint(), joint).unwrap();
+                        }
+                    }
+                }),
+            query((physics_joint(),))
+                .optional_changed(joint_frame0())
+                .optional_changed(joint_frame1())
+                .optional_changed(joint_limit())
+                .optional_changed(joint_swing_limit())
+                .optional_changed(joint_linear_limit())
+                .optional_changed(joint_motion_x())
+                .optional_changed(joint_motion_y())
+                .optional_changed(joint_motion_z())
+                .optional_changed(joint_motion_twist())
+                .optional_changed(joint_motion_swing1())
+                .optional_changed(joint_motion_swing2())
+                .optional_changed(joint_drive_velocity())
+                .optional_changed(joint_drive_stiffness())
+                .optional_changed(joint_drive_damping())
+                .optional_changed(joint_drive_target())
+                .optional_changed(joint_break_force())
+                .optional_changed(joint_break_torque())
+                .to_system(|q, world, qs, _| {
+                    for (id, (joint,)) in q.collect_cloned(world, qs) {
+                        configure_joint(world, id, joint);
+                    }
+                }),
+        ],
+    )
+}
+
+/// Returns the actor a joint should be attached to for `id`; `Some(None)` is the world frame.
+///
+/// Returns `None` if the entity does not have an actor (yet).
+fn get_joint_actor(world: &World, id: EntityId) -> Option<Option<PxRigidActorRef>> {
+    if id.is_null() {
+        return Some(None);
+    }
+    get_actor(world, id)
+        .and_then(|actor| actor.to_rigid_actor())
+        .map(Some)
+}
+
+fn get_joint_type(joint: PxJointRef) -> Option<JointType> {
+    match joint.as_px_any() {
+        PxAny::PxFixedJoint(_) => Some(JointType::Fixed),
+        PxAny::PxRevoluteJoint(_) => Some(JointType::Revolute),
+        PxAny::PxSphericalJoint(_) => Some(JointType::Spherical),
+        PxAny::PxPrismaticJoint(_) => Some(JointType::Prismatic),
+        PxAny::PxDistanceJoint(_) => Some(JointType::Distance),
+        PxAny::PxD6Joint(_) => Some(JointType::D6),
+        _ => None,
+    }
+}
+
+fn to_px_transform(frame: Mat4) -> PxTransform {
+    let (_, rotation, translation) = frame.to_scale_rotation_translation();
+    PxTransform::new(translation, rotation)
+}
+
+fn create_joint(
+    world: &World,
+    id: EntityId,
+    ty: JointType,
+    id0: EntityId,
+    id1: EntityId,
+) -> Option<PxJointRef> {
+    let actor0 = get_joint_actor(world, id0)?;
+    let actor1 = get_joint_actor(world, id1)?;
+    let is_dynamic =
+        |actor: Option<PxRigidActorRef>| actor.and_then(|a| a.to_rigid_dynamic()).is_some();
+    if !is_dynamic(actor0) && !is_dynamic(actor1) {
+        return None;
+    }
+
+    let physics = PxPhysicsRef::get();
+    let frame0 = to_px_transform(world.get(id, joint_frame0()).unwrap_or_default());
+    let frame1 = to_px_transform(world.get(id, joint_frame1()).unwrap_or_default());
+    let joint = match ty {
+        JointType::Fixed => {
+            PxFixedJointRef::new(physics, actor0, &frame0, actor1, &frame1).as_joint()
+        }
+        JointType::Revolute => {
+            PxRevoluteJointRef::new(physics, actor0, &frame0, actor1, &frame1).as_joint()
+        }
+        JointType::Spherical => {
+            PxSphericalJointRef::new(physics, actor0, &frame0, actor1, &frame1).as_joint()
+        }
+        JointType::Prismatic => {
+            PxPrismaticJointRef::new(physics, actor0, &frame0, actor1, &frame1).as_joint()
+        }
+        JointType::Distance => {
+            PxDistanceJointRef::new(physics, actor0, &frame0, actor1, &frame1).as_joint()
+        }
+        JointType::D6 => PxD6JointRef::new(physics, actor0, &frame0, actor1, &frame1).as_joint(),
+    };
+    joint.set_user_data(id);
+    Some(joint)
+}
+
+fn release_joint(world: &mut World, id: EntityId) {
+    if let Ok(joint) = world.get(id, physics_joint()) {
+        joint.remove_user_data::<EntityId>();
+        joint.release();
+    }
+    world.remove_component(id, physics_joint()).ok();
+}
+
+/// Applies the `joint_*` components of `id` to `joint`.
+fn configure_joint(world: &World, id: EntityId, joint: PxJointRef) {
+    let physics = PxPhysicsRef::get();
+    let limit = world.get(id, joint_limit()).ok();
+
+    joint.set_local_pose(
+        0,
+        &to_px_transform(world.get(id, joint_frame0()).unwrap_or_default()),
+    );
+    joint.set_local_pose(
+        1,
+        &to_px_transform(world.get(id, joint_frame1()).unwrap_or_default()),
+    );
+    joint.set_break_force(
+        world.get(id, joint_break_force()).unwrap_or(f32::MAX),
+        world.get(id, joint_break_torque()).unwrap_or(f32::MAX),
+    );
+
+    match joint.as_px_any() {
+        PxAny::PxRevoluteJoint(joint) => {
+            if let Some(Vec2 { x, y }) = limit {
+                joint.set_limit(&PxJointAngularLimitPair::new(x, y, -1.0));
+            }
+            joint.set_revolute_flag(PxRevoluteJointFlag::LIMIT_ENABLED, limit.is_some());
+
+            let drive_velocity = world.get(id, joint_drive_velocity()).ok();
+            joint.set_drive_velocity(drive_velocity.unwrap_or_default(), true);
+            joint.set_revolute_flag(PxRevoluteJointFlag::DRIVE_ENABLED, drive_velocity.is_some());
+        }
+        PxAny::PxSphericalJoint(joint) => {
+            if let Some(Vec2 { x, y }) = limit {
+                joint.set_limit_cone(&PxJointLimitCone::new(x, y, -1.0));
+            }
+            joint.set_spherical_flag(PxSphericalJointFlag::LIMIT_ENABLED, limit.is_some());
+        }
+        PxAny::PxPrismaticJoint(joint) => {
+            if let Some(Vec2 { x, y }) = limit {
+                joint.set_limit(&PxJointLinearLimitPair::new(physics, x, y, -1.0));
+            }
+            joint.set_prismatic_flag(PxPrismaticJointFlag::LIMIT_ENABLED, limit.is_some());
+        }
+        PxAny::PxDistanceJoint(joint) => {
+            if let Some(Vec2 { x, y }) = limit {
+                joint.set_min_distance(x);
+                joint.set_max_distance(y);
+            }
+            joint.set_distance_flag(PxDistanceJointFlag::MIN_DISTANCE_ENABLED, limit.is_some());
+            joint.set_distance_flag(PxDistanceJointFlag::MAX_DISTANCE_ENABLED, limit.is_some());
+
+            let stiffness = world.get(id, joint_drive_stiffness()).ok();
+            joint.set_stiffness(stiffness.unwrap_or_default());
+            joint.set_damping(world.get(id, joint_drive_damping()).unwrap_or_default());
+            joint.set_distance_flag(PxDistanceJointFlag::SPRING_ENABLED, stiffness.is_some());
+        }
+        PxAny::PxD6Joint(joint) => {
+            for (axis, motion) in [
+                (PxD6Axis::X, joint_motion_x()),
+                (PxD6Axis::Y, joint_motion_y()),
+                (PxD6Axis::Z, joint_motion_z()),
+                (PxD6Axis::Twist, joint_motion_twist()),
+                (PxD6Axis::Swing1, joint_motion_swing1()),
+                (PxD6Axis::Swing2, joint_motion_swing2()),
+            ] {
+                let motion = match world.get(id, motion).unwrap_or_default() {
+                    JointMotion::Locked => PxD6Motion::Locked,
+                    JointMotion::Limited => PxD6Motion::Limited,
+                    JointMotion::Free => PxD6Motion::Free,
+                };
+                joint.set_motion(axis, motion);
+            }
+            if let Some(Vec2 { x, y }) = limit {
+                joint.set_twist_limit(&PxJointAngularLimitPair::new(x, y, -1.0));
+            }
+            if let Ok(Vec2 { x, y }) = world.get(id, joint_swing_limit()) {
+                joint.set_swing_limit(&PxJointLimitCone::new(x, y, -1.0));
+            }
+            if let Ok(extent) = world.get(id, joint_linear_limit()) {
+                joint.set_distance_limit(&PxJointLinearLimit::new(physics, extent, -1.0));
+            }
+
+            let drives = [PxD6Drive::X, PxD6Drive::Y, PxD6Drive::Z, PxD6Drive::Slerp];
+            let stiffness = world.get(id, joint_drive_stiffness()).unwrap_or_default();
+            let damping = world.get(id, joint_drive_damping()).unwrap_or_default();
+            let has_drive = stiffness != 0. || damping != 0.;
+            // Setting the drives wakes up the bodies, so they're left alone unless there is a
+            // drive to set, or one that was set before has to be turned off
+            let had_drive = drives.iter().any(|index| {
+                let drive = joint.get_drive(*index);
+                drive.stiffness != 0. || drive.damping != 0.
+            });
+            if has_drive || had_drive {
+                let drive = PxD6JointDrive::new(stiffness, damping, f32::MAX, true);
+                for index in drives {
+                    joint.set_drive(index, &drive);
+                }
+            }
+            if has_drive {
+                joint.set_drive_position(
+                    &to_px_transform(world.get(id, joint_drive_target()).unwrap_or_default()),
+                    true,
+                );
+            }
+        }
+        _ => {}
+    }
+}
@@ -17,20 +17,23 @@ use physx::{
     rigid_static,
 };
 use physxx::{
-    AsPxActor, PxContactPairHeader, PxContactPoint, PxControllerManagerRef, PxMaterial,
-    PxPvdSceneFlag, PxRigidActor, PxSceneDesc, PxSceneFlags, PxSceneRef, PxSimulationEventCallback,
-    PxUserData,
+    AsPxActor, PxContactPair, PxContactPairHeader, PxControllerManagerRef, PxMaterial, PxPairFlag,
+    PxPvdSceneFlag, PxRigidActor, PxSceneDesc, PxSceneFlags, PxSceneRef, PxShape,
+    PxSimulationEventCallback, PxTriggerPair, PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
 use crate::physx::PhysicsKey;
 
+pub mod articulation;
 pub mod collider;
 pub mod helpers;
 pub mod intersection;
+pub mod joints;
 pub mod mesh;
 pub mod physx;
 pub mod rc_asset;
+pub mod snapshot;
 pub mod visualization;
 
 pub use ambient_ecs::generated::physics::components::*;
@@ -48,15 +51,38 @@ components!("physics", {
     wood_physics_material: PxMaterial,
     @[Debuggable, Resource]
     collisions: Arc<Mutex<Vec<(EntityId, EntityId, Vec<Vec3>, Vec<Vec3>)>>>,
+    @[Debuggable, Resource]
+    contact_events: Arc<Mutex<Vec<ContactEvent>>>,
 });
 pub fn init_all_components() {
     init_components();
     physx::init_components();
     collider::init_components();
+    articulation::init_components();
+    joints::init_components();
+    snapshot::init_components();
     visualization::init_components();
 }
 
 pub const GRAVITY: f32 = 9.82;
+/// The collision group of colliders without a `collision_group` component.
+pub const DEFAULT_COLLISION_GROUP: u32 = 1;
+/// A collision mask that matches every collision group.
+pub const ALL_COLLISION_GROUPS: u32 = u32::MAX;
+/// Set in `word2` of a shape's simulation filter data once its group and mask have been assigned.
+pub(crate) const COLLISION_FILTER_ASSIGNED: u32 = 1;
+
+/// Whether the main scene is created with enhanced determinism, which keeps simulations that are
+/// restored from a snapshot reproducible regardless of the order in which actors are re-added to
+/// the scene, at a performance cost. Off by default.
+#[derive(Debug, Clone)]
+pub struct EnhancedDeterminismKey;
+impl SyncAssetKey<bool> for EnhancedDeterminismKey {
+    fn load(&self, _assets: AssetCache) -> bool {
+        false
+    }
+}
+
 pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entity) {
     let physics = PhysicsKey.get(assets);
     server_resources.set(crate::physx::physics(), physics.clone());
@@ -64,43 +90,93 @@ pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entit
     let mut main_scene_desc = PxSceneDesc::new(physics.physics);
     main_scene_desc.set_cpu_dispatcher(&physics.dispatcher);
     main_scene_desc.set_gravity(vec3(0., 0., -GRAVITY));
-    main_scene_desc.update_flags(|flags| flags | PxSceneFlags::ENABLE_CCD);
+    let enhanced_determinism = EnhancedDeterminismKey.get(assets);
+    main_scene_desc.update_flags(|flags| {
+        let flags = flags | PxSceneFlags::ENABLE_CCD;
+        if enhanced_determinism {
+            flags | PxSceneFlags::ENABLE_ENHANCED_DETERMINISM
+        } else {
+            flags
+        }
+    });
     main_scene_desc.set_filter_shader(main_physx_scene_filter_shader, true);
     let collisions = Arc::new(Mutex::new(Vec::new()));
+    let contact_events = Arc::new(Mutex::new(Vec::new()));
     {
         let collisions = collisions.clone();
+        let contact_events = contact_events.clone();
+        let trigger_events = contact_events.clone();
         main_scene_desc.set_simulation_event_callbacks(PxSimulationEventCallback {
             collision_callback: Some(Box::new(
-                move |header: &PxContactPairHeader, contacts: Vec<PxContactPoint>| {
-                    if let (Some(a), Some(b)) = (header.actors[0], header.actors[1]) {
-                        let a = a
-                            .borrow_shapes()
-                            .get(0)
-                            .and_then(|s| s.get_user_data::<PxShapeUserData>())
-                            .map(|ud| ud.entity);
-                        let b = b
-                            .borrow_shapes()
-                            .get(0)
-                            .and_then(|s| s.get_user_data::<PxShapeUserData>())
-                            .map(|ud| ud.entity);
-
-                        let positions: Vec<Vec3> =
-                            contacts.iter().map(|point| point.position).collect();
-
-                        let normals: Vec<Vec3> =
-                            contacts.iter().map(|point| point.normal).collect();
-
-                        if let (Some(a), Some(b)) = (a, b) {
-                            collisions.lock().push((a, b, positions, normals));
+                move |_header: &PxContactPairHeader, pairs: &[PxContactPair]| {
+                    for pair in pairs {
+                        let (Some(a), Some(b)) =
+                            (shape_entity(&pair.shapes[0]), shape_entity(&pair.shapes[1]))
+                        else {
+                            continue;
+                        };
+
+                        if pair.events.contains(PxPairFlag::NOTIFY_TOUCH_FOUND) {
+                            let positions: Vec<Vec3> = pair
+                                .contact_points
+                                .iter()
+                                .map(|point| point.position)
+                                .collect();
+
+                            let normals: Vec<Vec3> = pair
+                                .contact_points
+                                .iter()
+                                .map(|point| point.normal)
+                                .collect();
+
+                            let impulse =
+                                pair.contact_points.iter().map(|point| point.impulse).sum();
+
+                            collisions
+                                .lock()
+                                .push((a, b, positions.clone(), normals.clone()));
+                            contact_events.lock().push(ContactEvent::Begin {
+                                entities: [a, b],
+                                positions,
+                                normals,
+                                impulse,
+                            });
+                        }
+                        if pair.events.contains(PxPairFlag::NOTIFY_TOUCH_LOST) {
+                            contact_events
+                                .lock()
+                                .push(ContactEvent::End { entities: [a, b] });
                         }
                     }
                 },
             )),
+            trigger_callback: Some(Box::new(move |pairs: &[PxTriggerPair]| {
+                for pair in pairs {
+                    let (Some(trigger), Some(other)) = (
+                        shape_entity(&pair.trigger_shape),
+                        shape_entity(&pair.other_shape),
+                    ) else {
+                        continue;
+                    };
+
+                    if pair.status.contains(PxPairFlag::NOTIFY_TOUCH_FOUND) {
+                        trigger_events
+                            .lock()
+                            .push(ContactEvent::TriggerEnter { trigger, other });
+                    } else if pair.status.contains(PxPairFlag::NOTIFY_TOUCH_LOST) {
+                        trigger_events
+                            .lock()
+                            .push(ContactEvent::TriggerExit { trigger, other });
+                    }
+                }
+            })),
         });
     }
     let main_scene = PxSceneRef::new(&physics.physics, &main_scene_desc);
     server_resources.set(self::collisions(), collisions);
+    server_resources.set(self::contact_events(), contact_events);
     server_resources.set(self::collider_loads(), vec![]);
+    server_resources.set(joints::joint_breaks(), vec![]);
 
     main_scene.get_scene_pvd_client().set_scene_pvd_flags(
         PxPvdSceneFlag::TRANSMIT_CONSTRAINTS
@@ -128,7 +204,33 @@ pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entit
     );
 }
 
+/// A contact or trigger event reported by the main physics scene during the last physics tick.
 #[derive(Debug, Clone)]
+pub enum ContactEvent {
+    /// Two colliders started touching.
+    Begin {
+        entities: [EntityId; 2],
+        positions: Vec<Vec3>,
+        normals: Vec<Vec3>,
+        /// The total impulse applied between the two colliders.
+        impulse: Vec3,
+    },
+    /// Two colliders stopped touching.
+    End { entities: [EntityId; 2] },
+    /// A collider entered a trigger volume.
+    TriggerEnter { trigger: EntityId, other: EntityId },
+    /// A collider left a trigger volume.
+    TriggerExit { trigger: EntityId, other: EntityId },
+}
+
+fn shape_entity(shape: &Option<PxShape>) -> Option<EntityId> {
+    shape
+        .as_ref()
+        .and_then(|shape| shape.get_user_data::<PxShapeUserData>())
+        .map(|ud| ud.entity)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PxShapeUserData {
     pub entity: EntityId,
     pub density: f32,
@@ -194,11 +296,30 @@ impl SyncAssetKey<PxMaterial> for PxWoodMaterialKey {
 unsafe extern "C" fn main_physx_scene_filter_shader(
     info: *mut physxx::sys::FilterShaderCallbackInfo,
 ) -> u16 {
+    let group_and_mask = |data: &physxx::sys::PxFilterData| {
+        if data.word2 & COLLISION_FILTER_ASSIGNED != 0 {
+            (data.word0, data.word1)
+        } else {
+            (DEFAULT_COLLISION_GROUP, ALL_COLLISION_GROUPS)
+        }
+    };
+    let (group0, mask0) = group_and_mask(&(*info).filterData0);
+    let (group1, mask1) = group_and_mask(&(*info).filterData1);
+    if group0 & mask1 == 0 || group1 & mask0 == 0 {
+        return (physxx::sys::PxFilterFlag::eSUPPRESS) as u16;
+    }
+
+    let is_trigger = |attributes: u32| attributes & physxx::sys::PxFilterObjectFlag::eTRIGGER != 0;
+    if is_trigger((*info).attributes0) || is_trigger((*info).attributes1) {
+        (*(*info).pairFlags).mBits = physxx::sys::PxPairFlag::eTRIGGER_DEFAULT as u16;
+        return (physxx::sys::PxFilterFlag::eDEFAULT) as u16;
+    }
     (*(*info).pairFlags).mBits |= (physxx::sys::PxPairFlag::eSOLVE_CONTACT
         | physxx::sys::PxPairFlag::eDETECT_DISCRETE_CONTACT
         | physxx::sys::PxPairFlag::eDETECT_CCD_CONTACT
         | physxx::sys::PxPairFlag::eCONTACT_DEFAULT
         | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_FOUND
+        | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_LOST
         | physxx::sys::PxPairFlag::eNOTIFY_CONTACT_POINTS) as u16;
     (physxx::sys::PxFilterFlag::eDEFAULT) as u16
 }
@@ -234,6 +355,8 @@ pub fn server_systems() -> SystemGroup {
                     }
                 }),
             Box::new(collider::server_systems()),
+            Box::new(joints::server_systems()),
+            Box::new(articulation::server_systems()),
             Box::new(visualization::server_systems()),
         ],
     )
@@ -258,7 +381,9 @@ pub fn fetch_simulation_system() -> DynSystem {
         profiling::scope!("fetch_simulation_system");
 
         world.resource(collisions()).lock().clear();
+        world.resource(contact_events()).lock().clear();
         world.resource_mut(collider_loads()).clear();
+        world.resource_mut(joints::joint_breaks()).clear();
         let scene = world.resource(main_physics_scene());
         // Ensure the previous simulation has completed
         scene.fetch_results(true);
@@ -297,6 +422,12 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
             for (id, _) in query(()).incl(revolute_joint()).collect_cloned(world, None) {
                 world.remove_component(id, revolute_joint()).unwrap();
             }
+            for (id, _) in query(())
+                .incl(joints::physics_joint())
+                .collect_cloned(world, None)
+            {
+                world.remove_component(id, joints::physics_joint()).unwrap();
+            }
             for (id, _) in query(())
                 .incl(articulation_reduce_coordinate())
                 .collect_cloned(world, None)
@@ -305,6 +436,14 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
                     .remove_component(id, articulation_reduce_coordinate())
                     .unwrap();
             }
+            for (id, _) in query(())
+                .incl(articulation::articulation_links())
+                .collect_cloned(world, None)
+            {
+                world
+                    .remove_component(id, articulation::articulation_links())
+                    .unwrap();
+            }
             for (id, _) in query(())
                 .incl(articulation_link())
                 .collect_cloned(world, None)
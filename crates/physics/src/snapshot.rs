@@ -0,0 +1,304 @@
+use std::collections::HashSet;
+
+use ambient_core::{
+    frame_index,
+    transform::{rotation, scale, translation},
+};
+use ambient_ecs::{
+    components, query, ComponentDesc, ComponentEntry, Entity, EntityId, Serializable, World,
+};
+use anyhow::Context;
+use itertools::Itertools;
+use physxx::{
+    AsPxActor, AsPxBase, AsPxRigidActor, PxActor, PxAny, PxBase, PxCollectionRef,
+    PxDefaultMemoryInputData, PxDefaultMemoryOutputStream, PxRigidActor, PxRigidActorRef,
+    PxUserData,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    collider::{collider_shapes, collider_shapes_convex},
+    joints::physics_joint,
+    kinematic, main_physics_scene, physics_controlled,
+    physx::{
+        character_controller, physics, physics_shape, rigid_actor, rigid_dynamic, rigid_static,
+    },
+    PxActorUserData, PxShapeUserData,
+};
+
+components!("physics", {
+    /// Set on entities whose actor was just restored from a snapshot, so that it isn't rebuilt
+    /// from the entity's components on the next frame.
+    restored_from_snapshot: (),
+});
+
+/// The state of the physics simulation of a world at a given frame.
+///
+/// The actors of the main PhysX scene are stored as a serialized PhysX collection, so that
+/// restoring a snapshot brings back their exact simulation state (velocities, sleep state and so
+/// on) rather than an approximation of it. As the snapshot is serializable, it can be saved to
+/// disk to reproduce a simulation later.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PhysicsSnapshot {
+    /// The `frame_index` of the world when the snapshot was taken.
+    pub frame: usize,
+    /// The serializable components of every entity taking part in the simulation.
+    pub entities: Vec<(EntityId, Entity)>,
+    /// The actors of the main scene, as a PhysX XML collection. The serial id of each actor is
+    /// its index in `actors`, plus one.
+    scene: Vec<u8>,
+    /// The entity of each serialized actor, and the user data of its shapes, which PhysX doesn't
+    /// serialize.
+    actors: Vec<(EntityId, Vec<PxShapeUserData>)>,
+}
+
+impl std::fmt::Debug for PhysicsSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhysicsSnapshot")
+            .field("frame", &self.frame)
+            .field("entities", &self.entities.len())
+            .field("actors", &self.actors.len())
+            .finish()
+    }
+}
+
+fn is_physics_namespace(desc: &ComponentDesc) -> bool {
+    desc.has_attribute::<Serializable>() && desc.path().starts_with("ambient_core::physics::")
+}
+
+/// Returns true if `desc` is part of an entity's physics state.
+fn is_physics_component(desc: &ComponentDesc) -> bool {
+    is_physics_namespace(desc)
+        || *desc == translation().desc()
+        || *desc == rotation().desc()
+        || *desc == scale().desc()
+}
+
+/// Returns true if `id` takes part in the simulation of `world`.
+fn is_simulated(world: &World, id: EntityId) -> bool {
+    world.has_component(id, rigid_actor())
+        || world.get_components(id).map_or(false, |components| {
+            components.iter().any(is_physics_namespace)
+        })
+}
+
+fn same_value(a: &ComponentEntry, b: &ComponentEntry) -> bool {
+    let serialize = |entry: &ComponentEntry| {
+        let ser = entry.attribute::<Serializable>()?;
+        bincode::serialize(ser.serialize(entry)).ok()
+    };
+    serialize(a) == serialize(b)
+}
+
+/// Captures the physics state of `world`.
+///
+/// The scene must not be simulating, so this must not be called between
+/// [`run_simulation_system`](crate::run_simulation_system) and
+/// [`fetch_simulation_system`](crate::fetch_simulation_system).
+pub fn take_snapshot(world: &World) -> anyhow::Result<PhysicsSnapshot> {
+    profiling::scope!("take_physics_snapshot");
+    let entities = query(())
+        .iter(world, None)
+        .map(|(id, _)| id)
+        .filter(|id| *id != world.resource_entity() && is_simulated(world, *id))
+        .map(|id| {
+            let data = world
+                .get_components(id)
+                .unwrap()
+                .into_iter()
+                .filter(|desc| desc.has_attribute::<Serializable>())
+                .filter_map(|desc| world.get_entry(id, desc).ok())
+                .collect::<Entity>();
+            (id, data)
+        })
+        .collect_vec();
+
+    let scene = *world.resource(main_physics_scene());
+    let mut collection = PxCollectionRef::new();
+    let mut actors = Vec::new();
+    for (id, (actor,)) in query((rigid_actor(),)).iter(world, None) {
+        let serialize = actor
+            .as_actor()
+            .get_user_data::<PxActorUserData>()
+            .map_or(false, |ud| ud.serialize);
+        if !serialize || actor.get_scene() != Some(scene) {
+            continue;
+        }
+        let shapes = actor
+            .get_shapes()
+            .iter()
+            .map(|shape| shape.get_user_data::<PxShapeUserData>().unwrap_or_default())
+            .collect_vec();
+        actors.push((id, shapes));
+        collection.add_with_serial_id(actor.as_base(), actors.len());
+    }
+
+    let physics = world.resource(physics());
+    physics.serialization_registry.complete(collection);
+    let stream = PxDefaultMemoryOutputStream::new();
+    let serialized = physics
+        .serialization_registry
+        .serialize_collection_to_xml(&stream, collection);
+    collection.release();
+    anyhow::ensure!(serialized, "Failed to serialize the physics scene");
+
+    Ok(PhysicsSnapshot {
+        frame: *world.resource(frame_index()),
+        entities,
+        scene: stream.get_data(),
+        actors,
+    })
+}
+
+/// Rolls the physics state of `world` back to `snapshot`.
+///
+/// Entities spawned after the snapshot was taken are despawned, and entities that have been
+/// despawned since are respawned with all of their captured components. The physics components
+/// of the other entities are reset to the captured values, and any physics components added
+/// since are removed.
+///
+/// The actors of the main scene are replaced with the ones stored in the snapshot. Joints and
+/// character controllers are rebuilt from their restored components on the following frame.
+///
+/// As with [`take_snapshot`], the scene must not be simulating. Snapshots replace arbitrary
+/// entities and PhysX actors, so they must only come from a trusted source such as the server
+/// itself, never from a client.
+pub fn restore_snapshot(world: &mut World, snapshot: &PhysicsSnapshot) -> anyhow::Result<()> {
+    profiling::scope!("restore_physics_snapshot");
+    let scene = *world.resource(main_physics_scene());
+
+    let physics = world.resource(physics()).clone();
+    let mut collection = physics
+        .serialization_registry
+        .create_collection_from_xml(
+            &PxDefaultMemoryInputData::new(snapshot.scene.clone()),
+            &physics.cooking,
+        )
+        .context("Failed to deserialize the physics scene")?;
+    let restored = collection
+        .get_objects()
+        .into_iter()
+        .filter_map(|(serial_id, object)| {
+            let actor: PxRigidActorRef = match object {
+                PxAny::PxRigidDynamic(actor) => actor.as_rigid_actor(),
+                PxAny::PxRigidStatic(actor) => actor.as_rigid_actor(),
+                _ => return None,
+            };
+            Some((snapshot.actors.get(serial_id.checked_sub(1)?)?, actor))
+        })
+        .collect_vec();
+    collection.release();
+
+    let captured = snapshot
+        .entities
+        .iter()
+        .map(|(id, _)| *id)
+        .collect::<HashSet<_>>();
+    for id in query(())
+        .iter(world, None)
+        .map(|(id, _)| id)
+        .filter(|id| *id != world.resource_entity() && !captured.contains(id))
+        .filter(|id| is_simulated(world, *id))
+        .collect_vec()
+    {
+        world.despawn(id);
+    }
+
+    // Joints and character controllers hold on to the actors being replaced, so they have to be
+    // rebuilt
+    for (id, _) in query((physics_joint(),)).collect_cloned(world, None) {
+        world.remove_component(id, physics_joint()).unwrap();
+    }
+    for (id, _) in query((character_controller(),)).collect_cloned(world, None) {
+        world
+            .remove_components(
+                id,
+                vec![character_controller().desc(), collider_shapes().desc()],
+            )
+            .unwrap();
+    }
+
+    for (id, data) in &snapshot.entities {
+        if !world.exists(*id) {
+            world.spawn_with_id(*id, data.clone());
+            continue;
+        }
+
+        let added = world
+            .get_components(*id)
+            .unwrap()
+            .into_iter()
+            .filter(|desc| is_physics_component(desc) && data.get_entry(*desc).is_none())
+            .collect_vec();
+        world.remove_components(*id, added).unwrap();
+
+        // Only the components that differ are written back, so that unchanged colliders aren't
+        // reloaded
+        let changed = data
+            .iter()
+            .filter(|entry| is_physics_component(&entry.desc()))
+            .filter(|entry| {
+                world
+                    .get_entry(*id, entry.desc())
+                    .map_or(true, |current| !same_value(&current, entry))
+            })
+            .cloned()
+            .collect::<Entity>();
+        world.add_components(*id, changed).unwrap();
+    }
+
+    for ((id, shape_data), actor) in restored {
+        if let Ok(old) = world.get(*id, rigid_actor()) {
+            if let Some(scene) = old.get_scene() {
+                scene.remove_actor(&old, false);
+            }
+            old.as_actor().remove_user_data::<PxActorUserData>();
+            old.release();
+        }
+
+        actor
+            .as_actor()
+            .set_user_data(PxActorUserData { serialize: true });
+        let shapes = actor.get_shapes();
+        for (shape, data) in shapes.iter().zip(shape_data) {
+            shape.set_user_data(PxShapeUserData {
+                entity: *id,
+                ..data.clone()
+            });
+        }
+        scene.add_actor(&actor);
+
+        // Dynamic bodies are built from the convex shapes, everything else from the concave ones
+        let is_simulated_body =
+            actor.to_rigid_dynamic().is_some() && !world.has_component(*id, kinematic());
+        let shapes_component = if is_simulated_body {
+            collider_shapes_convex()
+        } else {
+            collider_shapes()
+        };
+        let mut components = Entity::new()
+            .with(rigid_actor(), actor)
+            .with(physics_shape(), shapes[0].clone())
+            .with(shapes_component, shapes.clone())
+            .with(restored_from_snapshot(), ());
+        if is_simulated_body {
+            components.set(physics_controlled(), ());
+        }
+        if !world.has_component(*id, collider_shapes()) {
+            components.set(collider_shapes(), shapes.clone());
+        }
+        if !world.has_component(*id, collider_shapes_convex()) {
+            components.set(collider_shapes_convex(), shapes.clone());
+        }
+        if let Some(actor) = actor.to_rigid_dynamic() {
+            components.set(rigid_dynamic(), actor);
+            world.remove_component(*id, rigid_static()).unwrap();
+        } else if let Some(actor) = actor.to_rigid_static() {
+            components.set(rigid_static(), actor);
+            world.remove_component(*id, rigid_dynamic()).unwrap();
+        }
+        world.add_components(*id, components).unwrap();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+
+use ambient_core::{
+    hierarchy::parent,
+    transform::{local_to_world, translation},
+};
+use ambient_ecs::{Entity, EntityId};
+use ambient_model::animation_binder;
+use ambient_physics::{
+    articulation::{
+        articulation_bind_ids, articulation_blend, articulation_drive_stiffness,
+        articulation_error, articulation_links,
+    },
+    physx::{articulation_link, articulation_reduce_coordinate},
+};
+use common::TestWorld;
+use glam::{vec3, Mat4, Vec3};
+
+mod common;
+
+struct Rig {
+    entity: EntityId,
+    /// The hips, spine and head, which are each a meter above the previous one, and an arm that
+    /// is not a descendant of any of them.
+    bones: HashMap<&'static str, EntityId>,
+}
+
+fn spawn_rig(test: &mut TestWorld, bind_ids: &[&str]) -> Rig {
+    ambient_model::init_components();
+
+    let mut bones = HashMap::new();
+    let mut spawn_bone = |name: &'static str, position: Vec3, bone_parent: Option<&str>| {
+        let mut bone = Entity::new()
+            .with(translation(), position)
+            .with(local_to_world(), Mat4::from_translation(position));
+        if let Some(bone_parent) = bone_parent {
+            bone.set(parent(), bones[bone_parent]);
+        }
+        let id = bone.spawn(&mut test.world);
+        bones.insert(name, id);
+    };
+    spawn_bone("hips", vec3(0., 0., 10.), None);
+    spawn_bone("spine", vec3(0., 0., 11.), Some("hips"));
+    spawn_bone("head", vec3(0., 0., 12.), Some("spine"));
+    spawn_bone("arm", vec3(1., 0., 11.), None);
+
+    let entity = Entity::new()
+        .with(
+            articulation_bind_ids(),
+            bind_ids.iter().map(|id| id.to_string()).collect(),
+        )
+        .with(
+            animation_binder(),
+            bones
+                .iter()
+                .map(|(name, id)| (name.to_string(), *id))
+                .collect(),
+        )
+        .spawn(&mut test.world);
+
+    Rig { entity, bones }
+}
+
+#[test]
+fn articulations_are_built_from_the_bones() {
+    let mut test = TestWorld::new();
+    let rig = spawn_rig(&mut test, &["head", "spine", "hips"]);
+    test.step();
+
+    let links = test
+        .world
+        .get_cloned(rig.entity, articulation_links())
+        .unwrap();
+    // Parents come before their children, whatever the order of the bind ids
+    let order = links.iter().map(|link| link.bone).collect::<Vec<_>>();
+    assert_eq!(order[0], rig.bones["hips"]);
+    assert!(
+        order.iter().position(|b| *b == rig.bones["spine"])
+            < order.iter().position(|b| *b == rig.bones["head"])
+    );
+
+    let parent_of = |bone: &str| {
+        links
+            .iter()
+            .find(|link| link.bone == rig.bones[bone])
+            .unwrap()
+            .parent
+    };
+    assert_eq!(parent_of("hips"), None);
+    assert_eq!(parent_of("spine"), Some(rig.bones["hips"]));
+    assert_eq!(parent_of("head"), Some(rig.bones["spine"]));
+
+    for bone in ["hips", "spine", "head"] {
+        assert!(test
+            .world
+            .has_component(rig.bones[bone], articulation_link()));
+    }
+    assert!(!test
+        .world
+        .has_component(rig.bones["arm"], articulation_link()));
+}
+
+#[test]
+fn articulations_must_have_a_single_root() {
+    let mut test = TestWorld::new();
+    let rig = spawn_rig(&mut test, &["hips", "spine", "arm"]);
+    test.step();
+
+    assert!(!test
+        .world
+        .has_component(rig.entity, articulation_reduce_coordinate()));
+    let error = test
+        .world
+        .get_cloned(rig.entity, articulation_error())
+        .unwrap();
+    assert!(error.contains("hips") && error.contains("arm"), "{error}");
+
+    // Fixing the bind ids clears the error, and the articulation is built
+    test.world
+        .set(
+            rig.entity,
+            articulation_bind_ids(),
+            vec!["hips".to_string(), "spine".to_string()],
+        )
+        .unwrap();
+    test.step();
+    assert!(!test.world.has_component(rig.entity, articulation_error()));
+    assert!(test
+        .world
+        .has_component(rig.entity, articulation_reduce_coordinate()));
+}
+
+#[test]
+fn drives_are_updated_when_they_change() {
+    let mut test = TestWorld::new();
+    let rig = spawn_rig(&mut test, &["hips", "spine", "head"]);
+    test.step();
+    let drive_of = |test: &TestWorld, bone: &str| {
+        let links = test
+            .world
+            .get_cloned(rig.entity, articulation_links())
+            .unwrap();
+        let link = links
+            .into_iter()
+            .find(|link| link.bone == rig.bones[bone])
+            .unwrap();
+        (link.drive, link.drive_target)
+    };
+    assert_eq!(drive_of(&test, "spine"), (Some((0., 0.)), None));
+    assert_eq!(drive_of(&test, "hips"), (None, None));
+
+    test.world
+        .add_component(rig.entity, articulation_drive_stiffness(), 100.)
+        .unwrap();
+    test.world
+        .add_component(rig.bones["head"], articulation_drive_stiffness(), 50.)
+        .unwrap();
+    test.step();
+    // The bones are at rest, so the drives target the rest pose
+    assert_eq!(
+        drive_of(&test, "spine"),
+        (Some((100., 0.)), Some(Vec3::ZERO))
+    );
+    assert_eq!(drive_of(&test, "head"), (Some((50., 0.)), Some(Vec3::ZERO)));
+
+    // Bones that override the stiffness keep their drive
+    test.world
+        .set(rig.entity, articulation_drive_stiffness(), 200.)
+        .unwrap();
+    test.step();
+    assert_eq!(
+        drive_of(&test, "spine"),
+        (Some((200., 0.)), Some(Vec3::ZERO))
+    );
+    assert_eq!(drive_of(&test, "head"), (Some((50., 0.)), Some(Vec3::ZERO)));
+}
+
+#[test]
+fn articulations_wait_for_their_bones() {
+    let mut test = TestWorld::new();
+    let rig = spawn_rig(&mut test, &["hips", "tail"]);
+    test.step();
+    assert!(!test
+        .world
+        .has_component(rig.entity, articulation_reduce_coordinate()));
+
+    // Changing the bind ids rebuilds the articulation from the new bones
+    test.world
+        .set(
+            rig.entity,
+            articulation_bind_ids(),
+            vec!["hips".to_string(), "spine".to_string()],
+        )
+        .unwrap();
+    test.step();
+    assert_eq!(
+        test.world
+            .get_ref(rig.entity, articulation_links())
+            .unwrap()
+            .len(),
+        2
+    );
+
+    test.world
+        .set(
+            rig.entity,
+            articulation_bind_ids(),
+            vec!["hips".to_string()],
+        )
+        .unwrap();
+    test.step();
+    test.step();
+    assert_eq!(
+        test.world
+            .get_ref(rig.entity, articulation_links())
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(!test
+        .world
+        .has_component(rig.bones["spine"], articulation_link()));
+}
+
+#[test]
+fn blend_controls_whether_bones_are_simulated() {
+    let mut test = TestWorld::new();
+    let rig = spawn_rig(&mut test, &["hips", "spine", "head"]);
+    let hips = rig.bones["hips"];
+    test.step_n(30);
+
+    // Without a blend, the bones keep their animated pose
+    assert_eq!(
+        test.world.get(hips, translation()).unwrap(),
+        vec3(0., 0., 10.)
+    );
+
+    // Once fully simulated, the undriven articulation falls like a ragdoll
+    test.world
+        .add_component(rig.entity, articulation_blend(), 1.)
+        .unwrap();
+    test.step_n(30);
+    let z = test.world.get(hips, translation()).unwrap().z;
+    assert!(z < 9.5, "{z}");
+
+    // Despawning the articulation entity releases the links
+    test.world.despawn(rig.entity);
+    test.step();
+    assert!(!test.world.has_component(hips, articulation_link()));
+}
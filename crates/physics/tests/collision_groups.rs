@@ -0,0 +1,90 @@
+use ambient_core::transform::translation;
+use ambient_ecs::{Entity, EntityId};
+use ambient_native_std::shapes::Ray;
+use ambient_physics::{
+    collision_group, collision_mask, intersection::raycast, ALL_COLLISION_GROUPS,
+};
+use common::TestWorld;
+use glam::{vec3, Vec3};
+
+mod common;
+
+const GROUND: u32 = 1 << 1;
+const GHOST: u32 = 1 << 2;
+
+/// Drops a box onto a ground box, and returns the height it ended up at.
+fn drop_box(ground: Entity, falling: Entity) -> f32 {
+    let mut test = TestWorld::new();
+    test.spawn_box_with(vec3(0., 0., -0.5), vec3(10., 10., 0.5), false, ground);
+    let falling = test.spawn_box_with(vec3(0., 0., 1.), Vec3::splat(0.5), true, falling);
+    test.step_n(90);
+    test.world.get(falling, translation()).unwrap().z
+}
+
+#[test]
+fn colliders_in_default_groups_collide() {
+    let z = drop_box(Entity::new(), Entity::new());
+    assert!((z - 0.5).abs() < 0.05, "{z}");
+}
+
+#[test]
+fn colliders_collide_when_both_masks_match() {
+    let z = drop_box(
+        Entity::new()
+            .with(collision_group(), GROUND)
+            .with(collision_mask(), GHOST),
+        Entity::new()
+            .with(collision_group(), GHOST)
+            .with(collision_mask(), GROUND),
+    );
+    assert!((z - 0.5).abs() < 0.05, "{z}");
+}
+
+#[test]
+fn colliders_pass_through_when_either_mask_excludes_the_other() {
+    let excluded_by_falling = drop_box(
+        Entity::new().with(collision_group(), GROUND),
+        Entity::new()
+            .with(collision_group(), GHOST)
+            .with(collision_mask(), !GROUND),
+    );
+    assert!(excluded_by_falling < -1., "{excluded_by_falling}");
+
+    let excluded_by_ground = drop_box(
+        Entity::new()
+            .with(collision_group(), GROUND)
+            .with(collision_mask(), !GHOST),
+        Entity::new().with(collision_group(), GHOST),
+    );
+    assert!(excluded_by_ground < -1., "{excluded_by_ground}");
+}
+
+#[test]
+fn raycasts_only_hit_groups_in_mask() {
+    let mut test = TestWorld::new();
+    let near = test.spawn_box_with(
+        vec3(0., 0., 2.),
+        Vec3::splat(0.5),
+        false,
+        Entity::new().with(collision_group(), GHOST),
+    );
+    let far = test.spawn_box_with(
+        vec3(0., 0., 5.),
+        Vec3::splat(0.5),
+        false,
+        Entity::new().with(collision_group(), GROUND),
+    );
+    test.step();
+
+    let ray = Ray::new(Vec3::ZERO, Vec3::Z);
+    let hits = |mask| {
+        raycast(&test.world, ray, mask)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<EntityId>>()
+    };
+    assert_eq!(hits(ALL_COLLISION_GROUPS), vec![near, far]);
+    assert_eq!(hits(GROUND), vec![far]);
+    assert_eq!(hits(GHOST), vec![near]);
+    assert_eq!(hits(1 << 10), vec![]);
+}
@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+
+use std::sync::OnceLock;
+
+use ambient_core::{
+    asset_cache,
+    transform::{rotation, translation},
+};
+use ambient_ecs::{Entity, EntityId, FrameEvent, System, SystemGroup, World, WorldContext};
+use ambient_native_std::asset_cache::AssetCache;
+use ambient_physics::{
+    collider::{collider_shapes, collider_shapes_convex, collider_type, ColliderDef, ColliderType},
+    create_server_resources, fetch_simulation_system,
+    physx::{physics, sync_ecs_physics},
+    run_simulation_system, server_systems, wood_physics_material, PxShapeUserData,
+};
+use glam::{Quat, Vec3};
+use physxx::{PxBoxGeometry, PxShape, PxUserData};
+
+/// PhysX only allows one foundation per process, so all the tests share the asset cache that
+/// holds it.
+fn assets() -> AssetCache {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    static ASSETS: OnceLock<AssetCache> = OnceLock::new();
+    ASSETS
+        .get_or_init(|| {
+            let runtime = RUNTIME.get_or_init(|| tokio::runtime::Runtime::new().unwrap());
+            AssetCache::new(runtime.handle().clone())
+        })
+        .clone()
+}
+
+/// A server world with physics scenes, and the systems that the server runs each tick.
+pub struct TestWorld {
+    pub world: World,
+    systems: SystemGroup,
+}
+impl TestWorld {
+    pub fn new() -> Self {
+        ambient_ecs::init_components();
+        ambient_core::init_all_components();
+        ambient_physics::init_all_components();
+
+        let assets = assets();
+        let mut world = World::new("physics_test", WorldContext::Server);
+        world.add_resource(asset_cache(), assets.clone());
+        let mut resources = Entity::new();
+        create_server_resources(&assets, &mut resources);
+        world
+            .add_components(world.resource_entity(), resources)
+            .unwrap();
+
+        Self {
+            world,
+            systems: SystemGroup::new(
+                "physics_test",
+                vec![
+                    run_simulation_system(),
+                    fetch_simulation_system(),
+                    Box::new(sync_ecs_physics()),
+                    Box::new(server_systems()),
+                ],
+            ),
+        }
+    }
+
+    /// Runs one server tick.
+    pub fn step(&mut self) {
+        self.systems.run(&mut self.world, &FrameEvent);
+    }
+
+    pub fn step_n(&mut self, ticks: usize) {
+        for _ in 0..ticks {
+            self.step();
+        }
+    }
+
+    /// Spawns a box collider, without going through the asynchronous collider loading.
+    pub fn spawn_box(&mut self, position: Vec3, half_extents: Vec3, dynamic: bool) -> EntityId {
+        self.spawn_box_with(position, half_extents, dynamic, Entity::new())
+    }
+
+    pub fn spawn_box_with(
+        &mut self,
+        position: Vec3,
+        half_extents: Vec3,
+        dynamic: bool,
+        components: Entity,
+    ) -> EntityId {
+        let physics = self.world.resource(physics()).clone();
+        let material = self.world.resource(wood_physics_material()).clone();
+        let geometry = PxBoxGeometry::new(half_extents.x, half_extents.y, half_extents.z);
+        let shape = PxShape::new(physics.physics, &geometry, &[&material], Some(true), None);
+        shape.set_user_data(PxShapeUserData::default());
+
+        components
+            .with(translation(), position)
+            .with(rotation(), Quat::IDENTITY)
+            .with(
+                collider_type(),
+                if dynamic {
+                    ColliderType::Dynamic
+                } else {
+                    ColliderType::Static
+                },
+            )
+            .with(collider_shapes(), vec![shape.clone()])
+            .with(collider_shapes_convex(), vec![shape])
+            .spawn(&mut self.world)
+    }
+
+    /// Spawns a static collider from `collider`, without going through the asynchronous collider
+    /// loading.
+    pub fn spawn_collider(&mut self, position: Vec3, collider: ColliderDef) -> EntityId {
+        let spawner = futures::executor::block_on(collider.spawner(assets(), 1.)).unwrap();
+        let (concave, convex) = spawner(self.world.resource(physics()), Vec3::ONE);
+
+        Entity::new()
+            .with(translation(), position)
+            .with(rotation(), Quat::IDENTITY)
+            .with(collider_type(), ColliderType::Static)
+            .with(collider_shapes(), concave)
+            .with(collider_shapes_convex(), convex)
+            .spawn(&mut self.world)
+    }
+}
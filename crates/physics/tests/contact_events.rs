@@ -0,0 +1,101 @@
+use ambient_ecs::Entity;
+use ambient_physics::{collider::collider_shapes, contact_events, is_trigger, ContactEvent};
+use common::TestWorld;
+use glam::{vec3, Vec3};
+use physxx::PxShapeFlag;
+
+mod common;
+
+#[test]
+fn reports_contact_begin_and_end() {
+    let mut test = TestWorld::new();
+    let ground = test.spawn_box(vec3(0., 0., -0.5), vec3(10., 10., 0.5), false);
+    let falling = test.spawn_box(vec3(0., 0., 1.), Vec3::splat(0.5), true);
+
+    let mut began = false;
+    for _ in 0..120 {
+        test.step();
+        began |= test
+            .world
+            .resource(contact_events())
+            .lock()
+            .iter()
+            .any(|event| match event {
+                ContactEvent::Begin {
+                    entities,
+                    positions,
+                    normals,
+                    ..
+                } => {
+                    entities.contains(&ground)
+                        && entities.contains(&falling)
+                        && !positions.is_empty()
+                        && positions.len() == normals.len()
+                }
+                _ => false,
+            });
+    }
+    assert!(began);
+
+    // Events only last for the tick they were reported in
+    test.world.despawn(falling);
+    test.step_n(2);
+    assert!(test
+        .world
+        .resource(contact_events())
+        .lock()
+        .iter()
+        .all(|event| !matches!(event, ContactEvent::Begin { .. })));
+}
+
+#[test]
+fn reports_trigger_enter_and_exit() {
+    let mut test = TestWorld::new();
+    let trigger = test.spawn_box_with(
+        vec3(0., 0., 0.),
+        Vec3::splat(1.),
+        false,
+        Entity::new().with(is_trigger(), ()),
+    );
+    let falling = test.spawn_box(vec3(0., 0., 3.), Vec3::splat(0.25), true);
+
+    let mut events = Vec::new();
+    for _ in 0..180 {
+        test.step();
+        events.extend(test.world.resource(contact_events()).lock().clone());
+    }
+
+    let entered = events.iter().position(|event| {
+        matches!(event, ContactEvent::TriggerEnter { trigger: t, other } if *t == trigger && *other == falling)
+    });
+    let exited = events.iter().position(|event| {
+        matches!(event, ContactEvent::TriggerExit { trigger: t, other } if *t == trigger && *other == falling)
+    });
+    assert!(entered.is_some());
+    assert!(exited.is_some());
+    assert!(entered < exited);
+    // Triggers never make contacts
+    assert!(events
+        .iter()
+        .all(|event| !matches!(event, ContactEvent::Begin { .. })));
+}
+
+#[test]
+fn removing_is_trigger_restores_simulation_shapes() {
+    let mut test = TestWorld::new();
+    let id = test.spawn_box_with(
+        Vec3::ZERO,
+        Vec3::ONE,
+        false,
+        Entity::new().with(is_trigger(), ()),
+    );
+    test.step();
+    let shape = test.world.get_ref(id, collider_shapes()).unwrap()[0].clone();
+    assert!(shape.get_flags().contains(PxShapeFlag::TRIGGER_SHAPE));
+    assert!(!shape.get_flags().contains(PxShapeFlag::SIMULATION_SHAPE));
+
+    test.world.remove_component(id, is_trigger()).unwrap();
+    test.step();
+    assert!(!shape.get_flags().contains(PxShapeFlag::TRIGGER_SHAPE));
+    assert!(shape.get_flags().contains(PxShapeFlag::SIMULATION_SHAPE));
+}
@@ -0,0 +1,66 @@
+use ambient_core::transform::translation;
+use ambient_native_std::shapes::Ray;
+use ambient_physics::{collider::ColliderDef, intersection::raycast_first, ALL_COLLISION_GROUPS};
+use common::TestWorld;
+use glam::{uvec2, vec3, Vec3};
+
+mod common;
+
+fn height_at(test: &TestWorld, x: f32, y: f32) -> Option<f32> {
+    raycast_first(
+        &test.world,
+        Ray::new(vec3(x, y, 100.), -Vec3::Z),
+        ALL_COLLISION_GROUPS,
+    )
+    .map(|(_, distance)| 100. - distance)
+}
+
+#[test]
+fn flat_heightfield_is_solid() {
+    for level in [0., 1.] {
+        let mut test = TestWorld::new();
+        test.spawn_collider(
+            Vec3::ZERO,
+            ColliderDef::HeightField {
+                size: uvec2(4, 4),
+                heights: vec![level; 16],
+                cell_size: 2.,
+                height_scale: 1.,
+            },
+        );
+        let falling = test.spawn_box(vec3(3., 3., level + 2.), Vec3::splat(0.5), true);
+        test.step_n(90);
+
+        let z = test.world.get(falling, translation()).unwrap().z;
+        assert!((z - (level + 0.5)).abs() < 0.05, "{level}: {z}");
+        let height = height_at(&test, 1., 5.).unwrap();
+        assert!((height - level).abs() < 0.01, "{level}: {height}");
+    }
+}
+
+#[test]
+fn heightfield_heights_are_laid_out_by_row() {
+    let mut test = TestWorld::new();
+    // Rises along X, and is the same along Y
+    let heights = (0..3).flat_map(|_| [0., 1., 2., 3.]).collect::<Vec<f32>>();
+    test.spawn_collider(
+        vec3(10., 0., 0.),
+        ColliderDef::HeightField {
+            size: uvec2(4, 3),
+            heights,
+            cell_size: 1.,
+            height_scale: 2.,
+        },
+    );
+    test.step();
+
+    for (x, expected) in [(10.5, 1.), (11.5, 3.), (12.5, 5.)] {
+        for y in [0.5, 1.5] {
+            let height = height_at(&test, x, y).unwrap();
+            assert!((height - expected).abs() < 0.01, "({x}, {y}): {height}");
+        }
+    }
+    // The heightfield only covers 4 by 3 samples, 1 meter apart
+    assert_eq!(height_at(&test, 13.5, 1.), None);
+    assert_eq!(height_at(&test, 11., 2.5), None);
+}
@@ -0,0 +1,93 @@
+use ambient_core::transform::translation;
+use ambient_ecs::{generated::physics::types::JointType, Entity, EntityId};
+use ambient_physics::{
+    joint_break_force, joint_broken, joint_drive_damping, joint_drive_stiffness, joint_entity0,
+    joint_entity1, joint_frame0, joint_type,
+    joints::{joint_breaks, physics_joint},
+};
+use common::TestWorld;
+use glam::{vec3, Mat4, Vec3};
+use physxx::{PxAny, PxBase, PxD6Drive};
+
+mod common;
+
+fn joint_between(ty: JointType, id0: EntityId, id1: EntityId) -> Entity {
+    Entity::new()
+        .with(joint_type(), ty)
+        .with(joint_entity0(), id0)
+        .with(joint_entity1(), id1)
+}
+
+#[test]
+fn fixed_joint_holds_body_in_place() {
+    let mut test = TestWorld::new();
+    let body = test.spawn_box(vec3(0., 0., 5.), Vec3::splat(0.5), true);
+    let joint = joint_between(JointType::Fixed, EntityId::null(), body)
+        .with(joint_frame0(), Mat4::from_translation(vec3(0., 0., 5.)))
+        .spawn(&mut test.world);
+
+    test.step_n(60);
+    assert!(test.world.has_component(joint, physics_joint()));
+    let position = test.world.get(body, translation()).unwrap();
+    assert!(position.distance(vec3(0., 0., 5.)) < 0.01, "{position}");
+}
+
+#[test]
+fn weak_joint_breaks() {
+    let mut test = TestWorld::new();
+    let body = test.spawn_box(vec3(0., 0., 5.), Vec3::splat(0.5), true);
+    let joint = joint_between(JointType::Fixed, EntityId::null(), body)
+        .with(joint_frame0(), Mat4::from_translation(vec3(0., 0., 5.)))
+        .with(joint_break_force(), 0.001)
+        .spawn(&mut test.world);
+
+    let mut breaks = Vec::new();
+    for _ in 0..60 {
+        test.step();
+        breaks.extend(test.world.resource(joint_breaks()).iter().copied());
+    }
+    assert_eq!(breaks, vec![(joint, EntityId::null(), body)]);
+    assert!(test.world.has_component(joint, joint_broken()));
+    assert!(!test.world.has_component(joint, physics_joint()));
+    // The body is free to fall once the joint broke
+    assert!(test.world.get(body, translation()).unwrap().z < 5.);
+}
+
+#[test]
+fn d6_drive_is_only_set_when_configured() {
+    let mut test = TestWorld::new();
+    let body = test.spawn_box(vec3(0., 0., 5.), Vec3::splat(0.5), true);
+    let joint = joint_between(JointType::D6, EntityId::null(), body)
+        .with(joint_frame0(), Mat4::from_translation(vec3(0., 0., 5.)))
+        .spawn(&mut test.world);
+    test.step();
+
+    let drive = |test: &TestWorld| {
+        let PxAny::PxD6Joint(d6) = test.world.get(joint, physics_joint()).unwrap().as_px_any()
+        else {
+            panic!("Expected a D6 joint");
+        };
+        let drive = d6.get_drive(PxD6Drive::Slerp);
+        (drive.stiffness, drive.damping)
+    };
+    assert_eq!(drive(&test), (0., 0.));
+
+    test.world
+        .add_component(joint, joint_drive_stiffness(), 100.)
+        .unwrap();
+    test.world
+        .add_component(joint, joint_drive_damping(), 10.)
+        .unwrap();
+    test.step();
+    assert_eq!(drive(&test), (100., 10.));
+
+    // Removing the drive turns it off again
+    test.world
+        .remove_component(joint, joint_drive_stiffness())
+        .unwrap();
+    test.world
+        .remove_component(joint, joint_drive_damping())
+        .unwrap();
+    test.step();
+    assert_eq!(drive(&test), (0., 0.));
+}
@@ -0,0 +1,161 @@
+use ambient_ecs::{Entity, EntityId};
+use ambient_physics::{
+    collision_group,
+    intersection::{overlap, sweep, sweep_first, QueryShape},
+    ALL_COLLISION_GROUPS,
+};
+use common::TestWorld;
+use glam::{vec3, Quat, Vec3};
+
+mod common;
+
+const NEAR: u32 = 1 << 1;
+const FAR: u32 = 1 << 2;
+
+/// Spawns a box at x = 3 and another at x = 6, both a meter wide, in different groups.
+fn spawn_boxes(test: &mut TestWorld) -> (EntityId, EntityId) {
+    let near = test.spawn_box_with(
+        vec3(3., 0., 0.),
+        Vec3::splat(0.5),
+        false,
+        Entity::new().with(collision_group(), NEAR),
+    );
+    let far = test.spawn_box_with(
+        vec3(6., 0., 0.),
+        Vec3::splat(0.5),
+        false,
+        Entity::new().with(collision_group(), FAR),
+    );
+    test.step();
+    (near, far)
+}
+
+#[test]
+fn sweeps_return_the_closest_hit_first() {
+    let mut test = TestWorld::new();
+    let (near, far) = spawn_boxes(&mut test);
+    let sphere = QueryShape::Sphere { radius: 0.5 };
+
+    let hit = sweep_first(
+        &test.world,
+        sphere,
+        Vec3::ZERO,
+        Quat::IDENTITY,
+        Vec3::X,
+        100.,
+        ALL_COLLISION_GROUPS,
+    )
+    .unwrap();
+    assert_eq!(hit.entity, near);
+    // The sphere touches the box once its edge reaches the box's face at x = 2.5
+    assert!((hit.distance - 2.).abs() < 0.01, "{}", hit.distance);
+    assert!((hit.normal - -Vec3::X).length() < 0.01, "{}", hit.normal);
+
+    let hits = sweep(
+        &test.world,
+        sphere,
+        Vec3::ZERO,
+        Quat::IDENTITY,
+        Vec3::X,
+        100.,
+        ALL_COLLISION_GROUPS,
+    );
+    assert_eq!(hits.first().map(|hit| hit.entity), Some(near));
+    assert!(hits.windows(2).all(|w| w[0].distance <= w[1].distance));
+
+    // Sweeping in the other direction, or not far enough, misses
+    let miss = |direction: Vec3, max_distance: f32| {
+        sweep_first(
+            &test.world,
+            sphere,
+            Vec3::ZERO,
+            Quat::IDENTITY,
+            direction,
+            max_distance,
+            ALL_COLLISION_GROUPS,
+        )
+        .is_none()
+    };
+    assert!(miss(-Vec3::X, 100.));
+    assert!(miss(Vec3::X, 1.5));
+
+    // Only the groups in the mask are hit
+    let hit = sweep_first(
+        &test.world,
+        QueryShape::Cuboid {
+            half_extents: Vec3::splat(0.25),
+        },
+        Vec3::ZERO,
+        Quat::IDENTITY,
+        Vec3::X,
+        100.,
+        FAR,
+    )
+    .unwrap();
+    assert_eq!(hit.entity, far);
+    assert!((hit.distance - 5.25).abs() < 0.01, "{}", hit.distance);
+}
+
+#[test]
+fn overlaps_return_the_colliders_inside_the_shape() {
+    let mut test = TestWorld::new();
+    let (near, far) = spawn_boxes(&mut test);
+    let overlaps = |shape: QueryShape, position: Vec3, rotation: Quat, mask: u32| {
+        overlap(&test.world, shape, position, rotation, mask)
+    };
+
+    let sphere = QueryShape::Sphere { radius: 1. };
+    assert_eq!(
+        overlaps(
+            sphere,
+            vec3(2., 0., 0.),
+            Quat::IDENTITY,
+            ALL_COLLISION_GROUPS
+        ),
+        vec![near]
+    );
+    assert!(overlaps(sphere, Vec3::ZERO, Quat::IDENTITY, ALL_COLLISION_GROUPS).is_empty());
+    assert!(overlaps(sphere, vec3(2., 0., 0.), Quat::IDENTITY, FAR).is_empty());
+
+    let both = overlaps(
+        QueryShape::Cuboid {
+            half_extents: vec3(3., 1., 1.),
+        },
+        vec3(4.5, 0., 0.),
+        Quat::IDENTITY,
+        ALL_COLLISION_GROUPS,
+    );
+    assert_eq!(both.len(), 2);
+    assert!(both.contains(&near) && both.contains(&far));
+}
+
+#[test]
+fn capsules_extend_along_their_local_z_axis() {
+    let mut test = TestWorld::new();
+    let (near, _) = spawn_boxes(&mut test);
+    let capsule = QueryShape::Capsule {
+        radius: 0.25,
+        half_height: 1.5,
+    };
+
+    // Standing upright next to the box, the capsule does not reach it...
+    assert!(overlap(
+        &test.world,
+        capsule,
+        vec3(1.5, 0., 0.),
+        Quat::IDENTITY,
+        ALL_COLLISION_GROUPS
+    )
+    .is_empty());
+    // ...but it does once it is tipped over towards it
+    assert_eq!(
+        overlap(
+            &test.world,
+            capsule,
+            vec3(1.5, 0., 0.),
+            Quat::from_rotation_y(std::f32::consts::FRAC_PI_2),
+            ALL_COLLISION_GROUPS
+        ),
+        vec![near]
+    );
+}
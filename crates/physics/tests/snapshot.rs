@@ -0,0 +1,69 @@
+use ambient_core::transform::translation;
+use ambient_ecs::EntityId;
+use ambient_physics::{
+    linear_velocity,
+    snapshot::{restore_snapshot, take_snapshot},
+};
+use common::TestWorld;
+use glam::{vec3, Vec3};
+
+mod common;
+
+/// Steps the world, and returns where `id` was after each tick.
+fn trajectory(test: &mut TestWorld, id: EntityId, ticks: usize) -> Vec<Vec3> {
+    (0..ticks)
+        .map(|_| {
+            test.step();
+            test.world.get(id, translation()).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn restoring_a_snapshot_rolls_the_simulation_back() {
+    let mut test = TestWorld::new();
+    test.spawn_box(vec3(0., 0., -0.5), vec3(10., 10., 0.5), false);
+    let falling = test.spawn_box(vec3(0., 0., 3.), Vec3::splat(0.5), true);
+    test.step_n(10);
+
+    let snapshot = take_snapshot(&test.world).unwrap();
+    let position = test.world.get(falling, translation()).unwrap();
+    let velocity = test.world.get(falling, linear_velocity()).unwrap();
+    let expected = trajectory(&mut test, falling, 40);
+
+    let spawned_after = test.spawn_box(vec3(20., 0., 1.), Vec3::splat(0.5), true);
+    test.step();
+    restore_snapshot(&mut test.world, &snapshot).unwrap();
+
+    assert!(!test.world.exists(spawned_after));
+    assert_eq!(test.world.get(falling, translation()).unwrap(), position);
+    assert_eq!(
+        test.world.get(falling, linear_velocity()).unwrap(),
+        velocity
+    );
+
+    // The box lands on the ground just like it did the first time
+    let actual = trajectory(&mut test, falling, 40);
+    for (actual, expected) in actual.iter().zip(&expected) {
+        assert!(actual.distance(*expected) < 1e-4, "{actual} != {expected}");
+    }
+}
+
+#[test]
+fn restoring_a_snapshot_respawns_despawned_entities() {
+    let mut test = TestWorld::new();
+    let ground = test.spawn_box(vec3(0., 0., -0.5), vec3(10., 10., 0.5), false);
+    test.step();
+
+    let snapshot = take_snapshot(&test.world).unwrap();
+    test.world.despawn(ground);
+    test.step();
+    restore_snapshot(&mut test.world, &snapshot).unwrap();
+    test.step();
+
+    // The ground is solid again
+    let falling = test.spawn_box(vec3(0., 0., 1.), Vec3::splat(0.5), true);
+    test.step_n(90);
+    let z = test.world.get(falling, translation()).unwrap().z;
+    assert!((z - 0.5).abs() < 0.05, "{z}");
+}
@@ -2,11 +2,54 @@ use serde::{Deserialize, Serialize};
 
 use crate::is_false;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct AudioPipeline {
     /// Whether or not the audio should be converted to Ogg Vorbis.
+    ///
+    /// This is the same as setting `codec` to `Vorbis`.
     #[serde(default)]
     #[serde(skip_serializing_if = "is_false")]
     pub convert: bool,
+    /// The codec that the audio is encoded with.
+    ///
+    /// If not set, MP3 files are converted to Ogg Vorbis, WAV files are converted if `convert` is
+    /// set, and all other files are kept as they are.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub codec: Option<AudioCodec>,
+    /// The target bitrate of audio that is encoded with Ogg Vorbis, in kilobits per second.
+    ///
+    /// If not set, the audio is encoded with a high quality variable bitrate.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitrate: Option<u32>,
+    /// Normalizes the loudness of the audio to this RMS level, in dBFS (for example, `-16.0`).
+    ///
+    /// The audio has to be re-encoded to be normalized, so this is only applied to audio that is
+    /// encoded with Ogg Vorbis. The gain is limited so that the audio does not clip.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalize: Option<f32>,
+}
+
+impl AudioPipeline {
+    /// The codec that audio files with the extension `ext` are encoded with.
+    pub fn codec_for(&self, ext: &str) -> AudioCodec {
+        match (self.codec, ext) {
+            (Some(codec), _) => codec,
+            (None, "mp3") => AudioCodec::Vorbis,
+            (None, "wav") if self.convert => AudioCodec::Vorbis,
+            (None, _) => AudioCodec::Original,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioCodec {
+    /// Keep the original file. WAV, Ogg Vorbis, Opus, FLAC and MP3 files can be played as they
+    /// are.
+    Original,
+    /// Encode the audio with Ogg Vorbis, which is much smaller than WAV or FLAC.
+    Vorbis,
 }
@@ -1,7 +1,7 @@
 pub mod audio;
 pub mod materials;
 pub mod models;
-pub use audio::AudioPipeline;
+pub use audio::{AudioCodec, AudioPipeline};
 pub use materials::{MaterialsImporter, MaterialsPipeline, PipelinePbrMaterial};
 pub use models::{Collider, ModelImporter, ModelsPipeline};
 use serde::{Deserialize, Serialize};
@@ -0,0 +1,33 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AudioSettings {
+    /// The volume of all audio, from 0 to 1
+    #[serde(default = "default_volume")]
+    pub master_volume: f32,
+    /// The volume of each audio bus, such as `music`, `sfx`, `voice` or `ui`, from 0 to 1
+    #[serde(default = "default_bus_volumes")]
+    pub bus_volumes: BTreeMap<String, f32>,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: default_volume(),
+            bus_volumes: default_bus_volumes(),
+        }
+    }
+}
+
+fn default_volume() -> f32 {
+    1.0
+}
+
+fn default_bus_volumes() -> BTreeMap<String, f32> {
+    ["music", "sfx", "voice", "ui"]
+        .into_iter()
+        .map(|bus| (bus.to_string(), default_volume()))
+        .collect()
+}
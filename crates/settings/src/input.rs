@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InputSettings {
+    /// How far the gamepad sticks have to move from their center before they register, from 0 to 1
+    #[serde(default = "default_stick_deadzone")]
+    pub gamepad_stick_deadzone: f32,
+    /// How far the gamepad triggers have to be pulled before they register, from 0 to 1
+    #[serde(default = "default_trigger_deadzone")]
+    pub gamepad_trigger_deadzone: f32,
+    /// The bindings that replace the defaults of the input actions and axes declared by packages,
+    /// by package ID and then by action ID (or `axis_id.positive`/`axis_id.negative` for axes)
+    #[serde(default)]
+    pub bindings: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+}
+
+impl Default for InputSettings {
+    fn default() -> Self {
+        Self {
+            gamepad_stick_deadzone: default_stick_deadzone(),
+            gamepad_trigger_deadzone: default_trigger_deadzone(),
+            bindings: Default::default(),
+        }
+    }
+}
+
+fn default_stick_deadzone() -> f32 {
+    0.15
+}
+
+fn default_trigger_deadzone() -> f32 {
+    0.05
+}
@@ -714,6 +714,23 @@ impl TerrainStateCpu {
         self.heightmap.shape()
     }
 
+    /// The distance, in meters, between two texels of the heightmap.
+    pub fn texel_size(&self) -> f32 {
+        self.size.size_in_meters() / self.heightmap.shape()[2] as f32
+    }
+
+    /// Returns the size and heights of this terrain in the layout used by `heightfield_collider`
+    /// and `heightfield_collider_size`, e.g. to build a collider from the GPU heightmap read-back.
+    /// The samples are [`texel_size`](Self::texel_size) apart.
+    pub fn heightfield_collider(&self) -> (UVec2, Vec<f32>) {
+        let heightmap = self.heightmap.slice(s![..2, .., ..]).sum_axis(Axis(0));
+        let shape = heightmap.shape();
+        (
+            UVec2::new(shape[1] as u32, shape[0] as u32),
+            heightmap.iter().copied().collect(),
+        )
+    }
+
     pub fn to_gpu(&self, gpu: &Gpu, assets: &AssetCache) -> TerrainState {
         let state = TerrainState::new_empty(gpu, assets, self.size.clone());
         state.heightmap.write_array(gpu, &self.heightmap);
@@ -1037,7 +1054,10 @@ pub fn px_rigid_static_from_heightmap(
     }
     let hfd = PxHeightFieldDesc::new(texture_size as u32, texture_size as u32, &quantized.samples);
 
-    let xy_scale = 1.;
+    // The terrain is `total_size` meters across, and its heights are sampled
+    // `size_in_meters / texture_size` apart (see `TerrainStateCpu::texel_size`), so the samples
+    // of the collider have to be spaced the same way for it to match the terrain
+    let xy_scale = total_size as f32 / texture_size as f32;
     PxRigidStaticRef::new_with_geometry(
         physics.physics,
         &PxTransform::identity(),
@@ -190,6 +190,44 @@ impl wit::component::Host for Bindings {
     }
 }
 
+impl wit::timer::Host for Bindings {
+    fn start(
+        &mut self,
+        name: String,
+        clock: wit::timer::Clock,
+        delay: f32,
+        interval: Option<f32>,
+    ) -> wasm_bridge::Result<()> {
+        let id = self.id;
+        shared::implementation::timer::start(self.world_mut(), id, name, clock, delay, interval)
+    }
+
+    fn cancel(&mut self, name: String) -> wasm_bridge::Result<()> {
+        let id = self.id;
+        shared::implementation::timer::cancel(self.world_mut(), id, name)
+    }
+
+    fn remaining(&mut self, name: String) -> wasm_bridge::Result<Option<f32>> {
+        shared::implementation::timer::remaining(self.world(), self.id, name)
+    }
+
+    fn set_frame_wakeups(&mut self, enabled: bool) -> wasm_bridge::Result<()> {
+        self.base.frame_wakeups = enabled;
+        Ok(())
+    }
+}
+
+impl wit::hot_reload::Host for Bindings {
+    fn save_state(&mut self, state: Vec<u8>) -> wasm_bridge::Result<()> {
+        self.base.saved_state = Some(state);
+        Ok(())
+    }
+
+    fn take_state(&mut self) -> wasm_bridge::Result<Option<Vec<u8>>> {
+        Ok(self.base.restored_state.take())
+    }
+}
+
 impl wit::message::Host for Bindings {
     fn subscribe(&mut self, name: String) -> wasm_bridge::Result<()> {
         shared::implementation::message::subscribe(&mut self.base.subscribed_messages, name)
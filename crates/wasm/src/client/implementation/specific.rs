@@ -2,7 +2,7 @@
 //!
 //! If implementing a trait that is also available on the server, it should go in [super].
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use ambient_core::{
     async_ecs::async_run,
@@ -15,7 +15,7 @@ use ambient_ecs::generated::input::messages::ClipboardGet;
 use ambient_gpu::texture::Texture;
 use ambient_input::{player_prev_raw_input, player_raw_input};
 use ambient_native_std::mesh::MeshBuilder;
-use ambient_network::client::client_state;
+use ambient_network::{client::client_state, prediction};
 use ambient_procedurals::{
     new_material_handle, new_mesh_handle, new_sampler_handle, new_texture_handle,
     procedural_storage,
@@ -57,11 +57,13 @@ impl wit::client_message::Host for Bindings {
                     .context("no game client")?
                     .transport
                     .clone();
+                let sequence = prediction::next_input_sequence(world, &name, &data);
 
                 message::send_networked(
                     world,
                     connection,
                     module_id,
+                    sequence,
                     &name,
                     &data,
                     matches!(target, WitTarget::ServerReliable),
@@ -148,6 +150,33 @@ impl wit::client_input::Host for Bindings {
             .resource(ambient_core::window::window_ctl())
             .send(ambient_core::window::WindowCtl::GrabCursor(grab_mode))?)
     }
+
+    fn rumble_gamepad(
+        &mut self,
+        gamepad: u32,
+        strong: f32,
+        weak: f32,
+        duration: f32,
+    ) -> anyhow::Result<()> {
+        Ok(self
+            .world()
+            .resource(ambient_core::window::window_ctl())
+            .send(ambient_core::window::WindowCtl::RumbleGamepad {
+                gamepad,
+                strong,
+                weak,
+                duration: Duration::try_from_secs_f32(duration).unwrap_or_default(),
+            })?)
+    }
+
+    fn set_touch_mouse_emulation(&mut self, enabled: bool) -> anyhow::Result<()> {
+        Ok(self
+            .world()
+            .resource(ambient_core::window::window_ctl())
+            .send(ambient_core::window::WindowCtl::SetTouchMouseEmulation(
+                enabled,
+            ))?)
+    }
 }
 impl wit::client_camera::Host for Bindings {
     fn clip_position_to_world_ray(
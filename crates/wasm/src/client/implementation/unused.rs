@@ -105,6 +105,58 @@ impl wit::server_physics::Host for Bindings {
         unsupported()
     }
 
+    fn raycast_first_filtered(
+        &mut self,
+        _origin: wit::types::Vec3,
+        _direction: wit::types::Vec3,
+        _collision_mask: u32,
+    ) -> anyhow::Result<Option<(wit::types::EntityId, f32)>> {
+        unsupported()
+    }
+
+    fn raycast_filtered(
+        &mut self,
+        _origin: wit::types::Vec3,
+        _direction: wit::types::Vec3,
+        _collision_mask: u32,
+    ) -> anyhow::Result<Vec<(wit::types::EntityId, f32)>> {
+        unsupported()
+    }
+
+    fn sweep_first(
+        &mut self,
+        _shape: wit::server_physics::QueryShape,
+        _origin: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _direction: wit::types::Vec3,
+        _max_distance: f32,
+        _collision_mask: u32,
+    ) -> anyhow::Result<Option<wit::server_physics::ShapeCastHit>> {
+        unsupported()
+    }
+
+    fn sweep(
+        &mut self,
+        _shape: wit::server_physics::QueryShape,
+        _origin: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _direction: wit::types::Vec3,
+        _max_distance: f32,
+        _collision_mask: u32,
+    ) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+        unsupported()
+    }
+
+    fn overlap(
+        &mut self,
+        _shape: wit::server_physics::QueryShape,
+        _position: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _collision_mask: u32,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        unsupported()
+    }
+
     fn move_character(
         &mut self,
         _entity: wit::types::EntityId,
@@ -159,3 +211,33 @@ impl wit::server_ambient_package::Host for Bindings {
         unsupported()
     }
 }
+
+impl wit::server_world::Host for Bindings {
+    fn save(&mut self) -> anyhow::Result<Result<(), String>> {
+        unsupported()
+    }
+    fn was_restored(&mut self) -> anyhow::Result<bool> {
+        unsupported()
+    }
+}
+
+impl wit::server_storage::Host for Bindings {
+    fn get(&mut self, _: String) -> anyhow::Result<Result<Option<Vec<u8>>, String>> {
+        unsupported()
+    }
+    fn set(&mut self, _: String, _: Vec<u8>) -> anyhow::Result<Result<(), String>> {
+        unsupported()
+    }
+    fn delete(&mut self, _: String) -> anyhow::Result<Result<(), String>> {
+        unsupported()
+    }
+    fn list_keys(&mut self, _: String) -> anyhow::Result<Result<Vec<String>, String>> {
+        unsupported()
+    }
+    fn transaction(
+        &mut self,
+        _: Vec<wit::server_storage::Operation>,
+    ) -> anyhow::Result<Result<(), String>> {
+        unsupported()
+    }
+}
@@ -3,8 +3,8 @@ use ambient_ecs::World;
 use ambient_native_std::asset_cache::AssetCache;
 use ambient_network::{
     client::{
-        bi_stream_handlers, datagram_handlers, uni_stream_handlers, PlatformRecvStream,
-        PlatformSendStream,
+        bi_stream_handlers, datagram_handlers, uni_stream_handlers, ClientRecvStream,
+        PlatformRecvStream, PlatformSendStream,
     },
     log_network_result, unwrap_log_network_err, WASM_BISTREAM_ID, WASM_DATAGRAM_ID,
     WASM_UNISTREAM_ID,
@@ -14,7 +14,7 @@ use ambient_sys::task::PlatformBoxFuture;
 use anyhow::Context;
 use bytes::Bytes;
 
-use std::{pin::Pin, sync::Arc};
+use std::sync::Arc;
 
 use crate::shared::implementation::message::{self, process_network_message, read_unistream};
 
@@ -51,13 +51,13 @@ fn on_bistream(
 fn on_unistream(
     world: &mut World,
     _asset_cache: AssetCache,
-    mut recv_stream: PlatformRecvStream,
+    mut recv_stream: ClientRecvStream,
 ) -> PlatformBoxFuture<'static, ()> {
     // Reads an incoming unistream and dispatches to WASM
     let async_run = world.resource(async_run()).clone();
     PlatformBoxFuture::new(async move {
-        let (remote_module_id, name, data) =
-            unwrap_log_network_err!(read_unistream(Pin::new(&mut recv_stream))
+        let (remote_module_id, sequence, name, data) =
+            unwrap_log_network_err!(read_unistream(recv_stream.as_mut())
                 .await
                 .context("Failed to read unistream"));
 
@@ -66,6 +66,7 @@ fn on_unistream(
                 world,
                 None,
                 remote_module_id,
+                sequence,
                 name,
                 data
             ));
@@ -0,0 +1,182 @@
+//! Lets client modules predict the effect of the messages they send to the server.
+//!
+//! While a module is subscribed to `PredictInput`, the messages that the server has not
+//! acknowledged yet are replayed to the modules with it after every update from the server. See
+//! [ambient_network::prediction] for how they are reconciled.
+use std::sync::{Arc, OnceLock};
+
+use ambient_ecs::{generated::messages, query, DynSystem, FnSystem, Message, World};
+use ambient_network::prediction::{self, PredictionSystem};
+
+use crate::shared::{message::MessageExt, module_state, ModuleStateBehavior};
+
+fn predict_input_system() -> PredictionSystem {
+    static SYSTEM: OnceLock<PredictionSystem> = OnceLock::new();
+    SYSTEM
+        .get_or_init(|| {
+            Arc::new(|world, input| {
+                // Modules can't receive messages while they are running, so they predict the
+                // messages they send themselves, and are only sent the replays
+                if !input.replayed {
+                    return;
+                }
+                messages::PredictInput::new(input.sequence, input.name.clone(), input.data.clone())
+                    .run(world, None)
+                    .ok();
+            })
+        })
+        .clone()
+}
+
+fn is_predicting(world: &World) -> bool {
+    query(module_state())
+        .iter(world, None)
+        .any(|(_, state)| state.supports_message(messages::PredictInput::id()))
+}
+
+/// Replays the unacknowledged messages to the modules while any of them is subscribed to
+/// `PredictInput`.
+pub(super) fn system() -> DynSystem {
+    Box::new(FnSystem::new(|world, _| {
+        let system = predict_input_system();
+        let registered = prediction::has_prediction_system(world, &system);
+        match (is_predicting(world), registered) {
+            (true, false) => prediction::add_prediction_system(world, system),
+            (false, true) => prediction::remove_prediction_system(world, &system),
+            _ => {}
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use ambient_core::player::{local_user_id, user_id};
+    use ambient_ecs::{
+        components, Entity, EntityId, FrameEvent, Networked, Predicted, System, WorldContext,
+        WorldDiff,
+    };
+    use ambient_network::prediction::{input_sequence_ack, next_input_sequence};
+
+    use super::*;
+    use crate::shared::{
+        is_module, message::WorldEventSource, messenger, module_errors, ModuleState, ModuleUsage,
+    };
+
+    components!("test", {
+        @[Networked, Predicted]
+        position: f32,
+    });
+
+    /// Stands in for a module that moves the player by the amount in each `move` message.
+    struct TestModule {
+        player: EntityId,
+    }
+    impl ModuleStateBehavior for TestModule {
+        fn run(
+            &mut self,
+            world: &mut World,
+            _message_source: &WorldEventSource,
+            _message_name: &str,
+            message_data: &[u8],
+        ) -> anyhow::Result<()> {
+            let input = messages::PredictInput::deserialize_message(message_data)?;
+            if input.name == "move" {
+                *world.get_mut(self.player, position())? += input.data[0] as f32;
+            }
+            Ok(())
+        }
+
+        fn drain_spawned_entities(&mut self) -> HashSet<EntityId> {
+            HashSet::new()
+        }
+
+        fn adopt_spawned_entities(&mut self, _entities: HashSet<EntityId>) {}
+
+        fn save_state(&mut self, _world: &mut World) -> anyhow::Result<Option<Vec<u8>>> {
+            Ok(None)
+        }
+
+        fn listen_to_message(&mut self, _message_name: String) {}
+
+        fn supports_message(&self, message_name: &str) -> bool {
+            message_name == messages::PredictInput::id()
+        }
+
+        fn usage(&self) -> ModuleUsage {
+            ModuleUsage::default()
+        }
+    }
+
+    /// Sends a message to the server from the module, which predicts it straight away.
+    fn send_move(world: &mut World, player: EntityId, step: u8) {
+        next_input_sequence(world, "move", &[step]);
+        *world.get_mut(player, position()).unwrap() += step as f32;
+    }
+
+    /// Applies an update from the server that sets the position and acknowledges `ack`.
+    fn receive(world: &mut World, player: EntityId, value: f32, ack: u64) {
+        let mut diff =
+            WorldDiff::new()
+                .set(player, position(), value)
+                .set(player, input_sequence_ack(), ack);
+        prediction::reconcile(world, &mut diff);
+        diff.apply(world, Entity::new());
+        prediction::replay_inputs(world);
+    }
+
+    #[test]
+    fn unacknowledged_inputs_are_replayed_to_modules() {
+        ambient_ecs::init_components();
+        ambient_core::init_all_components();
+        ambient_network::init_all_components();
+        crate::shared::init_all_components();
+        init_components();
+
+        let mut world = World::new("prediction_test", WorldContext::Client);
+        world.add_resource(messenger(), Arc::new(|_, _, _, _| {}));
+        world.add_resource(local_user_id(), "alice".to_string());
+        let player = Entity::new()
+            .with(user_id(), "alice".to_string())
+            .with(position(), 0.)
+            .with(input_sequence_ack(), 0)
+            .spawn(&mut world);
+        let module = Entity::new()
+            .with(is_module(), ())
+            .with(module_errors(), Default::default())
+            .with(
+                module_state(),
+                ModuleState::from_behavior(TestModule { player }),
+            )
+            .spawn(&mut world);
+
+        let mut system = system();
+        system.run(&mut world, &FrameEvent);
+        assert!(prediction::has_prediction_system(
+            &world,
+            &predict_input_system()
+        ));
+
+        for step in [1, 2, 3] {
+            send_move(&mut world, player, step);
+        }
+        assert_eq!(world.get(player, position()).unwrap(), 6.);
+
+        // The server has only processed the first message, and moved the player a little
+        // further; the module replays the other two on top of that
+        receive(&mut world, player, 1.5, 1);
+        assert_eq!(world.get(player, position()).unwrap(), 6.5);
+
+        receive(&mut world, player, 6.5, 3);
+        assert_eq!(world.get(player, position()).unwrap(), 6.5);
+
+        // Once no module predicts the messages, the runtime stops replaying them
+        world.remove_component(module, module_state()).unwrap();
+        system.run(&mut world, &FrameEvent);
+        assert!(!prediction::has_prediction_system(
+            &world,
+            &predict_input_system()
+        ));
+    }
+}
@@ -15,7 +15,7 @@ use ambient_ecs::{
     query, EntityId, World,
 };
 use ambient_native_std::asset_url::AbsAssetUrl;
-use ambient_network::server::player_transport;
+use ambient_network::{persistence, server::player_transport};
 use reqwest::header::{HeaderMap, HeaderName};
 
 use super::super::Bindings;
@@ -28,6 +28,7 @@ use crate::shared::{
 };
 
 mod physics;
+mod storage;
 
 #[async_trait::async_trait]
 impl shared::wit::server_asset::Host for Bindings {}
@@ -92,7 +93,7 @@ fn send_networked(
         .collect();
 
     for conn in connections {
-        message::send_networked(world, conn, module_id, &name, &data, reliable)?;
+        message::send_networked(world, conn, module_id, 0, &name, &data, reliable)?;
     }
 
     Ok(())
@@ -202,3 +203,17 @@ impl shared::wit::server_ambient_package::Host for Bindings {
         Ok(())
     }
 }
+
+impl shared::wit::server_world::Host for Bindings {
+    fn save(&mut self) -> anyhow::Result<Result<(), String>> {
+        Ok(persistence::save_world_in_background(self.world_mut())
+            .map_err(|err| format!("{err:#}")))
+    }
+
+    fn was_restored(&mut self) -> anyhow::Result<bool> {
+        Ok(self
+            .world()
+            .resource_opt(persistence::restored_world())
+            .is_some())
+    }
+}
@@ -5,9 +5,14 @@ use crate::shared::{
     wit,
 };
 use ambient_native_std::shapes::Ray;
-use ambient_physics::physx::character_controller;
+use ambient_physics::{
+    collider::character_controller_filters,
+    intersection::{QueryShape, ShapeCastHit},
+    physx::character_controller,
+    ALL_COLLISION_GROUPS,
+};
 use anyhow::Context;
-use physxx::{PxControllerCollisionFlag, PxControllerFilters};
+use physxx::PxControllerCollisionFlag;
 
 impl shared::wit::server_physics::Host for Bindings {
     fn add_force(
@@ -169,26 +174,46 @@ impl shared::wit::server_physics::Host for Bindings {
         &mut self,
         origin: wit::types::Vec3,
         direction: wit::types::Vec3,
+    ) -> anyhow::Result<Option<(wit::types::EntityId, f32)>> {
+        self.raycast_first_filtered(origin, direction, ALL_COLLISION_GROUPS)
+    }
+
+    fn raycast(
+        &mut self,
+        origin: wit::types::Vec3,
+        direction: wit::types::Vec3,
+    ) -> anyhow::Result<Vec<(wit::types::EntityId, f32)>> {
+        self.raycast_filtered(origin, direction, ALL_COLLISION_GROUPS)
+    }
+
+    fn raycast_first_filtered(
+        &mut self,
+        origin: wit::types::Vec3,
+        direction: wit::types::Vec3,
+        collision_mask: u32,
     ) -> anyhow::Result<Option<(wit::types::EntityId, f32)>> {
         let direction = get_raycast_direction(direction)?;
         let result = ambient_physics::intersection::raycast_first(
             self.world(),
             Ray::new(origin.from_bindgen(), direction),
+            collision_mask,
         )
         .map(|t| (t.0.into_bindgen(), t.1.into_bindgen()));
 
         Ok(result)
     }
 
-    fn raycast(
+    fn raycast_filtered(
         &mut self,
         origin: wit::types::Vec3,
         direction: wit::types::Vec3,
+        collision_mask: u32,
     ) -> anyhow::Result<Vec<(wit::types::EntityId, f32)>> {
         let direction = get_raycast_direction(direction)?;
         let result = ambient_physics::intersection::raycast(
             self.world(),
             Ray::new(origin.from_bindgen(), direction),
+            collision_mask,
         )
         .into_iter()
         .map(|t| (t.0.into_bindgen(), t.1.into_bindgen()))
@@ -197,6 +222,75 @@ impl shared::wit::server_physics::Host for Bindings {
         Ok(result)
     }
 
+    fn sweep_first(
+        &mut self,
+        shape: wit::server_physics::QueryShape,
+        origin: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        direction: wit::types::Vec3,
+        max_distance: f32,
+        collision_mask: u32,
+    ) -> anyhow::Result<Option<wit::server_physics::ShapeCastHit>> {
+        let direction = get_raycast_direction(direction)?;
+        let result = ambient_physics::intersection::sweep_first(
+            self.world(),
+            query_shape_from_bindgen(shape),
+            origin.from_bindgen(),
+            rotation.from_bindgen(),
+            direction,
+            max_distance,
+            collision_mask,
+        )
+        .map(shape_cast_hit_into_bindgen);
+
+        Ok(result)
+    }
+
+    fn sweep(
+        &mut self,
+        shape: wit::server_physics::QueryShape,
+        origin: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        direction: wit::types::Vec3,
+        max_distance: f32,
+        collision_mask: u32,
+    ) -> anyhow::Result<Vec<wit::server_physics::ShapeCastHit>> {
+        let direction = get_raycast_direction(direction)?;
+        let result = ambient_physics::intersection::sweep(
+            self.world(),
+            query_shape_from_bindgen(shape),
+            origin.from_bindgen(),
+            rotation.from_bindgen(),
+            direction,
+            max_distance,
+            collision_mask,
+        )
+        .into_iter()
+        .map(shape_cast_hit_into_bindgen)
+        .collect();
+
+        Ok(result)
+    }
+
+    fn overlap(
+        &mut self,
+        shape: wit::server_physics::QueryShape,
+        position: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        collision_mask: u32,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        let result = ambient_physics::intersection::overlap(
+            self.world(),
+            query_shape_from_bindgen(shape),
+            position.from_bindgen(),
+            rotation.from_bindgen(),
+            collision_mask,
+        )
+        .into_bindgen();
+
+        Ok(result)
+    }
+
     fn move_character(
         &mut self,
         entity: wit::types::EntityId,
@@ -213,7 +307,7 @@ impl shared::wit::server_physics::Host for Bindings {
                     displacement.from_bindgen(),
                     min_dist,
                     elapsed_time,
-                    &PxControllerFilters::new(),
+                    &character_controller_filters(self.world(), entity.from_bindgen()),
                     None,
                 );
                 Ok(wit::server_physics::CharacterCollision {
@@ -267,3 +361,25 @@ fn get_raycast_direction(direction: wit::types::Vec3) -> anyhow::Result<glam::Ve
     }
     Ok(direction)
 }
+
+fn query_shape_from_bindgen(shape: wit::server_physics::QueryShape) -> QueryShape {
+    match shape {
+        wit::server_physics::QueryShape::Sphere(radius) => QueryShape::Sphere { radius },
+        wit::server_physics::QueryShape::Cuboid(half_extents) => QueryShape::Cuboid {
+            half_extents: half_extents.from_bindgen(),
+        },
+        wit::server_physics::QueryShape::Capsule((radius, half_height)) => QueryShape::Capsule {
+            radius,
+            half_height,
+        },
+    }
+}
+
+fn shape_cast_hit_into_bindgen(hit: ShapeCastHit) -> wit::server_physics::ShapeCastHit {
+    wit::server_physics::ShapeCastHit {
+        entity: hit.entity.into_bindgen(),
+        position: hit.position.into_bindgen(),
+        normal: hit.normal.into_bindgen(),
+        distance: hit.distance,
+    }
+}
@@ -0,0 +1,415 @@
+//! Key-value storage for server modules, with one directory per package.
+//!
+//! Each key is stored in its own file, named after the base32 encoding of the key. Writes go to a
+//! temporary file that is flushed to disk before it replaces the old value, so a value is never
+//! left half-written.
+//! Transactions are first written to a journal, which is replayed if the server stops before all
+//! of the operations in it have been applied.
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+use ambient_package_semantic_native::id as package_id;
+use anyhow::Context;
+use data_encoding::BASE32_NOPAD;
+
+use super::super::super::Bindings;
+use crate::shared::{package_ref, wit};
+
+/// Keys are limited in length so that their encoded form fits in a file name on all platforms.
+const MAX_KEY_LEN: usize = 128;
+
+const KEYS_DIR: &str = "keys";
+const JOURNAL: &str = "journal";
+
+impl wit::server_storage::Host for Bindings {
+    fn get(&mut self, key: String) -> anyhow::Result<Result<Option<Vec<u8>>, String>> {
+        Ok(self.with_storage(|storage| storage.get(&key)))
+    }
+
+    fn set(&mut self, key: String, value: Vec<u8>) -> anyhow::Result<Result<(), String>> {
+        Ok(self.with_storage(|storage| storage.set(&key, &value)))
+    }
+
+    fn delete(&mut self, key: String) -> anyhow::Result<Result<(), String>> {
+        Ok(self.with_storage(|storage| storage.delete(&key)))
+    }
+
+    fn list_keys(&mut self, prefix: String) -> anyhow::Result<Result<Vec<String>, String>> {
+        Ok(self.with_storage(|storage| storage.list(&prefix)))
+    }
+
+    fn transaction(
+        &mut self,
+        operations: Vec<wit::server_storage::Operation>,
+    ) -> anyhow::Result<Result<(), String>> {
+        let operations = operations
+            .into_iter()
+            .map(|operation| (operation.key, operation.value))
+            .collect::<Vec<_>>();
+        Ok(self.with_storage(|storage| storage.transaction(&operations)))
+    }
+}
+
+impl Bindings {
+    /// Runs `f` with the storage of the package of this module. Errors are returned to the module
+    /// rather than trapping, so that it can decide how to handle them.
+    fn with_storage<R>(
+        &self,
+        f: impl FnOnce(&PackageStorage) -> anyhow::Result<R>,
+    ) -> Result<R, String> {
+        let result = (|| {
+            let Some(root) = &self.storage_path else {
+                anyhow::bail!("Storage is not supported on hosted servers");
+            };
+            let world = self.world();
+            let package = world.get(self.id, package_ref())?;
+            let package_id = world
+                .get_ref(package, package_id())
+                .context("The package of this module has no ID")?;
+            f(&PackageStorage::open(root, package_id)?)
+        })();
+        result.map_err(|err| format!("{err:#}"))
+    }
+}
+
+struct PackageStorage {
+    path: PathBuf,
+}
+
+impl PackageStorage {
+    fn open(root: &Path, package_id: &str) -> anyhow::Result<Self> {
+        let storage = Self {
+            path: root.join(BASE32_NOPAD.encode(package_id.as_bytes())),
+        };
+        std::fs::create_dir_all(storage.path.join(KEYS_DIR))
+            .with_context(|| format!("Failed to create {}", storage.path.display()))?;
+        storage.recover()?;
+        Ok(storage)
+    }
+
+    fn get(&self, key: &str) -> anyhow::Result<Option<Vec<u8>>> {
+        match std::fs::read(self.key_path(key)?) {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err).with_context(|| format!("Failed to read {key:?}")),
+        }
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> anyhow::Result<()> {
+        let path = self.key_path(key)?;
+        write_durably(&path, value).with_context(|| format!("Failed to write {key:?}"))
+    }
+
+    fn delete(&self, key: &str) -> anyhow::Result<()> {
+        match std::fs::remove_file(self.key_path(key)?) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+                Err(err).with_context(|| format!("Failed to delete {key:?}"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns all keys starting with `prefix`, in sorted order.
+    fn list(&self, prefix: &str) -> anyhow::Result<Vec<String>> {
+        let mut keys = Vec::new();
+        for entry in std::fs::read_dir(self.path.join(KEYS_DIR))? {
+            let file_name = entry?.file_name();
+            // Skips the temporary files of writes that were interrupted
+            let Some(key) = file_name
+                .to_str()
+                .and_then(|name| BASE32_NOPAD.decode(name.as_bytes()).ok())
+                .and_then(|key| String::from_utf8(key).ok())
+            else {
+                continue;
+            };
+            if key.starts_with(prefix) {
+                keys.push(key);
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    /// Applies all of `operations`, or none of them if any of their keys is invalid. A value of
+    /// `None` deletes the key.
+    fn transaction(&self, operations: &[(String, Option<Vec<u8>>)]) -> anyhow::Result<()> {
+        for (key, _) in operations {
+            self.key_path(key)?;
+        }
+
+        let mut journal = Vec::new();
+        for (key, value) in operations {
+            write_bytes(&mut journal, key.as_bytes());
+            match value {
+                Some(value) => {
+                    journal.push(1);
+                    write_bytes(&mut journal, value);
+                }
+                None => journal.push(0),
+            }
+        }
+
+        // The transaction is committed once the journal has been renamed into place
+        write_durably(&self.path.join(JOURNAL), &journal)
+            .context("Failed to commit the transaction")?;
+
+        self.apply(operations)?;
+        std::fs::remove_file(self.path.join(JOURNAL))?;
+        Ok(())
+    }
+
+    /// Finishes a transaction that was committed but not fully applied, and discards one that
+    /// was never committed.
+    fn recover(&self) -> anyhow::Result<()> {
+        let _ = std::fs::remove_file(self.path.join(JOURNAL).with_extension("tmp"));
+
+        let journal_path = self.path.join(JOURNAL);
+        let mut journal = Vec::new();
+        match File::open(&journal_path) {
+            Ok(mut file) => file.read_to_end(&mut journal)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut operations = Vec::new();
+        let mut data = journal.as_slice();
+        while !data.is_empty() {
+            let key = read_bytes(&mut data).context("Corrupt storage journal")?;
+            let value = match data.split_first() {
+                Some((1, rest)) => {
+                    data = rest;
+                    Some(read_bytes(&mut data).context("Corrupt storage journal")?)
+                }
+                Some((0, rest)) => {
+                    data = rest;
+                    None
+                }
+                _ => anyhow::bail!("Corrupt storage journal"),
+            };
+            operations.push((String::from_utf8(key)?, value));
+        }
+
+        tracing::info!(
+            "Finishing an interrupted storage transaction in {}",
+            self.path.display()
+        );
+        self.apply(&operations)?;
+        std::fs::remove_file(journal_path)?;
+        Ok(())
+    }
+
+    fn apply(&self, operations: &[(String, Option<Vec<u8>>)]) -> anyhow::Result<()> {
+        for (key, value) in operations {
+            match value {
+                Some(value) => self.set(key, value)?,
+                None => self.delete(key)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn key_path(&self, key: &str) -> anyhow::Result<PathBuf> {
+        anyhow::ensure!(!key.is_empty(), "Storage keys can not be empty");
+        anyhow::ensure!(
+            key.len() <= MAX_KEY_LEN,
+            "Storage keys can be at most {MAX_KEY_LEN} bytes long, but {key:?} is {} bytes long",
+            key.len()
+        );
+        Ok(self
+            .path
+            .join(KEYS_DIR)
+            .join(BASE32_NOPAD.encode(key.as_bytes())))
+    }
+}
+
+/// Replaces the contents of `path` with `data` through a temporary file, and waits for both to
+/// reach the disk.
+fn write_durably(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    let temp_path = path.with_extension("tmp");
+    let mut file = File::create(&temp_path)?;
+    file.write_all(data)?;
+    file.sync_all()?;
+    std::fs::rename(&temp_path, path)?;
+    // The rename is only durable once the directory has been synced, which is not possible on
+    // Windows
+    #[cfg(unix)]
+    File::open(path.parent().context("The path has no parent")?)?.sync_all()?;
+    Ok(())
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn read_bytes(data: &mut &[u8]) -> Option<Vec<u8>> {
+    let len = u32::from_le_bytes(data.get(..4)?.try_into().ok()?) as usize;
+    let bytes = data.get(4..4 + len)?.to_vec();
+    *data = &data[4 + len..];
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Opens the storage of a package in a fresh directory, which is removed when dropped.
+    struct TestStorage {
+        root: PathBuf,
+    }
+    impl TestStorage {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!(
+                "ambient_storage_test_{name}_{}",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&root);
+            Self { root }
+        }
+
+        fn open(&self) -> PackageStorage {
+            PackageStorage::open(&self.root, "package").unwrap()
+        }
+    }
+    impl Drop for TestStorage {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.root);
+        }
+    }
+
+    fn journal(operations: &[(&str, Option<&str>)]) -> Vec<u8> {
+        let mut journal = Vec::new();
+        for (key, value) in operations {
+            write_bytes(&mut journal, key.as_bytes());
+            match value {
+                Some(value) => {
+                    journal.push(1);
+                    write_bytes(&mut journal, value.as_bytes());
+                }
+                None => journal.push(0),
+            }
+        }
+        journal
+    }
+
+    #[test]
+    fn values_can_be_set_listed_and_deleted() {
+        let test = TestStorage::new("values");
+        let storage = test.open();
+
+        assert_eq!(storage.get("score").unwrap(), None);
+        storage.set("score", b"10").unwrap();
+        storage.set("score", b"20").unwrap();
+        storage.set("player/alice", b"a").unwrap();
+        storage.set("player/bob", b"b").unwrap();
+        assert_eq!(storage.get("score").unwrap(), Some(b"20".to_vec()));
+        // No temporary files are left behind by the writes
+        assert_eq!(
+            std::fs::read_dir(storage.path.join(KEYS_DIR))
+                .unwrap()
+                .count(),
+            3
+        );
+
+        assert_eq!(
+            storage.list("player/").unwrap(),
+            vec!["player/alice", "player/bob"]
+        );
+        assert_eq!(storage.list("").unwrap().len(), 3);
+
+        storage.delete("player/alice").unwrap();
+        storage.delete("player/alice").unwrap();
+        assert_eq!(storage.list("player/").unwrap(), vec!["player/bob"]);
+
+        // Values persist across opens
+        assert_eq!(test.open().get("score").unwrap(), Some(b"20".to_vec()));
+
+        assert!(storage.set("", b"").is_err());
+        assert!(storage.set(&"k".repeat(MAX_KEY_LEN + 1), b"").is_err());
+    }
+
+    #[test]
+    fn transactions_apply_all_operations_or_none() {
+        let test = TestStorage::new("transactions");
+        let storage = test.open();
+        storage.set("gold", b"100").unwrap();
+        storage.set("item", b"sword").unwrap();
+
+        storage
+            .transaction(&[
+                ("gold".to_string(), Some(b"50".to_vec())),
+                ("item".to_string(), None),
+                ("receipt".to_string(), Some(b"sword".to_vec())),
+            ])
+            .unwrap();
+        assert_eq!(storage.get("gold").unwrap(), Some(b"50".to_vec()));
+        assert_eq!(storage.get("item").unwrap(), None);
+        assert_eq!(storage.get("receipt").unwrap(), Some(b"sword".to_vec()));
+        assert!(!storage.path.join(JOURNAL).exists());
+
+        // An invalid key rolls back the whole transaction
+        assert!(storage
+            .transaction(&[
+                ("gold".to_string(), Some(b"0".to_vec())),
+                (String::new(), Some(b"invalid".to_vec())),
+            ])
+            .is_err());
+        assert_eq!(storage.get("gold").unwrap(), Some(b"50".to_vec()));
+        assert!(!storage.path.join(JOURNAL).exists());
+    }
+
+    #[test]
+    fn committed_transactions_are_finished_when_reopened() {
+        let test = TestStorage::new("recovery");
+        let storage = test.open();
+        storage.set("gold", b"100").unwrap();
+        storage.set("item", b"sword").unwrap();
+
+        // The server stopped after committing a transaction, but before applying all of it
+        storage.set("gold", b"50").unwrap();
+        std::fs::write(
+            storage.path.join(JOURNAL),
+            journal(&[
+                ("gold", Some("50")),
+                ("item", None),
+                ("receipt", Some("sword")),
+            ]),
+        )
+        .unwrap();
+
+        let storage = test.open();
+        assert_eq!(storage.get("gold").unwrap(), Some(b"50".to_vec()));
+        assert_eq!(storage.get("item").unwrap(), None);
+        assert_eq!(storage.get("receipt").unwrap(), Some(b"sword".to_vec()));
+        assert!(!storage.path.join(JOURNAL).exists());
+    }
+
+    #[test]
+    fn uncommitted_transactions_are_discarded_when_reopened() {
+        let test = TestStorage::new("uncommitted");
+        let storage = test.open();
+        storage.set("gold", b"100").unwrap();
+
+        // The server stopped while writing the journal
+        let temp_path = storage.path.join(JOURNAL).with_extension("tmp");
+        std::fs::write(&temp_path, journal(&[("gold", Some("0"))])).unwrap();
+
+        let storage = test.open();
+        assert_eq!(storage.get("gold").unwrap(), Some(b"100".to_vec()));
+        assert!(!temp_path.exists());
+    }
+
+    #[test]
+    fn corrupt_journals_are_reported() {
+        let test = TestStorage::new("corrupt");
+        let storage = test.open();
+        let mut corrupt = journal(&[("gold", Some("50"))]);
+        corrupt.truncate(corrupt.len() - 1);
+        std::fs::write(storage.path.join(JOURNAL), corrupt).unwrap();
+
+        assert!(PackageStorage::open(&test.root, "package").is_err());
+    }
+}
@@ -37,6 +37,12 @@ impl wit::client_input::Host for Bindings {
     fn set_cursor_lock(&mut self, _: bool) -> anyhow::Result<()> {
         unsupported()
     }
+    fn rumble_gamepad(&mut self, _: u32, _: f32, _: f32, _: f32) -> anyhow::Result<()> {
+        unsupported()
+    }
+    fn set_touch_mouse_emulation(&mut self, _: bool) -> anyhow::Result<()> {
+        unsupported()
+    }
 }
 
 impl wit::client_camera::Host for Bindings {
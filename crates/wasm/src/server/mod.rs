@@ -1,7 +1,11 @@
 use crate::shared::{self, message::MessageExt};
-use ambient_ecs::{generated::messages, query, EntityId, FnSystem, SystemGroup, World};
+use ambient_ecs::{
+    generated::{messages, physics::messages as physics_messages},
+    query, EntityId, FnSystem, SystemGroup, World,
+};
 use ambient_native_std::asset_cache::AssetCache;
 use ambient_network::server::{ForkingEvent, ShutdownEvent};
+use ambient_physics::{report_contacts, ContactEvent};
 use std::{path::PathBuf, sync::Arc};
 
 mod implementation;
@@ -12,8 +16,11 @@ pub fn initialize(
     assets: &AssetCache,
     hosted: bool,
     data_path: PathBuf,
+    limits: shared::ModuleLimits,
     messenger: Arc<dyn Fn(&World, EntityId, shared::MessageType, &str) + Send + Sync>,
 ) -> anyhow::Result<()> {
+    world.add_resource(shared::module_limits(), limits);
+    let storage_path = (!hosted).then(|| data_path.join("storage"));
     shared::initialize(
         world,
         assets,
@@ -25,6 +32,7 @@ pub fn initialize(
             reqwest_client: reqwest::Client::new(),
             last_http_request_id: 0,
             hosted,
+            storage_path: storage_path.clone(),
         }),
         if hosted {
             None
@@ -55,6 +63,58 @@ pub fn systems() -> SystemGroup {
                         .unwrap();
                 }
             })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module contact events");
+                // trigger contact and trigger volume events
+                let contact_events = match world.resource_opt(ambient_physics::contact_events()) {
+                    Some(contact_events) => contact_events.lock().clone(),
+                    None => return,
+                };
+                let reports_contacts = |world: &World, entities: &[EntityId; 2]| {
+                    entities
+                        .iter()
+                        .any(|id| world.has_component(*id, report_contacts()))
+                };
+                for event in contact_events {
+                    match event {
+                        ContactEvent::Begin {
+                            entities,
+                            positions,
+                            normals,
+                            impulse,
+                        } => {
+                            if reports_contacts(world, &entities) {
+                                physics_messages::ContactBegin::new(
+                                    entities[0],
+                                    entities[1],
+                                    positions,
+                                    normals,
+                                    impulse,
+                                )
+                                .run(world, None)
+                                .unwrap();
+                            }
+                        }
+                        ContactEvent::End { entities } => {
+                            if reports_contacts(world, &entities) {
+                                physics_messages::ContactEnd::new(entities[0], entities[1])
+                                    .run(world, None)
+                                    .unwrap();
+                            }
+                        }
+                        ContactEvent::TriggerEnter { trigger, other } => {
+                            physics_messages::TriggerEnter::new(trigger, other)
+                                .run(world, None)
+                                .unwrap();
+                        }
+                        ContactEvent::TriggerExit { trigger, other } => {
+                            physics_messages::TriggerExit::new(trigger, other)
+                                .run(world, None)
+                                .unwrap();
+                        }
+                    }
+                }
+            })),
             Box::new(FnSystem::new(move |world, _| {
                 profiling::scope!("WASM module collider loads");
                 // trigger collider loads
@@ -71,6 +131,20 @@ pub fn systems() -> SystemGroup {
                     .run(world, None)
                     .unwrap();
             })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module joint breaks");
+                // trigger joint break events
+                let joint_breaks = match world.resource_opt(ambient_physics::joints::joint_breaks())
+                {
+                    Some(joint_breaks) => joint_breaks.clone(),
+                    None => return,
+                };
+                for (joint, entity0, entity1) in joint_breaks {
+                    physics_messages::JointBreak::new(joint, entity0, entity1)
+                        .run(world, None)
+                        .unwrap();
+                }
+            })),
             Box::new(shared::systems()),
         ],
     )
@@ -108,6 +182,8 @@ struct Bindings {
     /// Whether or not this server is running in a hosted environment,
     /// and should thus have some of its functionality disabled
     hosted: bool,
+    /// Where the storage of each package is kept, if storage is available
+    storage_path: Option<PathBuf>,
 }
 
 impl Bindings {
@@ -71,7 +71,7 @@ fn on_unistream(
     let async_run = world.resource(async_run()).clone();
     let user_id = user_id.to_owned();
     world.resource(runtime()).spawn(async move {
-        let (remote_module_id, name, data) =
+        let (remote_module_id, sequence, name, data) =
             unwrap_log_network_err!(read_unistream(recv_stream.as_mut())
                 .await
                 .context("Failed to read unistream"));
@@ -81,6 +81,7 @@ fn on_unistream(
                 world,
                 Some(user_id),
                 remote_module_id,
+                sequence,
                 name,
                 data
             ));
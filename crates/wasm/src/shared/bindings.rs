@@ -9,7 +9,13 @@ pub type QueryStateMap =
 pub struct BindingsBase {
     pub spawned_entities: HashSet<EntityId>,
     pub subscribed_messages: HashSet<String>,
+    /// Whether the module wants to run every frame, even if it is not subscribed to `Frame`
+    pub frame_wakeups: bool,
     pub query_states: QueryStateMap,
+    /// The state handed over by the previous version of the module, until the module takes it
+    pub restored_state: Option<Vec<u8>>,
+    /// The state saved by the module while it is being hot reloaded
+    pub saved_state: Option<Vec<u8>>,
 }
 
 /// Represents all the bindings for the imported world
@@ -24,6 +30,8 @@ pub trait BindingsBound:
     + super::wit::message::Host
     + super::wit::player::Host
     + super::wit::ambient_package::Host
+    + super::wit::timer::Host
+    + super::wit::hot_reload::Host
     // Client
     + super::wit::client_message::Host
     + super::wit::client_player::Host
@@ -41,6 +49,8 @@ pub trait BindingsBound:
     + super::wit::server_physics::Host
     + super::wit::server_http::Host
     + super::wit::server_ambient_package::Host
+    + super::wit::server_world::Host
+    + super::wit::server_storage::Host
     + Clone
     + Sync
     + Send
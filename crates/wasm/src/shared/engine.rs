@@ -10,6 +10,27 @@ pub struct Engine {
 }
 
 impl Engine {
+    /// Creates an engine. Modules only count the fuel they consume if `consume_fuel` is set,
+    /// as counting it slows them down.
+    #[cfg_attr(target_os = "unknown", allow(unused_variables))]
+    pub fn new(consume_fuel: bool) -> anyhow::Result<Self> {
+        let mut config = wasm_bridge::Config::new();
+        #[cfg(not(target_os = "unknown"))]
+        {
+            config.debug_info(true);
+            config.wasm_backtrace_details(wasm_bridge::WasmBacktraceDetails::Enable);
+            // Used to limit how long modules can run for; see `ModuleLimits`
+            config.consume_fuel(consume_fuel);
+        }
+
+        config.wasm_component_model(true);
+
+        Ok(Engine {
+            engine: wasm_bridge::Engine::new(&config)
+                .context("Failed to create wasm execution engine")?,
+        })
+    }
+
     pub fn inner(&self) -> &wasm_bridge::Engine {
         &self.engine
     }
@@ -22,26 +43,16 @@ impl std::fmt::Debug for Engine {
 }
 
 #[derive(Debug, Clone)]
-pub struct EngineKey;
+pub struct EngineKey {
+    /// Whether the modules that are run by the engine count the fuel they consume
+    pub consume_fuel: bool,
+}
 
 impl SyncAssetKey<Result<Engine, Arc<anyhow::Error>>> for EngineKey {
     fn load(
         &self,
         _assets: ambient_native_std::asset_cache::AssetCache,
     ) -> Result<Engine, Arc<anyhow::Error>> {
-        let mut config = wasm_bridge::Config::new();
-        #[cfg(not(target_os = "unknown"))]
-        {
-            config.debug_info(true);
-            config.wasm_backtrace_details(wasm_bridge::WasmBacktraceDetails::Enable);
-        }
-
-        config.wasm_component_model(true);
-
-        Ok(Engine {
-            engine: wasm_bridge::Engine::new(&config)
-                .context("Failed to create wasm execution engine")
-                .map_err(Arc::new)?,
-        })
+        Engine::new(self.consume_fuel).map_err(Arc::new)
     }
 }
@@ -1,7 +1,7 @@
 use ambient_core::runtime;
 use ambient_ecs::{generated::wasm::components::package_ref, EntityId, World};
 use ambient_network::{
-    client::NetworkTransport, log_network_result, WASM_DATAGRAM_ID, WASM_UNISTREAM_ID,
+    client::NetworkTransport, log_network_result, prediction, WASM_DATAGRAM_ID, WASM_UNISTREAM_ID,
 };
 
 use anyhow::Context;
@@ -78,6 +78,8 @@ pub fn on_datagram(world: &mut World, user_id: Option<String>, bytes: Bytes) ->
     let package_id = cursor.read_u128::<byteorder::BigEndian>()?;
     let package_id = EntityId(package_id);
 
+    let sequence = cursor.read_u64::<byteorder::BigEndian>()?;
+
     let name_len: usize = cursor.get_u32().try_into()?;
     let mut name = vec![0u8; name_len];
     cursor.read_exact(&mut name)?;
@@ -86,19 +88,21 @@ pub fn on_datagram(world: &mut World, user_id: Option<String>, bytes: Bytes) ->
     let position = cursor.position();
     let data = &bytes[usize::try_from(position)?..];
 
-    process_network_message(world, user_id, package_id, name, data.to_vec())?;
+    process_network_message(world, user_id, package_id, sequence, name, data.to_vec())?;
 
     Ok(())
 }
 
 pub async fn read_unistream<R: ?Sized + tokio::io::AsyncRead>(
     mut recv_stream: Pin<&mut R>,
-) -> anyhow::Result<(EntityId, String, Vec<u8>)> {
+) -> anyhow::Result<(EntityId, u64, String, Vec<u8>)> {
     use tokio::io::AsyncReadExt;
 
     let package_id = recv_stream.read_u128().await?;
     let package_id = EntityId(package_id);
 
+    let sequence = recv_stream.read_u64().await?;
+
     let name_len: usize = recv_stream
         .read_u32()
         .await?
@@ -115,18 +119,27 @@ pub async fn read_unistream<R: ?Sized + tokio::io::AsyncRead>(
         .read_to_end(&mut data)
         .await?;
 
-    Ok((package_id, name, data))
+    Ok((package_id, sequence, name, data))
 }
 
+/// Dispatches a message received over the network to WASM.
+///
+/// `sequence` is the number the sending client gave the message (see
+/// [`ambient_network::prediction`]), and is acknowledged to it.
 pub fn process_network_message(
     world: &mut World,
     user_id: Option<String>,
     package_id: EntityId,
+    sequence: u64,
     name: String,
     data: Vec<u8>,
 ) -> anyhow::Result<()> {
     use crate::shared::message;
 
+    if let Some(user_id) = &user_id {
+        prediction::acknowledge_input(world, user_id, sequence);
+    }
+
     message::send(
         world,
         Target::PackageOrModule(package_id),
@@ -163,10 +176,13 @@ pub fn send_local(
 }
 
 /// Sends a message over the network for the specified module
+///
+/// Messages sent by clients are numbered with `sequence`; the server uses zero.
 pub fn send_networked(
     world: &World,
     transport: Arc<dyn NetworkTransport>,
     module_id: EntityId,
+    sequence: u64,
     name: &str,
     data: &[u8],
     reliable: bool,
@@ -174,10 +190,10 @@ pub fn send_networked(
     let package_id = world.get(module_id, package_ref())?;
 
     if reliable {
-        send_unistream(world, transport, package_id, name, data);
+        send_unistream(world, transport, package_id, sequence, name, data);
         Ok(())
     } else {
-        send_datagram(world, transport, package_id, name, data)
+        send_datagram(world, transport, package_id, sequence, name, data)
     }
 }
 
@@ -185,6 +201,7 @@ fn send_datagram(
     world: &World,
     transport: Arc<dyn NetworkTransport>,
     package_id: EntityId,
+    sequence: u64,
     name: &str,
     data: &[u8],
 ) -> anyhow::Result<()> {
@@ -194,6 +211,7 @@ fn send_datagram(
     payload.put_f64(DatagramLatencyStat::now().as_secs_f64());
 
     payload.put_u128(package_id.0);
+    payload.put_u64(sequence);
 
     payload.put_u32(name.len().try_into()?);
     payload.extend_from_slice(name.as_bytes());
@@ -215,6 +233,7 @@ fn send_unistream(
     world: &World,
     transport: Arc<dyn NetworkTransport>,
     package_id: EntityId,
+    sequence: u64,
     name: &str,
     data: &[u8],
 ) {
@@ -224,6 +243,7 @@ fn send_unistream(
     world.resource(runtime()).spawn(async move {
         let mut payload = BytesMut::new();
         payload.put_u128(package_id.0);
+        payload.put_u64(sequence);
 
         payload.put_u32(name.len().try_into()?);
         payload.put(name.as_bytes());
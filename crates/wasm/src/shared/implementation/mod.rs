@@ -4,6 +4,7 @@ pub mod entity;
 pub mod message;
 pub mod package;
 pub mod player;
+pub mod timer;
 
 pub fn unsupported<T>() -> anyhow::Result<T> {
     anyhow::bail!("This function is not supported on this side of the API. Please report this if you were able to access this function.")
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use ambient_ecs::{EntityId, World};
+
+use crate::shared::{
+    timer::{module_timers, Clock, ModuleTimers, Timer},
+    wit,
+};
+
+pub fn start(
+    world: &mut World,
+    module_id: EntityId,
+    name: String,
+    clock: wit::timer::Clock,
+    delay: f32,
+    interval: Option<f32>,
+) -> anyhow::Result<()> {
+    let clock = match clock {
+        wit::timer::Clock::Game => Clock::Game,
+        wit::timer::Clock::Wall => Clock::Wall,
+    };
+    let interval = interval
+        .map(|interval| {
+            anyhow::ensure!(
+                interval > 0.,
+                "The interval of timer {name:?} must be positive, but was {interval}"
+            );
+            Ok(Duration::from_secs_f32(interval))
+        })
+        .transpose()?;
+    let timer = Timer {
+        clock,
+        due: clock.now(world) + Duration::from_secs_f32(delay.max(0.)),
+        interval,
+    };
+
+    // Starting a timer with the name of a running timer replaces it
+    match world.get_mut(module_id, module_timers()) {
+        Ok(timers) => {
+            timers.0.insert(name, timer);
+        }
+        Err(_) => {
+            let mut timers = ModuleTimers::default();
+            timers.0.insert(name, timer);
+            world.add_component(module_id, module_timers(), timers)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn cancel(world: &mut World, module_id: EntityId, name: String) -> anyhow::Result<()> {
+    if let Ok(timers) = world.get_mut(module_id, module_timers()) {
+        timers.0.remove(&name);
+    }
+    Ok(())
+}
+
+pub fn remaining(world: &World, module_id: EntityId, name: String) -> anyhow::Result<Option<f32>> {
+    Ok(world
+        .get_ref(module_id, module_timers())
+        .ok()
+        .and_then(|timers| timers.0.get(&name))
+        .map(|timer| {
+            timer
+                .due
+                .saturating_sub(timer.clock.now(world))
+                .as_secs_f32()
+        }))
+}
@@ -8,15 +8,19 @@ pub mod build;
 pub mod conversion;
 pub mod host_guest_state;
 pub mod message;
+pub mod stats;
+pub mod timer;
 pub mod wit;
 
 pub use ambient_ecs::generated::wasm::components::*;
-use ambient_sys::task::PlatformBoxFuture;
-pub use internal::{messenger, module_bytecode, module_errors, module_state, module_state_maker};
+use ambient_sys::{task::PlatformBoxFuture, time::Instant};
+pub use internal::{
+    messenger, module_bytecode, module_errors, module_limits, module_state, module_state_maker,
+};
 pub use module::*;
 use tracing::{Instrument, Span};
 
-use std::{path::Path, str::FromStr, sync::Arc};
+use std::{collections::HashSet, path::Path, str::FromStr, sync::Arc};
 
 use ambient_core::{asset_cache, async_ecs::async_run, hierarchy::despawn_recursive, runtime};
 use ambient_ecs::{
@@ -29,6 +33,7 @@ pub use ambient_ecs::generated::wasm::components::*;
 use ambient_native_std::{
     asset_cache::AssetCache, asset_url::AbsAssetUrl, download_asset::download_uncached_bytes,
 };
+use ambient_network::persistence::restored_world;
 use itertools::Itertools;
 #[cfg(not(target_os = "unknown"))]
 use wasi_cap_std_sync::Dir;
@@ -40,7 +45,9 @@ mod internal {
         components, Debuggable, Description, EntityId, Networked, Resource, Store, World,
     };
 
-    use super::{MessageType, ModuleBytecode, ModuleErrors, ModuleState, ModuleStateMaker};
+    use super::{
+        MessageType, ModuleBytecode, ModuleErrors, ModuleLimits, ModuleState, ModuleStateMaker,
+    };
 
     components!("wasm::shared", {
         module_state: ModuleState,
@@ -48,11 +55,15 @@ mod internal {
         module_bytecode: ModuleBytecode,
         @[Networked, Store, Debuggable]
         module_errors: ModuleErrors,
+        @[Description["Set once the module has been sent `WorldRestore`, so that it is not migrated again when it is reloaded."]]
+        module_world_restored: (),
 
         @[Resource, Description["Used to signal messages from the WASM host/runtime."]]
         messenger: Arc<dyn Fn(&World, EntityId, MessageType, &str) + Send + Sync>,
         @[Resource]
         module_state_maker: ModuleStateMaker,
+        @[Resource, Description["The resource limits of each module; if not set, modules are not limited."]]
+        module_limits: ModuleLimits,
     });
 }
 
@@ -81,6 +92,8 @@ pub fn init_all_components() {
     #[cfg(not(target_os = "unknown"))]
     native_bindings::init_components();
     message::init_components();
+    stats::init_components();
+    timer::init_components();
 }
 
 pub const MAXIMUM_ERROR_COUNT: usize = 5;
@@ -192,7 +205,7 @@ pub fn systems() -> SystemGroup {
                         .collect_vec();
 
                     for (id, bytecode) in modules {
-                        reload(world, id, bytecode);
+                        reload(world, id, bytecode, true);
                     }
                 },
             ),
@@ -230,6 +243,8 @@ pub fn systems() -> SystemGroup {
                     message::run(world, message);
                 }
             })),
+            Box::new(stats::systems()),
+            Box::new(timer::systems()),
         ],
     )
 }
@@ -277,32 +292,106 @@ pub(crate) fn reload_all(world: &mut World) {
         .collect_vec();
 
     for (module_id, bytecode) in modules {
-        reload(world, module_id, bytecode);
+        reload(world, module_id, bytecode, false);
     }
 }
 
-fn reload(world: &mut World, module_id: EntityId, new_bytecode: Option<ModuleBytecode>) {
-    unload(world, module_id, "reloading");
+/// Replaces a module with `new_bytecode`, or unloads it if there is none.
+///
+/// If `allow_hot_reload` is set, modules that opted into hot reloading hand their state and
+/// entities to the new version; otherwise, they are started afresh.
+fn reload(
+    world: &mut World,
+    module_id: EntityId,
+    new_bytecode: Option<ModuleBytecode>,
+    allow_hot_reload: bool,
+) {
+    let new_bytecode = new_bytecode.filter(|bytecode| !bytecode.0.is_empty());
+
+    let hot_reload = match &new_bytecode {
+        Some(_) if allow_hot_reload => hot_unload(world, module_id),
+        _ => None,
+    };
+    if hot_reload.is_none() {
+        unload(world, module_id, "reloading");
+    }
 
     if let Some(new_bytecode) = new_bytecode {
-        if !new_bytecode.0.is_empty() {
-            load(world, module_id, &new_bytecode.0);
+        load(world, module_id, &new_bytecode.0, hot_reload);
+    }
+}
+
+/// What a module hands over to its new version when it is hot reloaded.
+struct HotReload {
+    state: Vec<u8>,
+    spawned_entities: HashSet<EntityId>,
+}
+
+/// Saves the state of a module for its new version, sends it `ModuleUnload`, and removes it
+/// without despawning the entities it spawned.
+///
+/// Returns `None` if the module has not opted into hot reloading or did not save any state, in
+/// which case it is left loaded, or if it failed to save its state, in which case it is unloaded.
+fn hot_unload(world: &mut World, module_id: EntityId) -> Option<HotReload> {
+    let mut state = world.get_cloned(module_id, module_state()).ok()?;
+    let saved = match run_and_catch_panics(|| state.save_state(world)) {
+        Ok(saved) => saved?,
+        Err(err) => {
+            // Unloading clears the errors of the module, so the reason is recorded afterwards
+            unload(world, module_id, "reloading");
+            update_errors(
+                world,
+                &[(
+                    module_id,
+                    format!("Failed to save state for hot reloading: {err}"),
+                )],
+            );
+            return None;
         }
+    };
+
+    run_unload_message(world, module_id);
+
+    // Entities that the module despawned while unloading are not handed over
+    let spawned_entities = state
+        .drain_spawned_entities()
+        .into_iter()
+        .filter(|id| world.exists(*id))
+        .collect();
+    if let Ok(module_errors) = world.get_mut(module_id, module_errors()) {
+        module_errors.0.clear();
     }
+    world.remove_component(module_id, module_state()).unwrap();
+    stats::stop(world, module_id);
+
+    let messenger = world.resource(messenger()).clone();
+    messenger(world, module_id, MessageType::Info, "Hot reloading");
+
+    Some(HotReload {
+        state: saved,
+        spawned_entities,
+    })
 }
 
 /// Loads a wasm module from the given bytecode and attaches it to the given entity.
-fn load(world: &mut World, id: EntityId, component_bytecode: &[u8]) {
+fn load(world: &mut World, id: EntityId, component_bytecode: &[u8], hot_reload: Option<HotReload>) {
     let messenger = world.resource(messenger()).clone();
     let module_state_maker = world.resource(module_state_maker()).clone();
 
     let rt = world.resource(runtime());
     let async_run = world.resource(async_run()).clone();
     let component_bytecode = component_bytecode.to_vec();
+    let limits = world
+        .resource_opt(module_limits())
+        .copied()
+        .unwrap_or_default();
     let name = world
         .get_ref(id, module_name())
         .map(|x| x.clone())
         .unwrap_or_else(|_| "Unknown".to_string());
+    let (hot_reload_state, preserved_entities) = hot_reload
+        .map(|hot_reload| (Some(hot_reload.state), hot_reload.spawned_entities))
+        .unwrap_or_default();
 
     let _span = tracing::info_span!("load_module").entered();
 
@@ -328,6 +417,8 @@ fn load(world: &mut World, id: EntityId, component_bytecode: &[u8]) {
                 messenger(world, id, MessageType::Stderr, msg);
             }),
             id,
+            limits,
+            hot_reload_state,
             #[cfg(not(target_os = "unknown"))]
             preopened_dir,
         })
@@ -340,20 +431,29 @@ fn load(world: &mut World, id: EntityId, component_bytecode: &[u8]) {
                 match res {
                     Ok(mut sms) => {
                         // Subscribe the module to messages that it should be aware of.
+                        // `Frame` is not among them, so that idle modules are not run every frame
                         let autosubscribe_messages =
-                            [messages::Frame::id(), messages::ModuleLoad::id()];
+                            [messages::ModuleLoad::id(), messages::WorldRestore::id()];
                         for id in autosubscribe_messages {
                             sms.listen_to_message(id.to_string());
                         }
+                        sms.adopt_spawned_entities(preserved_entities);
 
                         world.add_component(id, module_state(), sms).unwrap();
+                        stats::start(world, id);
 
                         tracing::info!("Running startup event for module {name}");
                         messages::ModuleLoad::new().run(world, Some(id)).unwrap();
 
+                        run_world_restore_message(world, id);
+
                         tracing::info!("Finished loading module {name}");
                     }
-                    Err(err) => update_errors(world, &[(id, format!("{err:?}"))]),
+                    Err(err) => {
+                        // The entities of the previous version have no module to clean them up
+                        despawn_spawned_entities(world, preserved_entities);
+                        update_errors(world, &[(id, format!("{err:?}"))]);
+                    }
                 }
             })
         });
@@ -366,6 +466,24 @@ fn load(world: &mut World, id: EntityId, component_bytecode: &[u8]) {
     rt.spawn(task);
 }
 
+/// Lets the module migrate the state it saved before the server restarted. This is only done
+/// the first time the module is loaded, as its state has already been migrated by the time it is
+/// hot reloaded or re-enabled.
+fn run_world_restore_message(world: &mut World, module_id: EntityId) {
+    let Some(restored) = world.resource_opt(restored_world()).cloned() else {
+        return;
+    };
+    if world.has_component(module_id, internal::module_world_restored()) {
+        return;
+    }
+    world
+        .add_component(module_id, internal::module_world_restored(), ())
+        .unwrap();
+    messages::WorldRestore::new(restored.saved_version, restored.skipped_components)
+        .run(world, Some(module_id))
+        .unwrap();
+}
+
 fn update_errors(world: &mut World, errors: &[(EntityId, String)]) {
     let messenger = world.resource(messenger()).clone();
     for (id, err) in errors {
@@ -410,10 +528,20 @@ fn run(
         return;
     }
 
-    let result =
-        run_and_catch_panics(|| state.run(world, message_source, message_name, message_data));
+    let mut limit_exceeded = false;
+    let start = Instant::now();
+    let result = run_and_catch_panics(|| {
+        let result = state.run(world, message_source, message_name, message_data);
+        limit_exceeded = matches!(&result, Err(err) if err.is::<LimitExceeded>());
+        result
+    });
+    stats::record_run(world, id, message_name, start.elapsed());
 
     if let Err(message) = result {
+        if limit_exceeded {
+            // Unloading clears the errors of the module, so the reason is recorded afterwards
+            unload(world, id, "it exceeded its resource limits");
+        }
         update_errors(world, &[(id, message)]);
     }
 }
@@ -424,17 +552,7 @@ pub(crate) fn unload(world: &mut World, module_id: EntityId, reason: &str) {
     }
 
     let messenger = world.resource(messenger()).clone();
-    if let Err(e) = messages::ModuleUnload::new().run(world, Some(module_id)) {
-        messenger(
-            world,
-            module_id,
-            MessageType::Info,
-            &format!(
-                "Failed to run module unload message (reason: {})",
-                e.to_string()
-            ),
-        );
-    }
+    run_unload_message(world, module_id);
 
     let spawned_entities = world
         .get_mut(module_id, module_state())
@@ -446,12 +564,12 @@ pub(crate) fn unload(world: &mut World, module_id: EntityId, reason: &str) {
     }
 
     world.remove_component(module_id, module_state()).unwrap();
-
-    for id in spawned_entities {
-        if !world.has_component(id, dont_despawn_on_unload()) {
-            despawn_recursive(world, id);
-        }
-    }
+    stats::stop(world, module_id);
+    // Timers only outlive the module when it is hot reloaded
+    world
+        .remove_component(module_id, timer::module_timers())
+        .ok();
+    despawn_spawned_entities(world, spawned_entities);
 
     messenger(
         world,
@@ -461,6 +579,29 @@ pub(crate) fn unload(world: &mut World, module_id: EntityId, reason: &str) {
     );
 }
 
+fn run_unload_message(world: &mut World, module_id: EntityId) {
+    if let Err(e) = messages::ModuleUnload::new().run(world, Some(module_id)) {
+        let messenger = world.resource(messenger()).clone();
+        messenger(
+            world,
+            module_id,
+            MessageType::Info,
+            &format!(
+                "Failed to run module unload message (reason: {})",
+                e.to_string()
+            ),
+        );
+    }
+}
+
+fn despawn_spawned_entities(world: &mut World, spawned_entities: HashSet<EntityId>) {
+    for id in spawned_entities {
+        if !world.has_component(id, dont_despawn_on_unload()) {
+            despawn_recursive(world, id);
+        }
+    }
+}
+
 fn run_and_catch_panics<R>(f: impl FnOnce() -> anyhow::Result<R>) -> Result<R, String> {
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
     match result {
@@ -482,3 +623,161 @@ fn run_and_catch_panics<R>(f: impl FnOnce() -> anyhow::Result<R>) -> Result<R, S
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use parking_lot::Mutex;
+
+    use super::*;
+
+    /// Stands in for a module, recording the messages it receives.
+    #[derive(Default)]
+    struct TestModule {
+        received: Arc<Mutex<Vec<String>>>,
+        subscribed_messages: HashSet<String>,
+        state: Option<Vec<u8>>,
+        spawned_entities: HashSet<EntityId>,
+    }
+    impl ModuleStateBehavior for TestModule {
+        fn run(
+            &mut self,
+            _world: &mut World,
+            _message_source: &WorldEventSource,
+            message_name: &str,
+            _message_data: &[u8],
+        ) -> anyhow::Result<()> {
+            self.received.lock().push(message_name.to_string());
+            Ok(())
+        }
+
+        fn drain_spawned_entities(&mut self) -> HashSet<EntityId> {
+            std::mem::take(&mut self.spawned_entities)
+        }
+
+        fn adopt_spawned_entities(&mut self, entities: HashSet<EntityId>) {
+            self.spawned_entities.extend(entities);
+        }
+
+        fn save_state(&mut self, world: &mut World) -> anyhow::Result<Option<Vec<u8>>> {
+            if !self.supports_message(HOT_RELOAD_SAVE_MESSAGE) {
+                return Ok(None);
+            }
+            self.run(
+                world,
+                &WorldEventSource::Runtime,
+                HOT_RELOAD_SAVE_MESSAGE,
+                &[],
+            )?;
+            Ok(self.state.clone())
+        }
+
+        fn listen_to_message(&mut self, message_name: String) {
+            self.subscribed_messages.insert(message_name);
+        }
+
+        fn supports_message(&self, message_name: &str) -> bool {
+            self.subscribed_messages.contains(message_name)
+        }
+
+        fn usage(&self) -> ModuleUsage {
+            ModuleUsage::default()
+        }
+    }
+
+    /// Spawns a loaded module that has spawned an entity of its own.
+    fn spawn_module(
+        world: &mut World,
+        hot_reloadable: bool,
+    ) -> (EntityId, EntityId, Arc<Mutex<Vec<String>>>) {
+        let spawned = world.spawn(ambient_ecs::Entity::new());
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let mut module = TestModule {
+            received: received.clone(),
+            state: hot_reloadable.then(|| b"state".to_vec()),
+            spawned_entities: HashSet::from([spawned]),
+            ..Default::default()
+        };
+        module.listen_to_message(messages::ModuleUnload::id().to_string());
+        if hot_reloadable {
+            module.listen_to_message(HOT_RELOAD_SAVE_MESSAGE.to_string());
+        }
+
+        let id = world.spawn(
+            ambient_ecs::Entity::new()
+                .with(is_module(), ())
+                .with(module_errors(), Default::default())
+                .with(module_state(), ModuleState::from_behavior(module)),
+        );
+        (id, spawned, received)
+    }
+
+    fn test_world() -> World {
+        ambient_ecs::init_components();
+        ambient_core::init_all_components();
+        init_all_components();
+
+        let mut world = World::new("wasm_test", WorldContext::Server);
+        world.add_resource(messenger(), Arc::new(|_, _, _, _| {}));
+        world
+    }
+
+    #[test]
+    fn hot_unload_hands_over_state_and_entities() {
+        let mut world = test_world();
+        let (id, spawned, received) = spawn_module(&mut world, true);
+
+        let hot_reload = hot_unload(&mut world, id).unwrap();
+        assert_eq!(hot_reload.state, b"state");
+        assert_eq!(hot_reload.spawned_entities, HashSet::from([spawned]));
+
+        // The old version saves its state, and is then unloaded like any other module
+        assert_eq!(
+            *received.lock(),
+            [HOT_RELOAD_SAVE_MESSAGE, messages::ModuleUnload::id()]
+        );
+        assert!(!world.has_component(id, module_state()));
+        assert!(world.exists(spawned));
+    }
+
+    #[test]
+    fn modules_without_saved_state_are_reloaded_from_scratch() {
+        let mut world = test_world();
+        let (id, spawned, received) = spawn_module(&mut world, false);
+
+        assert!(hot_unload(&mut world, id).is_none());
+        assert!(received.lock().is_empty());
+        assert!(world.has_component(id, module_state()));
+
+        reload(&mut world, id, None, true);
+        assert_eq!(*received.lock(), [messages::ModuleUnload::id()]);
+        assert!(!world.has_component(id, module_state()));
+        assert!(!world.exists(spawned));
+    }
+
+    #[test]
+    fn restored_worlds_are_only_migrated_once() {
+        let mut world = test_world();
+        let (id, _, received) = spawn_module(&mut world, true);
+        world
+            .get_mut(id, module_state())
+            .unwrap()
+            .listen_to_message(messages::WorldRestore::id().to_string());
+
+        run_world_restore_message(&mut world, id);
+        assert!(received.lock().is_empty());
+
+        world.add_resource(
+            restored_world(),
+            ambient_network::persistence::RestoredWorld {
+                saved_version: "1.0.0".to_string(),
+                skipped_components: vec![],
+            },
+        );
+        run_world_restore_message(&mut world, id);
+        assert_eq!(*received.lock(), [messages::WorldRestore::id()]);
+
+        // Reloading the module, or disabling and enabling it again, doesn't migrate it again
+        run_world_restore_message(&mut world, id);
+        assert_eq!(received.lock().len(), 1);
+    }
+}
@@ -5,7 +5,7 @@ use super::engine::EngineKey;
 use super::ModuleStateMaker;
 use super::{bindings::BindingsBound, conversion::IntoBindgen};
 use super::{ModuleStateMaker, WorldEventSource};
-use ambient_ecs::{EntityId, World};
+use ambient_ecs::{generated::messages, EntityId, Message, World};
 use ambient_native_std::asset_cache::{AssetCache, SyncAssetKeyExt};
 use ambient_sys::task::PlatformBoxFuture;
 use data_encoding::BASE64;
@@ -84,11 +84,81 @@ impl<'de> Deserialize<'de> for ModuleBytecode {
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct ModuleErrors(pub Vec<String>);
 
+/// Limits on the resources a module can use, so that a misbehaving module can not stall or
+/// exhaust the host. These are only enforced on native.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct ModuleLimits {
+    /// The fuel a module can consume while handling a single message, which is roughly the number
+    /// of WebAssembly instructions it can execute
+    pub fuel_per_call: Option<u64>,
+    /// The maximum size of each of the memories of a module, in bytes
+    pub max_memory: Option<usize>,
+}
+
+/// The error returned when a module exceeds one of its [ModuleLimits]. Modules that do this are
+/// unloaded, as they may have been stopped in an inconsistent state.
+#[derive(Debug)]
+pub struct LimitExceeded(pub String);
+impl std::fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl std::error::Error for LimitExceeded {}
+
+/// The resources a module has used since it was loaded.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct ModuleUsage {
+    pub fuel_consumed: u64,
+    /// The number of calls the module has made to the host
+    pub host_calls: u64,
+}
+
 /// Binding and linking table generic over the host and guest bindings
 struct BindingContext<Bindings: BindingsBound> {
     bindings: Bindings,
     wasi: WasiCtx,
     table: Table,
+    #[cfg(not(target_os = "unknown"))]
+    memory_limiter: MemoryLimiter,
+    #[cfg(not(target_os = "unknown"))]
+    host_calls: u64,
+}
+
+/// Stops the memories of a module from growing past [ModuleLimits::max_memory].
+#[cfg(not(target_os = "unknown"))]
+struct MemoryLimiter {
+    max_memory: Option<usize>,
+    /// The size a memory tried to grow to past the limit, if any
+    exceeded: Option<usize>,
+}
+
+#[cfg(not(target_os = "unknown"))]
+impl wasm_bridge::ResourceLimiter for MemoryLimiter {
+    fn memory_growing(
+        &mut self,
+        _current: usize,
+        desired: usize,
+        _maximum: Option<usize>,
+    ) -> anyhow::Result<bool> {
+        match self.max_memory {
+            Some(max_memory) if desired > max_memory => {
+                self.exceeded = Some(desired);
+                // Trap instead of failing the allocation, as most guests abort on failed allocations
+                anyhow::bail!("memory limit exceeded")
+            }
+            _ => Ok(true),
+        }
+    }
+
+    fn table_growing(
+        &mut self,
+        _current: u32,
+        _desired: u32,
+        _maximum: Option<u32>,
+    ) -> anyhow::Result<bool> {
+        Ok(true)
+    }
 }
 
 impl<B: BindingsBound> preview2::WasiView for BindingContext<B> {
@@ -118,17 +188,30 @@ pub trait ModuleStateBehavior: Send + Sync {
         message_data: &[u8],
     ) -> anyhow::Result<()>;
     fn drain_spawned_entities(&mut self) -> HashSet<EntityId>;
+    /// Makes this module the owner of entities spawned by a previous version of it.
+    fn adopt_spawned_entities(&mut self, entities: HashSet<EntityId>);
+    /// Asks the module for the state to hand to its next version; `None` if it is not subscribed
+    /// to [HOT_RELOAD_SAVE_MESSAGE], or did not save any state.
+    fn save_state(&mut self, world: &mut World) -> anyhow::Result<Option<Vec<u8>>>;
     fn listen_to_message(&mut self, event_name: String);
     fn supports_message(&self, event_name: &str) -> bool;
+    fn usage(&self) -> ModuleUsage;
 }
 
 pub type Messenger = Box<dyn Fn(&World, &str) + Sync + Send>;
 
+/// The runtime message that asks a module to save its state with `hot-reload.save-state` before
+/// it is hot reloaded. Modules that are not subscribed to it are started afresh instead.
+pub const HOT_RELOAD_SAVE_MESSAGE: &str = "ambient_core::hot_reload_save";
+
 pub struct ModuleStateArgs<'a> {
     pub component_bytecode: &'a [u8],
     pub stdout_output: Messenger,
     pub stderr_output: Messenger,
     pub id: EntityId,
+    pub limits: ModuleLimits,
+    /// The state saved by the previous version of this module, if it is being hot reloaded
+    pub hot_reload_state: Option<Vec<u8>>,
     #[cfg(not(target_os = "unknown"))]
     /// Makes the `data` directory available during development
     pub preopened_dir: Option<wasi_cap_std_sync::Dir>,
@@ -168,6 +251,13 @@ impl ModuleState {
             PlatformBoxFuture::new(async move { Self::new(&assets, args, bindings).await })
         })
     }
+
+    #[cfg(test)]
+    pub(crate) fn from_behavior(behavior: impl ModuleStateBehavior + 'static) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(behavior)),
+        }
+    }
 }
 
 impl ModuleStateBehavior for ModuleState {
@@ -187,6 +277,14 @@ impl ModuleStateBehavior for ModuleState {
         self.inner.write().drain_spawned_entities()
     }
 
+    fn adopt_spawned_entities(&mut self, entities: HashSet<EntityId>) {
+        self.inner.write().adopt_spawned_entities(entities)
+    }
+
+    fn save_state(&mut self, world: &mut World) -> anyhow::Result<Option<Vec<u8>>> {
+        self.inner.write().save_state(world)
+    }
+
     fn listen_to_message(&mut self, message_name: String) {
         self.inner.write().listen_to_message(message_name)
     }
@@ -194,6 +292,10 @@ impl ModuleStateBehavior for ModuleState {
     fn supports_message(&self, message_name: &str) -> bool {
         self.inner.read().supports_message(message_name)
     }
+
+    fn usage(&self) -> ModuleUsage {
+        self.inner.read().usage()
+    }
 }
 
 #[cfg(target_os = "unknown")]
@@ -210,6 +312,10 @@ struct InstanceState<Bindings: BindingsBound> {
     guest_bindings: shared::wit::Bindings,
     _guest_instance: Instance,
 
+    /// Only set if the module has a fuel limit, as fuel is not counted otherwise
+    #[cfg(not(target_os = "unknown"))]
+    fuel: Option<FuelBudget>,
+
     stdout_consumer: WasiOutputStreamConsumer,
     stderr_consumer: WasiOutputStreamConsumer,
 }
@@ -226,11 +332,14 @@ impl<Bindings: BindingsBound> InstanceState<Bindings> {
         args: ModuleStateArgs<'_>,
         bindings: Arc<dyn Fn(EntityId) -> Bindings + Send + Sync>,
     ) -> anyhow::Result<Self> {
-        let bindings = bindings(args.id);
+        let mut bindings = bindings(args.id);
+        bindings.base_mut().restored_state = args.hot_reload_state;
 
-        let engine = EngineKey
-            .get(assets)
-            .map_err(|err| anyhow::anyhow!("{err:?}"))?;
+        let engine = EngineKey {
+            consume_fuel: args.limits.fuel_per_call.is_some(),
+        }
+        .get(assets)
+        .map_err(|err| anyhow::anyhow!("{err:?}"))?;
 
         let (stdout_output, stdout_consumer) = WasiOutputStream::make(args.stdout_output);
         let (stderr_output, stderr_consumer) = WasiOutputStream::make(args.stderr_output);
@@ -252,9 +361,36 @@ impl<Bindings: BindingsBound> InstanceState<Bindings> {
                 wasi,
                 bindings,
                 table,
+                #[cfg(not(target_os = "unknown"))]
+                memory_limiter: MemoryLimiter {
+                    max_memory: args.limits.max_memory,
+                    exceeded: None,
+                },
+                #[cfg(not(target_os = "unknown"))]
+                host_calls: 0,
             },
         );
 
+        #[cfg(not(target_os = "unknown"))]
+        let mut fuel = args
+            .limits
+            .fuel_per_call
+            .map(|per_call| FuelBudget { per_call, added: 0 });
+        #[cfg(not(target_os = "unknown"))]
+        {
+            store.limiter(|context| &mut context.memory_limiter);
+            store.call_hook(|context, hook| {
+                if matches!(hook, wasm_bridge::CallHook::CallingHost) {
+                    context.host_calls += 1;
+                }
+                Ok(())
+            });
+            // Instantiating and initialising the module share the budget of a single call
+            if let Some(fuel) = &mut fuel {
+                fuel.refuel(&mut store)?;
+            }
+        }
+
         // let mut store = wasmtime::Store::new(
         //     engine,
         //     ExecutionContext {
@@ -303,6 +439,9 @@ impl<Bindings: BindingsBound> InstanceState<Bindings> {
             guest_bindings,
             _guest_instance: guest_instance,
 
+            #[cfg(not(target_os = "unknown"))]
+            fuel,
+
             stdout_consumer,
             stderr_consumer,
         })
@@ -349,6 +488,11 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for InstanceState<Bindings> {
         message_name: &str,
         message_data: &[u8],
     ) -> anyhow::Result<()> {
+        #[cfg(not(target_os = "unknown"))]
+        if let Some(fuel) = &mut self.fuel {
+            fuel.refuel(&mut self.store)?;
+        }
+
         self.store.data_mut().bindings.set_world(world);
 
         let guest = &self.guest_bindings.ambient_bindings_guest();
@@ -373,13 +517,37 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for InstanceState<Bindings> {
         self.stdout_consumer.process_incoming(world);
         self.stderr_consumer.process_incoming(world);
 
-        result
+        result.map_err(|err| self.check_limits(err, message_name))
     }
 
     fn drain_spawned_entities(&mut self) -> HashSet<EntityId> {
         std::mem::take(&mut self.store.data_mut().bindings.base_mut().spawned_entities)
     }
 
+    fn adopt_spawned_entities(&mut self, entities: HashSet<EntityId>) {
+        self.store
+            .data_mut()
+            .bindings
+            .base_mut()
+            .spawned_entities
+            .extend(entities);
+    }
+
+    fn save_state(&mut self, world: &mut World) -> anyhow::Result<Option<Vec<u8>>> {
+        if !self.supports_message(HOT_RELOAD_SAVE_MESSAGE) {
+            return Ok(None);
+        }
+
+        self.store.data_mut().bindings.base_mut().saved_state = None;
+        self.run(
+            world,
+            &WorldEventSource::Runtime,
+            HOT_RELOAD_SAVE_MESSAGE,
+            &[],
+        )?;
+        Ok(self.store.data_mut().bindings.base_mut().saved_state.take())
+    }
+
     fn listen_to_message(&mut self, event_name: String) {
         self.store
             .data_mut()
@@ -390,12 +558,77 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for InstanceState<Bindings> {
     }
 
     fn supports_message(&self, event_name: &str) -> bool {
-        self.store
-            .data()
-            .bindings
-            .base()
-            .subscribed_messages
-            .contains(event_name)
+        let base = self.store.data().bindings.base();
+        base.subscribed_messages.contains(event_name)
+            || (base.frame_wakeups && event_name == messages::Frame::id())
+    }
+
+    #[cfg(target_os = "unknown")]
+    fn usage(&self) -> ModuleUsage {
+        ModuleUsage::default()
+    }
+
+    #[cfg(not(target_os = "unknown"))]
+    fn usage(&self) -> ModuleUsage {
+        ModuleUsage {
+            fuel_consumed: self.store.fuel_consumed().unwrap_or_default(),
+            host_calls: self.store.data().host_calls,
+        }
+    }
+}
+
+impl<Bindings: BindingsBound> InstanceState<Bindings> {
+    /// Turns `err` into a [LimitExceeded] if it was caused by the module exceeding its limits.
+    #[cfg(target_os = "unknown")]
+    fn check_limits(&mut self, err: anyhow::Error, _message_name: &str) -> anyhow::Error {
+        err
+    }
+
+    /// Turns `err` into a [LimitExceeded] if it was caused by the module exceeding its limits.
+    #[cfg(not(target_os = "unknown"))]
+    fn check_limits(&mut self, err: anyhow::Error, message_name: &str) -> anyhow::Error {
+        if let Some(fuel) = &self.fuel {
+            if err.downcast_ref::<wasm_bridge::Trap>() == Some(&wasm_bridge::Trap::OutOfFuel) {
+                return LimitExceeded(format!(
+                    "Used more than its limit of {} fuel while handling {message_name}",
+                    fuel.per_call
+                ))
+                .into();
+            }
+        }
+
+        let limiter = &mut self.store.data_mut().memory_limiter;
+        if let (Some(desired), Some(max_memory)) = (limiter.exceeded.take(), limiter.max_memory) {
+            return LimitExceeded(format!(
+                "Tried to grow its memory to {} MiB, more than its limit of {} MiB, while handling {message_name}",
+                desired / (1024 * 1024),
+                max_memory / (1024 * 1024)
+            ))
+            .into();
+        }
+
+        err
+    }
+}
+
+/// Tops up the fuel of a store before each call, so that every call gets the same budget.
+#[cfg(not(target_os = "unknown"))]
+struct FuelBudget {
+    per_call: u64,
+    /// The total fuel added to the store so far
+    added: u64,
+}
+
+#[cfg(not(target_os = "unknown"))]
+impl FuelBudget {
+    fn refuel<T>(&mut self, store: &mut Store<T>) -> anyhow::Result<()> {
+        let remaining = self
+            .added
+            .saturating_sub(store.fuel_consumed().unwrap_or_default());
+        let top_up = self.per_call.saturating_sub(remaining);
+        store.add_fuel(top_up)?;
+        self.added += top_up;
+        Ok(())
     }
 }
 
@@ -484,3 +717,49 @@ impl WasiOutputStreamConsumer {
         }
     }
 }
+
+#[cfg(all(test, not(target_os = "unknown")))]
+mod tests {
+    use super::*;
+    use crate::shared::engine::Engine;
+
+    /// `(module (func (export "run") (loop (br 0))))`, whose `run` never returns
+    const RUNAWAY_MODULE: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00, 0x03,
+        0x02, 0x01, 0x00, 0x07, 0x07, 0x01, 0x03, 0x72, 0x75, 0x6e, 0x00, 0x00, 0x0a, 0x09, 0x01,
+        0x07, 0x00, 0x03, 0x40, 0x0c, 0x00, 0x0b, 0x0b,
+    ];
+
+    #[test]
+    fn runaway_modules_run_out_of_fuel() {
+        let engine = Engine::new(true).unwrap();
+        let module = wasm_bridge::Module::new(engine.inner(), RUNAWAY_MODULE).unwrap();
+        let mut store = Store::new(engine.inner(), ());
+        let instance = wasm_bridge::Instance::new(&mut store, &module, &[]).unwrap();
+        let run = instance
+            .get_typed_func::<(), ()>(&mut store, "run")
+            .unwrap();
+
+        let mut fuel = FuelBudget {
+            per_call: 10_000,
+            added: 0,
+        };
+        // Every call gets the whole budget again, even though the previous call used all of it
+        for _ in 0..2 {
+            fuel.refuel(&mut store).unwrap();
+            let err = run.call(&mut store, ()).unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<wasm_bridge::Trap>(),
+                Some(&wasm_bridge::Trap::OutOfFuel)
+            );
+        }
+        assert!(store.fuel_consumed().unwrap() >= 20_000);
+    }
+
+    #[test]
+    fn fuel_is_only_counted_when_limited() {
+        let engine = Engine::new(false).unwrap();
+        let store = Store::new(engine.inner(), ());
+        assert_eq!(store.fuel_consumed(), None);
+    }
+}
@@ -0,0 +1,259 @@
+//! Measures the time, fuel and host calls that each module uses, and publishes the totals of the
+//! last second on its entity, so that it is possible to tell which module is slowing down a frame.
+use std::{collections::HashMap, time::Duration};
+
+use ambient_ecs::{components, query, Entity, EntityId, FnSystem, SystemGroup, World};
+use ambient_sys::time::Instant;
+use itertools::Itertools;
+
+use super::{
+    module_fuel_consumed, module_host_calls, module_message_names, module_message_run_times,
+    module_run_time, module_state, ModuleStateBehavior, ModuleUsage,
+};
+
+components!("wasm::shared::stats", {
+    module_stats: ModuleStats,
+});
+
+/// How often the stats of each module are published.
+const WINDOW: Duration = Duration::from_secs(1);
+
+/// The usage of a module since the start of the current window.
+#[derive(Debug, Clone)]
+pub struct ModuleStats {
+    window_start: Instant,
+    usage_at_start: ModuleUsage,
+    message_times: HashMap<String, Duration>,
+}
+
+/// Starts measuring the module `id`, which was just loaded.
+pub(crate) fn start(world: &mut World, id: EntityId) {
+    // The fuel used to instantiate the module is not counted
+    let usage_at_start = world
+        .get_ref(id, module_state())
+        .map(|state| state.usage())
+        .unwrap_or_default();
+    world
+        .add_component(
+            id,
+            module_stats(),
+            ModuleStats {
+                window_start: Instant::now(),
+                usage_at_start,
+                message_times: HashMap::new(),
+            },
+        )
+        .ok();
+}
+
+/// Stops measuring the module `id`, which was unloaded.
+pub(crate) fn stop(world: &mut World, id: EntityId) {
+    world
+        .remove_components(
+            id,
+            vec![
+                module_stats().desc(),
+                module_run_time().desc(),
+                module_fuel_consumed().desc(),
+                module_host_calls().desc(),
+                module_message_names().desc(),
+                module_message_run_times().desc(),
+            ],
+        )
+        .ok();
+}
+
+/// Records that the module `id` spent `elapsed` handling `message_name`.
+pub(crate) fn record_run(world: &mut World, id: EntityId, message_name: &str, elapsed: Duration) {
+    if let Ok(stats) = world.get_mut(id, module_stats()) {
+        *stats
+            .message_times
+            .entry(message_name.to_string())
+            .or_default() += elapsed;
+    }
+}
+
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "core/wasm/stats",
+        vec![Box::new(FnSystem::new(|world, _| {
+            profiling::scope!("WASM module stats");
+            let now = Instant::now();
+            let finished = query((module_stats(), module_state()))
+                .iter(world, None)
+                .filter(|(_, (stats, _))| now.duration_since(stats.window_start) >= WINDOW)
+                .map(|(id, (stats, state))| (id, stats.clone(), state.usage()))
+                .collect_vec();
+
+            for (id, stats, usage) in finished {
+                let messages = stats
+                    .message_times
+                    .into_iter()
+                    .sorted_by(|a, b| b.1.cmp(&a.1))
+                    .collect_vec();
+                let total: Duration = messages.iter().map(|(_, time)| *time).sum();
+
+                world
+                    .add_components(
+                        id,
+                        Entity::new()
+                            .with(module_run_time(), as_millis(total))
+                            .with(
+                                module_fuel_consumed(),
+                                usage
+                                    .fuel_consumed
+                                    .saturating_sub(stats.usage_at_start.fuel_consumed),
+                            )
+                            .with(
+                                module_host_calls(),
+                                usage
+                                    .host_calls
+                                    .saturating_sub(stats.usage_at_start.host_calls),
+                            )
+                            .with(
+                                module_message_run_times(),
+                                messages.iter().map(|(_, time)| as_millis(*time)).collect(),
+                            )
+                            .with(
+                                module_message_names(),
+                                messages.into_iter().map(|(name, _)| name).collect(),
+                            )
+                            .with(
+                                module_stats(),
+                                ModuleStats {
+                                    window_start: now,
+                                    usage_at_start: usage,
+                                    message_times: HashMap::new(),
+                                },
+                            ),
+                    )
+                    .ok();
+            }
+        }))],
+    )
+}
+
+fn as_millis(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Arc};
+
+    use ambient_ecs::{FrameEvent, System, WorldContext};
+    use parking_lot::Mutex;
+
+    use super::*;
+    use crate::shared::{message::WorldEventSource, ModuleState};
+
+    /// Stands in for a module whose usage is set by the test.
+    struct TestModule {
+        usage: Arc<Mutex<ModuleUsage>>,
+    }
+    impl ModuleStateBehavior for TestModule {
+        fn run(
+            &mut self,
+            _world: &mut World,
+            _message_source: &WorldEventSource,
+            _message_name: &str,
+            _message_data: &[u8],
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn drain_spawned_entities(&mut self) -> HashSet<EntityId> {
+            HashSet::new()
+        }
+
+        fn adopt_spawned_entities(&mut self, _entities: HashSet<EntityId>) {}
+
+        fn save_state(&mut self, _world: &mut World) -> anyhow::Result<Option<Vec<u8>>> {
+            Ok(None)
+        }
+
+        fn listen_to_message(&mut self, _message_name: String) {}
+
+        fn supports_message(&self, _message_name: &str) -> bool {
+            false
+        }
+
+        fn usage(&self) -> ModuleUsage {
+            *self.usage.lock()
+        }
+    }
+
+    fn assert_millis(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-3, "{actual} != {expected}");
+    }
+
+    /// Makes the current window of the module `id` end now.
+    fn end_window(world: &mut World, id: EntityId) {
+        world.get_mut(id, module_stats()).unwrap().window_start = Instant::now() - WINDOW;
+    }
+
+    #[test]
+    fn stats_are_published_once_per_window() {
+        ambient_ecs::init_components();
+        crate::shared::init_all_components();
+        let mut world = World::new("stats_test", WorldContext::Server);
+        let mut systems = systems();
+
+        // Instantiating the module used some fuel, which is not counted
+        let usage = Arc::new(Mutex::new(ModuleUsage {
+            fuel_consumed: 100,
+            host_calls: 5,
+        }));
+        let id = world.spawn(Entity::new().with(
+            module_state(),
+            ModuleState::from_behavior(TestModule {
+                usage: usage.clone(),
+            }),
+        ));
+        start(&mut world, id);
+
+        record_run(&mut world, id, "a", Duration::from_millis(3));
+        record_run(&mut world, id, "b", Duration::from_millis(5));
+        record_run(&mut world, id, "a", Duration::from_millis(4));
+        *usage.lock() = ModuleUsage {
+            fuel_consumed: 160,
+            host_calls: 12,
+        };
+
+        systems.run(&mut world, &FrameEvent);
+        assert!(!world.has_component(id, module_run_time()));
+
+        end_window(&mut world, id);
+        systems.run(&mut world, &FrameEvent);
+        assert_millis(world.get(id, module_run_time()).unwrap(), 12.);
+        assert_eq!(world.get(id, module_fuel_consumed()).unwrap(), 60);
+        assert_eq!(world.get(id, module_host_calls()).unwrap(), 7);
+        // The messages are sorted by the time spent handling them
+        assert_eq!(
+            world.get_cloned(id, module_message_names()).unwrap(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+        let times = world.get_cloned(id, module_message_run_times()).unwrap();
+        assert_millis(times[0], 7.);
+        assert_millis(times[1], 5.);
+
+        // The next window starts from scratch
+        record_run(&mut world, id, "b", Duration::from_millis(1));
+        end_window(&mut world, id);
+        systems.run(&mut world, &FrameEvent);
+        assert_millis(world.get(id, module_run_time()).unwrap(), 1.);
+        assert_eq!(world.get(id, module_fuel_consumed()).unwrap(), 0);
+        assert_eq!(world.get(id, module_host_calls()).unwrap(), 0);
+        assert_eq!(
+            world.get_cloned(id, module_message_names()).unwrap(),
+            vec!["b".to_string()]
+        );
+
+        // Unloaded modules are no longer measured
+        stop(&mut world, id);
+        assert!(!world.has_component(id, module_stats()));
+        assert!(!world.has_component(id, module_run_time()));
+        record_run(&mut world, id, "a", Duration::from_millis(1));
+        assert!(!world.has_component(id, module_stats()));
+    }
+}
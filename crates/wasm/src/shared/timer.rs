@@ -0,0 +1,290 @@
+//! Timers that modules start through the `timer` interface.
+//!
+//! The timers of a module are kept on its entity rather than in its instance, so that they keep
+//! running while the module is hot reloaded. When a timer is due, its module is sent a `TimerFire`
+//! message; modules that are only waiting on timers are not run at all in the meantime.
+use std::{collections::HashMap, time::Duration};
+
+use ambient_core::{app_start_time, game_time};
+use ambient_ecs::{components, generated::messages, query, EntityId, FnSystem, SystemGroup, World};
+use ambient_sys::time::Instant;
+
+use super::{message::MessageExt, module_state};
+
+components!("wasm::shared::timer", {
+    module_timers: ModuleTimers,
+});
+
+/// The clock a timer counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Clock {
+    /// Advances once per frame, with the game time
+    Game,
+    /// Advances in real time
+    Wall,
+}
+impl Clock {
+    /// The current time of this clock, relative to the start of the app.
+    pub fn now(self, world: &World) -> Duration {
+        match self {
+            Clock::Game => *world.resource(game_time()),
+            Clock::Wall => Instant::now().duration_since(*world.resource(app_start_time())),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Timer {
+    pub clock: Clock,
+    /// When the timer next fires, on its clock
+    pub due: Duration,
+    /// How often the timer repeats; if not set, it fires once
+    pub interval: Option<Duration>,
+}
+
+/// The running timers of a module, by name.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleTimers(pub HashMap<String, Timer>);
+
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "core/wasm/timer",
+        vec![Box::new(FnSystem::new(|world, _| {
+            profiling::scope!("WASM module timers");
+            let game_now = Clock::Game.now(world);
+            let wall_now = Clock::Wall.now(world);
+
+            // Modules that are being reloaded receive their timers once they have loaded
+            let mut fired = Vec::new();
+            for (id, (timers, _)) in query((module_timers(), module_state())).iter(world, None) {
+                for (name, timer) in &timers.0 {
+                    let now = match timer.clock {
+                        Clock::Game => game_now,
+                        Clock::Wall => wall_now,
+                    };
+                    if now >= timer.due {
+                        fired.push((id, name.clone(), now));
+                    }
+                }
+            }
+
+            for (id, name, now) in fired {
+                reschedule(world, id, &name, now);
+                messages::TimerFire::new(name).run(world, Some(id)).ok();
+            }
+        }))],
+    )
+}
+
+/// Schedules the next firing of a repeating timer, or removes a one-shot timer.
+fn reschedule(world: &mut World, id: EntityId, name: &str, now: Duration) {
+    let Ok(timers) = world.get_mut(id, module_timers()) else {
+        return;
+    };
+    let Some(timer) = timers.0.get_mut(name) else {
+        return;
+    };
+    match timer.interval {
+        Some(interval) => {
+            timer.due += interval;
+            // A timer that fell behind skips the firings it missed, rather than firing every
+            // frame until it catches up
+            if timer.due <= now {
+                timer.due = now + interval;
+            }
+        }
+        None => {
+            timers.0.remove(name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Arc};
+
+    use ambient_ecs::{Entity, FrameEvent, Message, System, WorldContext};
+    use parking_lot::Mutex;
+
+    use super::*;
+    use crate::shared::{
+        implementation::timer::{cancel, remaining, start},
+        is_module,
+        message::WorldEventSource,
+        messenger, module_errors, wit, ModuleState, ModuleStateBehavior, ModuleUsage,
+    };
+
+    /// Stands in for a module, recording the timers that fire.
+    struct TestModule {
+        fired: Arc<Mutex<Vec<String>>>,
+    }
+    impl ModuleStateBehavior for TestModule {
+        fn run(
+            &mut self,
+            _world: &mut World,
+            _message_source: &WorldEventSource,
+            _message_name: &str,
+            message_data: &[u8],
+        ) -> anyhow::Result<()> {
+            let message = messages::TimerFire::deserialize_message(message_data)?;
+            self.fired.lock().push(message.name);
+            Ok(())
+        }
+
+        fn drain_spawned_entities(&mut self) -> HashSet<EntityId> {
+            HashSet::new()
+        }
+
+        fn adopt_spawned_entities(&mut self, _entities: HashSet<EntityId>) {}
+
+        fn save_state(&mut self, _world: &mut World) -> anyhow::Result<Option<Vec<u8>>> {
+            Ok(None)
+        }
+
+        fn listen_to_message(&mut self, _message_name: String) {}
+
+        fn supports_message(&self, message_name: &str) -> bool {
+            message_name == messages::TimerFire::id()
+        }
+
+        fn usage(&self) -> ModuleUsage {
+            ModuleUsage::default()
+        }
+    }
+
+    struct TestWorld {
+        world: World,
+        systems: SystemGroup,
+        module: EntityId,
+        fired: Arc<Mutex<Vec<String>>>,
+    }
+    impl TestWorld {
+        fn new() -> Self {
+            ambient_ecs::init_components();
+            ambient_core::init_all_components();
+            crate::shared::init_all_components();
+
+            let mut world = World::new("timer_test", WorldContext::Server);
+            world.add_resource(messenger(), Arc::new(|_, _, _, _| {}));
+            world.add_resource(game_time(), Duration::ZERO);
+            world.add_resource(app_start_time(), Instant::now());
+
+            let fired = Arc::new(Mutex::new(Vec::new()));
+            let module = world.spawn(
+                Entity::new()
+                    .with(is_module(), ())
+                    .with(module_errors(), Default::default())
+                    .with(
+                        module_state(),
+                        ModuleState::from_behavior(TestModule {
+                            fired: fired.clone(),
+                        }),
+                    ),
+            );
+
+            Self {
+                world,
+                systems: systems(),
+                module,
+                fired,
+            }
+        }
+
+        fn start(&mut self, name: &str, delay: f32, interval: Option<f32>) {
+            start(
+                &mut self.world,
+                self.module,
+                name.to_string(),
+                wit::timer::Clock::Game,
+                delay,
+                interval,
+            )
+            .unwrap();
+        }
+
+        fn remaining(&self, name: &str) -> Option<f32> {
+            remaining(&self.world, self.module, name.to_string()).unwrap()
+        }
+
+        /// Advances the game time to `secs`, and returns the timers that fired, sorted by name.
+        fn advance_to(&mut self, secs: f32) -> Vec<String> {
+            *self.world.resource_mut(game_time()) = Duration::from_secs_f32(secs);
+            self.systems.run(&mut self.world, &FrameEvent);
+            let mut fired = std::mem::take(&mut *self.fired.lock());
+            fired.sort();
+            fired
+        }
+    }
+
+    #[test]
+    fn timers_fire_once_or_repeatedly() {
+        let mut test = TestWorld::new();
+        test.start("once", 1., None);
+        test.start("repeat", 0.5, Some(0.5));
+
+        assert!(test.advance_to(0.4).is_empty());
+        assert!((test.remaining("once").unwrap() - 0.6).abs() < 1e-4);
+
+        assert_eq!(test.advance_to(0.5), ["repeat"]);
+        assert_eq!(test.advance_to(1.), ["once", "repeat"]);
+        assert_eq!(test.remaining("once"), None);
+        assert!(test.advance_to(1.2).is_empty());
+
+        // A timer that fell behind fires once, and then continues from the current time
+        assert_eq!(test.advance_to(3.2), ["repeat"]);
+        assert!(test.advance_to(3.6).is_empty());
+        assert_eq!(test.advance_to(3.8), ["repeat"]);
+
+        cancel(&mut test.world, test.module, "repeat".to_string()).unwrap();
+        assert!(test.advance_to(10.).is_empty());
+        assert_eq!(test.remaining("repeat"), None);
+    }
+
+    #[test]
+    fn starting_a_running_timer_replaces_it() {
+        let mut test = TestWorld::new();
+        test.start("timer", 1., Some(1.));
+        test.start("timer", 2., None);
+
+        assert!(test.advance_to(1.).is_empty());
+        assert_eq!(test.advance_to(2.), ["timer"]);
+        assert!(test.advance_to(4.).is_empty());
+
+        assert!(start(
+            &mut test.world,
+            test.module,
+            "invalid".to_string(),
+            wit::timer::Clock::Game,
+            1.,
+            Some(0.),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn wall_clock_timers_ignore_the_game_time() {
+        let mut test = TestWorld::new();
+        start(
+            &mut test.world,
+            test.module,
+            "wall".to_string(),
+            wit::timer::Clock::Wall,
+            60.,
+            None,
+        )
+        .unwrap();
+
+        assert!(test.advance_to(120.).is_empty());
+        assert!(test.remaining("wall").unwrap() > 59.);
+    }
+
+    #[test]
+    fn timers_stop_when_their_module_is_unloaded() {
+        let mut test = TestWorld::new();
+        test.start("timer", 1., None);
+
+        crate::shared::unload(&mut test.world, test.module, "test");
+        assert!(!test.world.has_component(test.module, module_timers()));
+        assert!(test.advance_to(2.).is_empty());
+    }
+}
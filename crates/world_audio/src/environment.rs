@@ -0,0 +1,156 @@
+//! Environmental audio: the reverb of the zone that the listener is in, and the occlusion of
+//! spatial sounds by the occluders between them and the listener.
+//!
+//! The client does not have a physics scene, so occlusion is tested against the bounding boxes of
+//! the entities with `audio_occluder`, rather than their colliders.
+use ambient_audio::{OcclusionParams, ReverbParams};
+use ambient_core::{bounding::world_bounding_aabb, transform::local_to_world};
+use ambient_ecs::{generated::audio::components::*, query, EntityId, FnSystem, SystemGroup, World};
+use ambient_native_std::shapes::{Ray, RayIntersectable, AABB};
+use glam::Vec3;
+use itertools::Itertools;
+
+use crate::{audio_listener, occlusion_params, reverb_params};
+
+/// How far from the emitter the rays that detect partial occlusion are aimed.
+const OCCLUSION_SPREAD: f32 = 0.5;
+
+pub fn systems() -> SystemGroup {
+    SystemGroup::new(
+        "audio/environment",
+        vec![
+            Box::new(FnSystem::new(|world, _| {
+                update_reverb(world);
+            })),
+            Box::new(FnSystem::new(|world, _| {
+                update_occlusion(world);
+            })),
+        ],
+    )
+}
+
+fn listener_position(world: &World) -> Option<Vec3> {
+    query((audio_listener(), local_to_world()))
+        .iter(world, None)
+        .next()
+        .map(|(_, (_, ltw))| ltw.w_axis.truncate())
+}
+
+/// Applies the reverb of the smallest reverb zone that contains the listener.
+fn update_reverb(world: &World) {
+    let Ok(params) = world.get_ref(world.resource_entity(), reverb_params()) else {
+        return;
+    };
+
+    let zone = listener_position(world).and_then(|listener| {
+        query((reverb_zone_radius(), local_to_world()))
+            .incl(is_reverb_zone())
+            .iter(world, None)
+            .filter(|(_, (&radius, ltw))| {
+                ltw.w_axis.truncate().distance_squared(listener) <= radius * radius
+            })
+            .min_by(|(_, (a, _)), (_, (b, _))| a.total_cmp(b))
+            .map(|(id, _)| id)
+    });
+
+    let new_params = match zone {
+        Some(zone) => ReverbParams {
+            room_size: world.get(zone, reverb_room_size()).unwrap_or(0.5),
+            damping: world.get(zone, reverb_damping()).unwrap_or(0.5),
+            wet: world.get(zone, reverb_wet()).unwrap_or(0.3),
+        },
+        None => ReverbParams {
+            wet: 0.0,
+            ..*params.lock()
+        },
+    };
+    *params.lock() = new_params;
+}
+
+/// Casts rays from the listener to each emitter, and muffles the emitters that are blocked.
+fn update_occlusion(world: &mut World) {
+    let Some(listener) = listener_position(world) else {
+        return;
+    };
+
+    let occluders = query(world_bounding_aabb())
+        .incl(audio_occluder())
+        .iter(world, None)
+        .map(|(_, &aabb)| aabb)
+        .filter(|aabb| !contains(aabb, listener))
+        .collect_vec();
+
+    let mut changed = Vec::new();
+    for (id, (params, ltw)) in query((occlusion_params(), local_to_world())).iter(world, None) {
+        let emitter = ltw.w_axis.truncate();
+        let amount = occlusion_amount(listener, emitter, &occluders);
+
+        let mut params = params.lock();
+        if params.amount != amount {
+            params.amount = amount;
+            changed.push((id, amount));
+        }
+    }
+
+    for (id, amount) in changed {
+        world.add_component(id, audio_occlusion(), amount).ok();
+    }
+}
+
+/// Returns the fraction of the rays from the listener to the emitter and around it that are
+/// blocked, so that sounds partially behind an occluder are only partially muffled.
+fn occlusion_amount(listener: Vec3, emitter: Vec3, occluders: &[AABB]) -> f32 {
+    let to_emitter = emitter - listener;
+    if occluders.is_empty() || to_emitter.length_squared() < 1e-6 {
+        return 0.0;
+    }
+
+    // Occluders around the emitter, such as the model of the object emitting the sound, do not
+    // block it
+    let occluders = occluders
+        .iter()
+        .filter(|aabb| !contains(aabb, emitter))
+        .collect_vec();
+
+    let (a, b) = to_emitter.normalize().any_orthonormal_pair();
+    let targets = [
+        emitter,
+        emitter + a * OCCLUSION_SPREAD,
+        emitter - a * OCCLUSION_SPREAD,
+        emitter + b * OCCLUSION_SPREAD,
+        emitter - b * OCCLUSION_SPREAD,
+    ];
+
+    let blocked = targets
+        .iter()
+        .filter(|&&target| {
+            let to_target = target - listener;
+            let distance = to_target.length();
+            let ray = Ray::new(listener, to_target / distance);
+            occluders.iter().any(|aabb| {
+                aabb.ray_intersect(ray)
+                    .is_some_and(|t| t > 0.0 && t < distance)
+            })
+        })
+        .count();
+
+    blocked as f32 / targets.len() as f32
+}
+
+fn contains(aabb: &AABB, point: Vec3) -> bool {
+    point.cmpge(aabb.min).all() && point.cmple(aabb.max).all()
+}
+
+/// The occlusion parameters of a sound played by `player`.
+pub(crate) fn player_occlusion_params(world: &World, player: EntityId) -> OcclusionParams {
+    let defaults = OcclusionParams::default();
+    OcclusionParams {
+        amount: 0.0,
+        low_pass: world
+            .get(player, audio_occlusion_low_pass())
+            .unwrap_or(defaults.low_pass),
+        gain: world
+            .get(player, audio_occlusion_gain())
+            .unwrap_or(defaults.gain),
+    }
+}
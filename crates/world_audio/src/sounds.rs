@@ -3,7 +3,8 @@ use std::sync::Arc;
 use ambient_audio::{
     hrtf::HrtfLib,
     track::{Track, TrackDecodeStream},
-    Attenuation, AudioEmitter, AudioListener, AudioMixer, Sound, SoundId, Source, Spatial,
+    Attenuation, AudioEmitter, AudioListener, AudioMixer, OcclusionParams, ReverbParams, Sound,
+    SoundId, Source, Spatial,
 };
 use ambient_ecs::{components, query, EntityId, Resource, World};
 use ambient_element::ElementComponentExt;
@@ -34,6 +35,9 @@ components!("audio", {
     onepole_arc: Arc<Mutex<f32>>,
     looping_arc: Arc<Mutex<bool>>,
     sound_id: SoundId,
+    @[Resource]
+    reverb_params: Arc<Mutex<ReverbParams>>,
+    occlusion_params: Arc<Mutex<OcclusionParams>>,
 });
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -1,7 +1,12 @@
-use std::{io::Cursor, sync::Arc};
+use std::{io::Cursor, sync::Arc, time::Duration};
 
 use crate::{audio_emitter, audio_listener, hrtf_lib};
-use ambient_audio::{hrtf::HrtfLib, AudioFromUrl, Source};
+use ambient_audio::{
+    bus::{BusEffect, Ducking, DEFAULT_BUS},
+    hrtf::HrtfLib,
+    track::Track,
+    AudioFromUrl, ReverbParams, Source,
+};
 use ambient_audio::{Attenuation, AudioEmitter, AudioListener};
 use ambient_core::{
     asset_cache,
@@ -14,7 +19,11 @@ use ambient_ecs::{
     generated::audio::components::*, generated::hierarchy::components::children, query,
     SystemGroup, World,
 };
-use ambient_native_std::{asset_cache::AsyncAssetKeyExt, asset_url::AbsAssetUrl, unwrap_log_warn};
+use ambient_native_std::{
+    asset_cache::{AssetCache, AsyncAssetKeyExt},
+    asset_url::AbsAssetUrl,
+    unwrap_log_warn,
+};
 use glam::{vec4, Mat4};
 use parking_lot::Mutex;
 use std::str::FromStr;
@@ -27,6 +36,10 @@ pub fn setup_audio(world: &mut World) -> anyhow::Result<()> {
         "../IRC_1002_C.bin"
     )))?);
     world.add_resource(hrtf_lib(), hrtf);
+    world.add_resource(
+        crate::reverb_params(),
+        Arc::new(Mutex::new(ReverbParams::default())),
+    );
     Ok(())
 }
 
@@ -43,6 +56,48 @@ pub fn audio_systems() -> SystemGroup {
     SystemGroup::new(
         "audio",
         vec![
+            Box::new(crate::environment::systems()),
+            // Applies the components of bus entities to the buses of the mixer
+            query(audio_bus())
+                .incl(is_audio_bus())
+                .to_system(|q, world, qs, _| {
+                    let r = world.resource_entity();
+                    if !world.has_component(r, crate::audio_mixer()) {
+                        return;
+                    }
+
+                    let mixer = world.resource(crate::audio_mixer());
+                    for (bus_entity, bus) in q.iter(world, qs) {
+                        mixer.set_bus_volume(
+                            bus,
+                            world.get(bus_entity, audio_bus_volume()).unwrap_or(1.0),
+                        );
+                        mixer.set_bus_muted(
+                            bus,
+                            world.get(bus_entity, audio_bus_muted()).unwrap_or(false),
+                        );
+                        mixer.set_bus_effects(bus, bus_effects(world, bus_entity));
+                        mixer.set_bus_ducking(bus, bus_ducking(world, bus_entity));
+                    }
+                }),
+            // Restores the buses of despawned bus entities
+            query(audio_bus())
+                .incl(is_audio_bus())
+                .despawned()
+                .to_system(|q, world, qs, _| {
+                    let r = world.resource_entity();
+                    if !world.has_component(r, crate::audio_mixer()) {
+                        return;
+                    }
+
+                    let mixer = world.resource(crate::audio_mixer());
+                    for (_, bus) in q.collect_cloned(world, qs) {
+                        mixer.set_bus_volume(&bus, 1.0);
+                        mixer.set_bus_muted(&bus, false);
+                        mixer.set_bus_effects(&bus, Vec::new());
+                        mixer.set_bus_ducking(&bus, None);
+                    }
+                }),
             query(audio_url())
                 .incl(is_spatial_audio_player())
                 .incl(play_now())
@@ -94,6 +149,7 @@ pub fn audio_systems() -> SystemGroup {
                         continue;
                     };
                     mixer.stop(id);
+                    let _ = world.remove_component(playing_entity, crate::occlusion_params());
                     // stopping an emitter is different
                     if world.has_component(playing_entity, audio_emitter()) {
                         let _ = world.remove_component(playing_entity, audio_emitter());
@@ -156,6 +212,10 @@ pub fn audio_systems() -> SystemGroup {
                     let pan = world.get(player, panning()).unwrap_or(0.0);
                     let freq = world.get(player, onepole_lpf()).unwrap_or(20000.0);
                     let looping = world.get(player, looping()).unwrap_or(false);
+                    let streaming = world.get(player, audio_streaming()).unwrap_or(false);
+                    let bus = world
+                        .get_cloned(player, audio_bus())
+                        .unwrap_or_else(|_| DEFAULT_BUS.to_string());
 
                     world.remove_component(player, play_now()).unwrap();
 
@@ -170,7 +230,7 @@ pub fn audio_systems() -> SystemGroup {
 
                     runtime.spawn(async move {
                         let track =
-                            unwrap_log_warn!(AudioFromUrl { url: url.clone() }.get(&assets).await);
+                            unwrap_log_warn!(PlayerTrack::load(url, streaming, &assets).await);
                         let id_arc = Arc::new(Mutex::new(None));
                         let id_arc_clone = id_arc.clone();
                         let count_arc = Arc::new(Mutex::new(None));
@@ -191,16 +251,11 @@ pub fn audio_systems() -> SystemGroup {
                             };
                             id_arc.lock().replace(id);
 
-                            let mut t: Box<dyn Source> = if looping {
-                                Box::new(track.decode().repeat())
-                            } else {
-                                let decoded = track.decode();
-                                let count = decoded.sample_count().unwrap();
-                                let sr = decoded.sample_rate();
-                                *count_arc.lock() = Some(count);
-                                *sr_arc.lock() = Some(sr);
-                                Box::new(decoded)
-                            };
+                            let mut t = track.decode(looping);
+                            if !looping {
+                                *count_arc.lock() = t.sample_count();
+                                *sr_arc.lock() = Some(t.sample_rate());
+                            }
                             let a = Arc::new(Mutex::new(amp));
                             t = t.gain(a.clone());
                             let p = Arc::new(Mutex::new(pan));
@@ -214,7 +269,7 @@ pub fn audio_systems() -> SystemGroup {
                             let _ = world.add_component(id, crate::onepole_arc(), f);
 
                             let mixer = world.resource(crate::audio_mixer());
-                            let sound = mixer.play(t);
+                            let sound = mixer.play_on_bus(t, &bus);
 
                             let _ = world.add_component(id, crate::sound_id(), sound.id);
                         });
@@ -241,6 +296,41 @@ pub fn audio_systems() -> SystemGroup {
     )
 }
 
+fn bus_effects(world: &World, bus_entity: EntityId) -> Vec<BusEffect> {
+    [
+        world
+            .get(bus_entity, audio_bus_high_pass())
+            .ok()
+            .map(BusEffect::HighPass),
+        world
+            .get(bus_entity, audio_bus_low_pass())
+            .ok()
+            .map(BusEffect::LowPass),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn bus_ducking(world: &World, bus_entity: EntityId) -> Option<Ducking> {
+    let trigger = world.get_cloned(bus_entity, audio_bus_ducked_by()).ok()?;
+    let defaults = Ducking::new(trigger, 0.3);
+    Some(Ducking {
+        gain: world
+            .get(bus_entity, audio_bus_duck_gain())
+            .unwrap_or(defaults.gain),
+        attack: world
+            .get(bus_entity, audio_bus_duck_attack())
+            .map(|seconds| Duration::from_secs_f32(seconds.max(0.0)))
+            .unwrap_or(defaults.attack),
+        release: world
+            .get(bus_entity, audio_bus_duck_release())
+            .map(|seconds| Duration::from_secs_f32(seconds.max(0.0)))
+            .unwrap_or(defaults.release),
+        ..defaults
+    })
+}
+
 fn process_player(world: &mut World, player: EntityId, url: &str) {
     // check if mute_audio is set
     let r = world.resource_entity();
@@ -250,6 +340,11 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
 
     let amp = world.get(player, amplitude()).unwrap_or(1.0);
     let looping = world.get(player, looping()).unwrap_or(false);
+    let streaming = world.get(player, audio_streaming()).unwrap_or(false);
+    let occlusion = crate::environment::player_occlusion_params(world, player);
+    let bus = world
+        .get_cloned(player, audio_bus())
+        .unwrap_or_else(|_| DEFAULT_BUS.to_string());
     world.remove_component(player, play_now()).unwrap();
 
     let assets = world.resource(asset_cache()).clone();
@@ -258,7 +353,7 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
     let url = unwrap_log_warn!(AbsAssetUrl::from_str(url).and_then(|u| u.to_download_url(&assets)));
 
     runtime.spawn(async move {
-        let track = unwrap_log_warn!(AudioFromUrl { url: url.clone() }.get(&assets).await);
+        let track = unwrap_log_warn!(PlayerTrack::load(url, streaming, &assets).await);
         async_run.run(move |world| {
             let Ok(listener_id) = world.get(player, spatial_audio_listener()) else {
                 return;
@@ -285,23 +380,65 @@ fn process_player(world: &mut World, player: EntityId, url: &str) {
                 },
                 pos: pos_emitter,
             }));
+            let occlusion = Arc::new(Mutex::new(occlusion));
             let _ = world.add_component(emitter_id, audio_emitter(), emitter.clone());
             let _ = world.add_component(listener_id, audio_listener(), listener.clone());
+            let _ = world.add_component(emitter_id, crate::occlusion_params(), occlusion.clone());
 
             let hrtf_lib = world.resource(hrtf_lib());
+            let reverb = world.resource(crate::reverb_params()).clone();
 
             let mixer = world.resource(crate::audio_mixer());
-            let source: Box<dyn Source> = if looping {
-                Box::new(track.decode().repeat().spatial(hrtf_lib, listener, emitter))
-            } else {
-                Box::new(track.decode().spatial(hrtf_lib, listener, emitter))
-            };
-            let sound = mixer.play(source);
+            let source = track
+                .decode(looping)
+                .spatial(hrtf_lib, listener, emitter)
+                .occlusion(occlusion)
+                .reverb(reverb);
+            let sound = mixer.play_on_bus(source, &bus);
             let _ = world.add_component(emitter_id, crate::sound_id(), sound.id);
         });
     });
 }
 
+/// The track of an audio player, which is either loaded into memory or streamed from the asset
+/// cache.
+enum PlayerTrack {
+    Memory(Arc<Track>),
+    #[cfg(not(target_os = "unknown"))]
+    Streamed(Arc<ambient_audio::streamed_track::StreamedTrack>),
+}
+
+impl PlayerTrack {
+    async fn load(
+        url: AbsAssetUrl,
+        streaming: bool,
+        assets: &AssetCache,
+    ) -> Result<Self, Arc<ambient_audio::Error>> {
+        // Streaming reads from the asset cache on disk, so on the web the track is loaded into
+        // memory instead
+        #[cfg(not(target_os = "unknown"))]
+        if streaming {
+            let track = ambient_audio::AudioStreamFromUrl { url }
+                .get(assets)
+                .await?;
+            return Ok(Self::Streamed(track));
+        }
+        #[cfg(target_os = "unknown")]
+        let _ = streaming;
+
+        Ok(Self::Memory(AudioFromUrl { url }.get(assets).await?))
+    }
+
+    fn decode(&self, looping: bool) -> Box<dyn Source> {
+        match self {
+            Self::Memory(track) if looping => Box::new(track.decode().repeat()),
+            Self::Memory(track) => Box::new(track.decode()),
+            #[cfg(not(target_os = "unknown"))]
+            Self::Streamed(track) => Box::new(track.decode(looping)),
+        }
+    }
+}
+
 pub fn client_systems() -> SystemGroup {
     SystemGroup::new("audio", vec![Box::new(audio_systems())])
 }
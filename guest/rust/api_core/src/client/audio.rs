@@ -8,10 +8,22 @@ use crate::{
         hierarchy::components::{children, parent, unmanaged_children},
         transform::components::translation,
     },
+    ecs::{Component, SupportedValue},
     entity,
     prelude::{game_time, Entity, EntityId, Vec3},
 };
 
+/// The bus that all other buses are mixed into.
+pub const MASTER_BUS: &str = "master";
+/// The bus for music.
+pub const MUSIC_BUS: &str = "music";
+/// The bus for sound effects, which sounds are played on by default.
+pub const SFX_BUS: &str = "sfx";
+/// The bus for dialogue.
+pub const VOICE_BUS: &str = "voice";
+/// The bus for the sounds of the user interface.
+pub const UI_BUS: &str = "ui";
+
 /// stop the audio on the given entity
 pub fn stop(entity: EntityId) {
     if entity::exists(entity) {
@@ -59,6 +71,25 @@ impl SpatialAudioPlayer {
         entity::add_component(self.player, looping(), val);
     }
 
+    /// Set whether the sounds of this player are streamed from disk while they play, rather than
+    /// loaded into memory first. Use this for long sounds, such as music
+    pub fn set_streaming(&self, val: bool) {
+        entity::add_component(self.player, audio_streaming(), val);
+    }
+
+    /// Set the bus that the sounds of this player are played on, such as [MUSIC_BUS]
+    pub fn set_bus(&self, bus: impl Into<String>) {
+        entity::add_component(self.player, audio_bus(), bus.into());
+    }
+
+    /// Set how the sounds of this player are muffled when an entity with `audio_occluder` is
+    /// between them and the listener: the cutoff frequency of the low pass filter in Hz, and the
+    /// gain, when they are fully occluded
+    pub fn set_occlusion(&self, low_pass: f32, gain: f32) {
+        entity::add_component(self.player, audio_occlusion_low_pass(), low_pass);
+        entity::add_component(self.player, audio_occlusion_gain(), gain);
+    }
+
     pub fn play_sound_on_entity(&self, url: impl Into<String>, emitter: EntityId) {
         entity::add_component(self.player, spatial_audio_emitter(), emitter);
         entity::add_component(self.player, audio_url(), url.into());
@@ -118,6 +149,11 @@ impl AudioPlayer {
     pub fn set_looping(&self, val: bool) {
         entity::add_component(self.entity, looping(), val);
     }
+    /// Set whether the sound is streamed from disk while it plays, rather than loaded into memory
+    /// first. Use this for long sounds, such as music
+    pub fn set_streaming(&self, val: bool) {
+        entity::add_component(self.entity, audio_streaming(), val);
+    }
 
     /// Add a simple onepole lowpass filter to the sound with one param: roll off frequency
     pub fn add_one_pole_lpf(&self, rolloff_freq: f32) {
@@ -132,6 +168,10 @@ impl AudioPlayer {
     pub fn set_panning(&self, pan: f32) {
         entity::add_component(self.entity, panning(), pan);
     }
+    /// Set the bus that the sounds of this player are played on, such as [MUSIC_BUS]
+    pub fn set_bus(&self, bus: impl Into<String>) {
+        entity::add_component(self.entity, audio_bus(), bus.into());
+    }
     /// Play the sound, this will generate a new entity that represents the playing sound.
     pub fn play(&self, url: String) -> EntityId {
         entity::add_component(self.entity, audio_url(), url);
@@ -147,3 +187,67 @@ impl AudioPlayer {
         id
     }
 }
+
+/// Controls an audio bus, which groups sounds so that their volume and effects can be controlled
+/// together.
+///
+/// The volume of a bus is multiplied with the volume that the player chose for it in their
+/// settings. When the controlling entity is despawned, the bus is restored to its defaults.
+#[derive(Debug, Clone)]
+pub struct AudioBus {
+    /// The entity that controls the bus
+    pub entity: EntityId,
+}
+
+impl AudioBus {
+    /// Start controlling the bus `bus`, such as [MUSIC_BUS]. Buses that do not exist yet are
+    /// created.
+    pub fn new(bus: impl Into<String>) -> Self {
+        let entity = Entity::new()
+            .with(is_audio_bus(), ())
+            .with(audio_bus(), bus.into())
+            .with(name(), "Audio bus".to_string())
+            .spawn();
+        Self { entity }
+    }
+    /// Set the volume of the bus, 0.0 is 0%, 1.0 is 100%
+    pub fn set_volume(&self, volume: f32) {
+        entity::add_component(self.entity, audio_bus_volume(), volume);
+    }
+    /// Mute or unmute the bus
+    pub fn set_muted(&self, muted: bool) {
+        entity::add_component(self.entity, audio_bus_muted(), muted);
+    }
+    /// Filter the bus with a low pass filter with the given cutoff frequency, or remove it
+    pub fn set_low_pass(&self, cutoff: Option<f32>) {
+        set_or_remove(self.entity, audio_bus_low_pass(), cutoff);
+    }
+    /// Filter the bus with a high pass filter with the given cutoff frequency, or remove it
+    pub fn set_high_pass(&self, cutoff: Option<f32>) {
+        set_or_remove(self.entity, audio_bus_high_pass(), cutoff);
+    }
+    /// Lower the volume of this bus to `gain` while the bus `trigger` is playing, such as ducking
+    /// the music while dialogue is playing. `None` stops ducking this bus.
+    pub fn set_ducked_by(&self, trigger: Option<(String, f32)>) {
+        let (trigger, gain) = trigger.unzip();
+        set_or_remove(self.entity, audio_bus_ducked_by(), trigger);
+        set_or_remove(self.entity, audio_bus_duck_gain(), gain);
+    }
+    /// Set how long it takes to lower the volume of this bus when it is ducked, and to restore it
+    /// afterwards
+    pub fn set_duck_times(&self, attack: Duration, release: Duration) {
+        entity::add_component(self.entity, audio_bus_duck_attack(), attack.as_secs_f32());
+        entity::add_component(self.entity, audio_bus_duck_release(), release.as_secs_f32());
+    }
+    /// Stop controlling the bus, restoring it to its defaults
+    pub fn despawn(self) {
+        entity::despawn(self.entity);
+    }
+}
+
+fn set_or_remove<T: SupportedValue>(entity: EntityId, component: Component<T>, value: Option<T>) {
+    match value {
+        Some(value) => entity::add_component(entity, component, value),
+        None => entity::remove_component(entity, component),
+    }
+}
@@ -1,17 +1,25 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use crate::{
-    core::ui::{components::focus, messages::FocusChanged},
+    core::{
+        input::components::{
+            input_action_id, input_action_package, input_action_pressed, input_axis_id,
+            input_axis_package, input_axis_value,
+        },
+        ui::{components::focus, messages::FocusChanged},
+    },
+    ecs::{query, Component, GeneralQuery},
     entity,
-    global::{CursorIcon, Vec2},
+    global::{CursorIcon, EntityId, Vec2},
     internal::{
         conversion::{FromBindgen, IntoBindgen},
         wit,
     },
     prelude::ModuleMessage,
 };
+use once_cell::sync::Lazy;
 
-pub use ambient_shared_types::MouseButton;
+pub use ambient_shared_types::{GamepadButton, MouseButton};
 
 /// Gets the local player's most recent raw input state.
 ///
@@ -51,6 +59,62 @@ pub fn set_cursor_lock(locked: bool) {
     wit::client_input::set_cursor_lock(locked);
 }
 
+/// Rumbles the gamepad with the given id for `duration` seconds, replacing any rumble that is
+/// already playing on it.
+///
+/// `strong` and `weak` are the strengths of the low- and high-frequency motors, from 0 to 1.
+/// This does nothing if the gamepad does not support rumble, which includes all gamepads on the
+/// web client.
+pub fn rumble_gamepad(gamepad: u32, strong: f32, weak: f32, duration: f32) {
+    wit::client_input::rumble_gamepad(gamepad, strong, weak, duration);
+}
+
+/// Sets whether the first touch on the screen also moves the cursor and holds down the left mouse
+/// button. This makes content that was made for the mouse, like UI, usable on touchscreens,
+/// such as in mobile browsers.
+///
+/// The touches are still available in [Input::touches] when this is enabled.
+pub fn set_touch_mouse_emulation(enabled: bool) {
+    wit::client_input::set_touch_mouse_emulation(enabled);
+}
+
+/// Returns true if the input action `action`, declared in the `actions` section of the manifest of
+/// `package`, is pressed. The player may have rebound the action, so this should be preferred over
+/// checking for specific keys or buttons.
+///
+/// Use `packages::this::entity()` for the actions of your own package.
+pub fn is_action_pressed(package: EntityId, action: &str) -> bool {
+    static QUERY: Lazy<GeneralQuery<(Component<String>, Component<EntityId>, Component<bool>)>> =
+        Lazy::new(|| {
+            query((
+                input_action_id(),
+                input_action_package(),
+                input_action_pressed(),
+            ))
+            .build()
+        });
+
+    QUERY
+        .evaluate()
+        .into_iter()
+        .any(|(_, (id, p, pressed))| p == package && id == action && pressed)
+}
+
+/// Returns the value of the input axis `axis`, declared in the `axes` section of the manifest of
+/// `package`, from -1 to 1. Returns 0 if there is no such axis.
+///
+/// Use `packages::this::entity()` for the axes of your own package.
+pub fn get_axis(package: EntityId, axis: &str) -> f32 {
+    static QUERY: Lazy<GeneralQuery<(Component<String>, Component<EntityId>, Component<f32>)>> =
+        Lazy::new(|| query((input_axis_id(), input_axis_package(), input_axis_value())).build());
+
+    QUERY
+        .evaluate()
+        .into_iter()
+        .find(|(_, (id, p, _))| *p == package && id == axis)
+        .map_or(0.0, |(_, (_, _, value))| value)
+}
+
 /// Focus id of the "game"; i.e. 3D world rather than any UI element
 pub const GAME_FOCUS_ID: &str = "Game";
 
@@ -457,6 +521,84 @@ impl FromBindgen for wit::client_input::MouseButton {
     }
 }
 
+impl FromBindgen for wit::client_input::GamepadButton {
+    type Item = GamepadButton;
+
+    fn from_bindgen(self) -> Self::Item {
+        match self {
+            Self::South => Self::Item::South,
+            Self::East => Self::Item::East,
+            Self::North => Self::Item::North,
+            Self::West => Self::Item::West,
+            Self::LeftBumper => Self::Item::LeftBumper,
+            Self::RightBumper => Self::Item::RightBumper,
+            Self::LeftTrigger => Self::Item::LeftTrigger,
+            Self::RightTrigger => Self::Item::RightTrigger,
+            Self::Select => Self::Item::Select,
+            Self::Start => Self::Item::Start,
+            Self::Mode => Self::Item::Mode,
+            Self::LeftStick => Self::Item::LeftStick,
+            Self::RightStick => Self::Item::RightStick,
+            Self::DPadUp => Self::Item::DPadUp,
+            Self::DPadDown => Self::Item::DPadDown,
+            Self::DPadLeft => Self::Item::DPadLeft,
+            Self::DPadRight => Self::Item::DPadRight,
+        }
+    }
+}
+
+/// The state of a connected gamepad.
+///
+/// The deadzones from the player's settings have already been applied to the sticks and triggers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gamepad {
+    /// The name of the gamepad, as reported by the system.
+    pub name: String,
+    /// All of the buttons being pressed this frame.
+    pub buttons: HashSet<GamepadButton>,
+    /// The position of the left stick, from -1 to 1 on both axes, with up being positive Y.
+    pub left_stick: Vec2,
+    /// The position of the right stick, from -1 to 1 on both axes, with up being positive Y.
+    pub right_stick: Vec2,
+    /// How far the left trigger is pulled, from 0 to 1.
+    pub left_trigger: f32,
+    /// How far the right trigger is pulled, from 0 to 1.
+    pub right_trigger: f32,
+}
+
+impl FromBindgen for wit::client_input::Gamepad {
+    type Item = Gamepad;
+    fn from_bindgen(self) -> Self::Item {
+        Self::Item {
+            name: self.name,
+            buttons: self.buttons.into_iter().map(|b| b.from_bindgen()).collect(),
+            left_stick: self.left_stick.from_bindgen(),
+            right_stick: self.right_stick.from_bindgen(),
+            left_trigger: self.left_trigger,
+            right_trigger: self.right_trigger,
+        }
+    }
+}
+
+/// A finger touching the screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Touch {
+    /// The position of the touch, in pixels from the top left of the window.
+    pub position: Vec2,
+    /// The movement of the touch since the last frame.
+    pub delta: Vec2,
+}
+
+impl FromBindgen for wit::client_input::Touch {
+    type Item = Touch;
+    fn from_bindgen(self) -> Self::Item {
+        Self::Item {
+            position: self.position.from_bindgen(),
+            delta: self.delta.from_bindgen(),
+        }
+    }
+}
+
 /// The state of a player's raw input. Get these with [get] or [get_previous].
 #[derive(Clone, Debug, PartialEq)]
 pub struct Input {
@@ -472,6 +614,17 @@ pub struct Input {
     pub mouse_wheel: f32,
     /// All of the mouse buttons being pressed this frame.
     pub mouse_buttons: HashSet<MouseButton>,
+    /// All of the connected gamepads, by their id. Use the id with [rumble_gamepad].
+    pub gamepads: BTreeMap<u32, Gamepad>,
+    /// All of the touches on the screen, by their id. A touch that starts and ends within the
+    /// same frame is still seen for one frame, so that taps are not missed.
+    pub touches: BTreeMap<u64, Touch>,
+    /// How far the touches moved since the last frame, on average. This can be used to pan the
+    /// camera by dragging with one or more fingers.
+    pub touch_pan: Vec2,
+    /// How much the distance between the first two touches changed since the last frame, in
+    /// pixels. This is positive when they move apart, which is usually used to zoom in.
+    pub touch_pinch: f32,
 }
 
 impl FromBindgen for wit::client_input::Input {
@@ -487,6 +640,18 @@ impl FromBindgen for wit::client_input::Input {
                 .into_iter()
                 .map(|b| b.from_bindgen())
                 .collect(),
+            gamepads: self
+                .gamepads
+                .into_iter()
+                .map(|g| (g.id, g.from_bindgen()))
+                .collect(),
+            touches: self
+                .touches
+                .into_iter()
+                .map(|t| (t.id, t.from_bindgen()))
+                .collect(),
+            touch_pan: self.touch_pan.from_bindgen(),
+            touch_pinch: self.touch_pinch,
         }
     }
 }
@@ -509,6 +674,25 @@ pub struct InputDelta {
     pub mouse_buttons: HashSet<MouseButton>,
     /// All of the mouse buttons that were released this frame.
     pub mouse_buttons_released: HashSet<MouseButton>,
+    /// The ids of the gamepads that were connected this frame.
+    pub gamepads_connected: HashSet<u32>,
+    /// The ids of the gamepads that were disconnected this frame.
+    pub gamepads_disconnected: HashSet<u32>,
+    /// The changes to the buttons of each gamepad that is connected this frame.
+    pub gamepads: BTreeMap<u32, GamepadDelta>,
+    /// The ids of the touches that started this frame.
+    pub touches_started: HashSet<u64>,
+    /// The ids of the touches that ended this frame.
+    pub touches_ended: HashSet<u64>,
+}
+
+/// The changes to the buttons of a gamepad between two frames. Part of [InputDelta].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GamepadDelta {
+    /// All of the buttons that were pressed this frame, but not last frame.
+    pub buttons: HashSet<GamepadButton>,
+    /// All of the buttons that were released this frame.
+    pub buttons_released: HashSet<GamepadButton>,
 }
 
 impl Input {
@@ -523,8 +707,52 @@ impl Input {
             mouse_wheel: c.mouse_wheel - p.mouse_wheel,
             mouse_buttons: &c.mouse_buttons - &p.mouse_buttons,
             mouse_buttons_released: &p.mouse_buttons - &c.mouse_buttons,
+            gamepads_connected: c
+                .gamepads
+                .keys()
+                .filter(|id| !p.gamepads.contains_key(id))
+                .copied()
+                .collect(),
+            gamepads_disconnected: p
+                .gamepads
+                .keys()
+                .filter(|id| !c.gamepads.contains_key(id))
+                .copied()
+                .collect(),
+            gamepads: c
+                .gamepads
+                .iter()
+                .map(|(id, c)| {
+                    // Newly connected gamepads had no buttons pressed last frame
+                    let none = HashSet::new();
+                    let p = p.gamepads.get(id).map_or(&none, |p| &p.buttons);
+                    let delta = GamepadDelta {
+                        buttons: &c.buttons - p,
+                        buttons_released: p - &c.buttons,
+                    };
+                    (*id, delta)
+                })
+                .collect(),
+            touches_started: c
+                .touches
+                .keys()
+                .filter(|id| !p.touches.contains_key(id))
+                .copied()
+                .collect(),
+            touches_ended: p
+                .touches
+                .keys()
+                .filter(|id| !c.touches.contains_key(id))
+                .copied()
+                .collect(),
         }
     }
+
+    /// Returns the connected gamepad with the lowest id, which is usually the one that was
+    /// connected first. This is convenient for games that are played with a single gamepad.
+    pub fn gamepad(&self) -> Option<(u32, &Gamepad)> {
+        self.gamepads.iter().next().map(|(id, g)| (*id, g))
+    }
 }
 
 impl IntoBindgen for CursorIcon {
@@ -2,8 +2,8 @@ use std::{
     cell::RefCell,
     future::Future,
     rc::Rc,
-    task::Poll,
-    time::{Duration, Instant},
+    task::{Poll, Waker},
+    time::Duration,
 };
 
 use crate::{
@@ -13,6 +13,7 @@ use crate::{
     internal::executor::EXECUTOR,
     message::Listener,
     prelude::RuntimeMessage,
+    timer,
 };
 
 /// The time, relative to the start of the game. Guaranteed to be monotonic.
@@ -86,12 +87,17 @@ pub fn run_async<R: CallbackReturn>(future: impl Future<Output = R> + 'static) {
 /// Stops execution of this function until the provided `condition` is true.
 /// Useful for waiting for something to happen in the game world.
 ///
+/// The condition is checked every frame, so this keeps the module running every frame until it
+/// is true.
+///
 /// This must be used with `.await` in either an `async fn` or an `async` block.
 pub async fn block_until(condition: impl Fn() -> bool) {
-    std::future::poll_fn(move |_cx| {
+    std::future::poll_fn(move |cx| {
         if condition() {
             Poll::Ready(())
         } else {
+            // Check again on the next frame
+            cx.waker().wake_by_ref();
             Poll::Pending
         }
     })
@@ -100,10 +106,11 @@ pub async fn block_until(condition: impl Fn() -> bool) {
 
 /// Stops execution of this function until `seconds` has passed.
 ///
+/// This uses a [timer](crate::timer), so the module is not run while it waits.
+///
 /// This must be used with `.await` in either an `async fn` or an `async` block.
 pub async fn sleep(seconds: f32) {
-    let target_time = Instant::now() + Duration::from_secs_f32(seconds);
-    block_until(|| Instant::now() > target_time).await
+    timer::wait(timer::Clock::Wall, Duration::from_secs_f32(seconds)).await
 }
 
 /// Stops execution of this function until the given [`RuntimeMessage`] is received.
@@ -114,18 +121,23 @@ pub async fn wait_for_runtime_message<T: RuntimeMessage + Clone + 'static>(
     is_relevant: impl Fn(&T) -> bool + 'static,
 ) -> T {
     let result = Rc::new(RefCell::new(None));
+    let waker = Rc::new(RefCell::new(None::<Waker>));
     let mut listener = Some(T::subscribe({
         let result = result.clone();
+        let waker = waker.clone();
         move |response| {
             if !is_relevant(&response) {
                 return;
             }
 
             *result.borrow_mut() = Some(response);
+            if let Some(waker) = waker.borrow_mut().take() {
+                waker.wake();
+            }
         }
     }));
 
-    std::future::poll_fn(move |_cx| match &*result.borrow() {
+    std::future::poll_fn(move |cx| match &*result.borrow() {
         Some(r) => {
             let r = (*r).clone();
             if let Some(listener) = listener.take() {
@@ -133,7 +145,10 @@ pub async fn wait_for_runtime_message<T: RuntimeMessage + Clone + 'static>(
             }
             Poll::Ready(r)
         }
-        _ => Poll::Pending,
+        _ => {
+            *waker.borrow_mut() = Some(cx.waker().clone());
+            Poll::Pending
+        }
     })
     .await
 }
@@ -152,28 +167,37 @@ pub async fn wait_for_fallible_runtime_messages<
     is_relevant_failure: impl Fn(&Failure) -> bool + 'static,
 ) -> Result<Success, Failure> {
     let result = Rc::new(RefCell::new(None));
+    let waker = Rc::new(RefCell::new(None::<Waker>));
     let mut success_listener = Some(Success::subscribe({
         let result = result.clone();
+        let waker = waker.clone();
         move |response| {
             if !is_relevant_success(&response) {
                 return;
             }
 
             *result.borrow_mut() = Some(Ok(response));
+            if let Some(waker) = waker.borrow_mut().take() {
+                waker.wake();
+            }
         }
     }));
     let mut failure_listener = Some(Failure::subscribe({
         let result = result.clone();
+        let waker = waker.clone();
         move |response| {
             if !is_relevant_failure(&response) {
                 return;
             }
 
             *result.borrow_mut() = Some(Err(response));
+            if let Some(waker) = waker.borrow_mut().take() {
+                waker.wake();
+            }
         }
     }));
 
-    std::future::poll_fn(move |_cx| match &*result.borrow() {
+    std::future::poll_fn(move |cx| match &*result.borrow() {
         Some(r) => {
             let r = (*r).clone();
             if let Some(listener) = success_listener.take() {
@@ -184,7 +208,10 @@ pub async fn wait_for_fallible_runtime_messages<
             }
             Poll::Ready(r)
         }
-        _ => Poll::Pending,
+        _ => {
+            *waker.borrow_mut() = Some(cx.waker().clone());
+            Poll::Pending
+        }
     })
     .await
 }
@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+
+use crate::internal::{executor::EXECUTOR, wit};
+
+/// The runtime message that asks this module to save its state before it is hot reloaded.
+const SAVE_MESSAGE: &str = "ambient_core::hot_reload_save";
+
+thread_local! {
+    static SAVE: RefCell<Option<Box<dyn Fn() -> Vec<u8>>>> = RefCell::new(None);
+}
+
+/// Opts this module into hot reloading.
+///
+/// When the module is rebuilt, `save` is called on the old version of the module, and the bytes
+/// it returns are given to the new version through [take_state]. The entities spawned by the old
+/// version are kept, and belong to the new version. Calling this again replaces `save`.
+///
+/// Modules that have not called this are unloaded and started afresh when they are rebuilt.
+pub fn on_save(save: impl Fn() -> Vec<u8> + 'static) {
+    let registered = SAVE.with(|cell| cell.borrow_mut().replace(Box::new(save)).is_some());
+    if registered {
+        return;
+    }
+
+    wit::message::subscribe(SAVE_MESSAGE);
+    EXECUTOR.register_callback(
+        SAVE_MESSAGE.to_string(),
+        Box::new(|_, _, _| {
+            if let Some(state) = SAVE.with(|cell| cell.borrow().as_ref().map(|save| save())) {
+                wit::hot_reload::save_state(&state);
+            }
+            Ok(())
+        }),
+    );
+}
+
+/// Returns the state saved by the previous version of this module if it was hot reloaded, or
+/// `None` if the module was started afresh.
+///
+/// This should be called in `main`, which runs again after a hot reload; the state can only be
+/// taken once.
+pub fn take_state() -> Option<Vec<u8>> {
+    wit::hot_reload::take_state()
+}
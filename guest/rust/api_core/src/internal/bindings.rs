@@ -11737,6 +11737,190 @@ pub mod ambient {
                                   
                                 }
                                 
+                                #[allow(clippy::all)]
+                                pub mod timer {
+                                  #[used]
+                                  #[doc(hidden)]
+                                  #[cfg(target_arch = "wasm32")]
+                                  static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                  #[repr(u8)]
+                                  #[derive(Clone, Copy, PartialEq, Eq)]
+                                  pub enum Clock {
+                                    Game,
+                                    Wall,
+                                  }
+                                  impl ::core::fmt::Debug for Clock {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      match self {
+                                        Clock::Game => {
+                                          f.debug_tuple("Clock::Game").finish()
+                                        }
+                                        Clock::Wall => {
+                                          f.debug_tuple("Clock::Wall").finish()
+                                        }
+                                      }
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn start(name: &str,clock: Clock,delay: f32,interval: Option<f32>,){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      let vec0 = name;
+                                      let ptr0 = vec0.as_ptr() as i32;
+                                      let len0 = vec0.len() as i32;
+                                      let (result1_0,result1_1,) = match interval {
+                                        Some(e) => (1i32, wit_bindgen::rt::as_f32(e)),
+                                        None => {
+                                          (0i32, 0.0f32)
+                                        }
+                                      };
+                                      #[link(wasm_import_module = "ambient:bindings/timer")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "start")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/timer_start")]
+                                        fn wit_import(
+                                        _: i32, _: i32, _: i32, _: f32, _: i32, _: f32, );
+                                      }
+                                      wit_import(ptr0, len0, clock.clone() as i32, wit_bindgen::rt::as_f32(delay), result1_0, result1_1);
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn cancel(name: &str,){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      let vec0 = name;
+                                      let ptr0 = vec0.as_ptr() as i32;
+                                      let len0 = vec0.len() as i32;
+                                      
+                                      #[link(wasm_import_module = "ambient:bindings/timer")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "cancel")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/timer_cancel")]
+                                        fn wit_import(
+                                        _: i32, _: i32, );
+                                      }
+                                      wit_import(ptr0, len0);
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn remaining(name: &str,) -> Option<f32>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let vec0 = name;
+                                      let ptr0 = vec0.as_ptr() as i32;
+                                      let len0 = vec0.len() as i32;
+                                      let ptr1 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/timer")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "remaining")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/timer_remaining")]
+                                        fn wit_import(
+                                        _: i32, _: i32, _: i32, );
+                                      }
+                                      wit_import(ptr0, len0, ptr1);
+                                      match i32::from(*((ptr1 + 0) as *const u8)) {
+                                        0 => None,
+                                        1 => Some(*((ptr1 + 4) as *const f32)),
+                                        #[cfg(not(debug_assertions))]
+                                        _ => ::core::hint::unreachable_unchecked(),
+                                        #[cfg(debug_assertions)]
+                                        _ => panic!("invalid enum discriminant"),
+                                      }
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn set_frame_wakeups(enabled: bool,){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[link(wasm_import_module = "ambient:bindings/timer")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "set-frame-wakeups")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/timer_set-frame-wakeups")]
+                                        fn wit_import(
+                                        _: i32, );
+                                      }
+                                      wit_import(match enabled { true => 1, false => 0 });
+                                    }
+                                  }
+                                  
+                                }
+                                
+                                
+                                #[allow(clippy::all)]
+                                pub mod hot_reload {
+                                  #[used]
+                                  #[doc(hidden)]
+                                  #[cfg(target_arch = "wasm32")]
+                                  static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                  #[allow(clippy::all)]
+                                  pub fn save_state(state: &[u8],){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      let vec0 = state;
+                                      let ptr0 = vec0.as_ptr() as i32;
+                                      let len0 = vec0.len() as i32;
+                                      
+                                      #[link(wasm_import_module = "ambient:bindings/hot-reload")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "save-state")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/hot-reload_save-state")]
+                                        fn wit_import(
+                                        _: i32, _: i32, );
+                                      }
+                                      wit_import(ptr0, len0);
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn take_state() -> Option<wit_bindgen::rt::vec::Vec::<u8>>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 12]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let ptr0 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/hot-reload")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "take-state")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/hot-reload_take-state")]
+                                        fn wit_import(
+                                        _: i32, );
+                                      }
+                                      wit_import(ptr0);
+                                      match i32::from(*((ptr0 + 0) as *const u8)) {
+                                        0 => None,
+                                        1 => Some({
+                                          let len1 = *((ptr0 + 8) as *const i32) as usize;
+                                          
+                                          Vec::from_raw_parts(*((ptr0 + 4) as *const i32) as *mut _, len1, len1)
+                                        }),
+                                        #[cfg(not(debug_assertions))]
+                                        _ => ::core::hint::unreachable_unchecked(),
+                                        #[cfg(debug_assertions)]
+                                        _ => panic!("invalid enum discriminant"),
+                                      }
+                                    }
+                                  }
+                                  
+                                }
+                                
                                 
                                 #[allow(clippy::all)]
                                 pub mod client_message {
@@ -12560,6 +12744,115 @@ pub mod ambient {
                                       }
                                     }
                                   }
+                                  #[repr(u8)]
+                                  #[derive(Clone, Copy, PartialEq, Eq)]
+                                  pub enum GamepadButton {
+                                    /// The bottom face button.
+                                    South,
+                                    /// The right face button.
+                                    East,
+                                    /// The top face button.
+                                    North,
+                                    /// The left face button.
+                                    West,
+                                    LeftBumper,
+                                    RightBumper,
+                                    LeftTrigger,
+                                    RightTrigger,
+                                    Select,
+                                    Start,
+                                    Mode,
+                                    LeftStick,
+                                    RightStick,
+                                    DPadUp,
+                                    DPadDown,
+                                    DPadLeft,
+                                    DPadRight,
+                                  }
+                                  impl ::core::fmt::Debug for GamepadButton {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      match self {
+                                        GamepadButton::South => {
+                                          f.debug_tuple("GamepadButton::South").finish()
+                                        }
+                                        GamepadButton::East => {
+                                          f.debug_tuple("GamepadButton::East").finish()
+                                        }
+                                        GamepadButton::North => {
+                                          f.debug_tuple("GamepadButton::North").finish()
+                                        }
+                                        GamepadButton::West => {
+                                          f.debug_tuple("GamepadButton::West").finish()
+                                        }
+                                        GamepadButton::LeftBumper => {
+                                          f.debug_tuple("GamepadButton::LeftBumper").finish()
+                                        }
+                                        GamepadButton::RightBumper => {
+                                          f.debug_tuple("GamepadButton::RightBumper").finish()
+                                        }
+                                        GamepadButton::LeftTrigger => {
+                                          f.debug_tuple("GamepadButton::LeftTrigger").finish()
+                                        }
+                                        GamepadButton::RightTrigger => {
+                                          f.debug_tuple("GamepadButton::RightTrigger").finish()
+                                        }
+                                        GamepadButton::Select => {
+                                          f.debug_tuple("GamepadButton::Select").finish()
+                                        }
+                                        GamepadButton::Start => {
+                                          f.debug_tuple("GamepadButton::Start").finish()
+                                        }
+                                        GamepadButton::Mode => {
+                                          f.debug_tuple("GamepadButton::Mode").finish()
+                                        }
+                                        GamepadButton::LeftStick => {
+                                          f.debug_tuple("GamepadButton::LeftStick").finish()
+                                        }
+                                        GamepadButton::RightStick => {
+                                          f.debug_tuple("GamepadButton::RightStick").finish()
+                                        }
+                                        GamepadButton::DPadUp => {
+                                          f.debug_tuple("GamepadButton::DPadUp").finish()
+                                        }
+                                        GamepadButton::DPadDown => {
+                                          f.debug_tuple("GamepadButton::DPadDown").finish()
+                                        }
+                                        GamepadButton::DPadLeft => {
+                                          f.debug_tuple("GamepadButton::DPadLeft").finish()
+                                        }
+                                        GamepadButton::DPadRight => {
+                                          f.debug_tuple("GamepadButton::DPadRight").finish()
+                                        }
+                                      }
+                                    }
+                                  }
+                                  #[derive(Clone)]
+                                  pub struct Gamepad {
+                                    pub id: u32,
+                                    pub name: wit_bindgen::rt::string::String,
+                                    pub buttons: wit_bindgen::rt::vec::Vec::<GamepadButton>,
+                                    pub left_stick: Vec2,
+                                    pub right_stick: Vec2,
+                                    pub left_trigger: f32,
+                                    pub right_trigger: f32,
+                                  }
+                                  impl ::core::fmt::Debug for Gamepad {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      f.debug_struct("Gamepad").field("id", &self.id).field("name", &self.name).field("buttons", &self.buttons).field("left-stick", &self.left_stick).field("right-stick", &self.right_stick).field("left-trigger", &self.left_trigger).field("right-trigger", &self.right_trigger).finish()
+                                    }
+                                  }
+                                  #[repr(C)]
+                                  #[derive(Copy, Clone)]
+                                  pub struct Touch {
+                                    pub id: u64,
+                                    pub position: Vec2,
+                                    pub delta: Vec2,
+                                  }
+                                  impl ::core::fmt::Debug for Touch {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      f.debug_struct("Touch").field("id", &self.id).field("position", &self.position).field("delta", &self.delta).finish()
+                                    }
+                                  }
                                   #[derive(Clone)]
                                   pub struct Input {
                                     pub keys: wit_bindgen::rt::vec::Vec::<VirtualKeyCode>,
@@ -12567,10 +12860,14 @@ pub mod ambient {
                                     pub mouse_delta: Vec2,
                                     pub mouse_wheel: f32,
                                     pub mouse_buttons: wit_bindgen::rt::vec::Vec::<MouseButton>,
+                                    pub gamepads: wit_bindgen::rt::vec::Vec::<Gamepad>,
+                                    pub touches: wit_bindgen::rt::vec::Vec::<Touch>,
+                                    pub touch_pan: Vec2,
+                                    pub touch_pinch: f32,
                                   }
                                   impl ::core::fmt::Debug for Input {
                                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                                      f.debug_struct("Input").field("keys", &self.keys).field("mouse-position", &self.mouse_position).field("mouse-delta", &self.mouse_delta).field("mouse-wheel", &self.mouse_wheel).field("mouse-buttons", &self.mouse_buttons).finish()
+                                      f.debug_struct("Input").field("keys", &self.keys).field("mouse-position", &self.mouse_position).field("mouse-delta", &self.mouse_delta).field("mouse-wheel", &self.mouse_wheel).field("mouse-buttons", &self.mouse_buttons).field("gamepads", &self.gamepads).field("touches", &self.touches).field("touch-pan", &self.touch_pan).field("touch-pinch", &self.touch_pinch).finish()
                                     }
                                   }
                                   #[derive(Clone, Copy)]
@@ -12746,7 +13043,7 @@ pub mod ambient {
                                     unsafe {
                                       
                                       #[repr(align(4))]
-                                      struct RetArea([u8; 36]);
+                                      struct RetArea([u8; 64]);
                                       let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
                                       let ptr0 = ret_area.as_mut_ptr() as i32;
                                       #[link(wasm_import_module = "ambient:bindings/client-input")]
@@ -12945,7 +13242,54 @@ pub mod ambient {
                                         }}});
                                       }
                                       wit_bindgen::rt::dealloc(base2, (len2 as usize) * 4, 2);
-                                      Input{keys:result1, mouse_position:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 8) as *const f32), y:*((ptr0 + 12) as *const f32), }, mouse_delta:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 16) as *const f32), y:*((ptr0 + 20) as *const f32), }, mouse_wheel:*((ptr0 + 24) as *const f32), mouse_buttons:result2, }
+                                      let base7 = *((ptr0 + 36) as *const i32);
+                                      let len7 = *((ptr0 + 40) as *const i32);
+                                      let mut result7 = Vec::with_capacity(len7 as usize);
+                                      for i in 0..len7 {
+                                        let base = base7 + i *44;
+                                        result7.push({
+                                          let len3 = *((base + 8) as *const i32) as usize;
+                                          let base4 = *((base + 12) as *const i32);
+                                          let len4 = *((base + 16) as *const i32);
+                                          let mut result4 = Vec::with_capacity(len4 as usize);
+                                          for i in 0..len4 {
+                                            let base = base4 + i *1;
+                                            result4.push({#[cfg(debug_assertions)]{match i32::from(*((base + 0) as *const u8)) {
+                                              0 => GamepadButton::South,
+                                              1 => GamepadButton::East,
+                                              2 => GamepadButton::North,
+                                              3 => GamepadButton::West,
+                                              4 => GamepadButton::LeftBumper,
+                                              5 => GamepadButton::RightBumper,
+                                              6 => GamepadButton::LeftTrigger,
+                                              7 => GamepadButton::RightTrigger,
+                                              8 => GamepadButton::Select,
+                                              9 => GamepadButton::Start,
+                                              10 => GamepadButton::Mode,
+                                              11 => GamepadButton::LeftStick,
+                                              12 => GamepadButton::RightStick,
+                                              13 => GamepadButton::DPadUp,
+                                              14 => GamepadButton::DPadDown,
+                                              15 => GamepadButton::DPadLeft,
+                                              16 => GamepadButton::DPadRight,
+                                              _ => panic!("invalid enum discriminant"),
+                                            }}#[cfg(not(debug_assertions))]{::core::mem::transmute::<_, GamepadButton>(i32::from(*((base + 0) as *const u8)) as u8)}});
+                                          }
+                                          wit_bindgen::rt::dealloc(base4, (len4 as usize) * 1, 1);
+                                          
+                                          Gamepad{id:*((base + 0) as *const i32) as u32, name:{#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 4) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 4) as *const i32) as *mut _, len3, len3)).unwrap()}}, buttons:result4, left_stick:super::super::super::ambient::bindings::types::Vec2{x:*((base + 20) as *const f32), y:*((base + 24) as *const f32), }, right_stick:super::super::super::ambient::bindings::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }, left_trigger:*((base + 36) as *const f32), right_trigger:*((base + 40) as *const f32), }
+                                        });
+                                      }
+                                      wit_bindgen::rt::dealloc(base7, (len7 as usize) * 44, 4);
+                                      let base8 = *((ptr0 + 44) as *const i32);
+                                      let len8 = *((ptr0 + 48) as *const i32);
+                                      let mut result8 = Vec::with_capacity(len8 as usize);
+                                      for i in 0..len8 {
+                                        let base = base8 + i *24;
+                                        result8.push(Touch{id:*((base + 0) as *const i64) as u64, position:super::super::super::ambient::bindings::types::Vec2{x:*((base + 8) as *const f32), y:*((base + 12) as *const f32), }, delta:super::super::super::ambient::bindings::types::Vec2{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), }, });
+                                      }
+                                      wit_bindgen::rt::dealloc(base8, (len8 as usize) * 24, 8);
+                                      Input{keys:result1, mouse_position:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 8) as *const f32), y:*((ptr0 + 12) as *const f32), }, mouse_delta:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 16) as *const f32), y:*((ptr0 + 20) as *const f32), }, mouse_wheel:*((ptr0 + 24) as *const f32), mouse_buttons:result2, gamepads:result7, touches:result8, touch_pan:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 52) as *const f32), y:*((ptr0 + 56) as *const f32), }, touch_pinch:*((ptr0 + 60) as *const f32), }
                                     }
                                   }
                                   #[allow(clippy::all)]
@@ -12956,7 +13300,7 @@ pub mod ambient {
                                     unsafe {
                                       
                                       #[repr(align(4))]
-                                      struct RetArea([u8; 36]);
+                                      struct RetArea([u8; 64]);
                                       let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
                                       let ptr0 = ret_area.as_mut_ptr() as i32;
                                       #[link(wasm_import_module = "ambient:bindings/client-input")]
@@ -13155,7 +13499,54 @@ pub mod ambient {
                                         }}});
                                       }
                                       wit_bindgen::rt::dealloc(base2, (len2 as usize) * 4, 2);
-                                      Input{keys:result1, mouse_position:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 8) as *const f32), y:*((ptr0 + 12) as *const f32), }, mouse_delta:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 16) as *const f32), y:*((ptr0 + 20) as *const f32), }, mouse_wheel:*((ptr0 + 24) as *const f32), mouse_buttons:result2, }
+                                      let base7 = *((ptr0 + 36) as *const i32);
+                                      let len7 = *((ptr0 + 40) as *const i32);
+                                      let mut result7 = Vec::with_capacity(len7 as usize);
+                                      for i in 0..len7 {
+                                        let base = base7 + i *44;
+                                        result7.push({
+                                          let len3 = *((base + 8) as *const i32) as usize;
+                                          let base4 = *((base + 12) as *const i32);
+                                          let len4 = *((base + 16) as *const i32);
+                                          let mut result4 = Vec::with_capacity(len4 as usize);
+                                          for i in 0..len4 {
+                                            let base = base4 + i *1;
+                                            result4.push({#[cfg(debug_assertions)]{match i32::from(*((base + 0) as *const u8)) {
+                                              0 => GamepadButton::South,
+                                              1 => GamepadButton::East,
+                                              2 => GamepadButton::North,
+                                              3 => GamepadButton::West,
+                                              4 => GamepadButton::LeftBumper,
+                                              5 => GamepadButton::RightBumper,
+                                              6 => GamepadButton::LeftTrigger,
+                                              7 => GamepadButton::RightTrigger,
+                                              8 => GamepadButton::Select,
+                                              9 => GamepadButton::Start,
+                                              10 => GamepadButton::Mode,
+                                              11 => GamepadButton::LeftStick,
+                                              12 => GamepadButton::RightStick,
+                                              13 => GamepadButton::DPadUp,
+                                              14 => GamepadButton::DPadDown,
+                                              15 => GamepadButton::DPadLeft,
+                                              16 => GamepadButton::DPadRight,
+                                              _ => panic!("invalid enum discriminant"),
+                                            }}#[cfg(not(debug_assertions))]{::core::mem::transmute::<_, GamepadButton>(i32::from(*((base + 0) as *const u8)) as u8)}});
+                                          }
+                                          wit_bindgen::rt::dealloc(base4, (len4 as usize) * 1, 1);
+                                          
+                                          Gamepad{id:*((base + 0) as *const i32) as u32, name:{#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 4) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 4) as *const i32) as *mut _, len3, len3)).unwrap()}}, buttons:result4, left_stick:super::super::super::ambient::bindings::types::Vec2{x:*((base + 20) as *const f32), y:*((base + 24) as *const f32), }, right_stick:super::super::super::ambient::bindings::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }, left_trigger:*((base + 36) as *const f32), right_trigger:*((base + 40) as *const f32), }
+                                        });
+                                      }
+                                      wit_bindgen::rt::dealloc(base7, (len7 as usize) * 44, 4);
+                                      let base8 = *((ptr0 + 44) as *const i32);
+                                      let len8 = *((ptr0 + 48) as *const i32);
+                                      let mut result8 = Vec::with_capacity(len8 as usize);
+                                      for i in 0..len8 {
+                                        let base = base8 + i *24;
+                                        result8.push(Touch{id:*((base + 0) as *const i64) as u64, position:super::super::super::ambient::bindings::types::Vec2{x:*((base + 8) as *const f32), y:*((base + 12) as *const f32), }, delta:super::super::super::ambient::bindings::types::Vec2{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), }, });
+                                      }
+                                      wit_bindgen::rt::dealloc(base8, (len8 as usize) * 24, 8);
+                                      Input{keys:result1, mouse_position:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 8) as *const f32), y:*((ptr0 + 12) as *const f32), }, mouse_delta:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 16) as *const f32), y:*((ptr0 + 20) as *const f32), }, mouse_wheel:*((ptr0 + 24) as *const f32), mouse_buttons:result2, gamepads:result7, touches:result8, touch_pan:super::super::super::ambient::bindings::types::Vec2{x:*((ptr0 + 52) as *const f32), y:*((ptr0 + 56) as *const f32), }, touch_pinch:*((ptr0 + 60) as *const f32), }
                                     }
                                   }
                                   #[allow(clippy::all)]
@@ -13316,6 +13707,40 @@ pub mod ambient {
                                       wit_import(match locked { true => 1, false => 0 });
                                     }
                                   }
+                                  #[allow(clippy::all)]
+                                  pub fn rumble_gamepad(gamepad: u32,strong: f32,weak: f32,duration: f32,){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[link(wasm_import_module = "ambient:bindings/client-input")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "rumble-gamepad")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/client-input_rumble-gamepad")]
+                                        fn wit_import(
+                                        _: i32, _: f32, _: f32, _: f32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_i32(gamepad), wit_bindgen::rt::as_f32(strong), wit_bindgen::rt::as_f32(weak), wit_bindgen::rt::as_f32(duration));
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn set_touch_mouse_emulation(enabled: bool,){
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[link(wasm_import_module = "ambient:bindings/client-input")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "set-touch-mouse-emulation")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/client-input_set-touch-mouse-emulation")]
+                                        fn wit_import(
+                                        _: i32, );
+                                      }
+                                      wit_import(match enabled { true => 1, false => 0 });
+                                    }
+                                  }
                                   
                                 }
                                 
@@ -13526,6 +13951,7 @@ pub mod ambient {
                                   
                                   pub type EntityId = super::super::super::ambient::bindings::types::EntityId;
                                   pub type Vec3 = super::super::super::ambient::bindings::types::Vec3;
+                                  pub type Quat = super::super::super::ambient::bindings::types::Quat;
                                   pub type Mat4 = super::super::super::ambient::bindings::types::Mat4;
                                   #[repr(C)]
                                   #[derive(Copy, Clone)]
@@ -13539,6 +13965,40 @@ pub mod ambient {
                                       f.debug_struct("CharacterCollision").field("side", &self.side).field("up", &self.up).field("down", &self.down).finish()
                                     }
                                   }
+                                  #[derive(Clone, Copy)]
+                                  pub enum QueryShape{
+                                    Sphere(f32),
+                                    Cuboid(Vec3),
+                                    Capsule((f32,f32,)),
+                                  }
+                                  impl ::core::fmt::Debug for QueryShape {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      match self {
+                                        QueryShape::Sphere(e) => {
+                                          f.debug_tuple("QueryShape::Sphere").field(e).finish()
+                                        }
+                                        QueryShape::Cuboid(e) => {
+                                          f.debug_tuple("QueryShape::Cuboid").field(e).finish()
+                                        }
+                                        QueryShape::Capsule(e) => {
+                                          f.debug_tuple("QueryShape::Capsule").field(e).finish()
+                                        }
+                                      }
+                                    }
+                                  }
+                                  #[repr(C)]
+                                  #[derive(Copy, Clone)]
+                                  pub struct ShapeCastHit {
+                                    pub entity: EntityId,
+                                    pub position: Vec3,
+                                    pub normal: Vec3,
+                                    pub distance: f32,
+                                  }
+                                  impl ::core::fmt::Debug for ShapeCastHit {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                      f.debug_struct("ShapeCastHit").field("entity", &self.entity).field("position", &self.position).field("normal", &self.normal).field("distance", &self.distance).finish()
+                                    }
+                                  }
                                   #[allow(clippy::all)]
                                   pub fn add_force(entity: EntityId,force: Vec3,){
                                     
@@ -13880,40 +14340,218 @@ pub mod ambient {
                                     }
                                   }
                                   #[allow(clippy::all)]
-                                  pub fn move_character(entity: EntityId,displacement: Vec3,min_dist: f32,elapsed_time: f32,) -> CharacterCollision{
+                                  pub fn raycast_first_filtered(origin: Vec3,direction: Vec3,collision_mask: u32,) -> Option<(EntityId,f32,)>{
                                     
                                     #[allow(unused_imports)]
                                     use wit_bindgen::rt::{alloc, vec::Vec, string::String};
                                     unsafe {
                                       
-                                      #[repr(align(1))]
-                                      struct RetArea([u8; 3]);
+                                      #[repr(align(8))]
+                                      struct RetArea([u8; 32]);
                                       let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
-                                      let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = entity;
-                                      let super::super::super::ambient::bindings::types::Vec3{ x:x1, y:y1, z:z1, } = displacement;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = origin;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x1, y:y1, z:z1, } = direction;
                                       let ptr2 = ret_area.as_mut_ptr() as i32;
                                       #[link(wasm_import_module = "ambient:bindings/server-physics")]
                                       extern "C" {
-                                        #[cfg_attr(target_arch = "wasm32", link_name = "move-character")]
-                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_move-character")]
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "raycast-first-filtered")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_raycast-first-filtered")]
                                         fn wit_import(
-                                        _: i64, _: i64, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
                                       }
-                                      wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_f32(min_dist), wit_bindgen::rt::as_f32(elapsed_time), ptr2);
-                                      CharacterCollision{side:{
-                                        #[cfg(not(debug_assertions))]
-                                        { ::core::mem::transmute::<u8, bool>(i32::from(*((ptr2 + 0) as *const u8)) as u8) }
-                                        #[cfg(debug_assertions)]
-                                        {
-                                          match i32::from(*((ptr2 + 0) as *const u8)) {
-                                            0 => false,
-                                            1 => true,
-                                            _ => panic!("invalid bool discriminant"),
-                                          }
-                                        }
-                                      }, up:{
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_i32(collision_mask), ptr2);
+                                      match i32::from(*((ptr2 + 0) as *const u8)) {
+                                        0 => None,
+                                        1 => Some((super::super::super::ambient::bindings::types::EntityId{id0:*((ptr2 + 8) as *const i64) as u64, id1:*((ptr2 + 16) as *const i64) as u64, }, *((ptr2 + 24) as *const f32))),
                                         #[cfg(not(debug_assertions))]
-                                        { ::core::mem::transmute::<u8, bool>(i32::from(*((ptr2 + 1) as *const u8)) as u8) }
+                                        _ => ::core::hint::unreachable_unchecked(),
+                                        #[cfg(debug_assertions)]
+                                        _ => panic!("invalid enum discriminant"),
+                                      }
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn raycast_filtered(origin: Vec3,direction: Vec3,collision_mask: u32,) -> wit_bindgen::rt::vec::Vec::<(EntityId,f32,)>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = origin;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x1, y:y1, z:z1, } = direction;
+                                      let ptr2 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "raycast-filtered")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_raycast-filtered")]
+                                        fn wit_import(
+                                        _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_i32(collision_mask), ptr2);
+                                      let len3 = *((ptr2 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr2 + 0) as *const i32) as *mut _, len3, len3)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn sweep_first(shape: QueryShape,origin: Vec3,rotation: Quat,direction: Vec3,max_distance: f32,collision_mask: u32,) -> Option<ShapeCastHit>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(8))]
+                                      struct RetArea([u8; 56]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let (result2_0,result2_1,result2_2,result2_3,) = match shape {
+                                        QueryShape::Sphere(e) => (0i32, wit_bindgen::rt::as_f32(e), 0.0f32, 0.0f32),
+                                        QueryShape::Cuboid(e) => {
+                                          let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = e;
+                                          
+                                          (1i32, wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0))
+                                        },
+                                        QueryShape::Capsule(e) => {
+                                          let (t1_0, t1_1, ) = e;
+                                          
+                                          (2i32, wit_bindgen::rt::as_f32(t1_0), wit_bindgen::rt::as_f32(t1_1), 0.0f32)
+                                        },
+                                      };
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x3, y:y3, z:z3, } = origin;
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x4, y:y4, z:z4, w:w4, } = rotation;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x5, y:y5, z:z5, } = direction;
+                                      let ptr6 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "sweep-first")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_sweep-first")]
+                                        fn wit_import(
+                                        _: i32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(result2_0, result2_1, result2_2, result2_3, wit_bindgen::rt::as_f32(x3), wit_bindgen::rt::as_f32(y3), wit_bindgen::rt::as_f32(z3), wit_bindgen::rt::as_f32(x4), wit_bindgen::rt::as_f32(y4), wit_bindgen::rt::as_f32(z4), wit_bindgen::rt::as_f32(w4), wit_bindgen::rt::as_f32(x5), wit_bindgen::rt::as_f32(y5), wit_bindgen::rt::as_f32(z5), wit_bindgen::rt::as_f32(max_distance), wit_bindgen::rt::as_i32(collision_mask), ptr6);
+                                      match i32::from(*((ptr6 + 0) as *const u8)) {
+                                        0 => None,
+                                        1 => Some(ShapeCastHit{entity:super::super::super::ambient::bindings::types::EntityId{id0:*((ptr6 + 8) as *const i64) as u64, id1:*((ptr6 + 16) as *const i64) as u64, }, position:super::super::super::ambient::bindings::types::Vec3{x:*((ptr6 + 24) as *const f32), y:*((ptr6 + 28) as *const f32), z:*((ptr6 + 32) as *const f32), }, normal:super::super::super::ambient::bindings::types::Vec3{x:*((ptr6 + 36) as *const f32), y:*((ptr6 + 40) as *const f32), z:*((ptr6 + 44) as *const f32), }, distance:*((ptr6 + 48) as *const f32), }),
+                                        #[cfg(not(debug_assertions))]
+                                        _ => ::core::hint::unreachable_unchecked(),
+                                        #[cfg(debug_assertions)]
+                                        _ => panic!("invalid enum discriminant"),
+                                      }
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn sweep(shape: QueryShape,origin: Vec3,rotation: Quat,direction: Vec3,max_distance: f32,collision_mask: u32,) -> wit_bindgen::rt::vec::Vec::<ShapeCastHit>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let (result2_0,result2_1,result2_2,result2_3,) = match shape {
+                                        QueryShape::Sphere(e) => (0i32, wit_bindgen::rt::as_f32(e), 0.0f32, 0.0f32),
+                                        QueryShape::Cuboid(e) => {
+                                          let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = e;
+                                          
+                                          (1i32, wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0))
+                                        },
+                                        QueryShape::Capsule(e) => {
+                                          let (t1_0, t1_1, ) = e;
+                                          
+                                          (2i32, wit_bindgen::rt::as_f32(t1_0), wit_bindgen::rt::as_f32(t1_1), 0.0f32)
+                                        },
+                                      };
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x3, y:y3, z:z3, } = origin;
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x4, y:y4, z:z4, w:w4, } = rotation;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x5, y:y5, z:z5, } = direction;
+                                      let ptr6 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "sweep")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_sweep")]
+                                        fn wit_import(
+                                        _: i32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(result2_0, result2_1, result2_2, result2_3, wit_bindgen::rt::as_f32(x3), wit_bindgen::rt::as_f32(y3), wit_bindgen::rt::as_f32(z3), wit_bindgen::rt::as_f32(x4), wit_bindgen::rt::as_f32(y4), wit_bindgen::rt::as_f32(z4), wit_bindgen::rt::as_f32(w4), wit_bindgen::rt::as_f32(x5), wit_bindgen::rt::as_f32(y5), wit_bindgen::rt::as_f32(z5), wit_bindgen::rt::as_f32(max_distance), wit_bindgen::rt::as_i32(collision_mask), ptr6);
+                                      let len7 = *((ptr6 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr6 + 0) as *const i32) as *mut _, len7, len7)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn overlap(shape: QueryShape,position: Vec3,rotation: Quat,collision_mask: u32,) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(4))]
+                                      struct RetArea([u8; 8]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let (result2_0,result2_1,result2_2,result2_3,) = match shape {
+                                        QueryShape::Sphere(e) => (0i32, wit_bindgen::rt::as_f32(e), 0.0f32, 0.0f32),
+                                        QueryShape::Cuboid(e) => {
+                                          let super::super::super::ambient::bindings::types::Vec3{ x:x0, y:y0, z:z0, } = e;
+                                          
+                                          (1i32, wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0))
+                                        },
+                                        QueryShape::Capsule(e) => {
+                                          let (t1_0, t1_1, ) = e;
+                                          
+                                          (2i32, wit_bindgen::rt::as_f32(t1_0), wit_bindgen::rt::as_f32(t1_1), 0.0f32)
+                                        },
+                                      };
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x3, y:y3, z:z3, } = position;
+                                      let super::super::super::ambient::bindings::types::Quat{ x:x4, y:y4, z:z4, w:w4, } = rotation;
+                                      let ptr5 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "overlap")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_overlap")]
+                                        fn wit_import(
+                                        _: i32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, );
+                                      }
+                                      wit_import(result2_0, result2_1, result2_2, result2_3, wit_bindgen::rt::as_f32(x3), wit_bindgen::rt::as_f32(y3), wit_bindgen::rt::as_f32(z3), wit_bindgen::rt::as_f32(x4), wit_bindgen::rt::as_f32(y4), wit_bindgen::rt::as_f32(z4), wit_bindgen::rt::as_f32(w4), wit_bindgen::rt::as_i32(collision_mask), ptr5);
+                                      let len6 = *((ptr5 + 4) as *const i32) as usize;
+                                      Vec::from_raw_parts(*((ptr5 + 0) as *const i32) as *mut _, len6, len6)
+                                    }
+                                  }
+                                  #[allow(clippy::all)]
+                                  pub fn move_character(entity: EntityId,displacement: Vec3,min_dist: f32,elapsed_time: f32,) -> CharacterCollision{
+                                    
+                                    #[allow(unused_imports)]
+                                    use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                    unsafe {
+                                      
+                                      #[repr(align(1))]
+                                      struct RetArea([u8; 3]);
+                                      let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                      let super::super::super::ambient::bindings::types::EntityId{ id0:id00, id1:id10, } = entity;
+                                      let super::super::super::ambient::bindings::types::Vec3{ x:x1, y:y1, z:z1, } = displacement;
+                                      let ptr2 = ret_area.as_mut_ptr() as i32;
+                                      #[link(wasm_import_module = "ambient:bindings/server-physics")]
+                                      extern "C" {
+                                        #[cfg_attr(target_arch = "wasm32", link_name = "move-character")]
+                                        #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-physics_move-character")]
+                                        fn wit_import(
+                                        _: i64, _: i64, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                      }
+                                      wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_f32(min_dist), wit_bindgen::rt::as_f32(elapsed_time), ptr2);
+                                      CharacterCollision{side:{
+                                        #[cfg(not(debug_assertions))]
+                                        { ::core::mem::transmute::<u8, bool>(i32::from(*((ptr2 + 0) as *const u8)) as u8) }
+                                        #[cfg(debug_assertions)]
+                                        {
+                                          match i32::from(*((ptr2 + 0) as *const u8)) {
+                                            0 => false,
+                                            1 => true,
+                                            _ => panic!("invalid bool discriminant"),
+                                          }
+                                        }
+                                      }, up:{
+                                        #[cfg(not(debug_assertions))]
+                                        { ::core::mem::transmute::<u8, bool>(i32::from(*((ptr2 + 1) as *const u8)) as u8) }
                                         #[cfg(debug_assertions)]
                                         {
                                           match i32::from(*((ptr2 + 1) as *const u8)) {
@@ -14234,6 +14872,349 @@ pub mod ambient {
                                       
                                     }
                                     
+                                    
+                                    #[allow(clippy::all)]
+                                    pub mod server_world {
+                                      #[used]
+                                      #[doc(hidden)]
+                                      #[cfg(target_arch = "wasm32")]
+                                      static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+                                      
+                                      #[allow(clippy::all)]
+                                      pub fn save() -> Result<(),wit_bindgen::rt::string::String>{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+                                          
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 12]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let ptr0 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "ambient:bindings/server-world")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "save")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-world_save")]
+                                            fn wit_import(
+                                            _: i32, );
+                                          }
+                                          wit_import(ptr0);
+                                          match i32::from(*((ptr0 + 0) as *const u8)) {
+                                            0 => Ok(()),
+                                            1 => Err({
+                                              let len1 = *((ptr0 + 8) as *const i32) as usize;
+                                              
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr0 + 4) as *const i32) as *mut _, len1, len1))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr0 + 4) as *const i32) as *mut _, len1, len1)).unwrap()}}
+                                            }),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => ::core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn was_restored() -> bool{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+                                          
+                                          #[link(wasm_import_module = "ambient:bindings/server-world")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "was-restored")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-world_was-restored")]
+                                            fn wit_import(
+                                            ) -> i32;
+                                          }
+                                          let ret = wit_import();
+                                          {
+                                            #[cfg(not(debug_assertions))]
+                                            { ::core::mem::transmute::<u8, bool>(ret as u8) }
+                                            #[cfg(debug_assertions)]
+                                            {
+                                              match ret {
+                                                0 => false,
+                                                1 => true,
+                                                _ => panic!("invalid bool discriminant"),
+                                              }
+                                            }
+                                          }
+                                        }
+                                      }
+                                      
+                                    }
+                                    
+                                    
+                                    #[allow(clippy::all)]
+                                    pub mod server_storage {
+                                      #[used]
+                                      #[doc(hidden)]
+                                      #[cfg(target_arch = "wasm32")]
+                                      static __FORCE_SECTION_REF: fn() = super::super::super::__link_section;
+  
+                                      #[derive(Clone)]
+                                      pub struct Operation<'a,> {
+                                        pub key: &'a str,
+                                        pub value: Option<&'a [u8]>,
+                                      }
+                                      impl<'a,> ::core::fmt::Debug for Operation<'a,> {
+                                        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                          f.debug_struct("Operation").field("key", &self.key).field("value", &self.value).finish()
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn get(key: &str,) -> Result<Option<wit_bindgen::rt::vec::Vec::<u8>>,wit_bindgen::rt::string::String>{
+    
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+      
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 16]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec0 = key;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let ptr1 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "get")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_get")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(ptr0, len0, ptr1);
+                                          match i32::from(*((ptr1 + 0) as *const u8)) {
+                                            0 => Ok(match i32::from(*((ptr1 + 4) as *const u8)) {
+                                              0 => None,
+                                              1 => Some({
+                                                let len2 = *((ptr1 + 12) as *const i32) as usize;
+            
+                                                Vec::from_raw_parts(*((ptr1 + 8) as *const i32) as *mut _, len2, len2)
+                                              }),
+                                              #[cfg(not(debug_assertions))]
+                                              _ => ::core::hint::unreachable_unchecked(),
+                                              #[cfg(debug_assertions)]
+                                              _ => panic!("invalid enum discriminant"),
+                                            }),
+                                            1 => Err({
+                                              let len3 = *((ptr1 + 8) as *const i32) as usize;
+  
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len3, len3)).unwrap()}}
+                                            }),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => ::core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn set(key: &str,value: &[u8],) -> Result<(),wit_bindgen::rt::string::String>{
+    
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+      
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 12]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec0 = key;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let vec1 = value;
+                                          let ptr1 = vec1.as_ptr() as i32;
+                                          let len1 = vec1.len() as i32;
+                                          let ptr2 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "set")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_set")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(ptr0, len0, ptr1, len1, ptr2);
+                                          match i32::from(*((ptr2 + 0) as *const u8)) {
+                                            0 => Ok(()),
+                                            1 => Err({
+                                            let len3 = *((ptr2 + 8) as *const i32) as usize;
+  
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr2 + 4) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr2 + 4) as *const i32) as *mut _, len3, len3)).unwrap()}}
+                                          }),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => ::core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn delete(key: &str,) -> Result<(),wit_bindgen::rt::string::String>{
+    
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+      
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 12]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec0 = key;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let ptr1 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "delete")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_delete")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(ptr0, len0, ptr1);
+                                          match i32::from(*((ptr1 + 0) as *const u8)) {
+                                            0 => Ok(()),
+                                            1 => Err({
+                                            let len2 = *((ptr1 + 8) as *const i32) as usize;
+  
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len2, len2)).unwrap()}}
+                                          }),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => ::core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn list_keys(prefix: &str,) -> Result<wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>,wit_bindgen::rt::string::String>{
+    
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+      
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 12]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec0 = prefix;
+                                          let ptr0 = vec0.as_ptr() as i32;
+                                          let len0 = vec0.len() as i32;
+                                          let ptr1 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "list-keys")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_list-keys")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(ptr0, len0, ptr1);
+                                          match i32::from(*((ptr1 + 0) as *const u8)) {
+                                            0 => Ok({
+                                              let base3 = *((ptr1 + 4) as *const i32);
+                                              let len3 = *((ptr1 + 8) as *const i32);
+                                              let mut result3 = Vec::with_capacity(len3 as usize);
+                                              for i in 0..len3 {
+                                                let base = base3 + i *8;
+                                                result3.push({
+                                                  let len2 = *((base + 4) as *const i32) as usize;
+              
+                                                  {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len2, len2)).unwrap()}}
+                                                });
+                                              }
+                                              wit_bindgen::rt::dealloc(base3, (len3 as usize) * 8, 4);
+          
+                                              result3
+                                            }),
+                                            1 => Err({
+                                              let len4 = *((ptr1 + 8) as *const i32) as usize;
+  
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len4, len4))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len4, len4)).unwrap()}}
+                                            }),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => ::core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn transaction(operations: &[Operation<'_,>],) -> Result<(),wit_bindgen::rt::string::String>{
+    
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        unsafe {
+      
+                                          #[repr(align(4))]
+                                          struct RetArea([u8; 12]);
+                                          let mut ret_area = ::core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let vec3 = operations;
+                                          let len3 = vec3.len() as i32;
+                                          let layout3 = alloc::Layout::from_size_align_unchecked(vec3.len() * 20, 4);
+                                          let result3 = if layout3.size() != 0
+                                          {
+                                            let ptr = alloc::alloc(layout3);
+                                            if ptr.is_null()
+                                            {
+                                              alloc::handle_alloc_error(layout3);
+                                            }
+                                            ptr
+                                          }else {
+                                            ::core::ptr::null_mut()
+                                          };
+                                          for (i, e) in vec3.into_iter().enumerate() {
+                                            let base = result3 as i32 + (i as i32) * 20;
+                                            {
+                                              let Operation{ key:key0, value:value0, } = e;
+                                              let vec1 = key0;
+                                              let ptr1 = vec1.as_ptr() as i32;
+                                              let len1 = vec1.len() as i32;
+                                              *((base + 4) as *mut i32) = len1;
+                                              *((base + 0) as *mut i32) = ptr1;
+                                              match value0 {
+                                                Some(e) => {
+                                                  *((base + 8) as *mut u8) = (1i32) as u8;
+                                                  let vec2 = e;
+                                                  let ptr2 = vec2.as_ptr() as i32;
+                                                  let len2 = vec2.len() as i32;
+                                                  *((base + 16) as *mut i32) = len2;
+                                                  *((base + 12) as *mut i32) = ptr2;
+                                                },
+                                                None => {
+                                                  {
+                                                    *((base + 8) as *mut u8) = (0i32) as u8;
+                                                  }
+                                                },
+                                              };
+          
+                                            }}
+                                            let ptr4 = ret_area.as_mut_ptr() as i32;
+                                            #[link(wasm_import_module = "ambient:bindings/server-storage")]
+                                            extern "C" {
+                                              #[cfg_attr(target_arch = "wasm32", link_name = "transaction")]
+                                              #[cfg_attr(not(target_arch = "wasm32"), link_name = "ambient:bindings/server-storage_transaction")]
+                                              fn wit_import(
+                                              _: i32, _: i32, _: i32, );
+                                            }
+                                            wit_import(result3 as i32, len3, ptr4);
+                                            if layout3.size() != 0 {
+                                              alloc::dealloc(result3, layout3);
+                                            }
+                                            match i32::from(*((ptr4 + 0) as *const u8)) {
+                                              0 => Ok(()),
+                                              1 => Err({
+                                              let len5 = *((ptr4 + 8) as *const i32) as usize;
+  
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr4 + 4) as *const i32) as *mut _, len5, len5))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr4 + 4) as *const i32) as *mut _, len5, len5)).unwrap()}}
+                                            }),
+                                              #[cfg(not(debug_assertions))]
+                                              _ => ::core::hint::unreachable_unchecked(),
+                                              #[cfg(debug_assertions)]
+                                              _ => panic!("invalid enum discriminant"),
+                                            }
+                                          }
+                                        }
+    
+                                      }
+                                    
                                   }
                                 }
                                 pub mod exports {
@@ -1,9 +1,13 @@
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::HashMap,
     future::Future,
     pin::Pin,
-    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
 };
 
 use indexmap::IndexMap;
@@ -20,20 +24,46 @@ type EventCallbackFn = Box<dyn FnMut(&wit::guest::Source, u128, &[u8]) -> Result
 // the function is too general to be passed in directly
 #[allow(clippy::redundant_closure)]
 pub(crate) static EXECUTOR: Lazy<Executor> = Lazy::new(|| Executor::new());
-static RAW_WAKER: RawWakerVTable = RawWakerVTable::new(
-    |_| RawWaker::new(std::ptr::null(), &RAW_WAKER),
-    |_| {},
-    |_| {},
-    |_| {},
-);
+
+/// Marks its future to be polled again.
+///
+/// Futures that wake themselves while being polled, like [crate::prelude::block_until], are
+/// polled again on the next frame; futures that are woken by a message, like those waiting on a
+/// timer, are not polled until then.
+#[derive(Default)]
+struct TaskWaker(AtomicBool);
+impl TaskWaker {
+    fn take(&self) -> bool {
+        self.0.swap(false, Ordering::Relaxed)
+    }
+
+    fn is_woken(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+struct Task {
+    future: EventFuture,
+    waker: Arc<TaskWaker>,
+}
 
 pub(crate) struct Executor {
-    waker: Waker,
-    current: RefCell<Vec<EventFuture>>,
+    current: RefCell<Vec<Task>>,
     incoming: RefCell<Vec<Pin<Box<dyn Future<Output = ResultEmpty>>>>>,
     current_callbacks: RefCell<Callbacks>,
     incoming_callbacks: RefCell<Callbacks>,
     callbacks_to_remove: RefCell<Vec<(String, u128)>>,
+    /// Whether the host has been asked to run this module every frame
+    frame_wakeups: Cell<bool>,
 }
 // WebAssembly, at time of writing, is single-threaded. This is a convenient little lie
 // to make it easy to use this in a global context.
@@ -43,12 +73,12 @@ unsafe impl Sync for Executor {}
 impl Executor {
     pub fn new() -> Self {
         Executor {
-            waker: unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &RAW_WAKER)) },
             current: RefCell::new(Default::default()),
             incoming: RefCell::new(Default::default()),
             current_callbacks: RefCell::new(Default::default()),
             incoming_callbacks: RefCell::new(Default::default()),
             callbacks_to_remove: RefCell::new(Default::default()),
+            frame_wakeups: Cell::new(false),
         }
     }
 
@@ -90,29 +120,53 @@ impl Executor {
             }
         }
 
-        // Load all pending futures into current.
+        // Load all pending futures into current. New futures are polled straight away.
         {
             let (mut current, mut incoming) =
                 (self.current.borrow_mut(), self.incoming.borrow_mut());
-            current.append(&mut incoming);
+            current.extend(incoming.drain(..).map(|future| {
+                let waker = Arc::new(TaskWaker::default());
+                waker.wake_by_ref();
+                Task { future, waker }
+            }));
         }
 
-        // Run all current futures.
+        // Run all current futures that have been woken.
         // These are extracted to ensure that a panic will not result in the same
         // tasks being executed forever.
         {
-            let mut futures = std::mem::take(&mut *self.current.borrow_mut());
-            futures.retain_mut(
-                |f| match f.as_mut().poll(&mut Context::from_waker(&self.waker)) {
+            let mut tasks = std::mem::take(&mut *self.current.borrow_mut());
+            tasks.retain_mut(|task| {
+                if !task.waker.take() {
+                    return true;
+                }
+                let waker = Waker::from(task.waker.clone());
+                match task.future.as_mut().poll(&mut Context::from_waker(&waker)) {
                     Poll::Ready(Ok(_)) => false,
                     Poll::Ready(Err(e)) => {
                         eprintln!("Error while handling future: {e:?}");
                         false
                     }
                     Poll::Pending => true,
-                },
-            );
-            *self.current.borrow_mut() = futures;
+                }
+            });
+            self.current.borrow_mut().append(&mut tasks);
+        }
+
+        self.update_frame_wakeups();
+    }
+
+    /// Asks the host to run this module every frame while any of its futures are waiting to be
+    /// polled, so that modules that are only waiting on messages and timers are not run at all.
+    pub fn update_frame_wakeups(&self) {
+        let needed = !self.incoming.borrow().is_empty()
+            || self
+                .current
+                .borrow()
+                .iter()
+                .any(|task| task.waker.is_woken());
+        if self.frame_wakeups.replace(needed) != needed {
+            wit::timer::set_frame_wakeups(needed);
         }
     }
 
@@ -322,6 +322,12 @@ mod raw {
                 pub fn looping() -> Component<bool> {
                     *LOOPING
                 }
+                static AUDIO_STREAMING: Lazy<Component<bool>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_streaming"));
+                #[doc = "**Audio streaming**: If true, the audio is decoded from the asset cache while it plays, instead of being loaded into memory first.\n\nUse this for long sounds, such as music and ambience. Not supported on the web, where the audio is loaded into memory.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_streaming() -> Component<bool> {
+                    *AUDIO_STREAMING
+                }
                 static ONEPOLE_LPF: Lazy<Component<f32>> =
                     Lazy::new(|| __internal_get_component("ambient_core::audio::onepole_lpf"));
                 #[doc = "**One pole low pass filter**: With this component, the audio will be filtered with a one pole low pass filter.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
@@ -376,6 +382,129 @@ mod raw {
                 pub fn stop_now() -> Component<()> {
                     *STOP_NOW
                 }
+                static AUDIO_BUS: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus"));
+                #[doc = "**Audio bus**: On an audio player, the bus that its sounds are played on, such as `music`, `sfx`, `voice` or `ui`. Sounds are played on the `sfx` bus if this is not set.\n\nOn an entity with `is_audio_bus`, the bus that the entity controls. The `master` bus is the bus that all other buses are mixed into.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus() -> Component<String> {
+                    *AUDIO_BUS
+                }
+                static IS_AUDIO_BUS: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::is_audio_bus"));
+                #[doc = "**Is audio bus**: The entity controls the audio bus named by its `audio_bus` component with its other `audio_bus_*` components.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn is_audio_bus() -> Component<()> {
+                    *IS_AUDIO_BUS
+                }
+                static AUDIO_BUS_VOLUME: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus_volume"));
+                #[doc = "**Audio bus volume**: The volume of the audio bus, which is multiplied with the volume that the player chose in their settings.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_volume() -> Component<f32> {
+                    *AUDIO_BUS_VOLUME
+                }
+                static AUDIO_BUS_MUTED: Lazy<Component<bool>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_bus_muted"));
+                #[doc = "**Audio bus muted**: Whether or not the audio bus is muted.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_muted() -> Component<bool> {
+                    *AUDIO_BUS_MUTED
+                }
+                static AUDIO_BUS_HIGH_PASS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_high_pass")
+                });
+                #[doc = "**Audio bus high pass**: The cutoff frequency of a high pass filter applied to the audio bus, in Hz. It is applied before `audio_bus_low_pass`.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_high_pass() -> Component<f32> {
+                    *AUDIO_BUS_HIGH_PASS
+                }
+                static AUDIO_BUS_LOW_PASS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_low_pass")
+                });
+                #[doc = "**Audio bus low pass**: The cutoff frequency of a low pass filter applied to the audio bus, in Hz.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_low_pass() -> Component<f32> {
+                    *AUDIO_BUS_LOW_PASS
+                }
+                static AUDIO_BUS_DUCKED_BY: Lazy<Component<String>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_ducked_by")
+                });
+                #[doc = "**Audio bus ducked by**: The name of the bus that ducks this audio bus: while it is playing, the volume of this bus is lowered to `audio_bus_duck_gain`.\n\nFor example, the `music` bus can be ducked by the `voice` bus so that dialogue can be heard over the music.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_ducked_by() -> Component<String> {
+                    *AUDIO_BUS_DUCKED_BY
+                }
+                static AUDIO_BUS_DUCK_GAIN: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_duck_gain")
+                });
+                #[doc = "**Audio bus duck gain**: The gain that the audio bus is lowered to while it is ducked, from 0 to 1. Defaults to 0.3.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_duck_gain() -> Component<f32> {
+                    *AUDIO_BUS_DUCK_GAIN
+                }
+                static AUDIO_BUS_DUCK_ATTACK: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_duck_attack")
+                });
+                #[doc = "**Audio bus duck attack**: How long it takes to lower the volume of the audio bus when it is ducked, in seconds. Defaults to 0.05.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_duck_attack() -> Component<f32> {
+                    *AUDIO_BUS_DUCK_ATTACK
+                }
+                static AUDIO_BUS_DUCK_RELEASE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_bus_duck_release")
+                });
+                #[doc = "**Audio bus duck release**: How long it takes to restore the volume of the audio bus after it is no longer ducked, in seconds. Defaults to 0.5.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_bus_duck_release() -> Component<f32> {
+                    *AUDIO_BUS_DUCK_RELEASE
+                }
+                static IS_REVERB_ZONE: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::is_reverb_zone"));
+                #[doc = "**Is reverb zone**: The entity is a spherical reverb zone, centered on its position with a radius of `reverb_zone_radius`.\n\nWhile the spatial audio listener is inside the zone, spatial sounds are reverberated with the `reverb_*` components of the zone. If the listener is inside several zones, the smallest one is used.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn is_reverb_zone() -> Component<()> {
+                    *IS_REVERB_ZONE
+                }
+                static REVERB_ZONE_RADIUS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::reverb_zone_radius")
+                });
+                #[doc = "**Reverb zone radius**: The radius of the reverb zone.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn reverb_zone_radius() -> Component<f32> {
+                    *REVERB_ZONE_RADIUS
+                }
+                static REVERB_ROOM_SIZE: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::reverb_room_size"));
+                #[doc = "**Reverb room size**: How large the room of the reverb zone sounds, from 0 to 1. Larger rooms have longer reverb tails. Defaults to 0.5.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn reverb_room_size() -> Component<f32> {
+                    *REVERB_ROOM_SIZE
+                }
+                static REVERB_DAMPING: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::reverb_damping"));
+                #[doc = "**Reverb damping**: How quickly the high frequencies of the reverb are absorbed, from 0 to 1. Defaults to 0.5.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn reverb_damping() -> Component<f32> {
+                    *REVERB_DAMPING
+                }
+                static REVERB_WET: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::reverb_wet"));
+                #[doc = "**Reverb wet**: The level of the reverb that is added to spatial sounds, from 0 to 1. Defaults to 0.3.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn reverb_wet() -> Component<f32> {
+                    *REVERB_WET
+                }
+                static AUDIO_OCCLUDER: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_occluder"));
+                #[doc = "**Audio occluder**: The entity blocks sound. Spatial sounds that are behind its bounding box, as seen from the listener, are muffled.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_occluder() -> Component<()> {
+                    *AUDIO_OCCLUDER
+                }
+                static AUDIO_OCCLUSION: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::audio::audio_occlusion"));
+                #[doc = "**Audio occlusion**: How much the spatial sound emitted by this entity is blocked from the listener by occluders, from 0 (not at all) to 1 (fully). Partial values mean that the sound is obstructed, such as by the edge of a wall.\n\nThis is set by the runtime.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_occlusion() -> Component<f32> {
+                    *AUDIO_OCCLUSION
+                }
+                static AUDIO_OCCLUSION_LOW_PASS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_occlusion_low_pass")
+                });
+                #[doc = "**Audio occlusion low pass**: On a spatial audio player, the cutoff frequency of the low pass filter applied to its sounds when they are fully occluded, in Hz. Defaults to 800.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_occlusion_low_pass() -> Component<f32> {
+                    *AUDIO_OCCLUSION_LOW_PASS
+                }
+                static AUDIO_OCCLUSION_GAIN: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::audio::audio_occlusion_gain")
+                });
+                #[doc = "**Audio occlusion gain**: On a spatial audio player, the gain of its sounds when they are fully occluded, from 0 to 1. Defaults to 0.4.\n\n\n\n*Attributes*: MaybeResource, Debuggable"]
+                pub fn audio_occlusion_gain() -> Component<f32> {
+                    *AUDIO_OCCLUSION_GAIN
+                }
             }
         }
         pub mod camera {
@@ -1951,6 +2080,260 @@ mod raw {
                 pub fn mouse_pickable_min() -> Component<Vec3> {
                     *MOUSE_PICKABLE_MIN
                 }
+                static TOUCH_IDS: Lazy<Component<Vec<u64>>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::input::touch_ids"));
+                #[doc = "**Touch IDs**: The IDs of the touches that started on this entity and have not ended yet. Entities with `mouse_pickable_min` and `mouse_pickable_max` capture the touches that start on them, even if they move off of them.\n\n*Attributes*: Debuggable"]
+                pub fn touch_ids() -> Component<Vec<u64>> {
+                    *TOUCH_IDS
+                }
+                static TOUCH_POSITIONS: Lazy<Component<Vec<Vec2>>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::input::touch_positions"));
+                #[doc = "**Touch positions**: The positions of the touches in `touch_ids`, in the same order, in pixels from the top left of the window.\n\n*Attributes*: Debuggable"]
+                pub fn touch_positions() -> Component<Vec<Vec2>> {
+                    *TOUCH_POSITIONS
+                }
+                static INPUT_ACTION_ID: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::input::input_action_id"));
+                #[doc = "**Input action ID**: The ID of this input action, as declared in the `actions` section of its package's manifest.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn input_action_id() -> Component<String> {
+                    *INPUT_ACTION_ID
+                }
+                static INPUT_ACTION_PACKAGE: Lazy<Component<EntityId>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::input::input_action_package")
+                });
+                #[doc = "**Input action package**: The package that declared this input action.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn input_action_package() -> Component<EntityId> {
+                    *INPUT_ACTION_PACKAGE
+                }
+                static INPUT_ACTION_BINDINGS: Lazy<Component<Vec<String>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::input::input_action_bindings")
+                });
+                #[doc = "**Input action bindings**: The default bindings of this input action, like `Key:Space` or `Gamepad:South`. Players can override them through their settings.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn input_action_bindings() -> Component<Vec<String>> {
+                    *INPUT_ACTION_BINDINGS
+                }
+                static INPUT_ACTION_PRESSED: Lazy<Component<bool>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::input::input_action_pressed")
+                });
+                #[doc = "**Input action pressed**: Whether or not any of the bindings of this input action are currently pressed. Only available on the client.\n\n*Attributes*: Debuggable"]
+                pub fn input_action_pressed() -> Component<bool> {
+                    *INPUT_ACTION_PRESSED
+                }
+                static INPUT_AXIS_ID: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::input::input_axis_id"));
+                #[doc = "**Input axis ID**: The ID of this input axis, as declared in the `axes` section of its package's manifest.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn input_axis_id() -> Component<String> {
+                    *INPUT_AXIS_ID
+                }
+                static INPUT_AXIS_PACKAGE: Lazy<Component<EntityId>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::input::input_axis_package")
+                });
+                #[doc = "**Input axis package**: The package that declared this input axis.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn input_axis_package() -> Component<EntityId> {
+                    *INPUT_AXIS_PACKAGE
+                }
+                static INPUT_AXIS_POSITIVE_BINDINGS: Lazy<Component<Vec<String>>> =
+                    Lazy::new(|| {
+                        __internal_get_component(
+                            "ambient_core::input::input_axis_positive_bindings",
+                        )
+                    });
+                #[doc = "**Input axis positive bindings**: The default bindings that move this input axis towards 1. Players can override them through their settings.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn input_axis_positive_bindings() -> Component<Vec<String>> {
+                    *INPUT_AXIS_POSITIVE_BINDINGS
+                }
+                static INPUT_AXIS_NEGATIVE_BINDINGS: Lazy<Component<Vec<String>>> =
+                    Lazy::new(|| {
+                        __internal_get_component(
+                            "ambient_core::input::input_axis_negative_bindings",
+                        )
+                    });
+                #[doc = "**Input axis negative bindings**: The default bindings that move this input axis towards -1. Players can override them through their settings.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn input_axis_negative_bindings() -> Component<Vec<String>> {
+                    *INPUT_AXIS_NEGATIVE_BINDINGS
+                }
+                static INPUT_AXIS_VALUE: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::input::input_axis_value"));
+                #[doc = "**Input axis value**: The current value of this input axis, from -1 to 1. Only available on the client.\n\n*Attributes*: Debuggable"]
+                pub fn input_axis_value() -> Component<f32> {
+                    *INPUT_AXIS_VALUE
+                }
+            }
+            #[doc = r" Auto-generated concept definitions. Concepts are collections of components that describe some form of gameplay concept."]
+            #[doc = r""]
+            #[doc = r" They do not have any runtime representation outside of the components that compose them."]
+            pub mod concepts {
+                use crate::{
+                    global::serde::{self, Deserialize, Serialize},
+                    prelude::*,
+                };
+                #[doc = "**Input Action**: A named input declared by a package that is either pressed or released. Spawned for each entry in the `actions` section of a package's manifest.\n\n**Required**:\n- `input_action_id`: The ID of this input action, as declared in the `actions` section of its package's manifest.\n- `input_action_package`: The package that declared this input action.\n- `input_action_bindings`: The default bindings of this input action, like `Key:Space` or `Gamepad:South`. Players can override them through their settings."]
+                #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+                #[serde(crate = "self::serde")]
+                pub struct InputAction {
+                    #[doc = "**Component**: `ambient_core::input::input_action_id`\n\n**Component description**: The ID of this input action, as declared in the `actions` section of its package's manifest.\n\n"]
+                    pub input_action_id: String,
+                    #[doc = "**Component**: `ambient_core::input::input_action_package`\n\n**Component description**: The package that declared this input action.\n\n"]
+                    pub input_action_package: EntityId,
+                    #[doc = "**Component**: `ambient_core::input::input_action_bindings`\n\n**Component description**: The default bindings of this input action, like `Key:Space` or `Gamepad:South`. Players can override them through their settings.\n\n"]
+                    pub input_action_bindings: Vec<String>,
+                }
+                impl Concept for InputAction {
+                    fn make(self) -> Entity {
+                        let mut entity = Entity::new()
+                            .with(
+                                crate::ambient_core::input::components::input_action_id(),
+                                self.input_action_id,
+                            )
+                            .with(
+                                crate::ambient_core::input::components::input_action_package(),
+                                self.input_action_package,
+                            )
+                            .with(
+                                crate::ambient_core::input::components::input_action_bindings(),
+                                self.input_action_bindings,
+                            );
+                        entity
+                    }
+                    fn get_spawned(id: EntityId) -> Option<Self> {
+                        Some(Self {
+                            input_action_id: entity::get_component(
+                                id,
+                                crate::ambient_core::input::components::input_action_id(),
+                            )?,
+                            input_action_package: entity::get_component(
+                                id,
+                                crate::ambient_core::input::components::input_action_package(),
+                            )?,
+                            input_action_bindings: entity::get_component(
+                                id,
+                                crate::ambient_core::input::components::input_action_bindings(),
+                            )?,
+                        })
+                    }
+                    fn get_unspawned(entity: &Entity) -> Option<Self> {
+                        Some(Self {
+                            input_action_id: entity
+                                .get(crate::ambient_core::input::components::input_action_id())?,
+                            input_action_package: entity.get(
+                                crate::ambient_core::input::components::input_action_package(),
+                            )?,
+                            input_action_bindings: entity.get(
+                                crate::ambient_core::input::components::input_action_bindings(),
+                            )?,
+                        })
+                    }
+                    fn contained_by_spawned(id: EntityId) -> bool {
+                        entity::has_components(
+                            id,
+                            &[
+                                &crate::ambient_core::input::components::input_action_id(),
+                                &crate::ambient_core::input::components::input_action_package(),
+                                &crate::ambient_core::input::components::input_action_bindings(),
+                            ],
+                        )
+                    }
+                    fn contained_by_unspawned(entity: &Entity) -> bool {
+                        entity.has_components(&[
+                            &crate::ambient_core::input::components::input_action_id(),
+                            &crate::ambient_core::input::components::input_action_package(),
+                            &crate::ambient_core::input::components::input_action_bindings(),
+                        ])
+                    }
+                }
+                impl ConceptComponents for InputAction {
+                    type Required = (
+                        Component<String>,
+                        Component<EntityId>,
+                        Component<Vec<String>>,
+                    );
+                    type Optional = ();
+                    fn required() -> Self::Required {
+                        (
+                            crate::ambient_core::input::components::input_action_id(),
+                            crate::ambient_core::input::components::input_action_package(),
+                            crate::ambient_core::input::components::input_action_bindings(),
+                        )
+                    }
+                    fn optional() -> Self::Optional {
+                        ()
+                    }
+                    fn from_required_data(
+                        required: <Self::Required as ComponentsTuple>::Data,
+                    ) -> Self {
+                        Self {
+                            input_action_id: required.0,
+                            input_action_package: required.1,
+                            input_action_bindings: required.2,
+                        }
+                    }
+                }
+                #[doc = "**Input Axis**: A named input declared by a package that ranges from -1 to 1. Spawned for each entry in the `axes` section of a package's manifest.\n\n**Required**:\n- `input_axis_id`: The ID of this input axis, as declared in the `axes` section of its package's manifest.\n- `input_axis_package`: The package that declared this input axis.\n- `input_axis_positive_bindings`: The default bindings that move this input axis towards 1. Players can override them through their settings.\n- `input_axis_negative_bindings`: The default bindings that move this input axis towards -1. Players can override them through their settings."]
+                #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+                #[serde(crate = "self::serde")]
+                pub struct InputAxis {
+                    #[doc = "**Component**: `ambient_core::input::input_axis_id`\n\n**Component description**: The ID of this input axis, as declared in the `axes` section of its package's manifest.\n\n"]
+                    pub input_axis_id: String,
+                    #[doc = "**Component**: `ambient_core::input::input_axis_package`\n\n**Component description**: The package that declared this input axis.\n\n"]
+                    pub input_axis_package: EntityId,
+                    #[doc = "**Component**: `ambient_core::input::input_axis_positive_bindings`\n\n**Component description**: The default bindings that move this input axis towards 1. Players can override them through their settings.\n\n"]
+                    pub input_axis_positive_bindings: Vec<String>,
+                    #[doc = "**Component**: `ambient_core::input::input_axis_negative_bindings`\n\n**Component description**: The default bindings that move this input axis towards -1. Players can override them through their settings.\n\n"]
+                    pub input_axis_negative_bindings: Vec<String>,
+                }
+                impl Concept for InputAxis {
+                    fn make(self) -> Entity {
+                        let mut entity = Entity :: new () . with (crate :: ambient_core :: input :: components :: input_axis_id () , self . input_axis_id) . with (crate :: ambient_core :: input :: components :: input_axis_package () , self . input_axis_package) . with (crate :: ambient_core :: input :: components :: input_axis_positive_bindings () , self . input_axis_positive_bindings) . with (crate :: ambient_core :: input :: components :: input_axis_negative_bindings () , self . input_axis_negative_bindings) ;
+                        entity
+                    }
+                    fn get_spawned(id: EntityId) -> Option<Self> {
+                        Some (Self { input_axis_id : entity :: get_component (id , crate :: ambient_core :: input :: components :: input_axis_id ()) ? , input_axis_package : entity :: get_component (id , crate :: ambient_core :: input :: components :: input_axis_package ()) ? , input_axis_positive_bindings : entity :: get_component (id , crate :: ambient_core :: input :: components :: input_axis_positive_bindings ()) ? , input_axis_negative_bindings : entity :: get_component (id , crate :: ambient_core :: input :: components :: input_axis_negative_bindings ()) ? , })
+                    }
+                    fn get_unspawned(entity: &Entity) -> Option<Self> {
+                        Some (Self { input_axis_id : entity . get (crate :: ambient_core :: input :: components :: input_axis_id ()) ? , input_axis_package : entity . get (crate :: ambient_core :: input :: components :: input_axis_package ()) ? , input_axis_positive_bindings : entity . get (crate :: ambient_core :: input :: components :: input_axis_positive_bindings ()) ? , input_axis_negative_bindings : entity . get (crate :: ambient_core :: input :: components :: input_axis_negative_bindings ()) ? , })
+                    }
+                    fn contained_by_spawned(id: EntityId) -> bool {
+                        entity :: has_components (id , & [& crate :: ambient_core :: input :: components :: input_axis_id () , & crate :: ambient_core :: input :: components :: input_axis_package () , & crate :: ambient_core :: input :: components :: input_axis_positive_bindings () , & crate :: ambient_core :: input :: components :: input_axis_negative_bindings ()])
+                    }
+                    fn contained_by_unspawned(entity: &Entity) -> bool {
+                        entity.has_components(&[
+                            &crate::ambient_core::input::components::input_axis_id(),
+                            &crate::ambient_core::input::components::input_axis_package(),
+                            &crate::ambient_core::input::components::input_axis_positive_bindings(),
+                            &crate::ambient_core::input::components::input_axis_negative_bindings(),
+                        ])
+                    }
+                }
+                impl ConceptComponents for InputAxis {
+                    type Required = (
+                        Component<String>,
+                        Component<EntityId>,
+                        Component<Vec<String>>,
+                        Component<Vec<String>>,
+                    );
+                    type Optional = ();
+                    fn required() -> Self::Required {
+                        (
+                            crate::ambient_core::input::components::input_axis_id(),
+                            crate::ambient_core::input::components::input_axis_package(),
+                            crate::ambient_core::input::components::input_axis_positive_bindings(),
+                            crate::ambient_core::input::components::input_axis_negative_bindings(),
+                        )
+                    }
+                    fn optional() -> Self::Optional {
+                        ()
+                    }
+                    fn from_required_data(
+                        required: <Self::Required as ComponentsTuple>::Data,
+                    ) -> Self {
+                        Self {
+                            input_axis_id: required.0,
+                            input_axis_package: required.1,
+                            input_axis_positive_bindings: required.2,
+                            input_axis_negative_bindings: required.3,
+                        }
+                    }
+                }
             }
             #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
             #[doc = r" and with other modules."]
@@ -2591,6 +2974,56 @@ mod raw {
                     once_cell::sync::Lazy,
                     prelude::*,
                 };
+                static BANDWIDTH_BUDGET: Lazy<Component<u32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::bandwidth_budget")
+                });
+                #[doc = "**Bandwidth budget**: Server-side. The number of bytes per second of component changes to send to each player; unlimited if not set.\n\nCan be set as a resource to apply to all players, or attached to a player entity to override it for that player.\n\nWhen the changes exceed the budget, the changes with the highest accumulated `NetworkPriority` are sent first, and the rest are delayed.\n\n*Attributes*: Debuggable, MaybeResource"]
+                pub fn bandwidth_budget() -> Component<u32> {
+                    *BANDWIDTH_BUDGET
+                }
+                static INPUT_SEQUENCE: Lazy<Component<u64>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::network::input_sequence"));
+                #[doc = "**Input sequence**: The sequence number of the last message sent from this client to the server.\n\nEvery message sent to the server is numbered, and the server acknowledges them with `input_sequence_ack`.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn input_sequence() -> Component<u64> {
+                    *INPUT_SEQUENCE
+                }
+                static INPUT_SEQUENCE_ACK: Lazy<Component<u64>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::input_sequence_ack")
+                });
+                #[doc = "**Input sequence acknowledgement**: Attached to player entities. The sequence number of the last message from the player's client that has been processed by the server.\n\nThe client uses this to reconcile its `Predicted` components with the values from the server.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn input_sequence_ack() -> Component<u64> {
+                    *INPUT_SEQUENCE_ACK
+                }
+                static INTERPOLATE_TRANSFORM: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::interpolate_transform")
+                });
+                #[doc = "**Interpolate transform**: If attached, the `translation`, `rotation` and `scale` of this entity are interpolated on the client, as if they had the `Interpolated` attribute.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn interpolate_transform() -> Component<()> {
+                    *INTERPOLATE_TRANSFORM
+                }
+                static INTERPOLATION_DELAY: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::interpolation_delay")
+                });
+                #[doc = "**Interpolation delay**: Client-side resource. How far behind the most recent updates from the server `Interpolated` components are rendered, in seconds.\n\nHigher values make interpolation more robust to late and lost updates, at the cost of latency. Defaults to 0.1 seconds.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn interpolation_delay() -> Component<f32> {
+                    *INTERPOLATION_DELAY
+                }
+                static INTERPOLATION_EXTRAPOLATION_LIMIT: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component(
+                        "ambient_core::network::interpolation_extrapolation_limit",
+                    )
+                });
+                #[doc = "**Interpolation extrapolation limit**: Client-side resource. How far past the most recent update from the server `Interpolated` components may be extrapolated when updates are late, in seconds.\n\nDefaults to 0.25 seconds. Set to 0 to disable extrapolation.\n\n*Attributes*: Debuggable, Resource"]
+                pub fn interpolation_extrapolation_limit() -> Component<f32> {
+                    *INTERPOLATION_EXTRAPOLATION_LIMIT
+                }
+                static SERVER_FRAME_TIME: Lazy<Component<Duration>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::server_frame_time")
+                });
+                #[doc = "**Server frame time**: Synced resource. The `game_time` of the server's most recent frame. Clients interpolate `Interpolated` components on the server's timeline, using this to tell when each update was produced.\n\n*Attributes*: Debuggable, Networked"]
+                pub fn server_frame_time() -> Component<Duration> {
+                    *SERVER_FRAME_TIME
+                }
                 static IS_REMOTE_ENTITY: Lazy<Component<()>> = Lazy::new(|| {
                     __internal_get_component("ambient_core::network::is_remote_entity")
                 });
@@ -2618,6 +3051,26 @@ mod raw {
                 pub fn no_sync() -> Component<()> {
                     *NO_SYNC
                 }
+                static RELEVANCE_RADIUS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::relevance_radius")
+                });
+                #[doc = "**Relevance radius**: If attached, this entity will only be synchronized to players whose viewpoint is within this distance of it.\n\nThe viewpoint of a player is the position of their active camera on the server if there is one, or the position of the player entity otherwise.\n\nEntities are spawned and despawned on the client as they enter and leave the radius.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn relevance_radius() -> Component<f32> {
+                    *RELEVANCE_RADIUS
+                }
+                static RELEVANCE_TEAM: Lazy<Component<u32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::network::relevance_team"));
+                #[doc = "**Relevance team**: If attached, this entity will only be synchronized to players whose player entity has the same `relevance_team`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn relevance_team() -> Component<u32> {
+                    *RELEVANCE_TEAM
+                }
+                static RELEVANCE_USER_IDS: Lazy<Component<Vec<String>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::network::relevance_user_ids")
+                });
+                #[doc = "**Relevance user IDs**: If attached, this entity will only be synchronized to the players with these user IDs.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn relevance_user_ids() -> Component<Vec<String>> {
+                    *RELEVANCE_USER_IDS
+                }
             }
         }
         pub mod package {
@@ -3144,6 +3597,62 @@ mod raw {
                 pub fn angular_velocity() -> Component<Vec3> {
                     *ANGULAR_VELOCITY
                 }
+                static ARTICULATION_BIND_IDS: Lazy<Component<Vec<String>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::articulation_bind_ids")
+                });
+                #[doc = "**Articulation bind ids**: The bind ids (see `bind_id`) of the bones of this entity's model that an articulation should be built from.\n\nEach bone becomes a link with a capsule collider reaching to its child links, and is attached to the closest ancestor bone in this list with a spherical joint. Exactly one of the bones must have no ancestor in the list; it becomes the root link. Otherwise, the articulation is not built and `articulation_error` is set.\n\n\n\nChanging this component will rebuild the articulation.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn articulation_bind_ids() -> Component<Vec<String>> {
+                    *ARTICULATION_BIND_IDS
+                }
+                static ARTICULATION_BLEND: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::articulation_blend")
+                });
+                #[doc = "**Articulation blend**: How much the pose of the articulation's bones is driven by the physics simulation instead of their animation.\n\nAt `0`, the bones are fully animated and the articulation follows the animation. At `1`, the bones are fully simulated (i.e. a ragdoll). Values in between blend between the two, which can be used for hit reactions.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn articulation_blend() -> Component<f32> {
+                    *ARTICULATION_BLEND
+                }
+                static ARTICULATION_DRIVE_DAMPING: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::articulation_drive_damping")
+                });
+                #[doc = "**Articulation drive damping**: The damping of the drives that pull each joint of the articulation towards the animated pose.\n\nCan be set on a bone entity to override the value on the articulation entity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn articulation_drive_damping() -> Component<f32> {
+                    *ARTICULATION_DRIVE_DAMPING
+                }
+                static ARTICULATION_DRIVE_STIFFNESS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::articulation_drive_stiffness")
+                });
+                #[doc = "**Articulation drive stiffness**: The stiffness of the drives that pull each joint of the articulation towards the animated pose. If not present, the joints are not driven.\n\nCan be set on a bone entity to override the value on the articulation entity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn articulation_drive_stiffness() -> Component<f32> {
+                    *ARTICULATION_DRIVE_STIFFNESS
+                }
+                static ARTICULATION_ERROR: Lazy<Component<String>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::articulation_error")
+                });
+                #[doc = "**Articulation error**: The reason the articulation could not be built from `articulation_bind_ids`, e.g. because more than one of the bones has no ancestor in the list.\n\nIt is rebuilt when `articulation_bind_ids` changes.\n\n*Attributes*: Debuggable"]
+                pub fn articulation_error() -> Component<String> {
+                    *ARTICULATION_ERROR
+                }
+                static ARTICULATION_LINK_RADIUS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::articulation_link_radius")
+                });
+                #[doc = "**Articulation link radius**: The radius (in meters) of the capsule collider of this bone's articulation link. Defaults to `0.05` if not present.\n\nSet on the bone entity before the articulation is built.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn articulation_link_radius() -> Component<f32> {
+                    *ARTICULATION_LINK_RADIUS
+                }
+                static ARTICULATION_SWING_LIMIT: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::articulation_swing_limit")
+                });
+                #[doc = "**Articulation swing limit**: The swing limit (in radians) of the joint connecting this bone's articulation link to its parent link, as the maximum angle around the Y and Z axes.\n\nIf not present, the swing is free. Set on the bone entity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn articulation_swing_limit() -> Component<Vec2> {
+                    *ARTICULATION_SWING_LIMIT
+                }
+                static ARTICULATION_TWIST_LIMIT: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::articulation_twist_limit")
+                });
+                #[doc = "**Articulation twist limit**: The lower and upper twist limit (in radians) of the joint connecting this bone's articulation link to its parent link, around the X axis.\n\nIf not present, the twist is free. Set on the bone entity.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn articulation_twist_limit() -> Component<Vec2> {
+                    *ARTICULATION_TWIST_LIMIT
+                }
                 static CUBE_COLLIDER: Lazy<Component<Vec3>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::cube_collider"));
                 #[doc = "**Cube collider**: If attached, this entity will have a cube physics collider.\n\n`x, y, z` is the size of the cube.\n\n*Attributes*: Debuggable, Networked, Store"]
@@ -3184,6 +3693,19 @@ mod raw {
                 pub fn collider_loads() -> Component<Vec<EntityId>> {
                     *COLLIDER_LOADS
                 }
+                static COLLISION_GROUP: Lazy<Component<u32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::collision_group")
+                });
+                #[doc = "**Collision group**: The collision groups this entity's colliders belong to, as a bitmask. Defaults to `1` if not present.\n\nTwo colliders only collide if each one's group shares a bit with the other's `collision_mask`. Scene queries with a collision mask only hit colliders whose group shares a bit with it.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn collision_group() -> Component<u32> {
+                    *COLLISION_GROUP
+                }
+                static COLLISION_MASK: Lazy<Component<u32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::collision_mask"));
+                #[doc = "**Collision mask**: The collision groups this entity's colliders collide with, as a bitmask. Defaults to all groups if not present.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn collision_mask() -> Component<u32> {
+                    *COLLISION_MASK
+                }
                 static CONTACT_OFFSET: Lazy<Component<f32>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::contact_offset"));
                 #[doc = "**Contact offset**: Contact offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's contact offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"]
@@ -3202,6 +3724,203 @@ mod raw {
                 pub fn dynamic() -> Component<bool> {
                     *DYNAMIC
                 }
+                static HEIGHTFIELD_COLLIDER: Lazy<Component<Vec<f32>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::heightfield_collider")
+                });
+                #[doc = "**Heightfield collider**: If attached, this entity will have a heightfield physics collider, which is a cheap and accurate collider for terrain.\n\nThe value is the height of each sample, row by row: the height at `(x, y)` is at index `y * size.x + x`, where `size` is `heightfield_collider_size`.\n\nThe samples are `heightfield_collider_cell_size` apart along the X and Y axes, starting at the entity's origin.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn heightfield_collider() -> Component<Vec<f32>> {
+                    *HEIGHTFIELD_COLLIDER
+                }
+                static HEIGHTFIELD_COLLIDER_CELL_SIZE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component(
+                        "ambient_core::physics::heightfield_collider_cell_size",
+                    )
+                });
+                #[doc = "**Heightfield collider cell size**: The distance (in meters) between two samples of the heightfield collider. Defaults to `1` if not present.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn heightfield_collider_cell_size() -> Component<f32> {
+                    *HEIGHTFIELD_COLLIDER_CELL_SIZE
+                }
+                static HEIGHTFIELD_COLLIDER_FROM_URL: Lazy<Component<String>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::heightfield_collider_from_url")
+                });
+                #[doc = "**Heightfield collider from URL**: If attached, this entity will have a heightfield physics collider loaded from the heightmap image at the URL.\n\nEach pixel becomes a sample, with its brightness (from `0` to `1`) multiplied by `heightfield_collider_height_scale` as the height. The image is laid out as seen from above, with its top towards +Y.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn heightfield_collider_from_url() -> Component<String> {
+                    *HEIGHTFIELD_COLLIDER_FROM_URL
+                }
+                static HEIGHTFIELD_COLLIDER_HEIGHT_SCALE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component(
+                        "ambient_core::physics::heightfield_collider_height_scale",
+                    )
+                });
+                #[doc = "**Heightfield collider height scale**: A multiplier for the heights of the heightfield collider. Defaults to `1` if not present.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn heightfield_collider_height_scale() -> Component<f32> {
+                    *HEIGHTFIELD_COLLIDER_HEIGHT_SCALE
+                }
+                static HEIGHTFIELD_COLLIDER_SIZE: Lazy<Component<UVec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::heightfield_collider_size")
+                });
+                #[doc = "**Heightfield collider size**: The number of samples of the heightfield collider along the X and Y axes. Required by `heightfield_collider`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn heightfield_collider_size() -> Component<UVec2> {
+                    *HEIGHTFIELD_COLLIDER_SIZE
+                }
+                static IS_TRIGGER: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::is_trigger"));
+                #[doc = "**Is trigger**: If attached, this entity's collider is a trigger volume: it does not physically collide with anything, but sends `TriggerEnter` and `TriggerExit` messages when other colliders enter or leave it.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn is_trigger() -> Component<()> {
+                    *IS_TRIGGER
+                }
+                static JOINT_BREAK_FORCE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_break_force")
+                });
+                #[doc = "**Joint break force**: The maximum force (in newtons) this joint can withstand before breaking.\n\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_break_force() -> Component<f32> {
+                    *JOINT_BREAK_FORCE
+                }
+                static JOINT_BREAK_TORQUE: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_break_torque")
+                });
+                #[doc = "**Joint break torque**: The maximum torque (in newton-meters) this joint can withstand before breaking.\n\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_break_torque() -> Component<f32> {
+                    *JOINT_BREAK_TORQUE
+                }
+                static JOINT_BROKEN: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_broken"));
+                #[doc = "**Joint broken**: This component is automatically attached to a joint entity once the joint has broken.\n\nRemoving it will recreate the joint.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_broken() -> Component<()> {
+                    *JOINT_BROKEN
+                }
+                static JOINT_DRIVE_DAMPING: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_damping")
+                });
+                #[doc = "**Joint drive damping**: The damping of the joint's drive.\n\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this is the damping of the spring.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_damping() -> Component<f32> {
+                    *JOINT_DRIVE_DAMPING
+                }
+                static JOINT_DRIVE_STIFFNESS: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_stiffness")
+                });
+                #[doc = "**Joint drive stiffness**: The stiffness of the joint's drive.\n\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this enables a spring with this stiffness between the minimum and maximum distance.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_stiffness() -> Component<f32> {
+                    *JOINT_DRIVE_STIFFNESS
+                }
+                static JOINT_DRIVE_TARGET: Lazy<Component<Mat4>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_target")
+                });
+                #[doc = "**Joint drive target**: The target pose of `joint_frame1` relative to `joint_frame0` that a `D6` joint's drives will try to reach.\n\nOnly used by `D6` joints.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_target() -> Component<Mat4> {
+                    *JOINT_DRIVE_TARGET
+                }
+                static JOINT_DRIVE_VELOCITY: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_drive_velocity")
+                });
+                #[doc = "**Joint drive velocity**: If attached, a `Revolute` joint will be driven at this angular velocity (radians/second) around its axis, like a motor.\n\nOnly used by `Revolute` joints.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_drive_velocity() -> Component<f32> {
+                    *JOINT_DRIVE_VELOCITY
+                }
+                static JOINT_ENTITY0: Lazy<Component<EntityId>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_entity0"));
+                #[doc = "**Joint entity 0**: The first entity connected by this joint.\n\nIf this is `EntityId::null()`, the joint is attached to the world frame.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_entity0() -> Component<EntityId> {
+                    *JOINT_ENTITY0
+                }
+                static JOINT_ENTITY1: Lazy<Component<EntityId>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_entity1"));
+                #[doc = "**Joint entity 1**: The second entity connected by this joint.\n\nIf this is `EntityId::null()`, the joint is attached to the world frame.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_entity1() -> Component<EntityId> {
+                    *JOINT_ENTITY1
+                }
+                static JOINT_FRAME0: Lazy<Component<Mat4>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_frame0"));
+                #[doc = "**Joint frame 0**: The frame of the joint relative to `joint_entity0` (or the world, if it is null).\n\nThe joint's axis is the X axis of this frame.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_frame0() -> Component<Mat4> {
+                    *JOINT_FRAME0
+                }
+                static JOINT_FRAME1: Lazy<Component<Mat4>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_frame1"));
+                #[doc = "**Joint frame 1**: The frame of the joint relative to `joint_entity1` (or the world, if it is null).\n\nThe joint's axis is the X axis of this frame.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_frame1() -> Component<Mat4> {
+                    *JOINT_FRAME1
+                }
+                static JOINT_LIMIT: Lazy<Component<Vec2>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_limit"));
+                #[doc = "**Joint limit**: If attached, the joint's motion will be limited. The meaning of `x, y` depends on the `joint_type`:\n\n- `Revolute`: the lower and upper angle (radians) around the joint axis.\n\n- `Spherical`: the angles (radians) of the limit cone around the Y and Z axes.\n\n- `Prismatic`: the lower and upper distance (meters) along the joint axis.\n\n- `Distance`: the minimum and maximum distance (meters) between the two frames.\n\n- `D6`: the lower and upper twist angle (radians). Only used if `joint_motion_twist` is `Limited`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_limit() -> Component<Vec2> {
+                    *JOINT_LIMIT
+                }
+                static JOINT_LINEAR_LIMIT: Lazy<Component<f32>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_linear_limit")
+                });
+                #[doc = "**Joint linear limit**: The maximum distance (meters) between the two frames of a `D6` joint along any `Limited` linear axis.\n\nOnly used by `D6` joints.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_linear_limit() -> Component<f32> {
+                    *JOINT_LINEAR_LIMIT
+                }
+                static JOINT_MOTION_SWING1: Lazy<
+                    Component<crate::ambient_core::physics::types::JointMotion>,
+                > = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_motion_swing1")
+                });
+                #[doc = "**Joint motion swing 1**: The motion allowed around the Y axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_motion_swing1(
+                ) -> Component<crate::ambient_core::physics::types::JointMotion> {
+                    *JOINT_MOTION_SWING1
+                }
+                static JOINT_MOTION_SWING2: Lazy<
+                    Component<crate::ambient_core::physics::types::JointMotion>,
+                > = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_motion_swing2")
+                });
+                #[doc = "**Joint motion swing 2**: The motion allowed around the Z axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_motion_swing2(
+                ) -> Component<crate::ambient_core::physics::types::JointMotion> {
+                    *JOINT_MOTION_SWING2
+                }
+                static JOINT_MOTION_TWIST: Lazy<
+                    Component<crate::ambient_core::physics::types::JointMotion>,
+                > = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_motion_twist")
+                });
+                #[doc = "**Joint motion twist**: The motion allowed around the X axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_motion_twist(
+                ) -> Component<crate::ambient_core::physics::types::JointMotion> {
+                    *JOINT_MOTION_TWIST
+                }
+                static JOINT_MOTION_X: Lazy<
+                    Component<crate::ambient_core::physics::types::JointMotion>,
+                > = Lazy::new(|| __internal_get_component("ambient_core::physics::joint_motion_x"));
+                #[doc = "**Joint motion X**: The motion allowed along the X axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_motion_x(
+                ) -> Component<crate::ambient_core::physics::types::JointMotion> {
+                    *JOINT_MOTION_X
+                }
+                static JOINT_MOTION_Y: Lazy<
+                    Component<crate::ambient_core::physics::types::JointMotion>,
+                > = Lazy::new(|| __internal_get_component("ambient_core::physics::joint_motion_y"));
+                #[doc = "**Joint motion Y**: The motion allowed along the Y axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_motion_y(
+                ) -> Component<crate::ambient_core::physics::types::JointMotion> {
+                    *JOINT_MOTION_Y
+                }
+                static JOINT_MOTION_Z: Lazy<
+                    Component<crate::ambient_core::physics::types::JointMotion>,
+                > = Lazy::new(|| __internal_get_component("ambient_core::physics::joint_motion_z"));
+                #[doc = "**Joint motion Z**: The motion allowed along the Z axis of a `D6` joint. Defaults to `Locked`.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_motion_z(
+                ) -> Component<crate::ambient_core::physics::types::JointMotion> {
+                    *JOINT_MOTION_Z
+                }
+                static JOINT_SWING_LIMIT: Lazy<Component<Vec2>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::joint_swing_limit")
+                });
+                #[doc = "**Joint swing limit**: The angles (radians) of the swing limit cone around the Y and Z axes of a `D6` joint.\n\nOnly used if `joint_motion_swing1` or `joint_motion_swing2` is `Limited`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn joint_swing_limit() -> Component<Vec2> {
+                    *JOINT_SWING_LIMIT
+                }
+                static JOINT_TYPE: Lazy<Component<crate::ambient_core::physics::types::JointType>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::physics::joint_type"));
+                #[doc = "**Joint type**: If attached, this entity is a physics joint connecting `joint_entity0` and `joint_entity1`.\n\nThe joint is created once the connected entities have physics actors, and is released when this entity is despawned.\n\n*Attributes*: Debuggable, Networked, Store, Enum"]
+                pub fn joint_type() -> Component<crate::ambient_core::physics::types::JointType> {
+                    *JOINT_TYPE
+                }
                 static KINEMATIC: Lazy<Component<()>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::kinematic"));
                 #[doc = "**Kinematic**: If attached, and this entity is dynamic, this entity will also be kinematic (i.e. unable to be affected by other entities motion). Otherwise, it will receive forces normally.\n\n*Attributes*: Debuggable, Networked, Store"]
@@ -3241,6 +3960,13 @@ mod raw {
                 pub fn plane_collider() -> Component<()> {
                     *PLANE_COLLIDER
                 }
+                static REPORT_CONTACTS: Lazy<Component<()>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::physics::report_contacts")
+                });
+                #[doc = "**Report contacts**: If attached, `ContactBegin` and `ContactEnd` messages will be sent when this entity's collider starts or stops touching another collider.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn report_contacts() -> Component<()> {
+                    *REPORT_CONTACTS
+                }
                 static REST_OFFSET: Lazy<Component<f32>> =
                     Lazy::new(|| __internal_get_component("ambient_core::physics::rest_offset"));
                 #[doc = "**Rest offset**: Rest offset (in meters) of this entity in the physics scene.\n\nUpdating this component will update the entity's rest offset for each attached shape in the physics scene.\n\n*Attributes*: Debuggable, Networked, Store"]
@@ -3356,47 +4082,920 @@ mod raw {
                         }
                     }
                 }
-            }
-        }
-        pub mod player {
-            #[doc = r" Auto-generated component definitions."]
-            pub mod components {
-                use crate::{
-                    ecs::{Component, __internal_get_component},
-                    once_cell::sync::Lazy,
-                    prelude::*,
-                };
-                static LOCAL_USER_ID: Lazy<Component<String>> =
-                    Lazy::new(|| __internal_get_component("ambient_core::player::local_user_id"));
-                #[doc = "**Local user ID**: The user ID of the local player.\n\n*Attributes*: Debuggable, Networked, Resource, Store"]
-                pub fn local_user_id() -> Component<String> {
-                    *LOCAL_USER_ID
-                }
-                static IS_PLAYER: Lazy<Component<()>> =
-                    Lazy::new(|| __internal_get_component("ambient_core::player::is_player"));
-                #[doc = "**Is player**: This entity is a player.\n\nNote that this is a logical construct; a player's body may be separate from the player itself.\n\n*Attributes*: Debuggable, Networked, Store"]
-                pub fn is_player() -> Component<()> {
-                    *IS_PLAYER
+                #[doc = "**Articulation**: An articulation built from the skeleton of this entity's model, for ragdolls and physically-driven rigs.\n\nEach bone listed in `articulation_bind_ids` becomes a link of the articulation. The links are configured through the `articulation_*` components on the bone entities, and the articulation as a whole through the components on this entity.\n`articulation_blend` controls whether the bones follow their animation or the simulation.\n\n**Required**:\n- `articulation_bind_ids`: The bind ids (see `bind_id`) of the bones of this entity's model that an articulation should be built from.\nEach bone becomes a link with a capsule collider reaching to its child links, and is attached to the closest ancestor bone in this list with a spherical joint. Exactly one of the bones must have no ancestor in the list; it becomes the root link. Otherwise, the articulation is not built and `articulation_error` is set.\n\nChanging this component will rebuild the articulation.\n- `articulation_blend`: How much the pose of the articulation's bones is driven by the physics simulation instead of their animation.\nAt `0`, the bones are fully animated and the articulation follows the animation. At `1`, the bones are fully simulated (i.e. a ragdoll). Values in between blend between the two, which can be used for hit reactions.\n\n\n**Optional**:\n- `articulation_drive_stiffness`: The stiffness of the drives that pull each joint of the articulation towards the animated pose. If not present, the joints are not driven.\nCan be set on a bone entity to override the value on the articulation entity.\n- `articulation_drive_damping`: The damping of the drives that pull each joint of the articulation towards the animated pose.\nCan be set on a bone entity to override the value on the articulation entity."]
+                #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+                #[serde(crate = "self::serde")]
+                pub struct Articulation {
+                    #[doc = "**Component**: `ambient_core::physics::articulation_bind_ids`\n\n**Component description**: The bind ids (see `bind_id`) of the bones of this entity's model that an articulation should be built from.\nEach bone becomes a link with a capsule collider reaching to its child links, and is attached to the closest ancestor bone in this list with a spherical joint. Exactly one of the bones must have no ancestor in the list; it becomes the root link. Otherwise, the articulation is not built and `articulation_error` is set.\n\nChanging this component will rebuild the articulation.\n\n"]
+                    pub articulation_bind_ids: Vec<String>,
+                    #[doc = "**Component**: `ambient_core::physics::articulation_blend`\n\n**Suggested value**: `0f32`\n\n**Component description**: How much the pose of the articulation's bones is driven by the physics simulation instead of their animation.\nAt `0`, the bones are fully animated and the articulation follows the animation. At `1`, the bones are fully simulated (i.e. a ragdoll). Values in between blend between the two, which can be used for hit reactions.\n\n"]
+                    pub articulation_blend: f32,
+                    #[doc = r" Optional components."]
+                    pub optional: ArticulationOptional,
                 }
-                static USER_ID: Lazy<Component<String>> =
-                    Lazy::new(|| __internal_get_component("ambient_core::player::user_id"));
-                #[doc = "**User ID**: An identifier attached to all things owned by a user, and supplied by the user.\n\nThis can be attached to more than just the player; by convention, it is also attached to related entities, including their camera and body.\n\n*Attributes*: Debuggable, Networked, Store"]
-                pub fn user_id() -> Component<String> {
-                    *USER_ID
+                #[doc = "Optional part of [Articulation]."]
+                #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+                #[serde(crate = "self::serde")]
+                pub struct ArticulationOptional {
+                    #[doc = "**Component**: `ambient_core::physics::articulation_drive_stiffness`\n\n**Component description**: The stiffness of the drives that pull each joint of the articulation towards the animated pose. If not present, the joints are not driven.\nCan be set on a bone entity to override the value on the articulation entity.\n\n"]
+                    pub articulation_drive_stiffness: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::articulation_drive_damping`\n\n**Component description**: The damping of the drives that pull each joint of the articulation towards the animated pose.\nCan be set on a bone entity to override the value on the articulation entity.\n\n"]
+                    pub articulation_drive_damping: Option<f32>,
                 }
-            }
-        }
-        pub mod prefab {
-            #[doc = r" Auto-generated component definitions."]
-            pub mod components {
-                use crate::{
-                    ecs::{Component, __internal_get_component},
-                    once_cell::sync::Lazy,
-                    prelude::*,
-                };
-                static PREFAB_FROM_URL: Lazy<Component<String>> =
-                    Lazy::new(|| __internal_get_component("ambient_core::prefab::prefab_from_url"));
-                #[doc = "**Prefab from URL**: Load and attach a prefab from a URL or relative path.\n\nWhen loaded, the components from this prefab will add to or replace the existing components for the entity.\n\n*Attributes*: Debuggable, Store"]
+                impl Concept for Articulation {
+                    fn make(self) -> Entity {
+                        let mut entity = Entity::new()
+                            .with(
+                                crate::ambient_core::physics::components::articulation_bind_ids(),
+                                self.articulation_bind_ids,
+                            )
+                            .with(
+                                crate::ambient_core::physics::components::articulation_blend(),
+                                self.articulation_blend,
+                            );
+                        if let Some(articulation_drive_stiffness) =
+                            self.optional.articulation_drive_stiffness
+                        {
+                            entity . set (crate :: ambient_core :: physics :: components :: articulation_drive_stiffness () , articulation_drive_stiffness) ;
+                        }
+                        if let Some(articulation_drive_damping) =
+                            self.optional.articulation_drive_damping
+                        {
+                            entity . set (crate :: ambient_core :: physics :: components :: articulation_drive_damping () , articulation_drive_damping) ;
+                        }
+                        entity
+                    }
+                    fn get_spawned(id: EntityId) -> Option<Self> {
+                        Some (Self { articulation_bind_ids : entity :: get_component (id , crate :: ambient_core :: physics :: components :: articulation_bind_ids ()) ? , articulation_blend : entity :: get_component (id , crate :: ambient_core :: physics :: components :: articulation_blend ()) ? , optional : ArticulationOptional { articulation_drive_stiffness : entity :: get_component (id , crate :: ambient_core :: physics :: components :: articulation_drive_stiffness ()) , articulation_drive_damping : entity :: get_component (id , crate :: ambient_core :: physics :: components :: articulation_drive_damping ()) , } })
+                    }
+                    fn get_unspawned(entity: &Entity) -> Option<Self> {
+                        Some (Self { articulation_bind_ids : entity . get (crate :: ambient_core :: physics :: components :: articulation_bind_ids ()) ? , articulation_blend : entity . get (crate :: ambient_core :: physics :: components :: articulation_blend ()) ? , optional : ArticulationOptional { articulation_drive_stiffness : entity . get (crate :: ambient_core :: physics :: components :: articulation_drive_stiffness ()) , articulation_drive_damping : entity . get (crate :: ambient_core :: physics :: components :: articulation_drive_damping ()) , } })
+                    }
+                    fn contained_by_spawned(id: EntityId) -> bool {
+                        entity::has_components(
+                            id,
+                            &[
+                                &crate::ambient_core::physics::components::articulation_bind_ids(),
+                                &crate::ambient_core::physics::components::articulation_blend(),
+                            ],
+                        )
+                    }
+                    fn contained_by_unspawned(entity: &Entity) -> bool {
+                        entity.has_components(&[
+                            &crate::ambient_core::physics::components::articulation_bind_ids(),
+                            &crate::ambient_core::physics::components::articulation_blend(),
+                        ])
+                    }
+                }
+                impl ConceptComponents for Articulation {
+                    type Required = (Component<Vec<String>>, Component<f32>);
+                    type Optional = (Component<f32>, Component<f32>);
+                    fn required() -> Self::Required {
+                        (
+                            crate::ambient_core::physics::components::articulation_bind_ids(),
+                            crate::ambient_core::physics::components::articulation_blend(),
+                        )
+                    }
+                    fn optional() -> Self::Optional {
+                        (
+                            crate::ambient_core::physics::components::articulation_drive_stiffness(
+                            ),
+                            crate::ambient_core::physics::components::articulation_drive_damping(),
+                        )
+                    }
+                    fn from_required_data(
+                        required: <Self::Required as ComponentsTuple>::Data,
+                    ) -> Self {
+                        Self {
+                            articulation_bind_ids: required.0,
+                            articulation_blend: required.1,
+                            optional: Default::default(),
+                        }
+                    }
+                }
+                #[doc = "**Joint**: A physics joint connecting two entities (or an entity and the world).\n\nThe joint is configured through the `joint_*` components; changing them will update the joint in the physics scene.\nWhen the joint breaks (see `joint_break_force` and `joint_break_torque`), a `JointBreak` message is sent.\n\n**Required**:\n- `joint_type`: If attached, this entity is a physics joint connecting `joint_entity0` and `joint_entity1`.\nThe joint is created once the connected entities have physics actors, and is released when this entity is despawned.\n- `joint_entity0`: The first entity connected by this joint.\nIf this is `EntityId::null()`, the joint is attached to the world frame.\n- `joint_entity1`: The second entity connected by this joint.\nIf this is `EntityId::null()`, the joint is attached to the world frame.\n- `joint_frame0`: The frame of the joint relative to `joint_entity0` (or the world, if it is null).\nThe joint's axis is the X axis of this frame.\n- `joint_frame1`: The frame of the joint relative to `joint_entity1` (or the world, if it is null).\nThe joint's axis is the X axis of this frame.\n\n\n**Optional**:\n- `joint_limit`: If attached, the joint's motion will be limited. The meaning of `x, y` depends on the `joint_type`:\n- `Revolute`: the lower and upper angle (radians) around the joint axis.\n- `Spherical`: the angles (radians) of the limit cone around the Y and Z axes.\n- `Prismatic`: the lower and upper distance (meters) along the joint axis.\n- `Distance`: the minimum and maximum distance (meters) between the two frames.\n- `D6`: the lower and upper twist angle (radians). Only used if `joint_motion_twist` is `Limited`.\n- `joint_swing_limit`: The angles (radians) of the swing limit cone around the Y and Z axes of a `D6` joint.\nOnly used if `joint_motion_swing1` or `joint_motion_swing2` is `Limited`.\n- `joint_linear_limit`: The maximum distance (meters) between the two frames of a `D6` joint along any `Limited` linear axis.\nOnly used by `D6` joints.\n- `joint_motion_x`: The motion allowed along the X axis of a `D6` joint. Defaults to `Locked`.\n- `joint_motion_y`: The motion allowed along the Y axis of a `D6` joint. Defaults to `Locked`.\n- `joint_motion_z`: The motion allowed along the Z axis of a `D6` joint. Defaults to `Locked`.\n- `joint_motion_twist`: The motion allowed around the X axis of a `D6` joint. Defaults to `Locked`.\n- `joint_motion_swing1`: The motion allowed around the Y axis of a `D6` joint. Defaults to `Locked`.\n- `joint_motion_swing2`: The motion allowed around the Z axis of a `D6` joint. Defaults to `Locked`.\n- `joint_drive_velocity`: If attached, a `Revolute` joint will be driven at this angular velocity (radians/second) around its axis, like a motor.\nOnly used by `Revolute` joints.\n- `joint_drive_stiffness`: The stiffness of the joint's drive.\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this enables a spring with this stiffness between the minimum and maximum distance.\n- `joint_drive_damping`: The damping of the joint's drive.\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this is the damping of the spring.\n- `joint_drive_target`: The target pose of `joint_frame1` relative to `joint_frame0` that a `D6` joint's drives will try to reach.\nOnly used by `D6` joints.\n- `joint_break_force`: The maximum force (in newtons) this joint can withstand before breaking.\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent.\n- `joint_break_torque`: The maximum torque (in newton-meters) this joint can withstand before breaking.\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent."]
+                #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+                #[serde(crate = "self::serde")]
+                pub struct Joint {
+                    #[doc = "**Component**: `ambient_core::physics::joint_type`\n\n**Component description**: If attached, this entity is a physics joint connecting `joint_entity0` and `joint_entity1`.\nThe joint is created once the connected entities have physics actors, and is released when this entity is despawned.\n\n"]
+                    pub joint_type: crate::ambient_core::physics::types::JointType,
+                    #[doc = "**Component**: `ambient_core::physics::joint_entity0`\n\n**Component description**: The first entity connected by this joint.\nIf this is `EntityId::null()`, the joint is attached to the world frame.\n\n"]
+                    pub joint_entity0: EntityId,
+                    #[doc = "**Component**: `ambient_core::physics::joint_entity1`\n\n**Component description**: The second entity connected by this joint.\nIf this is `EntityId::null()`, the joint is attached to the world frame.\n\n"]
+                    pub joint_entity1: EntityId,
+                    #[doc = "**Component**: `ambient_core::physics::joint_frame0`\n\n**Suggested value**: `Mat4::from_cols_array(&[1f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 0f32, 1f32, ])`\n\n**Component description**: The frame of the joint relative to `joint_entity0` (or the world, if it is null).\nThe joint's axis is the X axis of this frame.\n\n"]
+                    pub joint_frame0: Mat4,
+                    #[doc = "**Component**: `ambient_core::physics::joint_frame1`\n\n**Suggested value**: `Mat4::from_cols_array(&[1f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 0f32, 1f32, 0f32, 0f32, 0f32, 0f32, 1f32, ])`\n\n**Component description**: The frame of the joint relative to `joint_entity1` (or the world, if it is null).\nThe joint's axis is the X axis of this frame.\n\n"]
+                    pub joint_frame1: Mat4,
+                    #[doc = r" Optional components."]
+                    pub optional: JointOptional,
+                }
+                #[doc = "Optional part of [Joint]."]
+                #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+                #[serde(crate = "self::serde")]
+                pub struct JointOptional {
+                    #[doc = "**Component**: `ambient_core::physics::joint_limit`\n\n**Component description**: If attached, the joint's motion will be limited. The meaning of `x, y` depends on the `joint_type`:\n- `Revolute`: the lower and upper angle (radians) around the joint axis.\n- `Spherical`: the angles (radians) of the limit cone around the Y and Z axes.\n- `Prismatic`: the lower and upper distance (meters) along the joint axis.\n- `Distance`: the minimum and maximum distance (meters) between the two frames.\n- `D6`: the lower and upper twist angle (radians). Only used if `joint_motion_twist` is `Limited`.\n\n"]
+                    pub joint_limit: Option<Vec2>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_swing_limit`\n\n**Component description**: The angles (radians) of the swing limit cone around the Y and Z axes of a `D6` joint.\nOnly used if `joint_motion_swing1` or `joint_motion_swing2` is `Limited`.\n\n"]
+                    pub joint_swing_limit: Option<Vec2>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_linear_limit`\n\n**Component description**: The maximum distance (meters) between the two frames of a `D6` joint along any `Limited` linear axis.\nOnly used by `D6` joints.\n\n"]
+                    pub joint_linear_limit: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_motion_x`\n\n**Component description**: The motion allowed along the X axis of a `D6` joint. Defaults to `Locked`.\n\n"]
+                    pub joint_motion_x: Option<crate::ambient_core::physics::types::JointMotion>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_motion_y`\n\n**Component description**: The motion allowed along the Y axis of a `D6` joint. Defaults to `Locked`.\n\n"]
+                    pub joint_motion_y: Option<crate::ambient_core::physics::types::JointMotion>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_motion_z`\n\n**Component description**: The motion allowed along the Z axis of a `D6` joint. Defaults to `Locked`.\n\n"]
+                    pub joint_motion_z: Option<crate::ambient_core::physics::types::JointMotion>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_motion_twist`\n\n**Component description**: The motion allowed around the X axis of a `D6` joint. Defaults to `Locked`.\n\n"]
+                    pub joint_motion_twist:
+                        Option<crate::ambient_core::physics::types::JointMotion>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_motion_swing1`\n\n**Component description**: The motion allowed around the Y axis of a `D6` joint. Defaults to `Locked`.\n\n"]
+                    pub joint_motion_swing1:
+                        Option<crate::ambient_core::physics::types::JointMotion>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_motion_swing2`\n\n**Component description**: The motion allowed around the Z axis of a `D6` joint. Defaults to `Locked`.\n\n"]
+                    pub joint_motion_swing2:
+                        Option<crate::ambient_core::physics::types::JointMotion>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_drive_velocity`\n\n**Component description**: If attached, a `Revolute` joint will be driven at this angular velocity (radians/second) around its axis, like a motor.\nOnly used by `Revolute` joints.\n\n"]
+                    pub joint_drive_velocity: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_drive_stiffness`\n\n**Component description**: The stiffness of the joint's drive.\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this enables a spring with this stiffness between the minimum and maximum distance.\n\n"]
+                    pub joint_drive_stiffness: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_drive_damping`\n\n**Component description**: The damping of the joint's drive.\nFor `D6` joints, this applies to all drives towards `joint_drive_target`. For `Distance` joints, this is the damping of the spring.\n\n"]
+                    pub joint_drive_damping: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_drive_target`\n\n**Component description**: The target pose of `joint_frame1` relative to `joint_frame0` that a `D6` joint's drives will try to reach.\nOnly used by `D6` joints.\n\n"]
+                    pub joint_drive_target: Option<Mat4>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_break_force`\n\n**Component description**: The maximum force (in newtons) this joint can withstand before breaking.\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent.\n\n"]
+                    pub joint_break_force: Option<f32>,
+                    #[doc = "**Component**: `ambient_core::physics::joint_break_torque`\n\n**Component description**: The maximum torque (in newton-meters) this joint can withstand before breaking.\nIf unset, the joint is unbreakable. When the joint breaks, `joint_broken` is attached and a `JointBreak` message is sent.\n\n"]
+                    pub joint_break_torque: Option<f32>,
+                }
+                impl Concept for Joint {
+                    fn make(self) -> Entity {
+                        let mut entity = Entity::new()
+                            .with(
+                                crate::ambient_core::physics::components::joint_type(),
+                                self.joint_type,
+                            )
+                            .with(
+                                crate::ambient_core::physics::components::joint_entity0(),
+                                self.joint_entity0,
+                            )
+                            .with(
+                                crate::ambient_core::physics::components::joint_entity1(),
+                                self.joint_entity1,
+                            )
+                            .with(
+                                crate::ambient_core::physics::components::joint_frame0(),
+                                self.joint_frame0,
+                            )
+                            .with(
+                                crate::ambient_core::physics::components::joint_frame1(),
+                                self.joint_frame1,
+                            );
+                        if let Some(joint_limit) = self.optional.joint_limit {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_limit(),
+                                joint_limit,
+                            );
+                        }
+                        if let Some(joint_swing_limit) = self.optional.joint_swing_limit {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_swing_limit(),
+                                joint_swing_limit,
+                            );
+                        }
+                        if let Some(joint_linear_limit) = self.optional.joint_linear_limit {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_linear_limit(),
+                                joint_linear_limit,
+                            );
+                        }
+                        if let Some(joint_motion_x) = self.optional.joint_motion_x {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_motion_x(),
+                                joint_motion_x,
+                            );
+                        }
+                        if let Some(joint_motion_y) = self.optional.joint_motion_y {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_motion_y(),
+                                joint_motion_y,
+                            );
+                        }
+                        if let Some(joint_motion_z) = self.optional.joint_motion_z {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_motion_z(),
+                                joint_motion_z,
+                            );
+                        }
+                        if let Some(joint_motion_twist) = self.optional.joint_motion_twist {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_motion_twist(),
+                                joint_motion_twist,
+                            );
+                        }
+                        if let Some(joint_motion_swing1) = self.optional.joint_motion_swing1 {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_motion_swing1(),
+                                joint_motion_swing1,
+                            );
+                        }
+                        if let Some(joint_motion_swing2) = self.optional.joint_motion_swing2 {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_motion_swing2(),
+                                joint_motion_swing2,
+                            );
+                        }
+                        if let Some(joint_drive_velocity) = self.optional.joint_drive_velocity {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_drive_velocity(),
+                                joint_drive_velocity,
+                            );
+                        }
+                        if let Some(joint_drive_stiffness) = self.optional.joint_drive_stiffness {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_drive_stiffness(),
+                                joint_drive_stiffness,
+                            );
+                        }
+                        if let Some(joint_drive_damping) = self.optional.joint_drive_damping {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_drive_damping(),
+                                joint_drive_damping,
+                            );
+                        }
+                        if let Some(joint_drive_target) = self.optional.joint_drive_target {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_drive_target(),
+                                joint_drive_target,
+                            );
+                        }
+                        if let Some(joint_break_force) = self.optional.joint_break_force {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_break_force(),
+                                joint_break_force,
+                            );
+                        }
+                        if let Some(joint_break_torque) = self.optional.joint_break_torque {
+                            entity.set(
+                                crate::ambient_core::physics::components::joint_break_torque(),
+                                joint_break_torque,
+                            );
+                        }
+                        entity
+                    }
+                    fn get_spawned(id: EntityId) -> Option<Self> {
+                        Some(Self {
+                            joint_type: entity::get_component(
+                                id,
+                                crate::ambient_core::physics::components::joint_type(),
+                            )?,
+                            joint_entity0: entity::get_component(
+                                id,
+                                crate::ambient_core::physics::components::joint_entity0(),
+                            )?,
+                            joint_entity1: entity::get_component(
+                                id,
+                                crate::ambient_core::physics::components::joint_entity1(),
+                            )?,
+                            joint_frame0: entity::get_component(
+                                id,
+                                crate::ambient_core::physics::components::joint_frame0(),
+                            )?,
+                            joint_frame1: entity::get_component(
+                                id,
+                                crate::ambient_core::physics::components::joint_frame1(),
+                            )?,
+                            optional: JointOptional {
+                                joint_limit: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_limit(),
+                                ),
+                                joint_swing_limit: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_swing_limit(),
+                                ),
+                                joint_linear_limit: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_linear_limit(),
+                                ),
+                                joint_motion_x: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_motion_x(),
+                                ),
+                                joint_motion_y: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_motion_y(),
+                                ),
+                                joint_motion_z: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_motion_z(),
+                                ),
+                                joint_motion_twist: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_motion_twist(),
+                                ),
+                                joint_motion_swing1: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_motion_swing1(),
+                                ),
+                                joint_motion_swing2: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_motion_swing2(),
+                                ),
+                                joint_drive_velocity: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_drive_velocity(
+                                    ),
+                                ),
+                                joint_drive_stiffness: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_drive_stiffness(
+                                    ),
+                                ),
+                                joint_drive_damping: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_drive_damping(),
+                                ),
+                                joint_drive_target: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_drive_target(),
+                                ),
+                                joint_break_force: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_break_force(),
+                                ),
+                                joint_break_torque: entity::get_component(
+                                    id,
+                                    crate::ambient_core::physics::components::joint_break_torque(),
+                                ),
+                            },
+                        })
+                    }
+                    fn get_unspawned(entity: &Entity) -> Option<Self> {
+                        Some(Self {
+                            joint_type: entity
+                                .get(crate::ambient_core::physics::components::joint_type())?,
+                            joint_entity0: entity
+                                .get(crate::ambient_core::physics::components::joint_entity0())?,
+                            joint_entity1: entity
+                                .get(crate::ambient_core::physics::components::joint_entity1())?,
+                            joint_frame0: entity
+                                .get(crate::ambient_core::physics::components::joint_frame0())?,
+                            joint_frame1: entity
+                                .get(crate::ambient_core::physics::components::joint_frame1())?,
+                            optional: JointOptional {
+                                joint_limit: entity
+                                    .get(crate::ambient_core::physics::components::joint_limit()),
+                                joint_swing_limit: entity.get(
+                                    crate::ambient_core::physics::components::joint_swing_limit(),
+                                ),
+                                joint_linear_limit: entity.get(
+                                    crate::ambient_core::physics::components::joint_linear_limit(),
+                                ),
+                                joint_motion_x:
+                                    entity.get(
+                                        crate::ambient_core::physics::components::joint_motion_x(),
+                                    ),
+                                joint_motion_y:
+                                    entity.get(
+                                        crate::ambient_core::physics::components::joint_motion_y(),
+                                    ),
+                                joint_motion_z:
+                                    entity.get(
+                                        crate::ambient_core::physics::components::joint_motion_z(),
+                                    ),
+                                joint_motion_twist: entity.get(
+                                    crate::ambient_core::physics::components::joint_motion_twist(),
+                                ),
+                                joint_motion_swing1: entity.get(
+                                    crate::ambient_core::physics::components::joint_motion_swing1(),
+                                ),
+                                joint_motion_swing2: entity.get(
+                                    crate::ambient_core::physics::components::joint_motion_swing2(),
+                                ),
+                                joint_drive_velocity: entity.get(
+                                    crate::ambient_core::physics::components::joint_drive_velocity(
+                                    ),
+                                ),
+                                joint_drive_stiffness: entity.get(
+                                    crate::ambient_core::physics::components::joint_drive_stiffness(
+                                    ),
+                                ),
+                                joint_drive_damping: entity.get(
+                                    crate::ambient_core::physics::components::joint_drive_damping(),
+                                ),
+                                joint_drive_target: entity.get(
+                                    crate::ambient_core::physics::components::joint_drive_target(),
+                                ),
+                                joint_break_force: entity.get(
+                                    crate::ambient_core::physics::components::joint_break_force(),
+                                ),
+                                joint_break_torque: entity.get(
+                                    crate::ambient_core::physics::components::joint_break_torque(),
+                                ),
+                            },
+                        })
+                    }
+                    fn contained_by_spawned(id: EntityId) -> bool {
+                        entity::has_components(
+                            id,
+                            &[
+                                &crate::ambient_core::physics::components::joint_type(),
+                                &crate::ambient_core::physics::components::joint_entity0(),
+                                &crate::ambient_core::physics::components::joint_entity1(),
+                                &crate::ambient_core::physics::components::joint_frame0(),
+                                &crate::ambient_core::physics::components::joint_frame1(),
+                            ],
+                        )
+                    }
+                    fn contained_by_unspawned(entity: &Entity) -> bool {
+                        entity.has_components(&[
+                            &crate::ambient_core::physics::components::joint_type(),
+                            &crate::ambient_core::physics::components::joint_entity0(),
+                            &crate::ambient_core::physics::components::joint_entity1(),
+                            &crate::ambient_core::physics::components::joint_frame0(),
+                            &crate::ambient_core::physics::components::joint_frame1(),
+                        ])
+                    }
+                }
+                impl ConceptComponents for Joint {
+                    type Required = (
+                        Component<crate::ambient_core::physics::types::JointType>,
+                        Component<EntityId>,
+                        Component<EntityId>,
+                        Component<Mat4>,
+                        Component<Mat4>,
+                    );
+                    type Optional = (
+                        Component<Vec2>,
+                        Component<Vec2>,
+                        Component<f32>,
+                        Component<crate::ambient_core::physics::types::JointMotion>,
+                        Component<crate::ambient_core::physics::types::JointMotion>,
+                        Component<crate::ambient_core::physics::types::JointMotion>,
+                        Component<crate::ambient_core::physics::types::JointMotion>,
+                        Component<crate::ambient_core::physics::types::JointMotion>,
+                        Component<crate::ambient_core::physics::types::JointMotion>,
+                        Component<f32>,
+                        Component<f32>,
+                        Component<f32>,
+                        Component<Mat4>,
+                        Component<f32>,
+                        Component<f32>,
+                    );
+                    fn required() -> Self::Required {
+                        (
+                            crate::ambient_core::physics::components::joint_type(),
+                            crate::ambient_core::physics::components::joint_entity0(),
+                            crate::ambient_core::physics::components::joint_entity1(),
+                            crate::ambient_core::physics::components::joint_frame0(),
+                            crate::ambient_core::physics::components::joint_frame1(),
+                        )
+                    }
+                    fn optional() -> Self::Optional {
+                        (
+                            crate::ambient_core::physics::components::joint_limit(),
+                            crate::ambient_core::physics::components::joint_swing_limit(),
+                            crate::ambient_core::physics::components::joint_linear_limit(),
+                            crate::ambient_core::physics::components::joint_motion_x(),
+                            crate::ambient_core::physics::components::joint_motion_y(),
+                            crate::ambient_core::physics::components::joint_motion_z(),
+                            crate::ambient_core::physics::components::joint_motion_twist(),
+                            crate::ambient_core::physics::components::joint_motion_swing1(),
+                            crate::ambient_core::physics::components::joint_motion_swing2(),
+                            crate::ambient_core::physics::components::joint_drive_velocity(),
+                            crate::ambient_core::physics::components::joint_drive_stiffness(),
+                            crate::ambient_core::physics::components::joint_drive_damping(),
+                            crate::ambient_core::physics::components::joint_drive_target(),
+                            crate::ambient_core::physics::components::joint_break_force(),
+                            crate::ambient_core::physics::components::joint_break_torque(),
+                        )
+                    }
+                    fn from_required_data(
+                        required: <Self::Required as ComponentsTuple>::Data,
+                    ) -> Self {
+                        Self {
+                            joint_type: required.0,
+                            joint_entity0: required.1,
+                            joint_entity1: required.2,
+                            joint_frame0: required.3,
+                            joint_frame1: required.4,
+                            optional: Default::default(),
+                        }
+                    }
+                }
+            }
+            #[doc = r" Auto-generated message definitions. Messages are used to communicate with the runtime, the other side of the network,"]
+            #[doc = r" and with other modules."]
+            pub mod messages {
+                use crate::{
+                    message::{
+                        Message, MessageSerde, MessageSerdeError, ModuleMessage, RuntimeMessage,
+                    },
+                    prelude::*,
+                };
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactBegin**: Sent when two colliders start touching, if either of them has `report_contacts`. The points and normals are in world space."]
+                pub struct ContactBegin {
+                    pub entity0: EntityId,
+                    pub entity1: EntityId,
+                    pub points: Vec<Vec3>,
+                    pub normals: Vec<Vec3>,
+                    pub impulse: Vec3,
+                }
+                impl ContactBegin {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        entity0: impl Into<EntityId>,
+                        entity1: impl Into<EntityId>,
+                        points: impl Into<Vec<Vec3>>,
+                        normals: impl Into<Vec<Vec3>>,
+                        impulse: impl Into<Vec3>,
+                    ) -> Self {
+                        Self {
+                            entity0: entity0.into(),
+                            entity1: entity1.into(),
+                            points: points.into(),
+                            normals: normals.into(),
+                            impulse: impulse.into(),
+                        }
+                    }
+                }
+                impl Message for ContactBegin {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactBegin"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.entity0.serialize_message_part(&mut output)?;
+                        self.entity1.serialize_message_part(&mut output)?;
+                        self.points.serialize_message_part(&mut output)?;
+                        self.normals.serialize_message_part(&mut output)?;
+                        self.impulse.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            entity0: EntityId::deserialize_message_part(&mut input)?,
+                            entity1: EntityId::deserialize_message_part(&mut input)?,
+                            points: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            normals: Vec::<Vec3>::deserialize_message_part(&mut input)?,
+                            impulse: Vec3::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactBegin {}
+                #[derive(Clone, Debug)]
+                #[doc = "**ContactEnd**: Sent when two colliders stop touching, if either of them has `report_contacts`."]
+                pub struct ContactEnd {
+                    pub entity0: EntityId,
+                    pub entity1: EntityId,
+                }
+                impl ContactEnd {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(entity0: impl Into<EntityId>, entity1: impl Into<EntityId>) -> Self {
+                        Self {
+                            entity0: entity0.into(),
+                            entity1: entity1.into(),
+                        }
+                    }
+                }
+                impl Message for ContactEnd {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::ContactEnd"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.entity0.serialize_message_part(&mut output)?;
+                        self.entity1.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            entity0: EntityId::deserialize_message_part(&mut input)?,
+                            entity1: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for ContactEnd {}
+                #[derive(Clone, Debug)]
+                #[doc = "**TriggerEnter**: Sent when a collider enters a trigger volume (see `is_trigger`)."]
+                pub struct TriggerEnter {
+                    pub trigger: EntityId,
+                    pub other: EntityId,
+                }
+                impl TriggerEnter {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(trigger: impl Into<EntityId>, other: impl Into<EntityId>) -> Self {
+                        Self {
+                            trigger: trigger.into(),
+                            other: other.into(),
+                        }
+                    }
+                }
+                impl Message for TriggerEnter {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::TriggerEnter"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.trigger.serialize_message_part(&mut output)?;
+                        self.other.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            trigger: EntityId::deserialize_message_part(&mut input)?,
+                            other: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for TriggerEnter {}
+                #[derive(Clone, Debug)]
+                #[doc = "**TriggerExit**: Sent when a collider leaves a trigger volume (see `is_trigger`)."]
+                pub struct TriggerExit {
+                    pub trigger: EntityId,
+                    pub other: EntityId,
+                }
+                impl TriggerExit {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(trigger: impl Into<EntityId>, other: impl Into<EntityId>) -> Self {
+                        Self {
+                            trigger: trigger.into(),
+                            other: other.into(),
+                        }
+                    }
+                }
+                impl Message for TriggerExit {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::TriggerExit"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.trigger.serialize_message_part(&mut output)?;
+                        self.other.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            trigger: EntityId::deserialize_message_part(&mut input)?,
+                            other: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for TriggerExit {}
+                #[derive(Clone, Debug)]
+                #[doc = "**JointBreak**: Sent when a physics joint breaks."]
+                pub struct JointBreak {
+                    pub joint: EntityId,
+                    pub entity0: EntityId,
+                    pub entity1: EntityId,
+                }
+                impl JointBreak {
+                    #[allow(clippy::too_many_arguments)]
+                    pub fn new(
+                        joint: impl Into<EntityId>,
+                        entity0: impl Into<EntityId>,
+                        entity1: impl Into<EntityId>,
+                    ) -> Self {
+                        Self {
+                            joint: joint.into(),
+                            entity0: entity0.into(),
+                            entity1: entity1.into(),
+                        }
+                    }
+                }
+                impl Message for JointBreak {
+                    fn id() -> &'static str {
+                        "ambient_core::physics::JointBreak"
+                    }
+                    fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                        let mut output = vec![];
+                        self.joint.serialize_message_part(&mut output)?;
+                        self.entity0.serialize_message_part(&mut output)?;
+                        self.entity1.serialize_message_part(&mut output)?;
+                        Ok(output)
+                    }
+                    fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                        Ok(Self {
+                            joint: EntityId::deserialize_message_part(&mut input)?,
+                            entity0: EntityId::deserialize_message_part(&mut input)?,
+                            entity1: EntityId::deserialize_message_part(&mut input)?,
+                        })
+                    }
+                }
+                impl RuntimeMessage for JointBreak {}
+            }
+            #[doc = r" Auto-generated type definitions."]
+            pub mod types {
+                use crate::{global::serde, message::*};
+                #[derive(
+                    Copy,
+                    Clone,
+                    Debug,
+                    PartialEq,
+                    Eq,
+                    serde :: Serialize,
+                    serde :: Deserialize,
+                    Default,
+                )]
+                #[serde(crate = "self::serde")]
+                #[doc = "**JointType**: The type of a physics joint."]
+                pub enum JointType {
+                    #[default]
+                    #[doc = "Locks all relative motion between the two entities."]
+                    Fixed,
+                    #[doc = "Allows rotation around the joint axis, like a hinge."]
+                    Revolute,
+                    #[doc = "Allows rotation around all axes, like a ball-and-socket."]
+                    Spherical,
+                    #[doc = "Allows translation along the joint axis, like a slider."]
+                    Prismatic,
+                    #[doc = "Keeps the distance between the two entities within a range."]
+                    Distance,
+                    #[doc = "Configurable joint with per-axis motion, limits and drives."]
+                    D6,
+                }
+                impl crate::ecs::EnumComponent for JointType {
+                    fn to_u32(&self) -> u32 {
+                        match self {
+                            Self::Fixed => JointType::Fixed as u32,
+                            Self::Revolute => JointType::Revolute as u32,
+                            Self::Spherical => JointType::Spherical as u32,
+                            Self::Prismatic => JointType::Prismatic as u32,
+                            Self::Distance => JointType::Distance as u32,
+                            Self::D6 => JointType::D6 as u32,
+                        }
+                    }
+                    fn from_u32(value: u32) -> Option<Self> {
+                        if value == JointType::Fixed as u32 {
+                            return Some(Self::Fixed);
+                        }
+                        if value == JointType::Revolute as u32 {
+                            return Some(Self::Revolute);
+                        }
+                        if value == JointType::Spherical as u32 {
+                            return Some(Self::Spherical);
+                        }
+                        if value == JointType::Prismatic as u32 {
+                            return Some(Self::Prismatic);
+                        }
+                        if value == JointType::Distance as u32 {
+                            return Some(Self::Distance);
+                        }
+                        if value == JointType::D6 as u32 {
+                            return Some(Self::D6);
+                        }
+                        None
+                    }
+                }
+                impl crate::ecs::SupportedValue for JointType {
+                    fn from_result(result: crate::ecs::WitComponentValue) -> Option<Self> {
+                        use crate::ecs::EnumComponent;
+                        u32::from_result(result).and_then(Self::from_u32)
+                    }
+                    fn into_result(self) -> crate::ecs::WitComponentValue {
+                        use crate::ecs::EnumComponent;
+                        self.to_u32().into_result()
+                    }
+                    fn from_value(value: crate::ecs::ComponentValue) -> Option<Self> {
+                        use crate::ecs::EnumComponent;
+                        u32::from_value(value).and_then(Self::from_u32)
+                    }
+                    fn into_value(self) -> crate::ecs::ComponentValue {
+                        use crate::ecs::EnumComponent;
+                        self.to_u32().into_value()
+                    }
+                }
+                impl MessageSerde for JointType {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        crate::ecs::EnumComponent::to_u32(self).serialize_message_part(output)
+                    }
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        crate::ecs::EnumComponent::from_u32(u32::deserialize_message_part(input)?)
+                            .ok_or(MessageSerdeError::InvalidValue)
+                    }
+                }
+                #[derive(
+                    Copy,
+                    Clone,
+                    Debug,
+                    PartialEq,
+                    Eq,
+                    serde :: Serialize,
+                    serde :: Deserialize,
+                    Default,
+                )]
+                #[serde(crate = "self::serde")]
+                #[doc = "**JointMotion**: The motion allowed along or around an axis of a `D6` joint."]
+                pub enum JointMotion {
+                    #[default]
+                    #[doc = "The axis is locked."]
+                    Locked,
+                    #[doc = "The axis is limited by the joint's limits."]
+                    Limited,
+                    #[doc = "The axis is free."]
+                    Free,
+                }
+                impl crate::ecs::EnumComponent for JointMotion {
+                    fn to_u32(&self) -> u32 {
+                        match self {
+                            Self::Locked => JointMotion::Locked as u32,
+                            Self::Limited => JointMotion::Limited as u32,
+                            Self::Free => JointMotion::Free as u32,
+                        }
+                    }
+                    fn from_u32(value: u32) -> Option<Self> {
+                        if value == JointMotion::Locked as u32 {
+                            return Some(Self::Locked);
+                        }
+                        if value == JointMotion::Limited as u32 {
+                            return Some(Self::Limited);
+                        }
+                        if value == JointMotion::Free as u32 {
+                            return Some(Self::Free);
+                        }
+                        None
+                    }
+                }
+                impl crate::ecs::SupportedValue for JointMotion {
+                    fn from_result(result: crate::ecs::WitComponentValue) -> Option<Self> {
+                        use crate::ecs::EnumComponent;
+                        u32::from_result(result).and_then(Self::from_u32)
+                    }
+                    fn into_result(self) -> crate::ecs::WitComponentValue {
+                        use crate::ecs::EnumComponent;
+                        self.to_u32().into_result()
+                    }
+                    fn from_value(value: crate::ecs::ComponentValue) -> Option<Self> {
+                        use crate::ecs::EnumComponent;
+                        u32::from_value(value).and_then(Self::from_u32)
+                    }
+                    fn into_value(self) -> crate::ecs::ComponentValue {
+                        use crate::ecs::EnumComponent;
+                        self.to_u32().into_value()
+                    }
+                }
+                impl MessageSerde for JointMotion {
+                    fn serialize_message_part(
+                        &self,
+                        output: &mut Vec<u8>,
+                    ) -> Result<(), MessageSerdeError> {
+                        crate::ecs::EnumComponent::to_u32(self).serialize_message_part(output)
+                    }
+                    fn deserialize_message_part(
+                        input: &mut dyn std::io::Read,
+                    ) -> Result<Self, MessageSerdeError> {
+                        crate::ecs::EnumComponent::from_u32(u32::deserialize_message_part(input)?)
+                            .ok_or(MessageSerdeError::InvalidValue)
+                    }
+                }
+            }
+        }
+        pub mod player {
+            #[doc = r" Auto-generated component definitions."]
+            pub mod components {
+                use crate::{
+                    ecs::{Component, __internal_get_component},
+                    once_cell::sync::Lazy,
+                    prelude::*,
+                };
+                static LOCAL_USER_ID: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::player::local_user_id"));
+                #[doc = "**Local user ID**: The user ID of the local player.\n\n*Attributes*: Debuggable, Networked, Resource, Store"]
+                pub fn local_user_id() -> Component<String> {
+                    *LOCAL_USER_ID
+                }
+                static IS_PLAYER: Lazy<Component<()>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::player::is_player"));
+                #[doc = "**Is player**: This entity is a player.\n\nNote that this is a logical construct; a player's body may be separate from the player itself.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn is_player() -> Component<()> {
+                    *IS_PLAYER
+                }
+                static USER_ID: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::player::user_id"));
+                #[doc = "**User ID**: An identifier attached to all things owned by a user, and supplied by the user.\n\nThis can be attached to more than just the player; by convention, it is also attached to related entities, including their camera and body.\n\n*Attributes*: Debuggable, Networked, Store"]
+                pub fn user_id() -> Component<String> {
+                    *USER_ID
+                }
+            }
+        }
+        pub mod prefab {
+            #[doc = r" Auto-generated component definitions."]
+            pub mod components {
+                use crate::{
+                    ecs::{Component, __internal_get_component},
+                    once_cell::sync::Lazy,
+                    prelude::*,
+                };
+                static PREFAB_FROM_URL: Lazy<Component<String>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::prefab::prefab_from_url"));
+                #[doc = "**Prefab from URL**: Load and attach a prefab from a URL or relative path.\n\nWhen loaded, the components from this prefab will add to or replace the existing components for the entity.\n\n*Attributes*: Debuggable, Store"]
                 pub fn prefab_from_url() -> Component<String> {
                     *PREFAB_FROM_URL
                 }
@@ -4017,7 +5616,7 @@ mod raw {
                 };
                 static BACKGROUND_COLOR: Lazy<Component<Vec4>> =
                     Lazy::new(|| __internal_get_component("ambient_core::rect::background_color"));
-                #[doc = "**Background color**: Background color of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Background color**: Background color of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store, Color"]
                 pub fn background_color() -> Component<Vec4> {
                     *BACKGROUND_COLOR
                 }
@@ -4029,7 +5628,7 @@ mod raw {
                 }
                 static BORDER_COLOR: Lazy<Component<Vec4>> =
                     Lazy::new(|| __internal_get_component("ambient_core::rect::border_color"));
-                #[doc = "**Border color**: Border color of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Border color**: Border color of an entity with a `rect` component.\n\n*Attributes*: Debuggable, Networked, Store, Color"]
                 pub fn border_color() -> Component<Vec4> {
                     *BORDER_COLOR
                 }
@@ -4106,7 +5705,7 @@ mod raw {
                 }
                 static COLOR: Lazy<Component<Vec4>> =
                     Lazy::new(|| __internal_get_component("ambient_core::rendering::color"));
-                #[doc = "**Color**: This entity will be tinted with the specified color if the color is not black.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Color**: This entity will be tinted with the specified color if the color is not black.\n\n*Attributes*: Debuggable, Networked, Store, Color"]
                 pub fn color() -> Component<Vec4> {
                     *COLOR
                 }
@@ -4118,7 +5717,7 @@ mod raw {
                 }
                 static FOG_COLOR: Lazy<Component<Vec3>> =
                     Lazy::new(|| __internal_get_component("ambient_core::rendering::fog_color"));
-                #[doc = "**Fog color**: The color of the fog for this `sun`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Fog color**: The color of the fog for this `sun`.\n\n*Attributes*: Debuggable, Networked, Store, Color"]
                 pub fn fog_color() -> Component<Vec3> {
                     *FOG_COLOR
                 }
@@ -4151,27 +5750,27 @@ mod raw {
                 static LIGHT_AMBIENT: Lazy<Component<Vec3>> = Lazy::new(|| {
                     __internal_get_component("ambient_core::rendering::light_ambient")
                 });
-                #[doc = "**Light ambient**: The ambient light color of the `sun`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Light ambient**: The ambient light color of the `sun`.\n\n*Attributes*: Debuggable, Networked, Store, Color"]
                 pub fn light_ambient() -> Component<Vec3> {
                     *LIGHT_AMBIENT
                 }
                 static LIGHT_DIFFUSE: Lazy<Component<Vec3>> = Lazy::new(|| {
                     __internal_get_component("ambient_core::rendering::light_diffuse")
                 });
-                #[doc = "**Light diffuse**: The diffuse light color of the `sun`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Light diffuse**: The diffuse light color of the `sun`.\n\n*Attributes*: Debuggable, Networked, Store, Color"]
                 pub fn light_diffuse() -> Component<Vec3> {
                     *LIGHT_DIFFUSE
                 }
                 static OUTLINE: Lazy<Component<Vec4>> =
                     Lazy::new(|| __internal_get_component("ambient_core::rendering::outline"));
-                #[doc = "**Outline**: If attached, this entity will be rendered with an outline with the color specified.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Outline**: If attached, this entity will be rendered with an outline with the color specified.\n\n*Attributes*: Debuggable, Networked, Store, Color"]
                 pub fn outline() -> Component<Vec4> {
                     *OUTLINE
                 }
                 static OUTLINE_RECURSIVE: Lazy<Component<Vec4>> = Lazy::new(|| {
                     __internal_get_component("ambient_core::rendering::outline_recursive")
                 });
-                #[doc = "**Outline (recursive)**: If attached, this entity and all of its children will be rendered with an outline with the color specified.\n\nYou do not need to attach `outline` if you have attached `outline_recursive`.\n\n*Attributes*: Debuggable, Networked, Store"]
+                #[doc = "**Outline (recursive)**: If attached, this entity and all of its children will be rendered with an outline with the color specified.\n\nYou do not need to attach `outline` if you have attached `outline_recursive`.\n\n*Attributes*: Debuggable, Networked, Store, Color"]
                 pub fn outline_recursive() -> Component<Vec4> {
                     *OUTLINE_RECURSIVE
                 }
@@ -4770,6 +6369,39 @@ mod raw {
                 pub fn package_ref() -> Component<EntityId> {
                     *PACKAGE_REF
                 }
+                static MODULE_RUN_TIME: Lazy<Component<f32>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::wasm::module_run_time"));
+                #[doc = "**Module run time**: The time in milliseconds that this module spent handling messages over the last second. Updated every second.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_run_time() -> Component<f32> {
+                    *MODULE_RUN_TIME
+                }
+                static MODULE_FUEL_CONSUMED: Lazy<Component<u64>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::wasm::module_fuel_consumed")
+                });
+                #[doc = "**Module fuel consumed**: The fuel that this module consumed over the last second, which is roughly the number of WebAssembly instructions it executed. Updated every second. Fuel is only counted for server modules that have a fuel limit, and is 0 otherwise.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_fuel_consumed() -> Component<u64> {
+                    *MODULE_FUEL_CONSUMED
+                }
+                static MODULE_HOST_CALLS: Lazy<Component<u64>> =
+                    Lazy::new(|| __internal_get_component("ambient_core::wasm::module_host_calls"));
+                #[doc = "**Module host calls**: The number of calls that this module made to the runtime over the last second. Updated every second; always 0 on the web.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_host_calls() -> Component<u64> {
+                    *MODULE_HOST_CALLS
+                }
+                static MODULE_MESSAGE_NAMES: Lazy<Component<Vec<String>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::wasm::module_message_names")
+                });
+                #[doc = "**Module message names**: The messages that this module handled over the last second, from slowest to fastest. The time spent handling each message is in `module_message_run_times` at the same index.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_message_names() -> Component<Vec<String>> {
+                    *MODULE_MESSAGE_NAMES
+                }
+                static MODULE_MESSAGE_RUN_TIMES: Lazy<Component<Vec<f32>>> = Lazy::new(|| {
+                    __internal_get_component("ambient_core::wasm::module_message_run_times")
+                });
+                #[doc = "**Module message run times**: The time in milliseconds that this module spent handling each of the messages in `module_message_names` over the last second.\n\n*Attributes*: Networked, Debuggable"]
+                pub fn module_message_run_times() -> Component<Vec<f32>> {
+                    *MODULE_MESSAGE_RUN_TIMES
+                }
             }
             #[doc = r" Auto-generated concept definitions. Concepts are collections of components that describe some form of gameplay concept."]
             #[doc = r""]
@@ -5071,6 +6703,111 @@ mod raw {
                 }
             }
             #[derive(Clone, Debug)]
+            #[doc = "**WorldRestore**: Sent to a server module after its first `ModuleLoad` if the world was restored from a save when the server started. Contains the package version that made the save, and the components that could not be restored because they no longer exist or their type changed, so that the module can migrate the restored state."]
+            pub struct WorldRestore {
+                pub saved_version: String,
+                pub skipped_components: Vec<String>,
+            }
+            impl WorldRestore {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    saved_version: impl Into<String>,
+                    skipped_components: impl Into<Vec<String>>,
+                ) -> Self {
+                    Self {
+                        saved_version: saved_version.into(),
+                        skipped_components: skipped_components.into(),
+                    }
+                }
+            }
+            impl Message for WorldRestore {
+                fn id() -> &'static str {
+                    "ambient_core::WorldRestore"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.saved_version.serialize_message_part(&mut output)?;
+                    self.skipped_components
+                        .serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        saved_version: String::deserialize_message_part(&mut input)?,
+                        skipped_components: Vec::<String>::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WorldRestore {}
+            #[derive(Clone, Debug)]
+            #[doc = "**TimerFire**: Sent to a module when one of its timers fires. Contains the name the timer was started with."]
+            pub struct TimerFire {
+                pub name: String,
+            }
+            impl TimerFire {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(name: impl Into<String>) -> Self {
+                    Self { name: name.into() }
+                }
+            }
+            impl Message for TimerFire {
+                fn id() -> &'static str {
+                    "ambient_core::TimerFire"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.name.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        name: String::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for TimerFire {}
+            #[derive(Clone, Debug)]
+            #[doc = "**PredictInput**: Sent to client modules after every update from the server, once for each message the client has sent to the server that the server has not acknowledged yet, oldest first. The `Predicted` components of the local player's entities have been reset to the server's values, so the module should apply the effect of the message to them again, as it did when it sent it. Contains the sequence number, name and data of the message."]
+            pub struct PredictInput {
+                pub sequence: u64,
+                pub name: String,
+                pub data: Vec<u8>,
+            }
+            impl PredictInput {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    sequence: impl Into<u64>,
+                    name: impl Into<String>,
+                    data: impl Into<Vec<u8>>,
+                ) -> Self {
+                    Self {
+                        sequence: sequence.into(),
+                        name: name.into(),
+                        data: data.into(),
+                    }
+                }
+            }
+            impl Message for PredictInput {
+                fn id() -> &'static str {
+                    "ambient_core::PredictInput"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.sequence.serialize_message_part(&mut output)?;
+                    self.name.serialize_message_part(&mut output)?;
+                    self.data.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        sequence: u64::deserialize_message_part(&mut input)?,
+                        name: String::deserialize_message_part(&mut input)?,
+                        data: Vec::<u8>::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for PredictInput {}
+            #[derive(Clone, Debug)]
             #[doc = "**ModuleUnload**: Sent to a module when it unloads."]
             pub struct ModuleUnload;
             impl ModuleUnload {
@@ -5375,6 +7112,170 @@ mod raw {
             }
             impl RuntimeMessage for WindowCursorLockChange {}
             #[derive(Clone, Debug)]
+            #[doc = "**WindowGamepadConnection**: Sent when a gamepad is connected or disconnected."]
+            pub struct WindowGamepadConnection {
+                pub gamepad: u32,
+                pub name: String,
+                pub connected: bool,
+            }
+            impl WindowGamepadConnection {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    gamepad: impl Into<u32>,
+                    name: impl Into<String>,
+                    connected: impl Into<bool>,
+                ) -> Self {
+                    Self {
+                        gamepad: gamepad.into(),
+                        name: name.into(),
+                        connected: connected.into(),
+                    }
+                }
+            }
+            impl Message for WindowGamepadConnection {
+                fn id() -> &'static str {
+                    "ambient_core::WindowGamepadConnection"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.gamepad.serialize_message_part(&mut output)?;
+                    self.name.serialize_message_part(&mut output)?;
+                    self.connected.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        gamepad: u32::deserialize_message_part(&mut input)?,
+                        name: String::deserialize_message_part(&mut input)?,
+                        connected: bool::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WindowGamepadConnection {}
+            #[derive(Clone, Debug)]
+            #[doc = "**WindowGamepadButton**: Sent when a gamepad button is pressed or released."]
+            pub struct WindowGamepadButton {
+                pub gamepad: u32,
+                pub button: String,
+                pub pressed: bool,
+            }
+            impl WindowGamepadButton {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    gamepad: impl Into<u32>,
+                    button: impl Into<String>,
+                    pressed: impl Into<bool>,
+                ) -> Self {
+                    Self {
+                        gamepad: gamepad.into(),
+                        button: button.into(),
+                        pressed: pressed.into(),
+                    }
+                }
+            }
+            impl Message for WindowGamepadButton {
+                fn id() -> &'static str {
+                    "ambient_core::WindowGamepadButton"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.gamepad.serialize_message_part(&mut output)?;
+                    self.button.serialize_message_part(&mut output)?;
+                    self.pressed.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        gamepad: u32::deserialize_message_part(&mut input)?,
+                        button: String::deserialize_message_part(&mut input)?,
+                        pressed: bool::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WindowGamepadButton {}
+            #[derive(Clone, Debug)]
+            #[doc = "**WindowGamepadAxis**: Sent when a gamepad axis changes, after the deadzone has been applied."]
+            pub struct WindowGamepadAxis {
+                pub gamepad: u32,
+                pub axis: String,
+                pub value: f32,
+            }
+            impl WindowGamepadAxis {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    gamepad: impl Into<u32>,
+                    axis: impl Into<String>,
+                    value: impl Into<f32>,
+                ) -> Self {
+                    Self {
+                        gamepad: gamepad.into(),
+                        axis: axis.into(),
+                        value: value.into(),
+                    }
+                }
+            }
+            impl Message for WindowGamepadAxis {
+                fn id() -> &'static str {
+                    "ambient_core::WindowGamepadAxis"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.gamepad.serialize_message_part(&mut output)?;
+                    self.axis.serialize_message_part(&mut output)?;
+                    self.value.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        gamepad: u32::deserialize_message_part(&mut input)?,
+                        axis: String::deserialize_message_part(&mut input)?,
+                        value: f32::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WindowGamepadAxis {}
+            #[derive(Clone, Debug)]
+            #[doc = "**WindowTouch**: Sent when a finger touches, moves on, or is lifted from the window. The phase is one of `Started`, `Moved`, `Ended` or `Cancelled`, and the position is in pixels from the top left of the window."]
+            pub struct WindowTouch {
+                pub id: u64,
+                pub phase: String,
+                pub position: Vec2,
+            }
+            impl WindowTouch {
+                #[allow(clippy::too_many_arguments)]
+                pub fn new(
+                    id: impl Into<u64>,
+                    phase: impl Into<String>,
+                    position: impl Into<Vec2>,
+                ) -> Self {
+                    Self {
+                        id: id.into(),
+                        phase: phase.into(),
+                        position: position.into(),
+                    }
+                }
+            }
+            impl Message for WindowTouch {
+                fn id() -> &'static str {
+                    "ambient_core::WindowTouch"
+                }
+                fn serialize_message(&self) -> Result<Vec<u8>, MessageSerdeError> {
+                    let mut output = vec![];
+                    self.id.serialize_message_part(&mut output)?;
+                    self.phase.serialize_message_part(&mut output)?;
+                    self.position.serialize_message_part(&mut output)?;
+                    Ok(output)
+                }
+                fn deserialize_message(mut input: &[u8]) -> Result<Self, MessageSerdeError> {
+                    Ok(Self {
+                        id: u64::deserialize_message_part(&mut input)?,
+                        phase: String::deserialize_message_part(&mut input)?,
+                        position: Vec2::deserialize_message_part(&mut input)?,
+                    })
+                }
+            }
+            impl RuntimeMessage for WindowTouch {}
+            #[derive(Clone, Debug)]
             #[doc = "**HttpResponse**: Sent when an HTTP response is received."]
             pub struct HttpResponse {
                 pub response_id: u64,
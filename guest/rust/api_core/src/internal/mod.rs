@@ -20,6 +20,7 @@ impl guest::Guest for Guest {
     fn init() {
         once_cell::sync::Lazy::force(&EXECUTOR);
         unsafe { main() };
+        EXECUTOR.update_frame_wakeups();
     }
 
     fn exec(source: guest::Source, message_name: String, message_data: Vec<u8>) {
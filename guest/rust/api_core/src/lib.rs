@@ -39,6 +39,12 @@ pub mod animation;
 /// Package-related functionality.
 pub mod package;
 
+/// Preserving the state of this module when it is rebuilt.
+pub mod hot_reload;
+
+/// Timers that are run by the host, in game time or wall time.
+pub mod timer;
+
 /// Internal implementation details.
 mod internal;
 
@@ -3,3 +3,9 @@ pub mod physics;
 
 /// **\[Server-only\]** HTTP-related functionality, including sending requests and receiving responses.
 pub mod http;
+
+/// **\[Server-only\]** Key-value storage that is kept on disk for each package, for data like inventories and leaderboards.
+pub mod storage;
+
+/// **\[Server-only\]** Persistence of the world, including saving it to disk on demand.
+pub mod world;
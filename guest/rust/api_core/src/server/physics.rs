@@ -5,7 +5,7 @@ use crate::{
         wit,
     },
 };
-use glam::Mat4;
+use glam::{Mat4, Quat};
 
 /// Applies a `force` (a [Vec3]) to the `entity` (an [EntityId]) specified.
 ///
@@ -153,21 +153,50 @@ pub struct RaycastHit {
     /// The entity that was hit.
     pub entity: EntityId,
 }
+/// A collision mask that matches every collision group. See the `collision_group` and `collision_mask` components.
+pub const ALL_COLLISION_GROUPS: u32 = u32::MAX;
+
 /// Casts a ray from `origin` in `direction`, and returns the [RaycastHit]s along the way.
 ///
 /// `direction` must be normalized.
 pub fn raycast(origin: Vec3, direction: Vec3) -> Vec<RaycastHit> {
-    wit::server_physics::raycast(origin.into_bindgen(), direction.into_bindgen())
-        .into_iter()
-        .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
-        .collect()
+    raycast_with_mask(origin, direction, ALL_COLLISION_GROUPS)
+}
+/// Casts a ray from `origin` in `direction`, and returns the [RaycastHit]s along the way
+/// for colliders in at least one of the groups in `collision_mask`.
+///
+/// `direction` must be normalized.
+pub fn raycast_with_mask(origin: Vec3, direction: Vec3, collision_mask: u32) -> Vec<RaycastHit> {
+    wit::server_physics::raycast_filtered(
+        origin.into_bindgen(),
+        direction.into_bindgen(),
+        collision_mask,
+    )
+    .into_iter()
+    .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
+    .collect()
 }
 /// Casts a ray from `origin` in `direction`, and returns the first [RaycastHit] if it hits.
 ///
 /// `direction` must be normalized.
 pub fn raycast_first(origin: Vec3, direction: Vec3) -> Option<RaycastHit> {
-    wit::server_physics::raycast_first(origin.into_bindgen(), direction.into_bindgen())
-        .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
+    raycast_first_with_mask(origin, direction, ALL_COLLISION_GROUPS)
+}
+/// Casts a ray from `origin` in `direction`, and returns the first [RaycastHit] if it hits
+/// a collider in at least one of the groups in `collision_mask`.
+///
+/// `direction` must be normalized.
+pub fn raycast_first_with_mask(
+    origin: Vec3,
+    direction: Vec3,
+    collision_mask: u32,
+) -> Option<RaycastHit> {
+    wit::server_physics::raycast_first_filtered(
+        origin.into_bindgen(),
+        direction.into_bindgen(),
+        collision_mask,
+    )
+    .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
 }
 fn raycast_result_to_hit(
     origin: Vec3,
@@ -182,6 +211,163 @@ fn raycast_result_to_hit(
     }
 }
 
+/// The volume to use for [sweep], [sweep_first] and [overlap].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QueryShape {
+    /// A sphere with the given `radius`.
+    Sphere {
+        /// The radius of the sphere.
+        radius: f32,
+    },
+    /// A cuboid with the given `half_extents` (i.e. half of its size on each axis).
+    Cuboid {
+        /// Half of the size of the cuboid on each axis.
+        half_extents: Vec3,
+    },
+    /// A capsule extending along the Z axis.
+    Capsule {
+        /// The radius of the capsule.
+        radius: f32,
+        /// The distance from the center of the capsule to the center of each of its hemispheres.
+        half_height: f32,
+    },
+}
+impl IntoBindgen for QueryShape {
+    type Item = wit::server_physics::QueryShape;
+
+    fn into_bindgen(self) -> Self::Item {
+        match self {
+            QueryShape::Sphere { radius } => Self::Item::Sphere(radius),
+            QueryShape::Cuboid { half_extents } => Self::Item::Cuboid(half_extents.into_bindgen()),
+            QueryShape::Capsule {
+                radius,
+                half_height,
+            } => Self::Item::Capsule((radius, half_height)),
+        }
+    }
+}
+
+/// Where a [sweep] hit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShapeCastHit {
+    /// The position of the hit.
+    pub position: Vec3,
+    /// The surface normal at the hit.
+    pub normal: Vec3,
+    /// The distance the shape travelled before the hit.
+    pub distance: f32,
+    /// The entity that was hit.
+    pub entity: EntityId,
+}
+impl FromBindgen for wit::server_physics::ShapeCastHit {
+    type Item = ShapeCastHit;
+
+    fn from_bindgen(self) -> Self::Item {
+        ShapeCastHit {
+            position: self.position.from_bindgen(),
+            normal: self.normal.from_bindgen(),
+            distance: self.distance,
+            entity: self.entity.from_bindgen(),
+        }
+    }
+}
+
+/// Sweeps `shape` with `rotation` from `origin` in `direction` for up to `max_distance`, and returns the [ShapeCastHit]s along the way.
+///
+/// `direction` must be normalized.
+pub fn sweep(
+    shape: QueryShape,
+    origin: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+) -> Vec<ShapeCastHit> {
+    sweep_with_mask(
+        shape,
+        origin,
+        rotation,
+        direction,
+        max_distance,
+        ALL_COLLISION_GROUPS,
+    )
+}
+/// Like [sweep], but only hits colliders in at least one of the groups in `collision_mask`.
+pub fn sweep_with_mask(
+    shape: QueryShape,
+    origin: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    collision_mask: u32,
+) -> Vec<ShapeCastHit> {
+    wit::server_physics::sweep(
+        shape.into_bindgen(),
+        origin.into_bindgen(),
+        rotation.into_bindgen(),
+        direction.into_bindgen(),
+        max_distance,
+        collision_mask,
+    )
+    .from_bindgen()
+}
+/// Sweeps `shape` with `rotation` from `origin` in `direction` for up to `max_distance`, and returns the first [ShapeCastHit] if it hits.
+///
+/// `direction` must be normalized.
+pub fn sweep_first(
+    shape: QueryShape,
+    origin: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+) -> Option<ShapeCastHit> {
+    sweep_first_with_mask(
+        shape,
+        origin,
+        rotation,
+        direction,
+        max_distance,
+        ALL_COLLISION_GROUPS,
+    )
+}
+/// Like [sweep_first], but only hits colliders in at least one of the groups in `collision_mask`.
+pub fn sweep_first_with_mask(
+    shape: QueryShape,
+    origin: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    collision_mask: u32,
+) -> Option<ShapeCastHit> {
+    wit::server_physics::sweep_first(
+        shape.into_bindgen(),
+        origin.into_bindgen(),
+        rotation.into_bindgen(),
+        direction.into_bindgen(),
+        max_distance,
+        collision_mask,
+    )
+    .from_bindgen()
+}
+/// Returns all entities with a collider that overlaps `shape` placed at `position` with `rotation`.
+pub fn overlap(shape: QueryShape, position: Vec3, rotation: Quat) -> Vec<EntityId> {
+    overlap_with_mask(shape, position, rotation, ALL_COLLISION_GROUPS)
+}
+/// Like [overlap], but only returns entities with a collider in at least one of the groups in `collision_mask`.
+pub fn overlap_with_mask(
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    collision_mask: u32,
+) -> Vec<EntityId> {
+    wit::server_physics::overlap(
+        shape.into_bindgen(),
+        position.into_bindgen(),
+        rotation.into_bindgen(),
+        collision_mask,
+    )
+    .from_bindgen()
+}
+
 /// Collision results when using [move_character].
 pub struct CharacterCollision {
     /// Side
@@ -0,0 +1,72 @@
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::internal::wit;
+
+#[derive(Error, Debug, Clone)]
+/// Errors that can occur when accessing storage.
+pub struct StorageError(pub String);
+impl fmt::Display for StorageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "storage error: {}", self.0)
+    }
+}
+
+/// An operation to apply to storage as part of a [transaction].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    /// Sets `key` to `value`.
+    Set {
+        /// The key to set.
+        key: String,
+        /// The value to set the key to.
+        value: Vec<u8>,
+    },
+    /// Deletes `key`, if it exists.
+    Delete {
+        /// The key to delete.
+        key: String,
+    },
+}
+
+/// Gets the value of `key`, or `None` if it has not been set.
+///
+/// Storage is kept on disk for each package, and survives server restarts. Keys must be between 1
+/// and 128 bytes long. Storage is not available on hosted servers.
+pub fn get(key: impl AsRef<str>) -> Result<Option<Vec<u8>>, StorageError> {
+    wit::server_storage::get(key.as_ref()).map_err(StorageError)
+}
+
+/// Sets the value of `key` to `value`, replacing any existing value.
+pub fn set(key: impl AsRef<str>, value: impl AsRef<[u8]>) -> Result<(), StorageError> {
+    wit::server_storage::set(key.as_ref(), value.as_ref()).map_err(StorageError)
+}
+
+/// Deletes `key`. Deleting a key that has not been set is not an error.
+pub fn delete(key: impl AsRef<str>) -> Result<(), StorageError> {
+    wit::server_storage::delete(key.as_ref()).map_err(StorageError)
+}
+
+/// Lists all keys that start with `prefix`, in sorted order.
+///
+/// Using prefixes like `inventory/<user id>` makes it possible to fetch related values together.
+pub fn list(prefix: impl AsRef<str>) -> Result<Vec<String>, StorageError> {
+    wit::server_storage::list_keys(prefix.as_ref()).map_err(StorageError)
+}
+
+/// Applies all of the `operations` atomically: either all of them are applied, or none of them
+/// are, even if the server stops while applying them.
+pub fn transaction(operations: &[Operation]) -> Result<(), StorageError> {
+    let operations = operations
+        .iter()
+        .map(|operation| match operation {
+            Operation::Set { key, value } => wit::server_storage::Operation {
+                key,
+                value: Some(value.as_slice()),
+            },
+            Operation::Delete { key } => wit::server_storage::Operation { key, value: None },
+        })
+        .collect::<Vec<_>>();
+    wit::server_storage::transaction(&operations).map_err(StorageError)
+}
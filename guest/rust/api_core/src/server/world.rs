@@ -0,0 +1,33 @@
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::internal::wit;
+
+#[derive(Error, Debug, Clone)]
+/// Errors that can occur when saving the world.
+pub struct SaveError(pub String);
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "world save error: {}", self.0)
+    }
+}
+
+/// Saves the `Store` components of the world to disk immediately, instead of waiting for the
+/// next periodic save.
+///
+/// This will fail if the server was not started with `--world-save <file>`. When the server
+/// restarts with the same save, the [WorldRestore](crate::core::messages::WorldRestore) message
+/// is sent to each server module after it loads, so that it can migrate the restored state.
+pub fn save() -> Result<(), SaveError> {
+    wit::server_world::save().map_err(SaveError)
+}
+
+/// Returns true if the world was restored from a save when the server started.
+///
+/// The `main` of each module runs after the world has been restored, so modules that spawn
+/// entities with `Store` components should only do so if this returns false; otherwise, they
+/// would be spawned again next to the restored ones on every restart.
+pub fn was_restored() -> bool {
+    wit::server_world::was_restored()
+}
@@ -0,0 +1,182 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    task::{Poll, Waker},
+    time::Duration,
+};
+
+use crate::{
+    core::messages::TimerFire,
+    internal::wit,
+    message::{Listener, RuntimeMessage},
+};
+
+/// Timers used by [wait] are named with this prefix, which should not be used by other timers.
+const WAIT_PREFIX: &str = "ambient/wait/";
+
+type TimerCallback = Rc<RefCell<dyn FnMut()>>;
+
+thread_local! {
+    static CALLBACKS: RefCell<HashMap<String, Vec<(u64, TimerCallback)>>> = RefCell::new(HashMap::new());
+    static NEXT_ID: Cell<u64> = Cell::new(0);
+    static FIRE_LISTENER: RefCell<Option<Listener>> = RefCell::new(None);
+}
+
+/// The clock that a timer counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Clock {
+    /// Advances with [game_time](crate::prelude::game_time), once per frame.
+    Game,
+    /// Advances in real time, even if frames are slow.
+    Wall,
+}
+impl From<Clock> for wit::timer::Clock {
+    fn from(clock: Clock) -> Self {
+        match clock {
+            Clock::Game => Self::Game,
+            Clock::Wall => Self::Wall,
+        }
+    }
+}
+
+/// Starts the timer `name`, which fires once after `delay` on `clock`.
+///
+/// Starting a timer with the name of a running timer replaces it. Timers are run by the host, so
+/// they keep running while this module is hot reloaded; the new version of the module only has to
+/// register its callbacks again with [on].
+pub fn start(name: &str, clock: Clock, delay: Duration) {
+    wit::timer::start(name, clock.into(), delay.as_secs_f32(), None);
+}
+
+/// Starts the timer `name`, which fires every `interval` on `clock` until it is [cancel]led.
+///
+/// If this module falls behind, for example because a frame took longer than `interval`, the
+/// timer fires once and then continues from the current time, rather than firing for every
+/// interval it missed.
+pub fn start_repeating(name: &str, clock: Clock, interval: Duration) {
+    let interval = interval.as_secs_f32();
+    wit::timer::start(name, clock.into(), interval, Some(interval));
+}
+
+/// Stops the timer `name`. Does nothing if it is not running.
+pub fn cancel(name: &str) {
+    wit::timer::cancel(name);
+}
+
+/// Returns the time until the timer `name` next fires, or `None` if it is not running.
+pub fn remaining(name: &str) -> Option<Duration> {
+    wit::timer::remaining(name).map(Duration::from_secs_f32)
+}
+
+/// Calls `callback` whenever the timer `name` fires.
+///
+/// To stop calling `callback`, call [TimerListener::stop] on the returned [TimerListener].
+pub fn on(name: &str, callback: impl FnMut() + 'static) -> TimerListener {
+    FIRE_LISTENER.with(|listener| {
+        listener
+            .borrow_mut()
+            .get_or_insert_with(|| TimerFire::subscribe(|fire| dispatch(&fire.name)));
+    });
+
+    let id = next_id();
+    CALLBACKS.with(|callbacks| {
+        callbacks
+            .borrow_mut()
+            .entry(name.to_string())
+            .or_default()
+            .push((id, Rc::new(RefCell::new(callback))));
+    });
+    TimerListener {
+        name: name.to_string(),
+        id,
+    }
+}
+
+/// Stops execution of this function until `delay` has passed on `clock`.
+///
+/// Unlike polling the time every frame, this does not run the module until the timer fires.
+///
+/// This must be used with `.await` in either an `async fn` or an `async` block.
+pub async fn wait(clock: Clock, delay: Duration) {
+    let name = format!("{WAIT_PREFIX}{}", next_id());
+    let state = Rc::new(RefCell::new((false, None::<Waker>)));
+    let _guard = WaitGuard {
+        listener: Some(on(&name, {
+            let state = state.clone();
+            move || {
+                let mut state = state.borrow_mut();
+                state.0 = true;
+                if let Some(waker) = state.1.take() {
+                    waker.wake();
+                }
+            }
+        })),
+        name: name.clone(),
+    };
+    start(&name, clock, delay);
+
+    std::future::poll_fn(|cx| {
+        let mut state = state.borrow_mut();
+        if state.0 {
+            Poll::Ready(())
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// A callback registered with [on].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimerListener {
+    name: String,
+    id: u64,
+}
+impl TimerListener {
+    /// Stops calling the callback. The timer itself keeps running; use [cancel] to stop it.
+    pub fn stop(self) {
+        CALLBACKS.with(|callbacks| {
+            let mut callbacks = callbacks.borrow_mut();
+            if let Some(named) = callbacks.get_mut(&self.name) {
+                named.retain(|(id, _)| *id != self.id);
+                if named.is_empty() {
+                    callbacks.remove(&self.name);
+                }
+            }
+        });
+    }
+}
+
+/// Stops the timer of a [wait] that finished or was dropped.
+struct WaitGuard {
+    name: String,
+    listener: Option<TimerListener>,
+}
+impl Drop for WaitGuard {
+    fn drop(&mut self) {
+        if let Some(listener) = self.listener.take() {
+            listener.stop();
+        }
+        cancel(&self.name);
+    }
+}
+
+fn next_id() -> u64 {
+    NEXT_ID.with(|next_id| next_id.replace(next_id.get() + 1))
+}
+
+fn dispatch(name: &str) {
+    // The callbacks are cloned out so that they can register or stop callbacks themselves
+    let callbacks = CALLBACKS.with(|callbacks| {
+        callbacks
+            .borrow()
+            .get(name)
+            .map(|named| named.iter().map(|(_, callback)| callback.clone()).collect())
+            .unwrap_or_else(Vec::new)
+    });
+    for callback in callbacks {
+        (callback.borrow_mut())();
+    }
+}
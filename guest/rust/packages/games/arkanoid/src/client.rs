@@ -9,6 +9,7 @@ use ambient_api::{
         },
         messages::Frame,
     },
+    input::{get_axis, is_action_pressed},
     prelude::*,
 };
 
@@ -47,19 +48,11 @@ async fn main() {
     .spawn();
 
     Frame::subscribe(move |_| {
-        let input = input::get();
-        let mut direction = 0.0;
-
-        if input.keys.contains(&KeyCode::Right) {
-            direction += 1.0;
-        }
-        if input.keys.contains(&KeyCode::Left) {
-            direction -= 1.0;
-        }
+        let package = packages::this::entity();
 
         Input {
-            direction,
-            start: input.keys.contains(&KeyCode::Space),
+            direction: get_axis(package, "paddle"),
+            start: is_action_pressed(package, "start"),
         }
         .send_server_reliable();
     });
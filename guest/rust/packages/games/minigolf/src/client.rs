@@ -1,6 +1,6 @@
 use ambient_api::{
     core::{messages::Frame, physics::components::linear_velocity},
-    input::is_game_focused,
+    input::{is_action_pressed, is_game_focused},
     prelude::*,
 };
 use packages::this::{
@@ -12,18 +12,22 @@ pub mod packages;
 
 #[main]
 fn main() {
+    let mut was_shoot_pressed = false;
     Frame::subscribe(move |_| {
         if !is_game_focused() {
             return;
         }
         let (delta, _input) = input::get_delta();
+        let shoot_pressed = is_action_pressed(packages::this::entity(), "shoot");
 
         Input {
             camera_rotation: delta.mouse_position,
             camera_zoom: delta.mouse_wheel,
-            shoot: delta.mouse_buttons.contains(&MouseButton::Left),
+            shoot: shoot_pressed && !was_shoot_pressed,
         }
         .send_server_unreliable();
+
+        was_shoot_pressed = shoot_pressed;
     });
 
     let ball_hit_player = audio::AudioPlayer::new();
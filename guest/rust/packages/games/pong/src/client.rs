@@ -9,6 +9,7 @@ use ambient_api::{
         },
         messages::Frame,
     },
+    input::get_axis,
     prelude::*,
 };
 
@@ -42,15 +43,7 @@ async fn main() {
     .spawn();
 
     Frame::subscribe(move |_| {
-        let input = input::get();
-        let mut direction = 0.0;
-
-        if input.keys.contains(&KeyCode::Up) {
-            direction += 1.0;
-        }
-        if input.keys.contains(&KeyCode::Down) {
-            direction -= 1.0;
-        }
+        let direction = get_axis(packages::this::entity(), "paddle");
         Input::new(direction).send_server_unreliable();
     });
 
@@ -7,6 +7,7 @@ use ambient_api::{
         transform::components::{local_to_world, translation},
     },
     element::{use_frame, use_state},
+    input::{get_axis, is_action_pressed, is_game_focused},
     prelude::*,
     ui::use_window_logical_resolution,
 };
@@ -43,7 +44,7 @@ pub fn main() {
 }
 
 fn handle_input() {
-    let mut last_input = input::get();
+    let mut was_respawn_pressed = false;
     // The most correct thing to do would be to store this in the ECS.
     let aim_direction = Arc::new(Mutex::new(Vec2::ZERO));
 
@@ -64,7 +65,7 @@ fn handle_input() {
     });
 
     fixed_rate_tick(Duration::from_millis(20), move |_| {
-        if !input::is_game_focused() {
+        if !is_game_focused() {
             return;
         }
 
@@ -74,39 +75,26 @@ fn handle_input() {
 
         let camera_ray = camera::clip_position_to_world_ray(camera_id, Vec2::ZERO);
 
-        let input = input::get();
-        let delta = input.delta(&last_input);
-        let direction = {
-            let mut direction = Vec2::ZERO;
-            if input.keys.contains(&KeyCode::W) {
-                direction.y += 1.;
-            }
-            if input.keys.contains(&KeyCode::S) {
-                direction.y -= 1.;
-            }
-            if input.keys.contains(&KeyCode::A) {
-                direction.x -= 1.;
-            }
-            if input.keys.contains(&KeyCode::D) {
-                direction.x += 1.;
-            }
-            direction
-        };
+        let package = packages::this::entity();
+        let respawn_pressed = is_action_pressed(package, "respawn");
 
         Input {
-            direction,
-            jump: input.keys.contains(&KeyCode::Space),
-            sprint: input.keys.contains(&KeyCode::LShift),
-            use_button: input.keys.contains(&KeyCode::E),
-            fire: input.mouse_buttons.contains(&MouseButton::Left),
+            direction: vec2(
+                get_axis(package, "move_right"),
+                get_axis(package, "move_forward"),
+            ),
+            jump: is_action_pressed(package, "jump"),
+            sprint: is_action_pressed(package, "sprint"),
+            use_button: is_action_pressed(package, "interact"),
+            fire: is_action_pressed(package, "fire"),
             aim_direction: *aim_direction.lock().unwrap(),
-            respawn: delta.keys.contains(&KeyCode::K),
+            respawn: respawn_pressed && !was_respawn_pressed,
             aim_ray_origin: camera_ray.origin,
             aim_ray_direction: camera_ray.dir,
         }
         .send_server_unreliable();
 
-        last_input = input;
+        was_respawn_pressed = respawn_pressed;
     });
 }
 
@@ -116,7 +104,7 @@ fn CoreUI(hooks: &mut Hooks) -> Element {
     let (visible, set_visible) = use_state(hooks, false);
 
     use_frame(hooks, move |_| {
-        set_visible(input::is_game_focused());
+        set_visible(is_game_focused());
     });
 
     if visible {
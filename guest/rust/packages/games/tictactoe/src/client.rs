@@ -6,6 +6,7 @@ use ambient_api::{
         player::components::is_player,
         rendering::components::{color, outline},
     },
+    input::is_action_pressed,
     prelude::*,
 };
 use packages::this::{
@@ -24,21 +25,27 @@ async fn main() {
         .await
         .unwrap();
 
+    let mut was_pressed = [false; 5];
     Frame::subscribe(move |_| {
-        process_input();
+        process_input(&mut was_pressed);
         process_colors(&cells);
     });
 }
 
-fn process_input() {
-    let (delta, _) = input::get_delta();
-    let keys = &delta.keys;
+fn process_input(was_pressed: &mut [bool; 5]) {
+    let package = packages::this::entity();
+    let pressed =
+        ["left", "right", "up", "down", "capture"].map(|action| is_action_pressed(package, action));
+    // Only send the actions that were pressed this frame
+    let [left, right, up, down, capture] = std::array::from_fn(|i| pressed[i] && !was_pressed[i]);
+    *was_pressed = pressed;
+
     let msg = Input {
-        left: keys.contains(&KeyCode::Left) || keys.contains(&KeyCode::A),
-        right: keys.contains(&KeyCode::Right) || keys.contains(&KeyCode::D),
-        up: keys.contains(&KeyCode::Up) || keys.contains(&KeyCode::W),
-        down: keys.contains(&KeyCode::Down) || keys.contains(&KeyCode::S),
-        capture: keys.contains(&KeyCode::Space),
+        left,
+        right,
+        up,
+        down,
+        capture,
     };
 
     if [msg.left, msg.right, msg.up, msg.down, msg.capture]
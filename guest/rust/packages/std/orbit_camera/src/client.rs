@@ -6,7 +6,7 @@ use ambient_api::{
         transform::components::{lookat_target, rotation, translation},
         ui::components::focusable,
     },
-    input::{is_game_focused, GAME_FOCUS_ID},
+    input::{is_action_pressed, is_game_focused, GAME_FOCUS_ID},
     prelude::*,
 };
 use packages::this::components::{camera_angle, camera_distance, is_orbit_camera};
@@ -57,19 +57,19 @@ pub fn main() {
     });
 
     query(is_orbit_camera()).each_frame(|cameras| {
-        let (delta, input) = input::get_delta();
+        let (delta, _) = input::get_delta();
 
         let distance_delta = if is_game_focused() {
             delta.mouse_wheel * -0.1
         } else {
             0.
         };
-        let angle_delta = if is_game_focused() && input.mouse_buttons.contains(&MouseButton::Right)
-        {
-            delta.mouse_position * 0.01
-        } else {
-            Vec2::ZERO
-        };
+        let angle_delta =
+            if is_game_focused() && is_action_pressed(packages::this::entity(), "orbit") {
+                delta.mouse_position * 0.01
+            } else {
+                Vec2::ZERO
+            };
 
         for (camera_id, _) in cameras {
             let distance =
@@ -9,7 +9,7 @@ use ambient_api::{
         transform::components::{local_to_parent, translation},
     },
     entity::{add_child, get_component, mutate_component_with_default, set_component},
-    input::is_game_focused,
+    input::{get_axis, is_action_pressed, is_game_focused},
     prelude::*,
 };
 use packages::{
@@ -28,21 +28,13 @@ pub fn main() {
         if !is_game_focused() {
             return;
         }
-        let (delta, input) = input::get_delta();
+        let (delta, _) = input::get_delta();
+        let package = packages::this::entity();
 
-        let mut displace = Vec2::ZERO;
-        if input.keys.contains(&KeyCode::W) {
-            displace.x += 1.0;
-        }
-        if input.keys.contains(&KeyCode::S) {
-            displace.x -= 1.0;
-        }
-        if input.keys.contains(&KeyCode::A) {
-            displace.y -= 1.0;
-        }
-        if input.keys.contains(&KeyCode::D) {
-            displace.y += 1.0;
-        }
+        let displace = vec2(
+            get_axis(package, "move_forward"),
+            get_axis(package, "move_right"),
+        );
         let rot = mutate_component_with_default(
             player::get_local(),
             player_intermediate_rotation(),
@@ -53,7 +45,7 @@ pub fn main() {
             },
         );
 
-        if input.keys.contains(&KeyCode::Space) {
+        if is_action_pressed(package, "jump") {
             Jump {}.send_server_reliable();
         }
 
@@ -61,9 +53,9 @@ pub fn main() {
             run_direction: displace,
             body_yaw: rot.x,
             head_pitch: rot.y,
-            running: input.keys.contains(&KeyCode::LShift),
-            ducking: input.keys.contains(&KeyCode::LControl),
-            shooting: input.mouse_buttons.contains(&MouseButton::Left),
+            running: is_action_pressed(package, "run"),
+            ducking: is_action_pressed(package, "duck"),
+            shooting: is_action_pressed(package, "shoot"),
         }
         .send_server_unreliable();
     });
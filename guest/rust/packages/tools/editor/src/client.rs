@@ -16,7 +16,7 @@ use ambient_api::{
         use_entity_component, use_frame, use_module_message, use_rerender_signal, use_spawn,
         use_state_with,
     },
-    input::{set_cursor_lock, set_cursor_visible},
+    input::{get_axis, is_action_pressed, set_cursor_lock, set_cursor_visible},
     prelude::*,
     ui::use_keyboard_input,
 };
@@ -57,6 +57,10 @@ pub fn main() {
 
     let mut cursor_locked = false;
 
+    let mut was_look_pressed = false;
+    let mut was_select_pressed = false;
+    let mut was_freeze_pressed = false;
+
     Frame::subscribe(move |_| {
         let fixed_tick_dt = game_time() - fixed_tick_last;
 
@@ -69,28 +73,32 @@ pub fn main() {
         };
 
         let (delta, input) = input::get_delta();
+        let package = packages::this::entity();
 
-        if gizmo_active.is_none() && delta.mouse_buttons.contains(&MouseButton::Right) {
+        let look = is_action_pressed(package, "look");
+        if gizmo_active.is_none() && look && !was_look_pressed {
             set_cursor_lock(true);
             set_cursor_visible(false);
             cursor_locked = true;
-        } else if delta.mouse_buttons_released.contains(&MouseButton::Right) {
+        } else if !look && was_look_pressed {
             set_cursor_lock(false);
             set_cursor_visible(true);
             cursor_locked = false;
         }
-        select_pressed |= delta.mouse_buttons.contains(&MouseButton::Left);
-        freeze_pressed |= delta.keys_released.contains(&KeyCode::R);
-
-        let movement = [
-            (KeyCode::W, -Vec2::Y),
-            (KeyCode::S, Vec2::Y),
-            (KeyCode::A, -Vec2::X),
-            (KeyCode::D, Vec2::X),
-        ]
-        .iter()
-        .filter(|(key, _)| input.keys.contains(key))
-        .fold(Vec2::ZERO, |acc, (_, dir)| acc + *dir);
+        was_look_pressed = look;
+
+        let select = is_action_pressed(package, "select");
+        select_pressed |= select && !was_select_pressed;
+        was_select_pressed = select;
+
+        let freeze = is_action_pressed(package, "freeze");
+        freeze_pressed |= !freeze && was_freeze_pressed;
+        was_freeze_pressed = freeze;
+
+        let movement = vec2(
+            get_axis(package, "move_right"),
+            -get_axis(package, "move_forward"),
+        );
 
         let mut aiming = false;
         if cursor_locked {
@@ -106,7 +114,7 @@ pub fn main() {
                     .into_iter()
                     .find(|g| g.is_hovered(camera_id, input.mouse_position));
 
-                if input.mouse_buttons.contains(&MouseButton::Left) {
+                if select {
                     if gizmo_active.is_none() {
                         if let Some(gizmo) = gizmo {
                             gizmo_active = Some(gizmo);
@@ -141,7 +149,7 @@ pub fn main() {
         if fixed_tick_dt > Duration::from_millis(20) {
             let ray = camera::screen_position_to_world_ray(camera_id, input.mouse_position);
 
-            let boost = input.keys.contains(&KeyCode::LShift);
+            let boost = is_action_pressed(package, "boost");
 
             Input {
                 aim_delta: accumulated_aim_delta,
@@ -497,19 +505,13 @@ fn make_sample_scene() {
         }
 
         let dt = delta_time();
-        let input = input::get();
+        let package = packages::this::entity();
 
-        let movement = [
-            (KeyCode::W, -Vec2::Y),
-            (KeyCode::S, Vec2::Y),
-            (KeyCode::A, -Vec2::X),
-            (KeyCode::D, Vec2::X),
-        ]
-        .iter()
-        .filter(|(key, _)| input.keys.contains(key))
-        .fold(Vec2::ZERO, |acc, (_, dir)| acc + *dir)
-        .extend(0.0)
-            * dt;
+        let movement = vec3(
+            get_axis(package, "move_right"),
+            -get_axis(package, "move_forward"),
+            0.0,
+        ) * dt;
 
         offset += movement;
         target += movement;
@@ -1,8 +1,14 @@
 use ambient_api::{
-    element::{use_frame, use_ref_with, use_rerender_signal},
+    core::wasm::components::{
+        is_module_on_server, module_fuel_consumed, module_host_calls, module_name, module_run_time,
+    },
+    element::{use_frame, use_query, use_ref_with, use_rerender_signal},
     prelude::*,
 };
 
+/// How many of the slowest modules to show.
+const MODULES_SHOWN: usize = 3;
+
 pub mod packages;
 
 #[main]
@@ -30,5 +36,29 @@ fn PerformanceBar(hooks: &mut Hooks) -> Element {
         let fps = frame_times.len() as f32 / frame_times.iter().sum::<f32>();
         fps
     };
-    Text::el(format!("Fps: {fps}"))
+    FlowColumn::el([Text::el(format!("Fps: {fps}")), ModuleTimes.el()])
+}
+
+#[element_component]
+fn ModuleTimes(hooks: &mut Hooks) -> Element {
+    let mut modules = use_query(hooks, (module_name(), module_run_time()));
+    modules.sort_by(|(_, (_, a)), (_, (_, b))| b.total_cmp(a));
+
+    FlowColumn::el(
+        modules
+            .into_iter()
+            .take(MODULES_SHOWN)
+            .map(|(id, (name, run_time))| {
+                let side = if entity::has_component(id, is_module_on_server()) {
+                    "server"
+                } else {
+                    "client"
+                };
+                let fuel = entity::get_component(id, module_fuel_consumed()).unwrap_or_default();
+                let host_calls = entity::get_component(id, module_host_calls()).unwrap_or_default();
+                Text::el(format!(
+                    "{name} ({side}): {run_time:.2} ms/s, {fuel} fuel, {host_calls} host calls"
+                ))
+            }),
+    )
 }
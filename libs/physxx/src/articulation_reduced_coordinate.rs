@@ -1,7 +1,7 @@
 use crate::{
     articulation::PxArticulationCacheFlags, AsArticulationBase, AsArticulationJointBase,
     PxArticulationAxis, PxArticulationDriveType, PxArticulationFlag, PxArticulationJointType,
-    PxArticulationLinkRef, PxArticulationMotion, PxPhysicsRef,
+    PxArticulationLinkRef, PxArticulationMotion, PxPhysicsRef, PxTransform,
 };
 
 #[derive(Clone, Copy)]
@@ -27,6 +27,14 @@ impl PxArticulationRef {
             buffer.into_iter().map(PxArticulationLinkRef).collect()
         }
     }
+    /// Moves the whole articulation so that its root link ends up at `pose`.
+    pub fn teleport_root_link(&self, pose: &PxTransform, autowake: bool) {
+        unsafe {
+            physx_sys::PxArticulationReducedCoordinate_teleportRootLink_mut(
+                self.0, &pose.0, autowake,
+            )
+        }
+    }
     pub fn set_flag(&mut self, flag: PxArticulationFlag, value: bool) {
         unsafe {
             physx_sys::PxArticulationReducedCoordinate_setArticulationFlag_mut(
@@ -51,6 +59,17 @@ unsafe impl Send for PxArticulationRef {}
 #[derive(Clone, Copy)]
 pub struct PxArticulationJointRef(*mut physx_sys::PxArticulationJointReducedCoordinate);
 impl PxArticulationJointRef {
+    /// Returns the joint connecting `link` to its parent, or `None` for the root link.
+    pub fn from_inbound_joint(link: &PxArticulationLinkRef) -> Option<Self> {
+        let joint = unsafe { physx_sys::PxArticulationLink_getInboundJoint(link.0) };
+        if joint.is_null() {
+            None
+        } else {
+            Some(Self(
+                joint as *mut physx_sys::PxArticulationJointReducedCoordinate,
+            ))
+        }
+    }
     pub fn set_joint_type(&mut self, joint_type: PxArticulationJointType) {
         unsafe {
             physx_sys::PxArticulationJointReducedCoordinate_setJointType_mut(
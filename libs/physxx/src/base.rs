@@ -1,8 +1,9 @@
 use crate::{
     AsPxJoint, AsPxRigidActor, AsPxRigidBody, PxAggregateRef, PxArticulationLinkRef,
-    PxConstraintRef, PxConvexMesh, PxFixedJointRef, PxHeightField, PxJointRef, PxMaterial,
-    PxRevoluteJointRef, PxRigidActorRef, PxRigidBodyRef, PxRigidDynamicRef, PxRigidStaticRef,
-    PxShape,
+    PxConstraintRef, PxConvexMesh, PxD6JointRef, PxDistanceJointRef, PxFixedJointRef,
+    PxHeightField, PxJointRef, PxMaterial, PxPrismaticJointRef, PxRevoluteJointRef,
+    PxRigidActorRef, PxRigidBodyRef, PxRigidDynamicRef, PxRigidStaticRef, PxShape,
+    PxSphericalJointRef,
 };
 
 pub trait AsPxBase: Sync + Send {
@@ -18,6 +19,10 @@ pub trait PxBase: Sync + Send + as_any::AsAny {
     fn to_joint(&self) -> Option<PxJointRef>;
     fn to_fixed_joint(&self) -> Option<PxFixedJointRef>;
     fn to_revolute_joint(&self) -> Option<PxRevoluteJointRef>;
+    fn to_spherical_joint(&self) -> Option<PxSphericalJointRef>;
+    fn to_prismatic_joint(&self) -> Option<PxPrismaticJointRef>;
+    fn to_distance_joint(&self) -> Option<PxDistanceJointRef>;
+    fn to_d6_joint(&self) -> Option<PxD6JointRef>;
 }
 impl<T: AsPxBase + 'static> PxBase for T {
     fn get_concrete_type(&self) -> u16 {
@@ -56,6 +61,10 @@ impl<T: AsPxBase + 'static> PxBase for T {
         match self.as_px_any() {
             PxAny::PxFixedJoint(o) => Some(o.as_joint()),
             PxAny::PxRevoluteJoint(o) => Some(o.as_joint()),
+            PxAny::PxSphericalJoint(o) => Some(o.as_joint()),
+            PxAny::PxPrismaticJoint(o) => Some(o.as_joint()),
+            PxAny::PxDistanceJoint(o) => Some(o.as_joint()),
+            PxAny::PxD6Joint(o) => Some(o.as_joint()),
             _ => None,
         }
     }
@@ -71,6 +80,30 @@ impl<T: AsPxBase + 'static> PxBase for T {
             _ => None,
         }
     }
+    fn to_spherical_joint(&self) -> Option<PxSphericalJointRef> {
+        match self.as_px_any() {
+            PxAny::PxSphericalJoint(o) => Some(o),
+            _ => None,
+        }
+    }
+    fn to_prismatic_joint(&self) -> Option<PxPrismaticJointRef> {
+        match self.as_px_any() {
+            PxAny::PxPrismaticJoint(o) => Some(o),
+            _ => None,
+        }
+    }
+    fn to_distance_joint(&self) -> Option<PxDistanceJointRef> {
+        match self.as_px_any() {
+            PxAny::PxDistanceJoint(o) => Some(o),
+            _ => None,
+        }
+    }
+    fn to_d6_joint(&self) -> Option<PxD6JointRef> {
+        match self.as_px_any() {
+            PxAny::PxD6Joint(o) => Some(o),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -94,6 +127,10 @@ pub enum PxAny {
     PxShape(PxShape),
     PxFixedJoint(PxFixedJointRef),
     PxRevoluteJoint(PxRevoluteJointRef),
+    PxSphericalJoint(PxSphericalJointRef),
+    PxPrismaticJoint(PxPrismaticJointRef),
+    PxDistanceJoint(PxDistanceJointRef),
+    PxD6Joint(PxD6JointRef),
     PxConstraint(PxConstraintRef),
     PxArticulationLink(PxArticulationLinkRef),
 }
@@ -163,9 +200,9 @@ impl PxAny {
                 physx_sys::PxJointConcreteType::eCONTACT => {
                     panic!("PhysX object type is not supported")
                 }
-                physx_sys::PxJointConcreteType::eD6 => panic!("PhysX object type is not supported"),
+                physx_sys::PxJointConcreteType::eD6 => PxAny::PxD6Joint(PxD6JointRef(obj as _)),
                 physx_sys::PxJointConcreteType::eDISTANCE => {
-                    panic!("PhysX object type is not supported")
+                    PxAny::PxDistanceJoint(PxDistanceJointRef(obj as _))
                 }
                 physx_sys::PxJointConcreteType::eFIXED => {
                     PxAny::PxFixedJoint(PxFixedJointRef(obj as _))
@@ -174,13 +211,13 @@ impl PxAny {
                     panic!("PhysX object type is not supported")
                 }
                 physx_sys::PxJointConcreteType::ePRISMATIC => {
-                    panic!("PhysX object type is not supported")
+                    PxAny::PxPrismaticJoint(PxPrismaticJointRef(obj as _))
                 }
                 physx_sys::PxJointConcreteType::eREVOLUTE => {
                     PxAny::PxRevoluteJoint(PxRevoluteJointRef(obj as _))
                 }
                 physx_sys::PxJointConcreteType::eSPHERICAL => {
-                    panic!("PhysX object type is not supported")
+                    PxAny::PxSphericalJoint(PxSphericalJointRef(obj as _))
                 }
 
                 _ => panic!("Unknown type"),
@@ -4,7 +4,7 @@ use glam::{DVec3, Vec3};
 use physx_sys::PxControllerCollisionFlag::*;
 
 use crate::{
-    to_glam_vec3, to_glam_vec3_f64, to_physx_vec3, to_physx_vec3_f64, PxMaterial,
+    to_glam_vec3, to_glam_vec3_f64, to_physx_vec3, to_physx_vec3_f64, PxFilterData, PxMaterial,
     PxRigidDynamicRef, PxSceneRef,
 };
 
@@ -152,10 +152,32 @@ unsafe impl Send for PxControllerDescRef {}
 //     }
 // }
 
-pub struct PxControllerFilters(physx_sys::PxControllerFilters);
+pub struct PxControllerFilters(
+    physx_sys::PxControllerFilters,
+    // The filters point to the filter data, so it has to stay in place for as long as they live
+    Option<Box<physx_sys::PxFilterData>>,
+);
 impl PxControllerFilters {
     pub fn new() -> Self {
-        Self(unsafe { physx_sys::PxControllerFilters_new(null_mut(), null_mut(), null_mut()) })
+        Self(
+            unsafe { physx_sys::PxControllerFilters_new(null_mut(), null_mut(), null_mut()) },
+            None,
+        )
+    }
+    /// Filters the shapes the controller collides with by their query filter data, like
+    /// `PxQueryFilterData` does for scene queries.
+    pub fn with_filter_data(filter_data: PxFilterData) -> Self {
+        let filter_data = Box::new(filter_data.to_physx());
+        Self(
+            unsafe {
+                physx_sys::PxControllerFilters_new(
+                    filter_data.as_ref() as *const physx_sys::PxFilterData,
+                    null_mut(),
+                    null_mut(),
+                )
+            },
+            Some(filter_data),
+        )
     }
 }
 
@@ -82,11 +82,16 @@ impl PxQuantizedHeightFieldSamples {
             samples: values
                 .iter()
                 .map(|&v| {
-                    let height = quantization * ((v - min_height) / delta_height);
+                    // A flat heightfield has all of its samples at the minimum height
+                    let height = if delta_height > 0. {
+                        quantization * ((v - min_height) / delta_height)
+                    } else {
+                        0.
+                    };
                     PxHeightFieldSample::new(height as i16)
                 })
                 .collect(),
-            height_scale: if delta_height != 0. { height_scale } else { 1. },
+            height_scale: if delta_height > 0. { height_scale } else { 1. },
             min_height,
         }
     }
@@ -4,7 +4,8 @@ use glam::Vec3;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    to_glam_vec3, AsPxBase, PxBaseRef, PxPhysicsRef, PxRigidActorRef, PxTransform, PxUserData,
+    to_glam_vec3, to_physx_vec3, AsPxBase, PxBaseRef, PxPhysicsRef, PxRigidActorRef, PxTransform,
+    PxUserData,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -321,3 +322,437 @@ impl PxJointAngularLimitPair {
         }
     }
 }
+
+bitflags! {
+    #[derive(Serialize, Deserialize)]
+    pub struct PxSphericalJointFlag: u32 {
+        const LIMIT_ENABLED = physx_sys::PxSphericalJointFlag::eLIMIT_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxSphericalJointRef(pub(crate) *mut physx_sys::PxSphericalJoint);
+impl PxSphericalJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxSphericalJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn set_spherical_flag(&self, flag: PxSphericalJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxSphericalJoint_setSphericalJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+    pub fn get_limit_cone(&self) -> PxJointLimitCone {
+        PxJointLimitCone::from_physx(unsafe { physx_sys::PxSphericalJoint_getLimitCone(self.0) })
+    }
+    pub fn set_limit_cone(&self, limit: &PxJointLimitCone) {
+        unsafe { physx_sys::PxSphericalJoint_setLimitCone_mut(self.0, &limit.to_physx() as _) }
+    }
+}
+impl AsPxBase for PxSphericalJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxSphericalJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxSphericalJointRef {}
+unsafe impl Send for PxSphericalJointRef {}
+
+bitflags! {
+    #[derive(Serialize, Deserialize)]
+    pub struct PxPrismaticJointFlag: u32 {
+        const LIMIT_ENABLED = physx_sys::PxPrismaticJointFlag::eLIMIT_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxPrismaticJointRef(pub(crate) *mut physx_sys::PxPrismaticJoint);
+impl PxPrismaticJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxPrismaticJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn set_prismatic_flag(&self, flag: PxPrismaticJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxPrismaticJoint_setPrismaticJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+    pub fn get_limit(&self) -> PxJointLinearLimitPair {
+        PxJointLinearLimitPair::from_physx(unsafe { physx_sys::PxPrismaticJoint_getLimit(self.0) })
+    }
+    pub fn set_limit(&self, limit: &PxJointLinearLimitPair) {
+        unsafe { physx_sys::PxPrismaticJoint_setLimit_mut(self.0, &limit.to_physx() as _) }
+    }
+}
+impl AsPxBase for PxPrismaticJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxPrismaticJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxPrismaticJointRef {}
+unsafe impl Send for PxPrismaticJointRef {}
+
+bitflags! {
+    #[derive(Serialize, Deserialize)]
+    pub struct PxDistanceJointFlag: u32 {
+        const MAX_DISTANCE_ENABLED = physx_sys::PxDistanceJointFlag::eMAX_DISTANCE_ENABLED;
+        const MIN_DISTANCE_ENABLED = physx_sys::PxDistanceJointFlag::eMIN_DISTANCE_ENABLED;
+        const SPRING_ENABLED = physx_sys::PxDistanceJointFlag::eSPRING_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxDistanceJointRef(pub(crate) *mut physx_sys::PxDistanceJoint);
+impl PxDistanceJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxDistanceJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn get_distance(&self) -> f32 {
+        unsafe { physx_sys::PxDistanceJoint_getDistance(self.0) }
+    }
+    pub fn set_min_distance(&self, distance: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setMinDistance_mut(self.0, distance) }
+    }
+    pub fn set_max_distance(&self, distance: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setMaxDistance_mut(self.0, distance) }
+    }
+    pub fn set_stiffness(&self, stiffness: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setStiffness_mut(self.0, stiffness) }
+    }
+    pub fn set_damping(&self, damping: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setDamping_mut(self.0, damping) }
+    }
+    pub fn set_distance_flag(&self, flag: PxDistanceJointFlag, value: bool) {
+        unsafe {
+            physx_sys::PxDistanceJoint_setDistanceJointFlag_mut(self.0, flag.bits() as _, value)
+        }
+    }
+}
+impl AsPxBase for PxDistanceJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxDistanceJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxDistanceJointRef {}
+unsafe impl Send for PxDistanceJointRef {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u32)]
+pub enum PxD6Axis {
+    X = physx_sys::PxD6Axis::eX,
+    Y = physx_sys::PxD6Axis::eY,
+    Z = physx_sys::PxD6Axis::eZ,
+    Twist = physx_sys::PxD6Axis::eTWIST,
+    Swing1 = physx_sys::PxD6Axis::eSWING1,
+    Swing2 = physx_sys::PxD6Axis::eSWING2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u32)]
+pub enum PxD6Motion {
+    Locked = physx_sys::PxD6Motion::eLOCKED,
+    Limited = physx_sys::PxD6Motion::eLIMITED,
+    Free = physx_sys::PxD6Motion::eFREE,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u32)]
+pub enum PxD6Drive {
+    X = physx_sys::PxD6Drive::eX,
+    Y = physx_sys::PxD6Drive::eY,
+    Z = physx_sys::PxD6Drive::eZ,
+    Swing = physx_sys::PxD6Drive::eSWING,
+    Twist = physx_sys::PxD6Drive::eTWIST,
+    Slerp = physx_sys::PxD6Drive::eSLERP,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PxD6JointDrive {
+    pub stiffness: f32,
+    pub damping: f32,
+    pub force_limit: f32,
+    pub is_acceleration: bool,
+}
+impl PxD6JointDrive {
+    pub fn new(stiffness: f32, damping: f32, force_limit: f32, is_acceleration: bool) -> Self {
+        Self {
+            stiffness,
+            damping,
+            force_limit,
+            is_acceleration,
+        }
+    }
+    fn from_physx(drive: &physx_sys::PxD6JointDrive) -> Self {
+        Self {
+            stiffness: drive.stiffness,
+            damping: drive.damping,
+            force_limit: drive.forceLimit,
+            is_acceleration: drive.flags.mBits
+                & physx_sys::PxD6JointDriveFlag::eACCELERATION as u32
+                != 0,
+        }
+    }
+    fn to_physx(&self) -> physx_sys::PxD6JointDrive {
+        unsafe {
+            physx_sys::PxD6JointDrive_new_1(
+                self.stiffness,
+                self.damping,
+                self.force_limit,
+                self.is_acceleration,
+            )
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxD6JointRef(pub(crate) *mut physx_sys::PxD6Joint);
+impl PxD6JointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxD6JointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn get_motion(&self, axis: PxD6Axis) -> PxD6Motion {
+        match unsafe { physx_sys::PxD6Joint_getMotion(self.0, axis as u32) } {
+            physx_sys::PxD6Motion::eLOCKED => PxD6Motion::Locked,
+            physx_sys::PxD6Motion::eLIMITED => PxD6Motion::Limited,
+            _ => PxD6Motion::Free,
+        }
+    }
+    pub fn set_motion(&self, axis: PxD6Axis, motion: PxD6Motion) {
+        unsafe { physx_sys::PxD6Joint_setMotion_mut(self.0, axis as u32, motion as u32) }
+    }
+    pub fn set_distance_limit(&self, limit: &PxJointLinearLimit) {
+        unsafe { physx_sys::PxD6Joint_setDistanceLimit_mut(self.0, &limit.to_physx() as _) }
+    }
+    pub fn set_twist_limit(&self, limit: &PxJointAngularLimitPair) {
+        unsafe { physx_sys::PxD6Joint_setTwistLimit_mut(self.0, &limit.to_physx() as _) }
+    }
+    pub fn set_swing_limit(&self, limit: &PxJointLimitCone) {
+        unsafe { physx_sys::PxD6Joint_setSwingLimit_mut(self.0, &limit.to_physx() as _) }
+    }
+    pub fn get_drive(&self, index: PxD6Drive) -> PxD6JointDrive {
+        PxD6JointDrive::from_physx(&unsafe { physx_sys::PxD6Joint_getDrive(self.0, index as u32) })
+    }
+    pub fn set_drive(&self, index: PxD6Drive, drive: &PxD6JointDrive) {
+        unsafe { physx_sys::PxD6Joint_setDrive_mut(self.0, index as u32, &drive.to_physx() as _) }
+    }
+    pub fn set_drive_position(&self, pose: &PxTransform, autowake: bool) {
+        unsafe { physx_sys::PxD6Joint_setDrivePosition_mut(self.0, &pose.0, autowake) }
+    }
+    pub fn set_drive_velocity(&self, linear: Vec3, angular: Vec3, autowake: bool) {
+        unsafe {
+            physx_sys::PxD6Joint_setDriveVelocity_mut(
+                self.0,
+                &to_physx_vec3(linear),
+                &to_physx_vec3(angular),
+                autowake,
+            )
+        }
+    }
+}
+impl AsPxBase for PxD6JointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxD6JointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxD6JointRef {}
+unsafe impl Send for PxD6JointRef {}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PxJointLimitCone {
+    pub restitution: f32,
+    pub bounce_threshold: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub contact_distance: f32,
+    pub y_angle: f32,
+    pub z_angle: f32,
+}
+impl PxJointLimitCone {
+    pub fn new(y_limit_angle: f32, z_limit_angle: f32, contact_dist: f32) -> Self {
+        Self::from_physx(unsafe {
+            physx_sys::PxJointLimitCone_new(y_limit_angle, z_limit_angle, contact_dist)
+        })
+    }
+    fn from_physx(limit: physx_sys::PxJointLimitCone) -> Self {
+        Self {
+            restitution: limit.restitution,
+            bounce_threshold: limit.bounceThreshold,
+            stiffness: limit.stiffness,
+            damping: limit.damping,
+            contact_distance: limit.contactDistance,
+            y_angle: limit.yAngle,
+            z_angle: limit.zAngle,
+        }
+    }
+    fn to_physx(&self) -> physx_sys::PxJointLimitCone {
+        physx_sys::PxJointLimitCone {
+            restitution: self.restitution,
+            bounceThreshold: self.bounce_threshold,
+            stiffness: self.stiffness,
+            damping: self.damping,
+            contactDistance: self.contact_distance,
+            yAngle: self.y_angle,
+            zAngle: self.z_angle,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PxJointLinearLimitPair {
+    pub restitution: f32,
+    pub bounce_threshold: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub contact_distance: f32,
+    pub upper: f32,
+    pub lower: f32,
+}
+impl PxJointLinearLimitPair {
+    pub fn new(
+        physics: PxPhysicsRef,
+        lower_limit: f32,
+        upper_limit: f32,
+        contact_dist: f32,
+    ) -> Self {
+        Self::from_physx(unsafe {
+            physx_sys::PxJointLinearLimitPair_new(
+                physics.get_tolerances_scale(),
+                lower_limit,
+                upper_limit,
+                contact_dist,
+            )
+        })
+    }
+    fn from_physx(limit: physx_sys::PxJointLinearLimitPair) -> Self {
+        Self {
+            restitution: limit.restitution,
+            bounce_threshold: limit.bounceThreshold,
+            stiffness: limit.stiffness,
+            damping: limit.damping,
+            contact_distance: limit.contactDistance,
+            upper: limit.upper,
+            lower: limit.lower,
+        }
+    }
+    fn to_physx(&self) -> physx_sys::PxJointLinearLimitPair {
+        physx_sys::PxJointLinearLimitPair {
+            restitution: self.restitution,
+            bounceThreshold: self.bounce_threshold,
+            stiffness: self.stiffness,
+            damping: self.damping,
+            contactDistance: self.contact_distance,
+            upper: self.upper,
+            lower: self.lower,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PxJointLinearLimit {
+    pub restitution: f32,
+    pub bounce_threshold: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub contact_distance: f32,
+    pub value: f32,
+}
+impl PxJointLinearLimit {
+    pub fn new(physics: PxPhysicsRef, extent: f32, contact_dist: f32) -> Self {
+        Self::from_physx(unsafe {
+            physx_sys::PxJointLinearLimit_new(physics.get_tolerances_scale(), extent, contact_dist)
+        })
+    }
+    fn from_physx(limit: physx_sys::PxJointLinearLimit) -> Self {
+        Self {
+            restitution: limit.restitution,
+            bounce_threshold: limit.bounceThreshold,
+            stiffness: limit.stiffness,
+            damping: limit.damping,
+            contact_distance: limit.contactDistance,
+            value: limit.value,
+        }
+    }
+    fn to_physx(&self) -> physx_sys::PxJointLinearLimit {
+        physx_sys::PxJointLinearLimit {
+            restitution: self.restitution,
+            bounceThreshold: self.bounce_threshold,
+            stiffness: self.stiffness,
+            damping: self.damping,
+            contactDistance: self.contact_distance,
+            value: self.value,
+        }
+    }
+}
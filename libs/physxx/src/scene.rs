@@ -13,13 +13,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     sweep::PxSweepHit, to_glam_vec3, to_physx_vec3, AsArticulationBase, AsPxActor, PxActorRef,
-    PxAggregateRef, PxCollectionRef, PxConstraintRef, PxDefaultCpuDispatcherRef, PxGeometry,
-    PxHitFlags, PxPhysicsRef, PxPvdSceneClientRef, PxRaycastHit, PxRigidActorRef, PxShape,
-    PxTransform,
+    PxAggregateRef, PxCollectionRef, PxConstraintRef, PxDefaultCpuDispatcherRef, PxFilterData,
+    PxGeometry, PxHitFlags, PxPhysicsRef, PxPvdSceneClientRef, PxRaycastHit, PxRigidActorRef,
+    PxShape, PxTransform,
 };
 
 pub fn extract_contact_points(
     iter: &physx_sys::PxContactStreamIterator,
+    impulses: Option<&[f32]>,
 ) -> Result<Vec<PxContactPoint>, &'static str> {
     if iter.contact.is_null() || iter.patch.is_null() {
         return Err("Null pointer detected in PxContactStreamIterator");
@@ -38,11 +39,17 @@ pub fn extract_contact_points(
         for patch in patches {
             for _ in 0..patch.nbContacts {
                 let contact = contacts[local_iter.nextContactIndex as usize];
+                let normal = to_glam_vec3(&patch.normal);
+                let impulse = impulses
+                    .and_then(|impulses| impulses.get(local_iter.nextContactIndex as usize))
+                    .copied()
+                    .unwrap_or_default();
 
                 contact_points.push(PxContactPoint {
                     position: to_glam_vec3(&contact.contact),
                     separation: contact.separation,
-                    normal: to_glam_vec3(&patch.normal),
+                    normal,
+                    impulse: normal * impulse,
                 });
 
                 local_iter.nextContactIndex += 1;
@@ -90,44 +97,74 @@ impl PxSceneDesc {
             );
         }
     }
-    pub fn set_simulation_event_callbacks<C: FnMut(&PxContactPairHeader, Vec<PxContactPoint>)>(
-        &mut self,
-        callbacks: PxSimulationEventCallback<C>,
-    ) {
+    pub fn set_simulation_event_callbacks(&mut self, callbacks: PxSimulationEventCallback) {
         unsafe {
-            unsafe extern "C" fn collision_callback_trampoline<
-                C: FnMut(&PxContactPairHeader, Vec<PxContactPoint>),
-            >(
+            unsafe extern "C" fn collision_callback_trampoline(
                 user_data: *mut std::ffi::c_void,
                 pair_header: *const physx_sys::PxContactPairHeader,
                 pairs: *const physx_sys::PxContactPair,
-                _nb_pairs: u32,
+                nb_pairs: u32,
             ) {
-                let mut cb: Box<C> = Box::from_raw(user_data as _);
+                let cb = &mut *(user_data as *mut PxCollisionCallback);
                 let pair_header_flags =
                     PxContactPairHeaderFlag::from_bits((*pair_header).flags.mBits).unwrap();
-                let mut contact_points_vec = Vec::new();
 
-                // Check for a valid contact stream
-                if let Some(pair) = pairs.as_ref() {
-                    // Create the PxContactStreamIterator from the contact pair data
-                    let contact_stream_iterator = unsafe {
-                        physx_sys::PxContactStreamIterator_new(
+                let pairs = if pairs.is_null() {
+                    &[]
+                } else {
+                    std::slice::from_raw_parts(pairs, nb_pairs as usize)
+                };
+                let pairs = pairs
+                    .iter()
+                    .map(|pair| {
+                        let flags = PxContactPairFlag::from_bits_truncate(pair.flags.mBits);
+
+                        // Create the PxContactStreamIterator from the contact pair data
+                        let contact_stream_iterator = physx_sys::PxContactStreamIterator_new(
                             pair.contactPatches,
                             pair.contactPoints,
                             std::ptr::null(), // Assuming we don't have the contactFaceIndices, passing a null pointer.
                             pair.patchCount as u32,
                             pair.contactCount as u32,
-                        )
-                    };
-
-                    if let Ok(points) = extract_contact_points(&contact_stream_iterator) {
-                        contact_points_vec = points;
-                    } else {
-                        // Handle the error
-                        println!("Error extracting contact points");
-                    }
-                }
+                        );
+                        let impulses = if flags.contains(PxContactPairFlag::INTERNAL_HAS_IMPULSES)
+                            && !pair.contactImpulses.is_null()
+                        {
+                            Some(std::slice::from_raw_parts(
+                                pair.contactImpulses,
+                                pair.contactCount as usize,
+                            ))
+                        } else {
+                            None
+                        };
+                        let contact_points = if pair.contactCount > 0 {
+                            extract_contact_points(&contact_stream_iterator, impulses)
+                                .unwrap_or_else(|err| {
+                                    tracing::warn!("Error extracting contact points: {err}");
+                                    Vec::new()
+                                })
+                        } else {
+                            Vec::new()
+                        };
+
+                        PxContactPair {
+                            shapes: [
+                                if flags.contains(PxContactPairFlag::REMOVED_SHAPE_0) {
+                                    None
+                                } else {
+                                    Some(PxShape::from_ptr(pair.shapes[0]))
+                                },
+                                if flags.contains(PxContactPairFlag::REMOVED_SHAPE_1) {
+                                    None
+                                } else {
+                                    Some(PxShape::from_ptr(pair.shapes[1]))
+                                },
+                            ],
+                            events: PxPairFlag::from_bits_truncate(pair.events.mBits),
+                            contact_points,
+                        }
+                    })
+                    .collect::<Vec<_>>();
 
                 cb(
                     &PxContactPairHeader {
@@ -146,18 +183,53 @@ impl PxSceneDesc {
                             },
                         ],
                     },
-                    contact_points_vec,
+                    &pairs,
                 );
+            }
 
-                Box::into_raw(cb); // Convert the box back into a raw pointer.
+            unsafe extern "C" fn trigger_callback_trampoline(
+                user_data: *mut std::ffi::c_void,
+                pairs: *const physx_sys::PxTriggerPair,
+                nb_pairs: u32,
+            ) {
+                let cb = &mut *(user_data as *mut PxTriggerCallback);
+                if pairs.is_null() {
+                    return;
+                }
+                let pairs = std::slice::from_raw_parts(pairs, nb_pairs as usize)
+                    .iter()
+                    .map(|pair| {
+                        let flags = PxTriggerPairFlag::from_bits_truncate(pair.flags.mBits);
+                        PxTriggerPair {
+                            trigger_shape: if flags
+                                .contains(PxTriggerPairFlag::REMOVED_SHAPE_TRIGGER)
+                            {
+                                None
+                            } else {
+                                Some(PxShape::from_ptr(pair.triggerShape))
+                            },
+                            other_shape: if flags.contains(PxTriggerPairFlag::REMOVED_SHAPE_OTHER) {
+                                None
+                            } else {
+                                Some(PxShape::from_ptr(pair.otherShape))
+                            },
+                            status: PxPairFlag::from_bits_truncate(pair.status as u16),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                cb(&pairs);
             }
 
             let mut cbs = physx_sys::SimulationEventCallbackInfo {
                 ..Default::default()
             };
             if let Some(cb) = callbacks.collision_callback {
-                cbs.collision_callback = Some(collision_callback_trampoline::<C>);
-                cbs.collision_user_data = Box::into_raw(cb) as _;
+                cbs.collision_callback = Some(collision_callback_trampoline);
+                cbs.collision_user_data = Box::into_raw(Box::new(cb)) as _;
+            }
+            if let Some(cb) = callbacks.trigger_callback {
+                cbs.trigger_callback = Some(trigger_callback_trampoline);
+                cbs.trigger_user_data = Box::into_raw(Box::new(cb)) as _;
             }
             self.0.simulationEventCallback = physx_sys::create_simulation_event_callbacks(&cbs);
         }
@@ -180,14 +252,72 @@ pub struct PxContactPoint {
     pub position: glam::Vec3,
     pub normal: glam::Vec3,
     pub separation: f32,
+    /// The impulse applied at this contact point; zero if impulses were not reported.
+    pub impulse: glam::Vec3,
 }
 
 pub struct PxContactPairHeader {
     pub actors: [Option<PxRigidActorRef>; 2],
 }
 
-pub struct PxSimulationEventCallback<C: FnMut(&PxContactPairHeader, Vec<PxContactPoint>)> {
-    pub collision_callback: Option<Box<C>>,
+pub struct PxContactPair {
+    /// The shapes in contact; `None` if the shape was removed from the scene.
+    pub shapes: [Option<PxShape>; 2],
+    pub events: PxPairFlag,
+    pub contact_points: Vec<PxContactPoint>,
+}
+
+pub struct PxTriggerPair {
+    /// The trigger shape; `None` if the shape was removed from the scene.
+    pub trigger_shape: Option<PxShape>,
+    /// The shape that entered or left the trigger; `None` if the shape was removed from the scene.
+    pub other_shape: Option<PxShape>,
+    /// Either [`PxPairFlag::NOTIFY_TOUCH_FOUND`] or [`PxPairFlag::NOTIFY_TOUCH_LOST`].
+    pub status: PxPairFlag,
+}
+
+pub type PxCollisionCallback = Box<dyn FnMut(&PxContactPairHeader, &[PxContactPair]) + Send + Sync>;
+pub type PxTriggerCallback = Box<dyn FnMut(&[PxTriggerPair]) + Send + Sync>;
+
+#[derive(Default)]
+pub struct PxSimulationEventCallback {
+    pub collision_callback: Option<PxCollisionCallback>,
+    pub trigger_callback: Option<PxTriggerCallback>,
+}
+
+bitflags! {
+    pub struct PxPairFlag: u16 {
+        const SOLVE_CONTACT = physx_sys::PxPairFlag::eSOLVE_CONTACT as u16;
+        const MODIFY_CONTACTS = physx_sys::PxPairFlag::eMODIFY_CONTACTS as u16;
+        const NOTIFY_TOUCH_FOUND = physx_sys::PxPairFlag::eNOTIFY_TOUCH_FOUND as u16;
+        const NOTIFY_TOUCH_PERSISTS = physx_sys::PxPairFlag::eNOTIFY_TOUCH_PERSISTS as u16;
+        const NOTIFY_TOUCH_LOST = physx_sys::PxPairFlag::eNOTIFY_TOUCH_LOST as u16;
+        const NOTIFY_TOUCH_CCD = physx_sys::PxPairFlag::eNOTIFY_TOUCH_CCD as u16;
+        const NOTIFY_THRESHOLD_FORCE_FOUND = physx_sys::PxPairFlag::eNOTIFY_THRESHOLD_FORCE_FOUND as u16;
+        const NOTIFY_THRESHOLD_FORCE_PERSISTS = physx_sys::PxPairFlag::eNOTIFY_THRESHOLD_FORCE_PERSISTS as u16;
+        const NOTIFY_THRESHOLD_FORCE_LOST = physx_sys::PxPairFlag::eNOTIFY_THRESHOLD_FORCE_LOST as u16;
+        const NOTIFY_CONTACT_POINTS = physx_sys::PxPairFlag::eNOTIFY_CONTACT_POINTS as u16;
+        const DETECT_DISCRETE_CONTACT = physx_sys::PxPairFlag::eDETECT_DISCRETE_CONTACT as u16;
+        const DETECT_CCD_CONTACT = physx_sys::PxPairFlag::eDETECT_CCD_CONTACT as u16;
+    }
+}
+
+bitflags! {
+    pub struct PxContactPairFlag: u16 {
+        const REMOVED_SHAPE_0 = physx_sys::PxContactPairFlag::eREMOVED_SHAPE_0 as u16;
+        const REMOVED_SHAPE_1 = physx_sys::PxContactPairFlag::eREMOVED_SHAPE_1 as u16;
+        const ACTOR_PAIR_HAS_FIRST_TOUCH = physx_sys::PxContactPairFlag::eACTOR_PAIR_HAS_FIRST_TOUCH as u16;
+        const ACTOR_PAIR_LOST_TOUCH = physx_sys::PxContactPairFlag::eACTOR_PAIR_LOST_TOUCH as u16;
+        const INTERNAL_HAS_IMPULSES = physx_sys::PxContactPairFlag::eINTERNAL_HAS_IMPULSES as u16;
+        const INTERNAL_CONTACTS_ARE_FLIPPED = physx_sys::PxContactPairFlag::eINTERNAL_CONTACTS_ARE_FLIPPED as u16;
+    }
+}
+
+bitflags! {
+    pub struct PxTriggerPairFlag: u8 {
+        const REMOVED_SHAPE_TRIGGER = physx_sys::PxTriggerPairFlag::eREMOVED_SHAPE_TRIGGER as u8;
+        const REMOVED_SHAPE_OTHER = physx_sys::PxTriggerPairFlag::eREMOVED_SHAPE_OTHER as u8;
+    }
 }
 
 bitflags! {
@@ -497,6 +627,11 @@ impl PxQueryFilterData {
     pub fn set_flags(&mut self, flags: PxQueryFlag) {
         self.0.flags.mBits = flags.bits as u16;
     }
+    /// Shapes whose query filter data doesn't share any bits with `data` will be ignored.
+    /// If `data` is all zeroes, no filtering is done.
+    pub fn set_data(&mut self, data: PxFilterData) {
+        self.0.data = data.to_physx();
+    }
 }
 impl Default for PxQueryFilterData {
     fn default() -> Self {
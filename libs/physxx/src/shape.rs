@@ -21,6 +21,36 @@ impl Default for PxShapeFlag {
     }
 }
 
+/// User-defined data used for simulation and scene query filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PxFilterData {
+    pub word0: u32,
+    pub word1: u32,
+    pub word2: u32,
+    pub word3: u32,
+}
+impl PxFilterData {
+    pub fn new(word0: u32, word1: u32, word2: u32, word3: u32) -> Self {
+        Self {
+            word0,
+            word1,
+            word2,
+            word3,
+        }
+    }
+    pub(crate) fn from_physx(data: physx_sys::PxFilterData) -> Self {
+        Self::new(data.word0, data.word1, data.word2, data.word3)
+    }
+    pub(crate) fn to_physx(self) -> physx_sys::PxFilterData {
+        physx_sys::PxFilterData {
+            word0: self.word0,
+            word1: self.word1,
+            word2: self.word2,
+            word3: self.word3,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct PxShape(pub *mut physx_sys::PxShape, pub usize);
 impl PxShape {
@@ -113,6 +143,18 @@ impl PxShape {
     pub fn set_rest_offset(&self, offset: f32) {
         unsafe { physx_sys::PxShape_setRestOffset_mut(self.0, offset) }
     }
+    pub fn get_simulation_filter_data(&self) -> PxFilterData {
+        PxFilterData::from_physx(unsafe { physx_sys::PxShape_getSimulationFilterData(self.0) })
+    }
+    pub fn set_simulation_filter_data(&self, data: &PxFilterData) {
+        unsafe { physx_sys::PxShape_setSimulationFilterData_mut(self.0, &data.to_physx()) }
+    }
+    pub fn get_query_filter_data(&self) -> PxFilterData {
+        PxFilterData::from_physx(unsafe { physx_sys::PxShape_getQueryFilterData(self.0) })
+    }
+    pub fn set_query_filter_data(&self, data: &PxFilterData) {
+        unsafe { physx_sys::PxShape_setQueryFilterData_mut(self.0, &data.to_physx()) }
+    }
 }
 impl AsPxBase for PxShape {
     fn as_base(&self) -> PxBaseRef {
@@ -12,6 +12,18 @@ pub struct Component {
     pub attributes: Vec<ItemPathBuf>,
     #[serde(default)]
     pub default: Option<toml::Value>,
+    #[serde(default)]
+    pub network_priority: Option<NetworkPriority>,
+}
+
+/// How urgently changes to a networked component are sent to clients.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct NetworkPriority {
+    /// How much the component's priority grows every tick its changes are not sent.
+    pub priority: f32,
+    /// The maximum number of times per second changes to the component are sent.
+    #[serde(default)]
+    pub max_rate: Option<f32>,
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
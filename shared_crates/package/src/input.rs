@@ -0,0 +1,80 @@
+use ambient_shared_types::InputBinding;
+use serde::{Deserialize, Serialize};
+
+/// A named input that is either pressed or released, like jumping or interacting.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct InputAction {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// The bindings that are used until the player rebinds the action.
+    #[serde(default)]
+    pub bindings: Vec<InputBinding>,
+}
+
+/// A named input that ranges from -1 to 1, like moving forwards and backwards.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct InputAxis {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// The bindings that move the axis towards 1 until the player rebinds them.
+    #[serde(default)]
+    pub positive: Vec<InputBinding>,
+    /// The bindings that move the axis towards -1 until the player rebinds them.
+    #[serde(default)]
+    pub negative: Vec<InputBinding>,
+}
+
+#[cfg(test)]
+mod tests {
+    use ambient_shared_types::{GamepadAxis, GamepadButton, MouseButton, VirtualKeyCode};
+
+    use super::*;
+
+    #[test]
+    fn can_parse_bindings() {
+        let t = r#"
+        name = "Jump"
+        bindings = ["Key:Space", "Mouse:Right", "Mouse:4", "Gamepad:South"]
+        "#;
+
+        let action: InputAction = toml::from_str(t).unwrap();
+
+        assert_eq!(
+            action.bindings,
+            vec![
+                InputBinding::Key(VirtualKeyCode::Space),
+                InputBinding::Mouse(MouseButton::Right),
+                InputBinding::Mouse(MouseButton::Other(4)),
+                InputBinding::Gamepad(GamepadButton::South),
+            ]
+        );
+    }
+
+    #[test]
+    fn can_parse_axis_directions() {
+        let t = r#"
+        positive = ["Key:W", "GamepadAxis:LeftStickY"]
+        negative = ["Key:S"]
+        "#;
+
+        let axis: InputAxis = toml::from_str(t).unwrap();
+
+        assert_eq!(
+            axis.positive,
+            vec![
+                InputBinding::Key(VirtualKeyCode::W),
+                InputBinding::GamepadAxis(GamepadAxis::LeftStickY),
+            ]
+        );
+        assert_eq!(axis.negative, vec![InputBinding::Key(VirtualKeyCode::S)]);
+    }
+
+    #[test]
+    fn will_fail_on_unknown_binding() {
+        let t = r#"
+        bindings = ["Keyboard:Space"]
+        "#;
+
+        assert!(toml::from_str::<InputAction>(t).is_err());
+    }
+}
@@ -10,5 +10,7 @@ mod message;
 pub use message::*;
 mod enum_;
 pub use enum_::*;
+mod input;
+pub use input::*;
 mod build_metadata;
 pub use build_metadata::*;
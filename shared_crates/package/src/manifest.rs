@@ -8,7 +8,8 @@ use sha2::Digest;
 use thiserror::Error;
 
 use crate::{
-    Component, Concept, Enum, ItemPathBuf, Message, PascalCaseIdentifier, SnakeCaseIdentifier,
+    Component, Concept, Enum, InputAction, InputAxis, ItemPathBuf, Message, PascalCaseIdentifier,
+    SnakeCaseIdentifier,
 };
 
 #[derive(Error, Debug, PartialEq)]
@@ -37,6 +38,12 @@ pub struct Manifest {
     #[serde(alias = "enum")]
     pub enums: IndexMap<PascalCaseIdentifier, Enum>,
     #[serde(default)]
+    #[serde(alias = "action")]
+    pub actions: IndexMap<SnakeCaseIdentifier, InputAction>,
+    #[serde(default)]
+    #[serde(alias = "axis")]
+    pub axes: IndexMap<SnakeCaseIdentifier, InputAxis>,
+    #[serde(default)]
     pub includes: HashMap<SnakeCaseIdentifier, PathBuf>,
     #[serde(default)]
     pub dependencies: IndexMap<SnakeCaseIdentifier, Dependency>,
@@ -297,9 +304,11 @@ mod tests {
 
     use crate::{
         Build, BuildRust, Component, ComponentType, Components, Concept, ConceptValue,
-        ContainerType, Dependency, Enum, Identifier, ItemPathBuf, Manifest, ManifestParseError,
-        Package, PackageId, PascalCaseIdentifier, SnakeCaseIdentifier,
+        ContainerType, Dependency, Enum, Identifier, InputAction, InputAxis, ItemPathBuf, Manifest,
+        ManifestParseError, NetworkPriority, Package, PackageId, PascalCaseIdentifier,
+        SnakeCaseIdentifier,
     };
+    use ambient_shared_types::{GamepadAxis, GamepadButton, InputBinding, VirtualKeyCode};
     use semver::Version;
 
     fn i(s: &str) -> Identifier {
@@ -398,6 +407,7 @@ mod tests {
                         type_: ComponentType::Item(i("i32").into()),
                         attributes: vec![i("store").into()],
                         default: None,
+                        network_priority: None,
                     }
                 )]),
                 concepts: IndexMap::from_iter([(
@@ -508,6 +518,7 @@ mod tests {
                             type_: ComponentType::Item(i("quat").into()),
                             attributes: vec![],
                             default: None,
+                            network_priority: None,
                         }
                     ),
                     (
@@ -518,6 +529,7 @@ mod tests {
                             type_: ComponentType::Item(i("vec3").into()),
                             attributes: vec![],
                             default: None,
+                            network_priority: None,
                         }
                     ),
                     (
@@ -528,6 +540,7 @@ mod tests {
                             type_: ComponentType::Item(i("empty").into()),
                             attributes: vec![],
                             default: None,
+                            network_priority: None,
                         }
                     ),
                     (
@@ -538,6 +551,7 @@ mod tests {
                             type_: ComponentType::Item(i("vec3").into()),
                             attributes: vec![],
                             default: None,
+                            network_priority: None,
                         }
                     ),
                 ]),
@@ -658,6 +672,63 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_parse_input_actions_and_axes() {
+        const TOML: &str = r#"
+        [package]
+        id = "lktsfudbjw2qikhyumt573ozxhadkiwm"
+        name = "Platformer"
+        version = "0.0.1"
+        content = { type = "Playable" }
+
+        [actions.jump]
+        name = "Jump"
+        bindings = ["Key:Space", "Gamepad:South"]
+
+        [axes.move_right]
+        name = "Move right"
+        description = "Moves the player sideways"
+        positive = ["Key:D", "GamepadAxis:LeftStickX"]
+        negative = ["Key:A"]
+        "#;
+
+        assert_eq!(
+            Manifest::parse(TOML),
+            Ok(Manifest {
+                package: Package {
+                    id: Some(PackageId("lktsfudbjw2qikhyumt573ozxhadkiwm".to_string())),
+                    name: "Platformer".to_string(),
+                    version: Version::parse("0.0.1").unwrap(),
+                    ..Default::default()
+                },
+                actions: IndexMap::from_iter([(
+                    sci("jump"),
+                    InputAction {
+                        name: Some("Jump".to_string()),
+                        description: None,
+                        bindings: vec![
+                            InputBinding::Key(VirtualKeyCode::Space),
+                            InputBinding::Gamepad(GamepadButton::South),
+                        ],
+                    }
+                )]),
+                axes: IndexMap::from_iter([(
+                    sci("move_right"),
+                    InputAxis {
+                        name: Some("Move right".to_string()),
+                        description: Some("Moves the player sideways".to_string()),
+                        positive: vec![
+                            InputBinding::Key(VirtualKeyCode::D),
+                            InputBinding::GamepadAxis(GamepadAxis::LeftStickX),
+                        ],
+                        negative: vec![InputBinding::Key(VirtualKeyCode::A)],
+                    }
+                )]),
+                ..Default::default()
+            })
+        )
+    }
+
     #[test]
     fn can_parse_container_types() {
         const TOML: &str = r#"
@@ -697,6 +768,7 @@ mod tests {
                             type_: ComponentType::Item(i("I32").into()),
                             attributes: vec![],
                             default: None,
+                            network_priority: None,
                         }
                     ),
                     (
@@ -710,6 +782,7 @@ mod tests {
                             },
                             attributes: vec![],
                             default: None,
+                            network_priority: None,
                         }
                     ),
                     (
@@ -723,6 +796,7 @@ mod tests {
                             },
                             attributes: vec![],
                             default: None,
+                            network_priority: None,
                         }
                     )
                 ]),
@@ -731,6 +805,38 @@ mod tests {
         )
     }
 
+    #[test]
+    fn can_parse_network_priorities() {
+        const TOML: &str = r#"
+        [package]
+        id = "lktsfudbjw2qikhyumt573ozxhadkiwm"
+        name = "Test"
+        version = "0.0.1"
+        content = { type = "Playable" }
+
+        [components]
+        health = { type = "F32", attributes = ["Networked"], network_priority = { priority = 10.0 } }
+        emote = { type = "String", attributes = ["Networked"], network_priority = { priority = 0.5, max_rate = 2.0 } }
+
+        "#;
+
+        let manifest = Manifest::parse(TOML).unwrap();
+        assert_eq!(
+            manifest.components[&ipb("health")].network_priority,
+            Some(NetworkPriority {
+                priority: 10.0,
+                max_rate: None
+            })
+        );
+        assert_eq!(
+            manifest.components[&ipb("emote")].network_priority,
+            Some(NetworkPriority {
+                priority: 0.5,
+                max_rate: Some(2.0)
+            })
+        );
+    }
+
     #[test]
     fn can_parse_dependencies() {
         const TOML: &str = r#"
@@ -77,6 +77,12 @@ pub fn generate(
             if let Some(default) = component.default.as_ref().and_then(|c| c.as_resolved()) {
                 doc_comment += &format!("\n\n*Suggested Default*: {default}")
             }
+            if let Some(network_priority) = &component.network_priority {
+                doc_comment += &format!("\n\n*Network priority*: {}", network_priority.priority);
+                if let Some(max_rate) = network_priority.max_rate {
+                    doc_comment += &format!(", at most {max_rate} updates per second");
+                }
+            }
 
             let doc_comment = doc_comment.trim();
 
@@ -88,10 +94,18 @@ pub fn generate(
                         .map(|s| make_path(s.as_str()))
                         .collect();
                     let description = component.description.to_owned().unwrap_or_default();
+                    let network_priority = component.network_priority.map(|network_priority| {
+                        let priority = network_priority.priority;
+                        let max_rate = match network_priority.max_rate {
+                            Some(max_rate) => quote! { Some(#max_rate) },
+                            None => quote! { None },
+                        };
+                        quote! { NetworkPriority[(#priority, #max_rate)], }
+                    });
 
                     Ok(quote! {
                         #[doc = #doc_comment]
-                        @[#(#attributes,)* Name[#name], Description[#description]]
+                        @[#(#attributes,)* #network_priority Name[#name], Description[#description]]
                         #ident: #ty,
                     })
                 }
@@ -128,7 +142,7 @@ pub fn generate(
             quote! {
                 use std::time::Duration;
                 use glam::{Vec2, Vec3, Vec4, UVec2, UVec3, UVec4, Mat4, Quat};
-                use crate::{EntityId, Debuggable, Networked, Store, Resource, MaybeResource, Name, Description, Enum, components};
+                use crate::{EntityId, Debuggable, Networked, Store, Resource, MaybeResource, Predicted, Interpolated, Color, NetworkPriority, Name, Description, Enum, components};
                 use ambient_shared_types::{ProceduralMeshHandle, ProceduralTextureHandle, ProceduralSamplerHandle, ProceduralMaterialHandle};
                 components!(#namespace_path, {
                     #(#components)*
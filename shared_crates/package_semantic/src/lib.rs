@@ -643,6 +643,9 @@ pub struct StandardAttributes {
     pub networked: ItemId<Attribute>,
     pub resource: ItemId<Attribute>,
     pub maybe_resource: ItemId<Attribute>,
+    pub predicted: ItemId<Attribute>,
+    pub interpolated: ItemId<Attribute>,
+    pub color: ItemId<Attribute>,
     pub store: ItemId<Attribute>,
     pub enum_: ItemId<Attribute>,
 }
@@ -705,6 +708,9 @@ pub fn create_root_scope(
         networked: make_attribute(items, root_scope, "Networked")?,
         resource: make_attribute(items, root_scope, "Resource")?,
         maybe_resource: make_attribute(items, root_scope, "MaybeResource")?,
+        predicted: make_attribute(items, root_scope, "Predicted")?,
+        interpolated: make_attribute(items, root_scope, "Interpolated")?,
+        color: make_attribute(items, root_scope, "Color")?,
         store: make_attribute(items, root_scope, "Store")?,
         enum_: make_attribute(items, root_scope, "Enum")?,
     };
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// A button on a gamepad.
+///
+/// The face buttons are named after their position, as their labels differ between controllers.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, EnumString, Display, Serialize, Deserialize)]
+pub enum GamepadButton {
+    /// The bottom face button (A on Xbox controllers, Cross on PlayStation controllers).
+    South,
+    /// The right face button (B on Xbox controllers, Circle on PlayStation controllers).
+    East,
+    /// The top face button (Y on Xbox controllers, Triangle on PlayStation controllers).
+    North,
+    /// The left face button (X on Xbox controllers, Square on PlayStation controllers).
+    West,
+    LeftBumper,
+    RightBumper,
+    /// The left trigger, which is considered pressed once it is pulled most of the way in.
+    LeftTrigger,
+    /// The right trigger, which is considered pressed once it is pulled most of the way in.
+    RightTrigger,
+    Select,
+    Start,
+    /// The button in the middle of the controller, such as the Xbox or PlayStation button.
+    Mode,
+    /// Pressing the left stick in.
+    LeftStick,
+    /// Pressing the right stick in.
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+/// An analog axis of a gamepad.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, EnumString, Display, Serialize, Deserialize)]
+pub enum GamepadAxis {
+    /// From -1 (left) to 1 (right).
+    LeftStickX,
+    /// From -1 (down) to 1 (up).
+    LeftStickY,
+    /// From -1 (left) to 1 (right).
+    RightStickX,
+    /// From -1 (down) to 1 (up).
+    RightStickY,
+    /// From 0 (released) to 1 (fully pulled).
+    LeftTrigger,
+    /// From 0 (released) to 1 (fully pulled).
+    RightTrigger,
+}
@@ -0,0 +1,75 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{GamepadAxis, GamepadButton, MouseButton, VirtualKeyCode};
+
+/// A physical input that an input action or axis can be bound to.
+///
+/// Bindings are written as `Device:Input`, e.g. `Key:Space`, `Mouse:Left`, `Gamepad:South`
+/// or `GamepadAxis:LeftStickY`. Mouse buttons other than `Left`, `Right` and `Middle` are
+/// written as their index, e.g. `Mouse:4`.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum InputBinding {
+    Key(VirtualKeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+    /// An analog gamepad axis. When bound to an action, the action is pressed once the axis
+    /// is more than halfway in its positive direction.
+    GamepadAxis(GamepadAxis),
+}
+impl fmt::Display for InputBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputBinding::Key(key) => write!(f, "Key:{key}"),
+            InputBinding::Mouse(MouseButton::Left) => write!(f, "Mouse:Left"),
+            InputBinding::Mouse(MouseButton::Right) => write!(f, "Mouse:Right"),
+            InputBinding::Mouse(MouseButton::Middle) => write!(f, "Mouse:Middle"),
+            InputBinding::Mouse(MouseButton::Other(button)) => write!(f, "Mouse:{button}"),
+            InputBinding::Gamepad(button) => write!(f, "Gamepad:{button}"),
+            InputBinding::GamepadAxis(axis) => write!(f, "GamepadAxis:{axis}"),
+        }
+    }
+}
+impl FromStr for InputBinding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (device, input) = s
+            .split_once(':')
+            .ok_or_else(|| format!("input binding `{s}` is not of the form `Device:Input`"))?;
+        let invalid = || format!("`{input}` is not a valid input for `{device}` bindings");
+
+        Ok(match device {
+            "Key" => Self::Key(VirtualKeyCode::from_str(input).map_err(|_| invalid())?),
+            "Mouse" => Self::Mouse(match input {
+                "Left" => MouseButton::Left,
+                "Right" => MouseButton::Right,
+                "Middle" => MouseButton::Middle,
+                other => MouseButton::Other(other.parse().map_err(|_| invalid())?),
+            }),
+            "Gamepad" => Self::Gamepad(GamepadButton::from_str(input).map_err(|_| invalid())?),
+            "GamepadAxis" => {
+                Self::GamepadAxis(GamepadAxis::from_str(input).map_err(|_| invalid())?)
+            }
+            _ => return Err(format!("unknown input device `{device}`")),
+        })
+    }
+}
+impl Serialize for InputBinding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+impl<'de> Deserialize<'de> for InputBinding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        InputBinding::from_str(&String::deserialize(deserializer)?)
+            .map_err(serde::de::Error::custom)
+    }
+}
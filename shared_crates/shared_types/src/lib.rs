@@ -1,6 +1,15 @@
 mod winit;
 pub use crate::winit::*;
 
+mod gamepad;
+pub use crate::gamepad::*;
+
+mod input_binding;
+pub use crate::input_binding::*;
+
+mod touch;
+pub use crate::touch::*;
+
 mod procedurals;
 pub use crate::procedurals::*;
 
@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// The stage of a touch on the screen.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, EnumString, Display, Serialize, Deserialize)]
+pub enum TouchPhase {
+    /// The finger touched the screen.
+    Started,
+    /// The finger moved while touching the screen.
+    Moved,
+    /// The finger was lifted from the screen.
+    Ended,
+    /// The system interrupted the touch, e.g. because the window lost focus.
+    Cancelled,
+}
+
+/// The gestures made by the touches on the screen between two frames.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct TouchGestures {
+    /// How far the touches moved, on average, in pixels.
+    pub pan: Vec2,
+    /// How much the distance between the first two touches changed, in pixels. This is positive
+    /// when the touches move apart, which is usually used to zoom in.
+    pub pinch: f32,
+}
+impl TouchGestures {
+    /// Recognizes the gestures made by the touches, given their positions by id in the previous
+    /// and the current frame.
+    ///
+    /// Only the touches that are in both frames are considered, so that touches starting or
+    /// ending do not make the gestures jump.
+    pub fn between(previous: &BTreeMap<u64, Vec2>, current: &BTreeMap<u64, Vec2>) -> Self {
+        let moved = current
+            .iter()
+            .filter_map(|(id, position)| Some((*previous.get(id)?, *position)))
+            .collect::<Vec<_>>();
+        if moved.is_empty() {
+            return Self::default();
+        }
+
+        let pan = moved.iter().map(|(from, to)| *to - *from).sum::<Vec2>() / moved.len() as f32;
+        let pinch = match moved.as_slice() {
+            [(a_from, a_to), (b_from, b_to), ..] => a_to.distance(*b_to) - a_from.distance(*b_from),
+            _ => 0.0,
+        };
+
+        Self { pan, pinch }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::vec2;
+
+    use super::*;
+
+    #[test]
+    fn pan_is_the_mean_movement_of_the_touches() {
+        let previous = BTreeMap::from([(1, vec2(0., 0.)), (2, vec2(10., 0.))]);
+        let current = BTreeMap::from([(1, vec2(2., 4.)), (2, vec2(14., 2.))]);
+        let gestures = TouchGestures::between(&previous, &current);
+        assert_eq!(gestures.pan, vec2(3., 3.));
+    }
+
+    #[test]
+    fn pinch_is_the_change_in_distance() {
+        let previous = BTreeMap::from([(1, vec2(0., 0.)), (2, vec2(10., 0.))]);
+        let current = BTreeMap::from([
+            (1, vec2(-5., 2.)),
+            (2, vec2(15., 2.)),
+            (3, vec2(100., 100.)),
+        ]);
+        assert_eq!(
+            TouchGestures::between(&previous, &current),
+            TouchGestures {
+                pan: vec2(0., 2.),
+                pinch: 10.
+            }
+        );
+
+        let closer = BTreeMap::from([(1, vec2(2., 0.)), (2, vec2(8., 0.))]);
+        assert_eq!(TouchGestures::between(&previous, &closer).pinch, -4.);
+    }
+
+    #[test]
+    fn new_and_single_touches_do_not_pinch() {
+        let previous = BTreeMap::from([(1, vec2(0., 0.))]);
+        let current = BTreeMap::from([(1, vec2(1., 0.)), (2, vec2(50., 50.))]);
+        assert_eq!(
+            TouchGestures::between(&previous, &current),
+            TouchGestures {
+                pan: vec2(1., 0.),
+                pinch: 0.
+            }
+        );
+        assert_eq!(
+            TouchGestures::between(&BTreeMap::new(), &current),
+            TouchGestures::default()
+        );
+    }
+}
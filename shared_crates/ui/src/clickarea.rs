@@ -1,17 +1,21 @@
 //! Defines the [ClickArea] element.
 
+use std::collections::BTreeMap;
+
 use ambient_cb::{cb, Cb};
 use ambient_element::{
     to_owned, use_frame, use_ref_with, use_runtime_message, Element, ElementComponent, Hooks,
 };
 use ambient_guest_bridge::{
     core::{
-        input::components::{is_mouse_over, mouse_pickable_max, mouse_pickable_min},
+        input::components::{
+            is_mouse_over, mouse_pickable_max, mouse_pickable_min, touch_ids, touch_positions,
+        },
         messages,
     },
     ecs::{EntityId, World},
 };
-use ambient_shared_types::MouseButton;
+use ambient_shared_types::{MouseButton, TouchGestures, TouchPhase};
 use glam::{Vec2, Vec3};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -33,7 +37,9 @@ impl From<bool> for MouseInput {
 }
 
 #[derive(Debug, Clone)]
-/// An area that tracks mouse events.
+/// An area that tracks mouse and touch events.
+///
+/// A touch belongs to the area it started on until it ends, even if it moves off of the area.
 pub struct ClickArea {
     /// The inner element.
     pub inner: Element,
@@ -48,6 +54,12 @@ pub struct ClickArea {
         Vec<Cb<dyn Fn(&mut World, EntityId, MouseInput, MouseButton) + Sync + Send>>,
     /// Callback for when the mouse wheel is scrolled.
     pub on_mouse_wheel: Vec<Cb<dyn Fn(&mut World, EntityId, Vec2, bool) + Sync + Send>>,
+    /// Callback for when a touch starts, moves or ends.
+    pub on_touch: Vec<Cb<dyn Fn(&mut World, EntityId, u64, TouchPhase, Vec2) + Sync + Send>>,
+    /// Callback for when the touches on the area move together.
+    pub on_pan: Vec<Cb<dyn Fn(&mut World, EntityId, Vec2) + Sync + Send>>,
+    /// Callback for when the touches on the area move apart or together.
+    pub on_pinch: Vec<Cb<dyn Fn(&mut World, EntityId, f32) + Sync + Send>>,
 }
 impl ClickArea {
     /// Create a new ClickArea.
@@ -59,6 +71,9 @@ impl ClickArea {
             on_mouse_hover: Vec::new(),
             on_mouse_input: Vec::new(),
             on_mouse_wheel: Vec::new(),
+            on_touch: Vec::new(),
+            on_pan: Vec::new(),
+            on_pinch: Vec::new(),
         }
     }
     /// Set the callback for when the mouse hovers over the area.
@@ -103,6 +118,33 @@ impl ClickArea {
         self.on_mouse_wheel.push(cb(handle));
         self
     }
+    /// Set the callback for when a touch starts, moves or ends. It receives the id of the touch,
+    /// and its position in pixels from the top left of the window.
+    pub fn on_touch<F: Fn(&mut World, EntityId, u64, TouchPhase, Vec2) + Sync + Send + 'static>(
+        mut self,
+        handle: F,
+    ) -> Self {
+        self.on_touch.push(cb(handle));
+        self
+    }
+    /// Set the callback for when the touches on the area move together. It receives how far they
+    /// moved since the last frame, on average, in pixels.
+    pub fn on_pan<F: Fn(&mut World, EntityId, Vec2) + Sync + Send + 'static>(
+        mut self,
+        handle: F,
+    ) -> Self {
+        self.on_pan.push(cb(handle));
+        self
+    }
+    /// Set the callback for when the first two touches on the area move apart or together. It
+    /// receives how much the distance between them changed since the last frame, in pixels.
+    pub fn on_pinch<F: Fn(&mut World, EntityId, f32) + Sync + Send + 'static>(
+        mut self,
+        handle: F,
+    ) -> Self {
+        self.on_pinch.push(cb(handle));
+        self
+    }
     /// Set the callback for when a mouse button is pressed.
     pub fn on_mouse_down<F: Fn(&mut World, EntityId, MouseButton) + Sync + Send + 'static>(
         self,
@@ -135,6 +177,9 @@ impl ElementComponent for ClickArea {
             on_mouse_hover,
             on_mouse_input,
             on_mouse_wheel,
+            on_touch,
+            on_pan,
+            on_pinch,
         } = *self;
         let id = use_ref_with(hooks, |_| None);
         let mouse_over_count = use_ref_with(hooks, |_| 0);
@@ -164,6 +209,55 @@ impl ElementComponent for ClickArea {
             }
         });
 
+        // The touches are captured by the picking system, so their changes are found by comparing
+        // them to the last frame
+        let touches = use_ref_with(hooks, |_| BTreeMap::<u64, Vec2>::new());
+        use_frame(hooks, {
+            to_owned![id];
+            move |world| {
+                if let Some(id) = *id.lock() {
+                    let ids = world.get_cloned(id, touch_ids()).unwrap_or_default();
+                    let positions = world.get_cloned(id, touch_positions()).unwrap_or_default();
+                    let current = ids.into_iter().zip(positions).collect::<BTreeMap<_, _>>();
+
+                    let mut previous = touches.lock();
+                    if previous.is_empty() && current.is_empty() {
+                        return;
+                    }
+                    for (&touch, &position) in &current {
+                        let phase = match previous.get(&touch) {
+                            None => TouchPhase::Started,
+                            Some(p) if *p != position => TouchPhase::Moved,
+                            Some(_) => continue,
+                        };
+                        for handler in &on_touch {
+                            handler(world, id, touch, phase, position);
+                        }
+                    }
+                    for (&touch, &position) in previous.iter() {
+                        if !current.contains_key(&touch) {
+                            for handler in &on_touch {
+                                handler(world, id, touch, TouchPhase::Ended, position);
+                            }
+                        }
+                    }
+
+                    let gestures = TouchGestures::between(&previous, &current);
+                    if gestures.pan != Vec2::ZERO {
+                        for handler in &on_pan {
+                            handler(world, id, gestures.pan);
+                        }
+                    }
+                    if gestures.pinch != 0. {
+                        for handler in &on_pinch {
+                            handler(world, id, gestures.pinch);
+                        }
+                    }
+                    *previous = current;
+                }
+            }
+        });
+
         use_runtime_message::<messages::WindowMouseInput>(hooks, {
             to_owned![id, mouse_over_count];
             move |world, event| {
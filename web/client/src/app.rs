@@ -70,6 +70,7 @@ fn systems() -> SystemGroup {
             Box::new(ambient_water::systems()),
             // Box::new(ambient_physics::client_systems()),
             // Box::new(ambient_gizmos::client_systems()),
+            Box::new(player::systems()),
             Box::new(wasm::systems()),
             Box::new(player::systems_final()),
         ],